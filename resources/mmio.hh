@@ -8,10 +8,22 @@
 
 #pragma once
 
+#include <array>
+#include <cassert>
 #include <cstdint>
 #include <limits>
+#include <optional>
+#include <utility>
 namespace zermio {
-  enum Permissions:uint8_t{Read = 0x01, Write = 0x02, ReadWrite=0x03};
+  enum Permissions:uint8_t{
+    Read = 0x01,
+    Write = 0x02,
+    ReadWrite = 0x03,
+    // Only the first write after a reset has effect on the hardware.
+    Once = 0x04,
+    WriteOnce = 0x06,
+    ReadWriteOnce = 0x07,
+  };
 
   template <Permissions P>
     concept Writable = (P & Permissions::Write) == Permissions::Write;
@@ -21,36 +33,97 @@ namespace zermio {
     concept Readable = (P & Permissions::Read) == Permissions::Read;
   static_assert(Readable<Read> && Readable<ReadWrite> && !Readable<Write>);
 
+  template <Permissions P>
+    concept WritableOnce = (P & Permissions::WriteOnce) == Permissions::WriteOnce;
+  static_assert(WritableOnce<WriteOnce> && WritableOnce<ReadWriteOnce> && !WritableOnce<ReadWrite>);
+  static_assert(Writable<WriteOnce> && Readable<ReadWriteOnce> && !Readable<WriteOnce>);
+
+
+  /* How the hardware modifies a field when it's written. */
+  enum WriteEffect:uint8_t{
+    Modify,
+    OneToClear,
+    OneToSet,
+    OneToToggle,
+    ZeroToClear,
+    ZeroToSet,
+    ZeroToToggle,
+    // Any write clears or sets the field.
+    Clear,
+    Set,
+  };
 
+  template <WriteEffect W>
+    concept ClearFlag = W == OneToClear || W == ZeroToClear || W == Clear;
+  template <WriteEffect W>
+    concept SetFlag = W == OneToSet || W == ZeroToSet || W == Set;
+  template <WriteEffect W>
+    concept ToggleFlag = W == OneToToggle || W == ZeroToToggle;
+
+  /* The type `T` defines the size of the load and store instructions used to access the mmio.
+   * A shadowed register, with `SHADOWED` set, only takes a value after two consecutive writes of
+   * it, a mismatch is reported by the hardware as an update error. Thus every store is run twice. */
+  template <typename T = uint32_t, bool SHADOWED = false>
   struct Register{
     const std::size_t addr = 0;
-    std::size_t cache = 0;
+    T cache = 0;
+    bool committed = false;
+    /* The bits of the fields with write side effects and the value that doesn't trigger them. */
+    T neutral_mask = 0;
+    T neutral_value = 0;
+
+    inline void commit() { store(cache); }
 
-    inline void commit() { *(reinterpret_cast<volatile std::size_t*>(addr)) = cache; }
+    inline void store(T value) {
+      *(reinterpret_cast<volatile T*>(addr)) = value;
+      if constexpr (SHADOWED) {
+        *(reinterpret_cast<volatile T*>(addr)) = value;
+      }
+      committed = true;
+    }
+
+    /* The cache with the side effect bits replaced by their neutral value. */
+    inline constexpr T neutral() const {
+      return static_cast<T>((cache & ~neutral_mask) | (neutral_value & neutral_mask));
+    }
+
+    /* Replace the side effect bits in the cache by their neutral value, so it can be written back
+     * without, for instance, clearing pending write-one-to-clear flags. */
+    inline void neutralize() { cache = neutral(); }
+
+    /* Only write the mmio if this instance never did it before, which detects a second write
+     * to write-once registers that the hardware would silently ignore. */
+    inline bool try_commit() {
+      if (committed) {
+        return false;
+      }
+      commit();
+      return true;
+    }
     inline void fetch() {
-      cache = *(reinterpret_cast<volatile std::size_t*>(addr));
+      cache = *(reinterpret_cast<volatile T*>(addr));
     }
   };
 
-  template <std::size_t OFFSET, std::size_t BITS, Permissions P>
+  template <std::size_t OFFSET, std::size_t BITS, Permissions P, typename T = uint32_t, typename E = T, WriteEffect W = Modify, bool SHADOWED = false>
     class BitField {
-      Register reg{0};
+      Register<T, SHADOWED> reg{0};
 
       public:
-      static consteval std::size_t mask() {
-        static_assert(BITS <= sizeof(std::size_t) * 8);
-        if constexpr (BITS == sizeof(std::size_t) * 8) {
-          return std::numeric_limits<std::size_t>::max();
-        } else {
-          return ((0x01 << BITS) - 1) << OFFSET;
-        }
+      static consteval T mask() {
+        static_assert(OFFSET + BITS <= sizeof(T) * 8);
+        return static_cast<T>(max() << OFFSET);
       }
 
-      static consteval std::size_t max() {
-        return (1 << BITS) - 1;
+      static consteval T max() {
+        if constexpr (BITS == sizeof(T) * 8) {
+          return std::numeric_limits<T>::max();
+        } else {
+          return static_cast<T>((T{1} << BITS) - 1);
+        }
       }
 
-      inline constexpr auto& write(const std::size_t value) {
+      inline constexpr auto& write(const T value) {
         static_assert(BITS > 1, ">> Error: This bitfield is multibit. Try using set or reset. <<");
         static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
         clear();
@@ -61,7 +134,7 @@ namespace zermio {
       inline constexpr auto& set() {
         static_assert(BITS == 1, ">> Error: This bitfield isn't multibit. Try using write or clear. <<");
         static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
-        reg.cache |= (0x01 << OFFSET);
+        reg.cache |= static_cast<T>(T{1} << OFFSET);
         return *this;
       }
 
@@ -75,14 +148,14 @@ namespace zermio {
       inline constexpr auto& toggle() {
         static_assert(BITS == 1, ">> Error: This bitfield isn't multibit. Try using write or clear. <<");
         static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
-        reg.cache ^= (0x01 << OFFSET);
+        reg.cache ^= static_cast<T>(T{1} << OFFSET);
         return *this;
       }
 
-      inline constexpr auto& write_mask(std::size_t value, std::size_t offset) {
+      inline constexpr auto& write_mask(T value, std::size_t offset) {
         static_assert(BITS > 1, ">> Error: This bitfield is multibit. Try using set or reset. <<");
         static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
-        reg.cache &= ~((value << (OFFSET+offset)) & mask());
+        reg.cache &= static_cast<T>(~((value << (OFFSET+offset)) & mask()));
         reg.cache |= ((value << (OFFSET+offset)) & mask());
         return *this;
       }
@@ -90,7 +163,7 @@ namespace zermio {
       inline constexpr auto& assign (bool bit)  {
         static_assert(BITS == 1, ">> Error: This bitfield is multibit. Try using write or clear. <<");
         static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
-        reg.cache |= (static_cast<std::size_t>(bit) << OFFSET);
+        reg.cache |= static_cast<T>(static_cast<T>(bit) << OFFSET);
         return *this;
       }
 
@@ -100,18 +173,222 @@ namespace zermio {
         return (reg.cache & mask()) == mask();
       }
 
-      inline constexpr std::size_t get() {
+      inline constexpr T get() {
         static_assert(BITS > 1, ">> Error: This bitfield is multibit. Try using set or reset. <<");
         static_assert(Readable<P>, ">> Error: This bitfield can't be read. <<");
         return (reg.cache & mask()) >> OFFSET;
       }
 
+      inline constexpr auto& write_variant(const E value) {
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        clear();
+        reg.cache |= ((static_cast<T>(value) << OFFSET) & mask());
+        return *this;
+      }
+
+      inline constexpr E variant() {
+        static_assert(Readable<P>, ">> Error: This bitfield can't be read. <<");
+        return static_cast<E>((reg.cache & mask()) >> OFFSET);
+      }
+
       inline constexpr auto& clear() {
         static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
-        reg.cache &= ~mask();
+        reg.cache &= static_cast<T>(~mask());
         return *this;
       }
 
-      inline void commit() { reg.commit(); }
+      inline void commit() {
+        static_assert(!WritableOnce<P>, ">> Error: This bitfield is write once. Try using try_commit. <<");
+        reg.commit();
+      }
+
+      /* Write only this field with the value that triggers its side effect, the other side effect
+       * fields are written with their neutral value. The cache is left untouched. */
+      inline void clear_flag() {
+        static_assert(ClearFlag<W>, ">> Error: This bitfield has no clear side effect. <<");
+        trigger();
+      }
+
+      inline void set_flag() {
+        static_assert(SetFlag<W>, ">> Error: This bitfield has no set side effect. <<");
+        trigger();
+      }
+
+      inline void toggle_flag() {
+        static_assert(ToggleFlag<W>, ">> Error: This bitfield has no toggle side effect. <<");
+        trigger();
+      }
+
+      inline bool try_commit() {
+        static_assert(WritableOnce<P>, ">> Error: This bitfield isn't write once. Try using commit. <<");
+        return reg.try_commit();
+      }
+
+      private:
+      inline void trigger() {
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        constexpr bool ones = W != ZeroToClear && W != ZeroToSet && W != ZeroToToggle;
+        if constexpr (ones) {
+          reg.store(static_cast<T>(reg.neutral() | mask()));
+        } else {
+          reg.store(static_cast<T>(reg.neutral() & ~mask()));
+        }
+      }
     };
+
+  /* A field of a field array selected at runtime, it's a view of the register of the array. */
+  template <std::size_t BITS, Permissions P, typename T = uint32_t, typename E = T, bool SHADOWED = false>
+    class IndexedBitField {
+      Register<T, SHADOWED>& reg;
+      const std::size_t offset;
+
+      public:
+      constexpr IndexedBitField(Register<T, SHADOWED>& reg, std::size_t offset): reg(reg), offset(offset) {}
+
+      static consteval T max() {
+        if constexpr (BITS == sizeof(T) * 8) {
+          return std::numeric_limits<T>::max();
+        } else {
+          return static_cast<T>((T{1} << BITS) - 1);
+        }
+      }
+
+      inline constexpr T mask() const {
+        return static_cast<T>(max() << offset);
+      }
+
+      inline constexpr auto& write(const T value) {
+        static_assert(BITS > 1, ">> Error: This bitfield is multibit. Try using set or reset. <<");
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        clear();
+        reg.cache |= ((value << offset) & mask());
+        return *this;
+      }
+
+      inline constexpr auto& set() {
+        static_assert(BITS == 1, ">> Error: This bitfield isn't multibit. Try using write or clear. <<");
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        reg.cache |= mask();
+        return *this;
+      }
+
+      inline constexpr auto& reset() {
+        static_assert(BITS == 1, ">> Error: This bitfield isn't multibit. Try using write or clear. <<");
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        clear();
+        return *this;
+      }
+
+      inline constexpr auto& toggle() {
+        static_assert(BITS == 1, ">> Error: This bitfield isn't multibit. Try using write or clear. <<");
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        reg.cache ^= mask();
+        return *this;
+      }
+
+      inline constexpr auto& assign(bool bit) {
+        static_assert(BITS == 1, ">> Error: This bitfield is multibit. Try using write or clear. <<");
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        clear();
+        reg.cache |= static_cast<T>(static_cast<T>(bit) << offset);
+        return *this;
+      }
+
+      inline constexpr bool is_set() const {
+        static_assert(BITS == 1, ">> Error: This bitfield is multibit. Try using write or clear. <<");
+        static_assert(Readable<P>, ">> Error: This bitfield can't be read. <<");
+        return (reg.cache & mask()) == mask();
+      }
+
+      inline constexpr T get() const {
+        static_assert(BITS > 1, ">> Error: This bitfield is multibit. Try using set or reset. <<");
+        static_assert(Readable<P>, ">> Error: This bitfield can't be read. <<");
+        return (reg.cache & mask()) >> offset;
+      }
+
+      inline constexpr auto& write_variant(const E value) {
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        clear();
+        reg.cache |= ((static_cast<T>(value) << offset) & mask());
+        return *this;
+      }
+
+      inline constexpr E variant() const {
+        static_assert(Readable<P>, ">> Error: This bitfield can't be read. <<");
+        return static_cast<E>((reg.cache & mask()) >> offset);
+      }
+
+      inline constexpr auto& clear() {
+        static_assert(Writable<P>, ">> Error: This bitfield can't be write. <<");
+        reg.cache &= static_cast<T>(~mask());
+        return *this;
+      }
+
+      inline void commit() {
+        static_assert(!WritableOnce<P>, ">> Error: This bitfield is write once. Try using try_commit. <<");
+        reg.commit();
+      }
+    };
+
+  /* A field array, i.e `PIN%s`, indexed with the `[]` operator. Like the BitField, it's meant to be
+   * a member of a register union. */
+  template <std::size_t OFFSET, std::size_t BITS, std::size_t STRIDE, std::size_t DIM, Permissions P, typename T = uint32_t, typename E = T, bool SHADOWED = false>
+    class FieldArray {
+      Register<T, SHADOWED> reg{0};
+
+      public:
+      static_assert(OFFSET + STRIDE * (DIM - 1) + BITS <= sizeof(T) * 8);
+
+      static constexpr std::size_t size() { return DIM; }
+
+      inline constexpr IndexedBitField<BITS, P, T, E, SHADOWED> operator[](std::size_t n) {
+        assert(n < DIM && "Field array index out of range");
+        return IndexedBitField<BITS, P, T, E, SHADOWED>(reg, OFFSET + n * STRIDE);
+      }
+
+      /* Returns nothing if `n` is beyond the end of the array. */
+      inline constexpr std::optional<IndexedBitField<BITS, P, T, E, SHADOWED>> at(std::size_t n) {
+        if (n >= DIM) {
+          return std::nullopt;
+        }
+        return IndexedBitField<BITS, P, T, E, SHADOWED>(reg, OFFSET + n * STRIDE);
+      }
+    };
+
+  /* A window of a device, like a FIFO or a buffer, accessed as an array of `N` words of type `T`.
+   * Unlike the registers there is no cache, every access runs a load or store instruction. */
+  template <typename T, std::size_t N, Permissions P>
+    struct Window {
+      const std::size_t addr = 0;
+
+      static constexpr std::size_t size() { return N; }
+
+      /* Returns nothing if `n` is beyond the end of the window. */
+      inline std::optional<T> read(std::size_t n) const requires Readable<P> {
+        if (n >= N) {
+          return std::nullopt;
+        }
+        return T(reinterpret_cast<volatile T*>(addr)[n]);
+      }
+
+      /* Returns false if `n` is beyond the end of the window. */
+      inline bool write(std::size_t n, T value) const requires Writable<P> {
+        if (n >= N) {
+          return false;
+        }
+        reinterpret_cast<volatile T*>(addr)[n] = value;
+        return true;
+      }
+    };
+
+  template <typename C, std::size_t... I>
+    constexpr std::array<C, sizeof...(I)> make_array(uintptr_t addr, std::size_t increment, std::index_sequence<I...>) {
+      return {C(addr + I * increment)...};
+    }
+
+  /* Build the instances of a dimensioned cluster, each one `increment` bytes after the previous. */
+  template <typename C, std::size_t N>
+    constexpr std::array<C, N> make_array(uintptr_t addr, std::size_t increment) {
+      return make_array<C>(addr, increment, std::make_index_sequence<N>{});
+    }
 };
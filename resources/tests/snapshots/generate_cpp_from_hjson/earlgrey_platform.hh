@@ -0,0 +1,19 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+
+#include  <cstddef>
+#include  <cstdint>
+namespace platform  {
+/* Addresses for the devices of the type Uart.*/
+    enum Uart: uintptr_t{Uart0 = 0x40000000,Uart1 = 0x40010000,};
+
+/* Addresses for the devices of the type Gpio.*/
+    enum Gpio: uintptr_t{Gpio0 = 0x40040000,};
+
+enum Interrupt: uintptr_t{Uart0TxWatermark = 1,Uart0RxWatermark = 2,Uart0RxOverflow = 3,Uart1TxWatermark = 4,Uart1RxWatermark = 5,Uart1RxOverflow = 6,Gpio0Gpio0 = 7,Gpio0Gpio1 = 8,Gpio0Gpio2 = 9,Gpio0Gpio3 = 10,Gpio0Gpio4 = 11,Gpio0Gpio5 = 12,Gpio0Gpio6 = 13,Gpio0Gpio7 = 14,Gpio0Gpio8 = 15,Gpio0Gpio9 = 16,Gpio0Gpio10 = 17,Gpio0Gpio11 = 18,Gpio0Gpio12 = 19,Gpio0Gpio13 = 20,Gpio0Gpio14 = 21,Gpio0Gpio15 = 22,Gpio0Gpio16 = 23,Gpio0Gpio17 = 24,Gpio0Gpio18 = 25,Gpio0Gpio19 = 26,Gpio0Gpio20 = 27,Gpio0Gpio21 = 28,Gpio0Gpio22 = 29,Gpio0Gpio23 = 30,Gpio0Gpio24 = 31,Gpio0Gpio25 = 32,Gpio0Gpio26 = 33,Gpio0Gpio27 = 34,Gpio0Gpio28 = 35,Gpio0Gpio29 = 36,Gpio0Gpio30 = 37,Gpio0Gpio31 = 38,};
+} // namespace platform
+
@@ -0,0 +1,332 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace gpio {
+
+/* Interrupt State Register */
+union IntrStateReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* raised if any of GPIO pin detects configured interrupt mode */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0xffffffff;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrStateReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrStateReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt Enable Register */
+union IntrEnableReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* raised if any of GPIO pin detects configured interrupt mode */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> gpio;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrEnableReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrEnableReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt Test Register */
+union IntrTestReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* raised if any of GPIO pin detects configured interrupt mode */
+    zermio::BitField<0, 32, zermio::Permissions::Write, uint32_t> gpio;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrTestReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrTestReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* GPIO Input data read value */
+union DataInReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* DATA_IN */
+    zermio::BitField<0, 32, zermio::Permissions::Read, uint32_t> data_in;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr DataInReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline DataInReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* GPIO interrupt enable for GPIO, rising edge. */
+union IntrCtrlEnRisingReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> en_0;
+    /* Field array EN, i.e `en[n]` is the same as `en_0` for n = 0. */
+    zermio::FieldArray<0, 1, 1, 32, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> en_1;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> en_2;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> en_3;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> en_4;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> en_5;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> en_6;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> en_7;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> en_8;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> en_9;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> en_10;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> en_11;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> en_12;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> en_13;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> en_14;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> en_15;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> en_16;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> en_17;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> en_18;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> en_19;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> en_20;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> en_21;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> en_22;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> en_23;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> en_24;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> en_25;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> en_26;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> en_27;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> en_28;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> en_29;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> en_30;
+    /* Enable the rising edge interrupt of the pin. */
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> en_31;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrCtrlEnRisingReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrCtrlEnRisingReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Control register of one input period counter. */
+union InpPrdCntCtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Enable the input period counter. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> enable;
+    /* Index of the input whose period shall be counted. */
+    zermio::BitField<8, 8, zermio::Permissions::ReadWrite, uint32_t> input_select;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr InpPrdCntCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline InpPrdCntCtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Output value of the input period counter, cleared on read. */
+/* Reading this register has side effects:
+ * - VALUE: Clear on read.
+ * Thus it can only be loaded explicitly with read_and_clear(). */
+union InpPrdCntValReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* VALUE */
+    zermio::BitField<0, 32, zermio::Permissions::Read, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr InpPrdCntValReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline InpPrdCntValReg& read_and_clear() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Gpio {
+    IntrStateReg intr_state;
+    IntrEnableReg intr_enable;
+    IntrTestReg intr_test;
+    DataInReg data_in;
+    IntrCtrlEnRisingReg intr_ctrl_en_rising;
+    std::array<InpPrdCntCtrlReg, 2> inp_prd_cnt_ctrl;
+    std::array<InpPrdCntValReg, 2> inp_prd_cnt_val;
+    /* Hardware parameter NUM_IOS. Number of input/output pins.*/
+    static constexpr uint32_t NumIos = 32;
+    /* Hardware parameter NUM_INP_PERIOD_COUNTERS. Number of input period counters.*/
+    static constexpr uint32_t NumInpPeriodCounters = 2;
+    
+    constexpr Gpio (platform::Gpio addr):
+        intr_state(addr + 0x0), 
+        intr_enable(addr + 0x4), 
+        intr_test(addr + 0x8), 
+        data_in(addr + 0xc), 
+        intr_ctrl_en_rising(addr + 0x10), 
+        inp_prd_cnt_ctrl(zermio::make_array<InpPrdCntCtrlReg, 2>(addr + 0x1c, 0x4)), 
+        inp_prd_cnt_val(zermio::make_array<InpPrdCntValReg, 2>(addr + 0x24, 0x4)){}
+};
+
+
+} // namespace gpio
+} // namespace mmio
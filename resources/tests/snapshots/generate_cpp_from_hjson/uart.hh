@@ -0,0 +1,422 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace uart {
+
+/* Interrupt State Register */
+union IntrStateReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* raised if the transmit FIFO is past the high-water mark. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> tx_watermark;
+    /* raised if the receive FIFO is past the high-water mark. */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_watermark;
+    /* raised if the receive FIFO has overflowed. */
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_overflow;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x7;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrStateReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrStateReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt Enable Register */
+union IntrEnableReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* raised if the transmit FIFO is past the high-water mark. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> tx_watermark;
+    /* raised if the receive FIFO is past the high-water mark. */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx_watermark;
+    /* raised if the receive FIFO has overflowed. */
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> rx_overflow;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrEnableReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrEnableReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt Test Register */
+union IntrTestReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* raised if the transmit FIFO is past the high-water mark. */
+    zermio::BitField<0, 1, zermio::Permissions::Write, uint32_t> tx_watermark;
+    /* raised if the receive FIFO is past the high-water mark. */
+    zermio::BitField<1, 1, zermio::Permissions::Write, uint32_t> rx_watermark;
+    /* raised if the receive FIFO has overflowed. */
+    zermio::BitField<2, 1, zermio::Permissions::Write, uint32_t> rx_overflow;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrTestReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrTestReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Alert Test Register */
+union AlertTestReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* This fatal alert is triggered when a fatal TL-UL bus integrity fault is detected. */
+    zermio::BitField<0, 1, zermio::Permissions::Write, uint32_t> fatal_fault;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr AlertTestReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline AlertTestReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Enumerated values of the field RXBLVL. */
+enum class CtrlRxblvl: uint32_t {
+    /* 2 characters */
+    Break2 = 0x0,
+    /* 4 characters */
+    Break4 = 0x1,
+    /* 8 characters */
+    Break8 = 0x2,
+    /* 16 characters */
+    Break16 = 0x3,
+};
+
+/* UART control register */
+/* Write enable: the hardware ignores the writes to this register while CTRL_REGWEN.CTRL_REGWEN is clear. */
+union CtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* TX enable */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> tx;
+    /* RX enable */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx;
+    /* Trigger level for RX break detection. */
+    zermio::BitField<4, 2, zermio::Permissions::ReadWrite, uint32_t, CtrlRxblvl> rxblvl;
+    /* BAUD clock rate control. */
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> nco;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x10000;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Lock of the UART control register */
+union CtrlRegwenReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* When cleared, CTRL can't be written until the next reset. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::ZeroToClear> ctrl_regwen;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x1;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x1;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x1;
+
+    constexpr CtrlRegwenReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CtrlRegwenReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline CtrlRegwenReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+};
+
+
+
+/* UART live status register */
+union StatusReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* TX buffer is full */
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> txfull;
+    /* RX buffer is full */
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rxfull;
+    /* TX FIFO is empty */
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> txempty;
+    /* RX is idle */
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> rxidle;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x14;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline StatusReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* UART read data */
+union RdataReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* RDATA */
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> rdata;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline RdataReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* UART write data */
+union WdataReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* WDATA */
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> wdata;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr WdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline WdataReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* UART RX timeout control */
+union TimeoutCtrlReg { 
+    private:
+      zermio::Register<uint32_t, true> reg_;
+    public:
+    /* RX timeout value in UART bit times */
+    zermio::BitField<0, 24, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::Modify, true> val;
+    /* Enable RX timeout feature */
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::Modify, true> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    /* Shadowed register: every store instruction is run twice with the same value, as the
+     * hardware only takes it after two consecutive writes. */
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline TimeoutCtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Uart {
+    IntrStateReg intr_state;
+    IntrEnableReg intr_enable;
+    IntrTestReg intr_test;
+    AlertTestReg alert_test;
+    CtrlReg ctrl;
+    CtrlRegwenReg ctrl_regwen;
+    StatusReg status;
+    RdataReg rdata;
+    WdataReg wdata;
+    TimeoutCtrlReg timeout_ctrl;
+    /* The window TX_BUF, 8 words of 32 bits.*/
+    zermio::Window<uint32_t, 8, zermio::Write> tx_buf;
+    /* Hardware parameter RX_FIFO_DEPTH. Number of bytes in the RX FIFO.*/
+    static constexpr uint32_t RxFifoDepth = 64;
+    /* Hardware parameter TX_FIFO_DEPTH. Number of bytes in the TX FIFO.*/
+    static constexpr uint32_t TxFifoDepth = 32;
+    
+    constexpr Uart (platform::Uart addr):
+        intr_state(addr + 0x0), 
+        intr_enable(addr + 0x4), 
+        intr_test(addr + 0x8), 
+        alert_test(addr + 0xc), 
+        ctrl(addr + 0x10), 
+        ctrl_regwen(addr + 0x14), 
+        status(addr + 0x18), 
+        rdata(addr + 0x1c), 
+        wdata(addr + 0x20), 
+        timeout_ctrl(addr + 0x24), 
+        tx_buf(addr + 0x40){}
+
+    /* Fetch CTRL_REGWEN and only commit CTRL if CTRL_REGWEN is set, returns false otherwise. */
+    inline bool try_commit_ctrl() {
+        if (!ctrl_regwen.fetch().ctrl_regwen.is_set()) { return false; }
+        ctrl.commit();
+        return true;
+    }
+};
+
+
+} // namespace uart
+} // namespace mmio
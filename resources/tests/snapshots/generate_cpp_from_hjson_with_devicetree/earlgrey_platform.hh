@@ -0,0 +1,19 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+
+#include  <cstddef>
+#include  <cstdint>
+namespace platform  {
+/* Addresses for the devices of the type Uart.*/
+    enum Uart: uintptr_t{Uart0 = 0x41000000,Uart1 = 0x40010000,Uart2 = 0x40020000,};
+
+/* Addresses for the devices of the type Gpio.*/
+    enum Gpio: uintptr_t{Gpio0 = 0x40040000,};
+
+enum Interrupt: uintptr_t{Uart0TxWatermark = 1,Uart0RxWatermark = 2,Uart0RxOverflow = 3,Uart1TxWatermark = 4,Uart1RxWatermark = 5,Uart1RxOverflow = 6,Uart2Irq = 7,Gpio00 = 40,Gpio01 = 41,};
+} // namespace platform
+
@@ -0,0 +1,19 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+
+#include  <cstddef>
+#include  <cstdint>
+namespace platform  {
+/* Addresses for the devices of the type Uart.*/
+    enum Uart: uintptr_t{Uart0 = 0x40000000,Uart1 = 0x40001000,};
+
+/* Addresses for the devices of the type Timer.*/
+    enum Timer: uintptr_t{Timer0 = 0x40010000,};
+
+enum Interrupt: uintptr_t{};
+} // namespace platform
+
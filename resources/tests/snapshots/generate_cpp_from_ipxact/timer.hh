@@ -0,0 +1,170 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace timer {
+
+/* Control register. */
+union CtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* EN */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Divider of the clock. */
+    zermio::BitField<8, 8, zermio::Permissions::ReadWrite, uint32_t> prescaler;
+    /* Lock the configuration until the next reset. */
+    zermio::BitField<31, 1, zermio::Permissions::WriteOnce, uint32_t> lock;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x100;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    /* Write the mmio unless this instance already did it, returns false otherwise. */
+    inline bool try_commit() { return reg_.try_commit(); }
+
+    /* Write the reset value unless this instance already wrote the mmio, returns false otherwise. */
+    inline bool reset() {
+        reg_.cache = reset_value;
+        return reg_.try_commit();
+    }
+
+    inline CtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Current value of the counter. */
+union CountReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* VALUE */
+    zermio::BitField<0, 32, zermio::Permissions::Read, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CountReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CountReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Value compared with the counter. */
+union CmpReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* VALUE */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CmpReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CmpReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Match status of the channel. */
+/* Reading this register has side effects:
+ * - MATCHED: Clear on read.
+ * Thus it can only be loaded explicitly with read_and_clear(). */
+union StatusReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* MATCHED */
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> matched;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline StatusReg& read_and_clear() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Compare channel. */
+struct ChCluster {
+    CmpReg cmp;
+    StatusReg status;
+    
+    constexpr ChCluster (uintptr_t addr):
+        cmp(addr + 0x0), 
+        status(addr + 0x4){}
+};
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Timer {
+    CtrlReg ctrl;
+    CountReg count;
+    std::array<ChCluster, 2> ch;
+    
+    constexpr Timer (platform::Timer addr):
+        ctrl(addr + 0x0), 
+        count(addr + 0x4), 
+        ch(zermio::make_array<ChCluster, 2>(addr + 0x10, 0x8)){}
+};
+
+
+} // namespace timer
+} // namespace mmio
@@ -0,0 +1,255 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace uart {
+
+/* Enumerated values of the field PARITY. */
+enum class CtrlParity: uint32_t {
+    /* NONE */
+    None = 0x0,
+    /* Even parity. */
+    Even = 0x1,
+    /* Odd parity. */
+    Odd = 0x2,
+};
+
+/* Control register. */
+union CtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Enable the transmitter. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> tx_en;
+    /* Enable the receiver. */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx_en;
+    /* Parity of the frames. */
+    zermio::BitField<4, 2, zermio::Permissions::ReadWrite, uint32_t, CtrlParity> parity;
+    /* Baud rate of the bus. */
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> nco;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x1b20002;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Status register. */
+union StatusReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* TXFULL */
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> txfull;
+    /* RXEMPTY */
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rxempty;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x2;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline StatusReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt status, cleared by writing 1. */
+union IntrStateReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* TX_EMPTY */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> tx_empty;
+    /* RX_FULL */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_full;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x3;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrStateReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrStateReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+};
+
+
+
+/* Received byte, popped from the FIFO when read. */
+/* Reading this register has side effects:
+ * - VALUE: Modify on read.
+ * Thus it can only be loaded explicitly with read_with_side_effects(). */
+union RdataReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* VALUE */
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline RdataReg& read_with_side_effects() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Byte to transmit. */
+union WdataReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* VALUE */
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr WdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline WdataReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Characters that raise an event when received. */
+union PatternReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* CHARACTER */
+    zermio::BitField<0, 8, zermio::Permissions::ReadWrite, uint32_t> character;
+    /* EN */
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr PatternReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline PatternReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Uart {
+    CtrlReg ctrl;
+    StatusReg status;
+    IntrStateReg intr_state;
+    RdataReg rdata;
+    WdataReg wdata;
+    std::array<PatternReg, 4> pattern;
+    /* The window TX_FIFO, 16 words of 32 bits.*/
+    zermio::Window<uint32_t, 16, zermio::Write> tx_fifo;
+    
+    constexpr Uart (platform::Uart addr):
+        ctrl(addr + 0x0), 
+        status(addr + 0x4), 
+        intr_state(addr + 0x8), 
+        rdata(addr + 0xc), 
+        wdata(addr + 0x10), 
+        pattern(zermio::make_array<PatternReg, 4>(addr + 0x20, 0x4)), 
+        tx_fifo(addr + 0x800){}
+};
+
+
+} // namespace uart
+} // namespace mmio
@@ -13,15 +13,25 @@ namespace gpio {
 /*  */
 union OutReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Pins. */
-    zermio::BitField<0, 32, zermio::Permissions::Write> pins;
-    constexpr OutReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::Write, uint32_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr OutReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline OutReg& fetch() {
         reg_.fetch();
         return *this;
@@ -33,15 +43,25 @@ union OutReg {
 /*  */
 union InReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Pins. */
-    zermio::BitField<0, 32, zermio::Permissions::Read> pins;
-    constexpr InReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::Read, uint32_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr InReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline InReg& fetch() {
         reg_.fetch();
         return *this;
@@ -53,15 +73,25 @@ union InReg {
 /*  */
 union InDbncReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* DBNC. */
-    zermio::BitField<0, 32, zermio::Permissions::Read> pins;
-    constexpr InDbncReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::Read, uint32_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr InDbncReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline InDbncReg& fetch() {
         reg_.fetch();
         return *this;
@@ -73,15 +103,25 @@ union InDbncReg {
 /*  */
 union OutputEnableReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* val */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> pins;
-    constexpr OutputEnableReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr OutputEnableReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline OutputEnableReg& fetch() {
         reg_.fetch();
         return *this;
@@ -13,47 +13,70 @@ namespace i2c {
 /* Interrupt State Register */
 union IntrStateReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* host mode interrupt: asserted whilst the FMT FIFO level is below the low threshold. This is a level status interrupt. */
-    zermio::BitField<0, 1, zermio::Permissions::Read> fmt_threshold;
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> fmt_threshold;
     /* host mode interrupt: asserted whilst the RX FIFO level is above the high threshold. This is a level status interrupt. */
-    zermio::BitField<1, 1, zermio::Permissions::Read> rx_threshold;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rx_threshold;
     /* target mode interrupt: asserted whilst the ACQ FIFO level is above the high threshold. This is a level status interrupt. */
-    zermio::BitField<2, 1, zermio::Permissions::Read> acq_threshold;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> acq_threshold;
     /* host mode interrupt: raised if the RX FIFO has overflowed. */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> rx_overflow;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_overflow;
     /* host mode interrupt: raised if the controller FSM is halted, such as on an unexpected NACK or lost arbitration.Check !!CONTROLLER_EVENTS for the reason.The interrupt will be released when the bits in !!CONTROLLER_EVENTS are cleared. */
-    zermio::BitField<4, 1, zermio::Permissions::Read> controller_halt;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> controller_halt;
     /* host mode interrupt: raised if the SCL line drops early (not supported without clock synchronization). */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> scl_interference;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> scl_interference;
     /* host mode interrupt: raised if the SDA line goes low when host is trying to assert high */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> sda_interference;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> sda_interference;
     /* host mode interrupt: raised if target stretches the clock beyond the allowed timeout period */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> stretch_timeout;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> stretch_timeout;
     /* host mode interrupt: raised if the target does not assert a constant value of SDA during transmission. */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> sda_unstable;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> sda_unstable;
     /* host and target mode interrupt.In host mode, raised if the host issues a repeated START or terminates the transaction by issuing STOP.In target mode, raised if the external host issues a STOP or repeated START. */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> cmd_complete;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> cmd_complete;
     /* target mode interrupt: raised if the target is stretching clocks for a read command. This is a level status interrupt. */
-    zermio::BitField<10, 1, zermio::Permissions::Read> tx_stretch;
+    zermio::BitField<10, 1, zermio::Permissions::Read, uint32_t> tx_stretch;
     /* target mode interrupt: asserted whilst the TX FIFO level is below the low threshold. This is a level status interrupt. */
-    zermio::BitField<11, 1, zermio::Permissions::Read> tx_threshold;
+    zermio::BitField<11, 1, zermio::Permissions::Read, uint32_t> tx_threshold;
     /* target mode interrupt: raised if the target is stretching clocks due to full ACQ FIFO or zero count in !!TARGET_ACK_CTRL.NBYTES (if enabled). This is a level status interrupt. */
-    zermio::BitField<12, 1, zermio::Permissions::Read> acq_stretch;
+    zermio::BitField<12, 1, zermio::Permissions::Read, uint32_t> acq_stretch;
     /* target mode interrupt: raised if STOP is received without a preceding NACK during an external host read. */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> unexp_stop;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> unexp_stop;
     /* target mode interrupt: raised if the host stops sending the clock during an ongoing transaction. */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> host_timeout;
-    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> host_timeout;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x63e8;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrStateReg& fetch() {
         reg_.fetch();
         return *this;
     }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrStateReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
 };
 
 
@@ -61,43 +84,53 @@ union IntrStateReg {
 /* Interrupt Enable Register */
 union IntrEnableReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Enable interrupt when !!INTR_STATE.fmt_threshold is set. */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> fmt_threshold;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> fmt_threshold;
     /* Enable interrupt when !!INTR_STATE.rx_threshold is set. */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> rx_threshold;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx_threshold;
     /* Enable interrupt when !!INTR_STATE.acq_threshold is set. */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> acq_threshold;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> acq_threshold;
     /* Enable interrupt when !!INTR_STATE.rx_overflow is set. */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> rx_overflow;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> rx_overflow;
     /* Enable interrupt when !!INTR_STATE.controller_halt is set. */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> controller_halt;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> controller_halt;
     /* Enable interrupt when !!INTR_STATE.scl_interference is set. */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> scl_interference;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> scl_interference;
     /* Enable interrupt when !!INTR_STATE.sda_interference is set. */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> sda_interference;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> sda_interference;
     /* Enable interrupt when !!INTR_STATE.stretch_timeout is set. */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> stretch_timeout;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> stretch_timeout;
     /* Enable interrupt when !!INTR_STATE.sda_unstable is set. */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> sda_unstable;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> sda_unstable;
     /* Enable interrupt when !!INTR_STATE.cmd_complete is set. */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> cmd_complete;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> cmd_complete;
     /* Enable interrupt when !!INTR_STATE.tx_stretch is set. */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> tx_stretch;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> tx_stretch;
     /* Enable interrupt when !!INTR_STATE.tx_threshold is set. */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> tx_threshold;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> tx_threshold;
     /* Enable interrupt when !!INTR_STATE.acq_stretch is set. */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> acq_stretch;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> acq_stretch;
     /* Enable interrupt when !!INTR_STATE.unexp_stop is set. */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> unexp_stop;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> unexp_stop;
     /* Enable interrupt when !!INTR_STATE.host_timeout is set. */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> host_timeout;
-    constexpr IntrEnableReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> host_timeout;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrEnableReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrEnableReg& fetch() {
         reg_.fetch();
         return *this;
@@ -109,43 +142,53 @@ union IntrEnableReg {
 /* Interrupt Test Register */
 union IntrTestReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Write 1 to force !!INTR_STATE.fmt_threshold to 1. */
-    zermio::BitField<0, 1, zermio::Permissions::Write> fmt_threshold;
+    zermio::BitField<0, 1, zermio::Permissions::Write, uint32_t> fmt_threshold;
     /* Write 1 to force !!INTR_STATE.rx_threshold to 1. */
-    zermio::BitField<1, 1, zermio::Permissions::Write> rx_threshold;
+    zermio::BitField<1, 1, zermio::Permissions::Write, uint32_t> rx_threshold;
     /* Write 1 to force !!INTR_STATE.acq_threshold to 1. */
-    zermio::BitField<2, 1, zermio::Permissions::Write> acq_threshold;
+    zermio::BitField<2, 1, zermio::Permissions::Write, uint32_t> acq_threshold;
     /* Write 1 to force !!INTR_STATE.rx_overflow to 1. */
-    zermio::BitField<3, 1, zermio::Permissions::Write> rx_overflow;
+    zermio::BitField<3, 1, zermio::Permissions::Write, uint32_t> rx_overflow;
     /* Write 1 to force !!INTR_STATE.controller_halt to 1. */
-    zermio::BitField<4, 1, zermio::Permissions::Write> controller_halt;
+    zermio::BitField<4, 1, zermio::Permissions::Write, uint32_t> controller_halt;
     /* Write 1 to force !!INTR_STATE.scl_interference to 1. */
-    zermio::BitField<5, 1, zermio::Permissions::Write> scl_interference;
+    zermio::BitField<5, 1, zermio::Permissions::Write, uint32_t> scl_interference;
     /* Write 1 to force !!INTR_STATE.sda_interference to 1. */
-    zermio::BitField<6, 1, zermio::Permissions::Write> sda_interference;
+    zermio::BitField<6, 1, zermio::Permissions::Write, uint32_t> sda_interference;
     /* Write 1 to force !!INTR_STATE.stretch_timeout to 1. */
-    zermio::BitField<7, 1, zermio::Permissions::Write> stretch_timeout;
+    zermio::BitField<7, 1, zermio::Permissions::Write, uint32_t> stretch_timeout;
     /* Write 1 to force !!INTR_STATE.sda_unstable to 1. */
-    zermio::BitField<8, 1, zermio::Permissions::Write> sda_unstable;
+    zermio::BitField<8, 1, zermio::Permissions::Write, uint32_t> sda_unstable;
     /* Write 1 to force !!INTR_STATE.cmd_complete to 1. */
-    zermio::BitField<9, 1, zermio::Permissions::Write> cmd_complete;
+    zermio::BitField<9, 1, zermio::Permissions::Write, uint32_t> cmd_complete;
     /* Write 1 to force !!INTR_STATE.tx_stretch to 1. */
-    zermio::BitField<10, 1, zermio::Permissions::Write> tx_stretch;
+    zermio::BitField<10, 1, zermio::Permissions::Write, uint32_t> tx_stretch;
     /* Write 1 to force !!INTR_STATE.tx_threshold to 1. */
-    zermio::BitField<11, 1, zermio::Permissions::Write> tx_threshold;
+    zermio::BitField<11, 1, zermio::Permissions::Write, uint32_t> tx_threshold;
     /* Write 1 to force !!INTR_STATE.acq_stretch to 1. */
-    zermio::BitField<12, 1, zermio::Permissions::Write> acq_stretch;
+    zermio::BitField<12, 1, zermio::Permissions::Write, uint32_t> acq_stretch;
     /* Write 1 to force !!INTR_STATE.unexp_stop to 1. */
-    zermio::BitField<13, 1, zermio::Permissions::Write> unexp_stop;
+    zermio::BitField<13, 1, zermio::Permissions::Write, uint32_t> unexp_stop;
     /* Write 1 to force !!INTR_STATE.host_timeout to 1. */
-    zermio::BitField<14, 1, zermio::Permissions::Write> host_timeout;
-    constexpr IntrTestReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<14, 1, zermio::Permissions::Write, uint32_t> host_timeout;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrTestReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrTestReg& fetch() {
         reg_.fetch();
         return *this;
@@ -157,27 +200,37 @@ union IntrTestReg {
 /* I2C Control Register */
 union CtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Enable Host I2C functionality */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> enablehost;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> enablehost;
     /* Enable Target I2C functionality */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> enabletarget;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> enabletarget;
     /* Enable I2C line loopback testIf line loopback is enabled, the internal design sees ACQ and RX data as "1" */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> llpbk;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> llpbk;
     /* Enable NACKing the address on a stretch timeout.This is a Target mode feature.If enabled (1), a stretch timeout will cause the device to NACK the address byte.If disabled (0), a stretch timeout will cause the device to ACK the address byte.SMBus requires that devices always ACK their address, even for read commands.However, non-SMBus protocols may have a different approach and can choose to NACK instead.Note that both cases handle data bytes the same way.For writes, the Target module will NACK all subsequent data bytes until it receives a Stop.For reads, the Target module will release SDA, causing 0xff to be returned for all data bytes until it receives a Stop. */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> nack_addr_after_timeout;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> nack_addr_after_timeout;
     /* Enable I2C Target ACK Control Mode.ACK Control Mode works together with !!TARGET_ACK_CTRL.NBYTES to allow software to control upper-layer protocol (N)ACKing (e.g. as in SMBus).This bit enables the mode when 1, and !!TARGET_ACK_CTRL.NBYTES limits how many bytes may be automatically ACK'd while the ACQ FIFO has space.If it is 0, the decision to ACK or NACK is made only from stretching timeouts and !!CTRL.NACK_ADDR_AFTER_TIMEOUT. */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> ack_ctrl_en;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> ack_ctrl_en;
     /* Enable the bus monitor in multi-controller mode.If a 0->1 transition happens while !!CTRL.ENABLEHOST and !!CTRL.ENABLETARGET are both 0, the bus monitor will enable and begin in the "bus busy" state.To transition to a bus free state, !!HOST_TIMEOUT_CTRL must be nonzero, so the bus monitor may count out idle cycles to confirm the freedom to transmit.In addition, the bus monitor will track whether the bus is free based on the enabled timeouts and detected Stop symbols.For multi-controller mode, ensure !!CTRL.MULTI_CONTROLLER_MONITOR_EN becomes 1 no later than !!CTRL.ENABLEHOST or !!CTRL.ENABLETARGET.This bit can be set at the same time as either or both of the other two, though.Note that if !!CTRL.MULTI_CONTROLLER_MONITOR_EN is set after !!CTRL.ENABLEHOST or !!CTRL.ENABLETARGET, the bus monitor will begin in the "bus free" state instead.This would violate the proper protocol for a controller to join a multi-controller environment.However, if this controller is known to be the first to join, this ordering will enable skipping the idle wait.When 0, the bus monitor will report that the bus is always free, so the controller FSM is never blocked from transmitting. */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> multi_controller_monitor_en;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> multi_controller_monitor_en;
     /* If set to 1, this bit causes a read transfer addressed to this target to set the corresponding bit in !!TARGET_EVENTS.While !!TARGET_EVENTS.TX_PENDING is 1, subsequent read transactions will stretch the clock, even if there is data in the TX FIFO.If enabled, this function allows software to confirm the data in the TX FIFO should be released for the current read.This may be useful for cases where the TX FIFO has data that does not apply to the current transfer.For example, the transaction could've targeted an alternate function via another address. */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> tx_stretch_ctrl_en;
-    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> tx_stretch_ctrl_en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline CtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -189,35 +242,45 @@ union CtrlReg {
 /* I2C Live Status Register for Host and Target modes */
 union StatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Host mode FMT FIFO is full */
-    zermio::BitField<0, 1, zermio::Permissions::Read> fmtfull;
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> fmtfull;
     /* Host mode RX FIFO is full */
-    zermio::BitField<1, 1, zermio::Permissions::Read> rxfull;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rxfull;
     /* Host mode FMT FIFO is empty */
-    zermio::BitField<2, 1, zermio::Permissions::Read> fmtempty;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> fmtempty;
     /* Host functionality is idle. No Host transaction is in progress */
-    zermio::BitField<3, 1, zermio::Permissions::Read> hostidle;
+    zermio::BitField<3, 1, zermio::Permissions::Read, uint32_t> hostidle;
     /* Target functionality is idle. No Target transaction is in progress */
-    zermio::BitField<4, 1, zermio::Permissions::Read> targetidle;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> targetidle;
     /* Host mode RX FIFO is empty */
-    zermio::BitField<5, 1, zermio::Permissions::Read> rxempty;
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> rxempty;
     /* Target mode TX FIFO is full */
-    zermio::BitField<6, 1, zermio::Permissions::Read> txfull;
+    zermio::BitField<6, 1, zermio::Permissions::Read, uint32_t> txfull;
     /* Target mode receive FIFO is full */
-    zermio::BitField<7, 1, zermio::Permissions::Read> acqfull;
+    zermio::BitField<7, 1, zermio::Permissions::Read, uint32_t> acqfull;
     /* Target mode TX FIFO is empty */
-    zermio::BitField<8, 1, zermio::Permissions::Read> txempty;
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> txempty;
     /* Target mode receive FIFO is empty */
-    zermio::BitField<9, 1, zermio::Permissions::Read> acqempty;
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> acqempty;
     /* Target mode stretching at (N)ACK phase due to zero count in !!TARGET_ACK_CTRL.NBYTES */
-    zermio::BitField<10, 1, zermio::Permissions::Read> ack_ctrl_stretch;
-    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<10, 1, zermio::Permissions::Read, uint32_t> ack_ctrl_stretch;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x33c;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline StatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -229,15 +292,25 @@ union StatusReg {
 /* I2C Read Data */
 union RdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 8, zermio::Permissions::Read> rdata;
-    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> rdata;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline RdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -249,25 +322,35 @@ union RdataReg {
 /* I2C Host Format DataWrites to this register are used to define and drive Controller-Mode transactions. */
 union FdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Format Byte.If no flags are set, hardware will transmit this byte directly.If READB is set, this field becomes the number of bytes hardware will automaticallyread from the bus. */
-    zermio::BitField<0, 8, zermio::Permissions::Write> fbyte;
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> fbyte;
     /* Issue a START condition before transmitting FBYTE. */
-    zermio::BitField<8, 1, zermio::Permissions::Write> start;
+    zermio::BitField<8, 1, zermio::Permissions::Write, uint32_t> start;
     /* Issue a STOP condition after transmitting FBYTE. */
-    zermio::BitField<9, 1, zermio::Permissions::Write> stop;
+    zermio::BitField<9, 1, zermio::Permissions::Write, uint32_t> stop;
     /* Transfer Direction Indicator.If unset, this write to FDATA defines a controller-transmitter operation (WRITE).A single byte of data (FBYTE) is written to the bus.If set, this write to FDATA defines a controller-receiver operation (READ).The value of FBYTE defines the number of bytes read from the bus. (256 if FBYTE==0)"After this number of bytes are read, the final byte will be NACKed to end the transferunless RCONT is also set. */
-    zermio::BitField<10, 1, zermio::Permissions::Write> readb;
+    zermio::BitField<10, 1, zermio::Permissions::Write, uint32_t> readb;
     /* Do not NACK the last byte read, let the read operation continue. */
-    zermio::BitField<11, 1, zermio::Permissions::Write> rcont;
+    zermio::BitField<11, 1, zermio::Permissions::Write, uint32_t> rcont;
     /* For the currrent controller-transmitter byte (WRITE), do not halt via CONTROLLER_EVENTSor assert the 'controller_halt' interrupt if the current byte is not ACK'd. */
-    zermio::BitField<12, 1, zermio::Permissions::Write> nakok;
-    constexpr FdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<12, 1, zermio::Permissions::Write, uint32_t> nakok;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -279,21 +362,31 @@ union FdataReg {
 /* I2C FIFO control register */
 union FifoCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 */
-    zermio::BitField<0, 1, zermio::Permissions::Write> rxrst;
+    zermio::BitField<0, 1, zermio::Permissions::Write, uint32_t> rxrst;
     /* FMT fifo reset. Write 1 to the register resets FMT_FIFO. Read returns 0 */
-    zermio::BitField<1, 1, zermio::Permissions::Write> fmtrst;
+    zermio::BitField<1, 1, zermio::Permissions::Write, uint32_t> fmtrst;
     /* ACQ FIFO reset. Write 1 to the register resets it. Read returns 0 */
-    zermio::BitField<7, 1, zermio::Permissions::Write> acqrst;
+    zermio::BitField<7, 1, zermio::Permissions::Write, uint32_t> acqrst;
     /* TX FIFO reset. Write 1 to the register resets it. Read returns 0 */
-    zermio::BitField<8, 1, zermio::Permissions::Write> txrst;
-    constexpr FifoCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 1, zermio::Permissions::Write, uint32_t> txrst;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FifoCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FifoCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -305,17 +398,27 @@ union FifoCtrlReg {
 /* Host mode FIFO configuration */
 union HostFifoConfigReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Threshold level for RX interrupts. Whilst the level of data in the RX FIFOis above this setting, the rx_threshold interrupt will be asserted. */
-    zermio::BitField<0, 12, zermio::Permissions::ReadWrite> rx_thresh;
+    zermio::BitField<0, 12, zermio::Permissions::ReadWrite, uint32_t> rx_thresh;
     /* Threshold level for FMT interrupts. Whilst the number of used entries in theFMT FIFO is below this setting, the fmt_threshold interrupt will be asserted. */
-    zermio::BitField<16, 12, zermio::Permissions::ReadWrite> fmt_thresh;
-    constexpr HostFifoConfigReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 12, zermio::Permissions::ReadWrite, uint32_t> fmt_thresh;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr HostFifoConfigReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline HostFifoConfigReg& fetch() {
         reg_.fetch();
         return *this;
@@ -327,17 +430,27 @@ union HostFifoConfigReg {
 /* Target mode FIFO configuration */
 union TargetFifoConfigReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Threshold level for TX interrupts. Whilst the number of used entries in theTX FIFO is below this setting, the tx_threshold interrupt will be asserted. */
-    zermio::BitField<0, 12, zermio::Permissions::ReadWrite> tx_thresh;
+    zermio::BitField<0, 12, zermio::Permissions::ReadWrite, uint32_t> tx_thresh;
     /* Threshold level for ACQ interrupts. Whilst the level of data in the ACQ FIFOis above this setting, the acq_threshold interrupt will be asserted. */
-    zermio::BitField<16, 12, zermio::Permissions::ReadWrite> acq_thresh;
-    constexpr TargetFifoConfigReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 12, zermio::Permissions::ReadWrite, uint32_t> acq_thresh;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetFifoConfigReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetFifoConfigReg& fetch() {
         reg_.fetch();
         return *this;
@@ -349,17 +462,27 @@ union TargetFifoConfigReg {
 /* Host mode FIFO status register */
 union HostFifoStatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Current fill level of FMT fifo */
-    zermio::BitField<0, 12, zermio::Permissions::Read> fmtlvl;
+    zermio::BitField<0, 12, zermio::Permissions::Read, uint32_t> fmtlvl;
     /* Current fill level of RX fifo */
-    zermio::BitField<16, 12, zermio::Permissions::Read> rxlvl;
-    constexpr HostFifoStatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 12, zermio::Permissions::Read, uint32_t> rxlvl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr HostFifoStatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline HostFifoStatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -371,17 +494,27 @@ union HostFifoStatusReg {
 /* Target mode FIFO status register */
 union TargetFifoStatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Current fill level of TX fifo */
-    zermio::BitField<0, 12, zermio::Permissions::Read> txlvl;
+    zermio::BitField<0, 12, zermio::Permissions::Read, uint32_t> txlvl;
     /* Current fill level of ACQ fifo */
-    zermio::BitField<16, 12, zermio::Permissions::Read> acqlvl;
-    constexpr TargetFifoStatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 12, zermio::Permissions::Read, uint32_t> acqlvl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetFifoStatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetFifoStatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -393,19 +526,29 @@ union TargetFifoStatusReg {
 /* I2C Override Control Register */
 union OvrdReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Override the SDA and SCL TX signals. */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> txovrden;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> txovrden;
     /* Value for SCL Override. Set to 0 to drive TX Low, and set to 1 for high-Z */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> sclval;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> sclval;
     /* Value for SDA Override. Set to 0 to drive TX Low, and set to 1 for high-Z */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> sdaval;
-    constexpr OvrdReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> sdaval;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr OvrdReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline OvrdReg& fetch() {
         reg_.fetch();
         return *this;
@@ -417,17 +560,27 @@ union OvrdReg {
 /* Oversampled RX values */
 union ValReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Last 16 oversampled values of SCL. Most recent bit is bit 0, oldest 15. */
-    zermio::BitField<0, 16, zermio::Permissions::Read> scl_rx;
+    zermio::BitField<0, 16, zermio::Permissions::Read, uint32_t> scl_rx;
     /* Last 16 oversampled values of SDA. Most recent bit is bit 16, oldest 31. */
-    zermio::BitField<16, 16, zermio::Permissions::Read> sda_rx;
-    constexpr ValReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::Read, uint32_t> sda_rx;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr ValReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline ValReg& fetch() {
         reg_.fetch();
         return *this;
@@ -439,17 +592,27 @@ union ValReg {
 /* Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.These must be greater than 2 in order for the change in SCL to propagate to the input of the FSM so that acknowledgements are detected correctly. */
 union Timing0Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* The actual time to hold SCL high in a given pulse.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. */
-    zermio::BitField<0, 13, zermio::Permissions::ReadWrite> thigh;
+    zermio::BitField<0, 13, zermio::Permissions::ReadWrite, uint32_t> thigh;
     /* The actual time to hold SCL low between any two SCL pulses.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. */
-    zermio::BitField<16, 13, zermio::Permissions::ReadWrite> tlow;
-    constexpr Timing0Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 13, zermio::Permissions::ReadWrite, uint32_t> tlow;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing0Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing0Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -461,17 +624,27 @@ union Timing0Reg {
 /* Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing1Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* The nominal rise time to anticipate for the bus (depends on capacitance).This field is sized to have a range of at least Standard Mode's 1000 ns max with a core clock at 1 GHz. */
-    zermio::BitField<0, 10, zermio::Permissions::ReadWrite> t_r;
+    zermio::BitField<0, 10, zermio::Permissions::ReadWrite, uint32_t> t_r;
     /* The nominal fall time to anticipate for the bus (influences SDA hold times).This field is sized to have a range of at least Standard Mode's 300 ns max with a core clock at 1 GHz. */
-    zermio::BitField<16, 9, zermio::Permissions::ReadWrite> t_f;
-    constexpr Timing1Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 9, zermio::Permissions::ReadWrite, uint32_t> t_f;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing1Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing1Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -483,17 +656,27 @@ union Timing1Reg {
 /* Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing2Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Actual setup time for repeated start signals.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. */
-    zermio::BitField<0, 13, zermio::Permissions::ReadWrite> tsu_sta;
+    zermio::BitField<0, 13, zermio::Permissions::ReadWrite, uint32_t> tsu_sta;
     /* Actual hold time for start signals.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. */
-    zermio::BitField<16, 13, zermio::Permissions::ReadWrite> thd_sta;
-    constexpr Timing2Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 13, zermio::Permissions::ReadWrite, uint32_t> thd_sta;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing2Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing2Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -505,17 +688,27 @@ union Timing2Reg {
 /* Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing3Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Actual setup time for data (or ack) bits.This field is sized to have a range of at least Standard Mode's 250 ns max with a core clock at 1 GHz. */
-    zermio::BitField<0, 9, zermio::Permissions::ReadWrite> tsu_dat;
+    zermio::BitField<0, 9, zermio::Permissions::ReadWrite, uint32_t> tsu_dat;
     /* Actual hold time for data (or ack) bits.(Note, where required, the parameters TVD_DAT is taken to be THD_DAT+T_F)This field is sized to have a range that accommodates Standard Mode's 3.45 us max for TVD_DAT with a core clock at 1 GHz.However, this field is generally expected to represent a time substantially shorter than that.It should be long enough to cover the maximum round-trip latency from output pins, through pads and voltage transitions on the board, and back to the input pins, but it should not be substantially greater. */
-    zermio::BitField<16, 13, zermio::Permissions::ReadWrite> thd_dat;
-    constexpr Timing3Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 13, zermio::Permissions::ReadWrite, uint32_t> thd_dat;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing3Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing3Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -527,17 +720,27 @@ union Timing3Reg {
 /* Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing4Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Actual setup time for stop signals.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. */
-    zermio::BitField<0, 13, zermio::Permissions::ReadWrite> tsu_sto;
+    zermio::BitField<0, 13, zermio::Permissions::ReadWrite, uint32_t> tsu_sto;
     /* Actual time between each STOP signal and the following START signal.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. */
-    zermio::BitField<16, 13, zermio::Permissions::ReadWrite> t_buf;
-    constexpr Timing4Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 13, zermio::Permissions::ReadWrite, uint32_t> t_buf;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing4Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing4Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -549,19 +752,29 @@ union Timing4Reg {
 /* I2C clock stretching and bus timeout control.This timeout must be enabled by setting !!TIMEOUT_CTRL.EN to 1, and the behavior of this feature depends on the value of !!TIMEOUT_CTRL.MODE.If the mode is "STRETCH_TIMEOUT", this is used in I2C controller mode to detect whether a connected target is stretching a single low time beyond the timeout value.Configured as such, this timeout is more informative and doesn't do more than assert the "stretch_timeout" interrupt.If the mode is "BUS_TIMEOUT", it is used to detect whether the clock has been held low for too long instead, inclusive of the controller's clock low time.This is useful for an SMBus context, where the VAL programmed should be tTIMEOUT:MIN. */
 union TimeoutCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Clock stretching timeout value (in units of input clock frequency) */
-    zermio::BitField<0, 30, zermio::Permissions::ReadWrite> val;
+    zermio::BitField<0, 30, zermio::Permissions::ReadWrite, uint32_t> val;
     /* Selects the timeout mode, between a stretch timeout and a bus timeout.Between the two modes, the primary difference is how much of the clock low period is counted.For a stretch timeout, only the time that another device holds the clock low will be counted.For a bus timeout, the entire clock low time is counted, consistent with the SMBus tTIMEOUT type.!!TIMEOUT_CTRL.EN must be 1 for either of these features to be enabled. */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> mode;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> mode;
     /* Enable stretch timeout or bus timeout feature */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> en;
-    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TimeoutCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -573,21 +786,31 @@ union TimeoutCtrlReg {
 /* I2C target address and mask pairs */
 union TargetIdReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* I2C target address number 0 */
-    zermio::BitField<0, 7, zermio::Permissions::ReadWrite> address0;
+    zermio::BitField<0, 7, zermio::Permissions::ReadWrite, uint32_t> address0;
     /* I2C target mask number 0.At least one bit in MASK0 must be set to 1 for ADDRESS0 to be used. */
-    zermio::BitField<7, 7, zermio::Permissions::ReadWrite> mask0;
+    zermio::BitField<7, 7, zermio::Permissions::ReadWrite, uint32_t> mask0;
     /* I2C target address number 1 */
-    zermio::BitField<14, 7, zermio::Permissions::ReadWrite> address1;
+    zermio::BitField<14, 7, zermio::Permissions::ReadWrite, uint32_t> address1;
     /* I2C target mask number 1.At least one bit in MASK1 must be set to 1 for ADDRESS1 to be used. */
-    zermio::BitField<21, 7, zermio::Permissions::ReadWrite> mask1;
-    constexpr TargetIdReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<21, 7, zermio::Permissions::ReadWrite, uint32_t> mask1;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetIdReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetIdReg& fetch() {
         reg_.fetch();
         return *this;
@@ -599,17 +822,27 @@ union TargetIdReg {
 /* I2C target acquired data */
 union AcqdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Address for accepted transaction or acquired byte */
-    zermio::BitField<0, 8, zermio::Permissions::Read> abyte;
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> abyte;
     /* Indicates any control symbols associated with the ABYTE.For the STOP symbol, a stretch timeout or other unexpected events will cause a NACK_STOP to appear in the ACQ FIFO.If the ACQ FIFO doesn't have enough space to record a START and a STOP, the transaction will be dropped entirely on a stretch timeout.In that case, the START byte will not appear (neither as START nor NACK_START), but a standalone NACK_STOP may, if there was space.Software can discard any standalone NACK_STOP that appears.See the associated values for more information about the contents. */
-    zermio::BitField<8, 3, zermio::Permissions::Read> signal;
-    constexpr AcqdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 3, zermio::Permissions::Read, uint32_t> signal;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr AcqdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline AcqdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -621,15 +854,25 @@ union AcqdataReg {
 /* I2C target transmit data */
 union TxdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 8, zermio::Permissions::Write> txdata;
-    constexpr TxdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> txdata;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TxdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TxdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -641,15 +884,25 @@ union TxdataReg {
 /* I2C host clock generation timeout value (in units of input clock frequency).In an active transaction in Target-Mode, if the Controller ceases to send SCL pulsesfor this number of cycles then the "host_timeout" interrupt will be asserted.In multi-controller monitoring mode, !!HOST_TIMEOUT_CTRL is required to be nonzero to transition out of the initial busy state.Set this CSR to 0 to disable this behaviour. */
 union HostTimeoutCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 20, zermio::Permissions::ReadWrite> host_timeout_ctrl;
-    constexpr HostTimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 20, zermio::Permissions::ReadWrite, uint32_t> host_timeout_ctrl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr HostTimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline HostTimeoutCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -661,17 +914,27 @@ union HostTimeoutCtrlReg {
 /* I2C target internal stretching timeout control.When the target has stretched beyond this time it will send a NACK for incoming data bytes or release SDA for outgoing data bytes.The behavior for the address byte is configurable via !!CTRL.ACK_ADDR_AFTER_TIMEOUT.Note that the count accumulates stretching time over the course of a transaction.In other words, this is equivalent to the SMBus cumulative target clock extension time. */
 union TargetTimeoutCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Clock stretching timeout value (in units of input clock frequency) */
-    zermio::BitField<0, 31, zermio::Permissions::ReadWrite> val;
+    zermio::BitField<0, 31, zermio::Permissions::ReadWrite, uint32_t> val;
     /* Enable timeout feature and send NACK once the timeout has been reached */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> en;
-    constexpr TargetTimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetTimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetTimeoutCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -681,18 +944,32 @@ union TargetTimeoutCtrlReg {
 
 
 /* Number of times the I2C target has NACK'ed a new transaction since the last read of this register.Reading this register clears it.This is useful because when the ACQ FIFO is full the software know that a NACK has occurred, but without this register would not know how many transactions it missed.When it reaches its maximum value it will stay at that value. */
+/* Reading this register has side effects:
+ * - TARGET_NACK_COUNT: Clear on read.
+ * Thus it can only be loaded explicitly with read_and_clear(). */
 union TargetNackCountReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 8, zermio::Permissions::ReadWrite> target_nack_count;
-    constexpr TargetNackCountReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::ReadWrite, uint32_t> target_nack_count;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetNackCountReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
-    inline TargetNackCountReg& fetch() {
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline TargetNackCountReg& read_and_clear() {
         reg_.fetch();
         return *this;
     }
@@ -703,17 +980,27 @@ union TargetNackCountReg {
 /* Controls for mid-transfer (N)ACK phase handling */
 union TargetAckCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Remaining number of bytes the Target module may ACK automatically.If !!CTRL.ACK_CTRL_EN is set to 1, the Target module will stretch the clock at the (N)ACK phase of a byte if this CSR is 0, awaiting software's instructions.At the beginning of each Write transfer, this byte count is reset to 0.Writes to this CSR also are only accepted while the Target module is stretching the clock.The Target module will always ACK its address if the ACQ FIFO has space.For data bytes afterwards, it will stop at the (N)ACK phase and stretch the clock when this CSR is 0.For each data byte that is ACK'd in a transaction, the byte count will decrease by 1.Note that a full ACQ FIFO can still cause the Target module to halt at the beginning of a new byte.The ACK Control Mode provides an additional synchronization point, during the (N)ACK phase instead of after.For both cases, !!TARGET_TIMEOUT_CTRL applies, and stretching past the timeout will produce an automatic NACK.This mode can be used to implement the mid-transfer (N)ACK responses required by various SMBus protocols. */
-    zermio::BitField<0, 9, zermio::Permissions::ReadWrite> nbytes;
+    zermio::BitField<0, 9, zermio::Permissions::ReadWrite, uint32_t> nbytes;
     /* When the Target module stretches on the (N)ACK phase of a Write due to !!TARGET_ACK_CTRL.NBYTES being 0, writing a 1 here will cause it to send a NACK.If software chooses to NACK, note that the NACKing behavior is the same as if a stretch timeout occurred.The rest of the transaction will be NACK'd, including subsequent transfers.For the address byte, the (N)ACK phase of subsequent transfers will follow the behavior specified by !!CTRL.NACK_ADDR_AFTER_TIMEOUT.Automatically clears to 0. */
-    zermio::BitField<31, 1, zermio::Permissions::Write> nack;
-    constexpr TargetAckCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<31, 1, zermio::Permissions::Write, uint32_t> nack;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetAckCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetAckCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -725,15 +1012,25 @@ union TargetAckCtrlReg {
 /* The data byte pending to be written to the ACQ FIFO.This CSR is only valid while the Target module is stretching in the (N)ACK phase, indicated by !!STATUS.ACK_CTRL_STRETCH .It is intended to be used with ACK Control Mode, so software may check the current byte. */
 union AcqFifoNextDataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 8, zermio::Permissions::Read> acq_fifo_next_data;
-    constexpr AcqFifoNextDataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> acq_fifo_next_data;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr AcqFifoNextDataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline AcqFifoNextDataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -745,17 +1042,27 @@ union AcqFifoNextDataReg {
 /* Timeout in Host-Mode for an unhandled NACK before hardware automatically ends the transaction.(in units of input clock frequency)If an active Controller-Transmitter transfer receives a NACK from the Target, the !!CONTROLLER_EVENTS.NACK bit is set.In turn, this causes the Controller FSM to halt awaiting software intervention, and the 'controller_halt' interrupt may assert.Software must clear the !!CONTROLLER_EVENTS.NACK bit to allow the state machine to continue, typically after clearing out the FMTFIFO to start a new transfer.While halted, the active transaction is not ended (no STOP (P) condition is created), and the block asserts SCL and leaves SDA released.This timeout can be used to automatically produce a STOP condition, whether as a backstop for slow software responses (longer timeout) or as a convenience (short timeout).If the timeout expires, the Controller FSM will issue a STOP (P) condition on the bus to end the active transaction.Additionally, the !!CONTROLLER_EVENTS.UNHANDLED_NACK_TIMEOUT bit is set to alert software, and the FSM will return to the idle state and halt until the bit is cleared.The enable bit must be set for this feature to operate. */
 union HostNackHandlerTimeoutReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Unhandled NAK timeout value (in units of input clock frequency) */
-    zermio::BitField<0, 31, zermio::Permissions::ReadWrite> val;
+    zermio::BitField<0, 31, zermio::Permissions::ReadWrite, uint32_t> val;
     /* Timeout enable */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> en;
-    constexpr HostNackHandlerTimeoutReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr HostNackHandlerTimeoutReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline HostNackHandlerTimeoutReg& fetch() {
         reg_.fetch();
         return *this;
@@ -767,25 +1074,48 @@ union HostNackHandlerTimeoutReg {
 /* Latched events that explain why the controller halted.Any bits that are set must be written (with a 1) to clear the CONTROLLER_HALT interrupt. */
 union ControllerEventsReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Received an unexpected NACK */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> nack;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> nack;
     /* A Host-Mode active transaction has been ended by the !!HOST_NACK_HANDLER_TIMEOUT mechanism. */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> unhandled_nack_timeout;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> unhandled_nack_timeout;
     /* A Host-Mode active transaction has terminated due to a bus timeout activated by !!TIMEOUT_CTRL. */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> bus_timeout;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> bus_timeout;
     /* A Host-Mode active transaction has terminated due to lost arbitration. */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> arbitration_lost;
-    constexpr ControllerEventsReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> arbitration_lost;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0xf;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr ControllerEventsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline ControllerEventsReg& fetch() {
         reg_.fetch();
         return *this;
     }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline ControllerEventsReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
 };
 
 
@@ -793,23 +1123,46 @@ union ControllerEventsReg {
 /* Latched events that can cause the target module to stretch the clock at the beginning of a read transfer.These events cause TX FIFO-related stretching even when the TX FIFO has data available.Any bits that are set must be written (with a 1) to clear the tx_stretch interrupt.This CSR serves as a gate to prevent the Target module from responding to a read command with unrelated, leftover data. */
 union TargetEventsReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* A new Target-Mode read transfer has arrived that addressed this target.This bit is used by software to confirm the release of the contents in the TX FIFO.If the contents do not apply, software should first reset the TX FIFO, then load it with the correct data, then clear this bit.Optionally enabled by !!CTRL.TX_STRETCH_CTRL_EN. */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> tx_pending;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> tx_pending;
     /* A Target-Mode read transfer has terminated due to a bus timeout activated by !!TIMEOUT_CTRL. */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> bus_timeout;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> bus_timeout;
     /* A Target-Mode read transfer has terminated due to lost arbitration. */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> arbitration_lost;
-    constexpr TargetEventsReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> arbitration_lost;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x7;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr TargetEventsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetEventsReg& fetch() {
         reg_.fetch();
         return *this;
     }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline TargetEventsReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
 };
 
 
@@ -847,6 +1200,14 @@ struct I2c {
     HostNackHandlerTimeoutReg host_nack_handler_timeout;
     ControllerEventsReg controller_events;
     TargetEventsReg target_events;
+    /* The window RX_BUF, 64 words of 8 bits.*/
+    zermio::Window<uint8_t, 64, zermio::Read> rx_buf;
+    /* Hardware parameter FIFO_DEPTH.*/
+    static constexpr uint32_t FifoDepth = 8;
+    /* Hardware parameter ACQ_FIFO_DEPTH.*/
+    static constexpr uint32_t AcqFifoDepth = 8;
+    /* Hardware parameter NUM_ALERTS.*/
+    static constexpr uint32_t NumAlerts = 1;
     
     constexpr I2c (platform::I2c addr):
         intr_state(addr + 0x0), 
@@ -879,7 +1240,8 @@ struct I2c {
         acq_fifo_next_data(addr + 0x70), 
         host_nack_handler_timeout(addr + 0x74), 
         controller_events(addr + 0x78), 
-        target_events(addr + 0x7c){}
+        target_events(addr + 0x7c), 
+        rx_buf(addr + 0x200){}
 };
 
 
@@ -13,15 +13,25 @@ namespace pwm {
 /*  */
 union WidthReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Pwm width. */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr WidthReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr WidthReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline WidthReg& fetch() {
         reg_.fetch();
         return *this;
@@ -33,15 +43,25 @@ union WidthReg {
 /*  */
 union CounterReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Pwm counter. */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr CounterReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CounterReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline CounterReg& fetch() {
         reg_.fetch();
         return *this;
@@ -5,6 +5,7 @@
 
 #pragma once
 
+#include  <cstddef>
 #include  <cstdint>
 namespace platform  {
 /* Addresses for the devices of the type Gpio.*/
@@ -28,6 +29,22 @@ namespace platform  {
 /* Addresses for the devices of the type Spi.*/
     enum Spi: uintptr_t{SpiLcd = 0x80300000,SpiEthmac = 0x80301000,Spi0 = 0x80302000,Spi1 = 0x80303000,Spi2 = 0x80304000,};
 
-enum Interrupt: uintptr_t{};
+enum Interrupt: uintptr_t{Uart0Irq = 1,Uart1Irq = 2,Uart2Irq = 3,I2c0Irq = 4,I2c1Irq = 5,};
+
+/* The address range of a memory region, the `size` is in bytes.*/
+struct MemoryRegion {
+    uintptr_t base;
+    size_t size;
+    uint8_t width;
+    bool readable;
+    bool writable;
+
+    constexpr uintptr_t end() const { return base + size; }
+    constexpr size_t entries() const { return size / (width / 8); }
+    constexpr bool contains(uintptr_t addr) const { return addr >= base && addr < end(); }
+};
+
+/* SRAM, 131072 words of 8 bits.*/
+inline constexpr MemoryRegion Sram{0x100000, 0x20000, 8, true, true};
 } // namespace platform
 
@@ -13,15 +13,25 @@ namespace timer {
 /*  */
 union MtimelReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* mtime low */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr MtimelReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimelReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline MtimelReg& fetch() {
         reg_.fetch();
         return *this;
@@ -33,15 +43,25 @@ union MtimelReg {
 /*  */
 union MtimehReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* mtime high */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr MtimehReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimehReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline MtimehReg& fetch() {
         reg_.fetch();
         return *this;
@@ -53,15 +73,25 @@ union MtimehReg {
 /*  */
 union MtimecmplReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* mtime cmp low */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr MtimecmplReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimecmplReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline MtimecmplReg& fetch() {
         reg_.fetch();
         return *this;
@@ -73,15 +103,25 @@ union MtimecmplReg {
 /*  */
 union MtimecmphReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* mtime cmp high */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr MtimecmphReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimecmphReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline MtimecmphReg& fetch() {
         reg_.fetch();
         return *this;
@@ -13,35 +13,58 @@ namespace uart {
 /* Interrupt State Register */
 union IntrStateReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* raised if the transmit FIFO is past the high-water mark. */
-    zermio::BitField<0, 1, zermio::Permissions::Read> tx_watermark;
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> tx_watermark;
     /* raised if the receive FIFO is past the high-water mark. */
-    zermio::BitField<1, 1, zermio::Permissions::Read> rx_watermark;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rx_watermark;
     /* raised if the transmit FIFO has emptied and no transmit is ongoing. */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> tx_done;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> tx_done;
     /* raised if the receive FIFO has overflowed. */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> rx_overflow;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_overflow;
     /* raised if a framing error has been detected on receive. */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> rx_frame_err;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_frame_err;
     /* raised if break condition has been detected on receive. */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> rx_break_err;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_break_err;
     /* raised if RX FIFO has characters remaining in the FIFO without beingretrieved for the programmed time period. */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> rx_timeout;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_timeout;
     /* raised if the receiver has detected a parity error. */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> rx_parity_err;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_parity_err;
     /* raised if the transmit FIFO is empty. */
-    zermio::BitField<8, 1, zermio::Permissions::Read> tx_empty;
-    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> tx_empty;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x101;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0xfc;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrStateReg& fetch() {
         reg_.fetch();
         return *this;
     }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrStateReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
 };
 
 
@@ -49,31 +72,41 @@ union IntrStateReg {
 /* Interrupt Enable Register */
 union IntrEnableReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Enable interrupt when !!INTR_STATE.tx_watermark is set. */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> tx_watermark;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> tx_watermark;
     /* Enable interrupt when !!INTR_STATE.rx_watermark is set. */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> rx_watermark;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx_watermark;
     /* Enable interrupt when !!INTR_STATE.tx_done is set. */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> tx_done;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> tx_done;
     /* Enable interrupt when !!INTR_STATE.rx_overflow is set. */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> rx_overflow;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> rx_overflow;
     /* Enable interrupt when !!INTR_STATE.rx_frame_err is set. */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> rx_frame_err;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> rx_frame_err;
     /* Enable interrupt when !!INTR_STATE.rx_break_err is set. */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> rx_break_err;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> rx_break_err;
     /* Enable interrupt when !!INTR_STATE.rx_timeout is set. */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> rx_timeout;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> rx_timeout;
     /* Enable interrupt when !!INTR_STATE.rx_parity_err is set. */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> rx_parity_err;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> rx_parity_err;
     /* Enable interrupt when !!INTR_STATE.tx_empty is set. */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> tx_empty;
-    constexpr IntrEnableReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> tx_empty;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrEnableReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrEnableReg& fetch() {
         reg_.fetch();
         return *this;
@@ -85,31 +118,41 @@ union IntrEnableReg {
 /* Interrupt Test Register */
 union IntrTestReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Write 1 to force !!INTR_STATE.tx_watermark to 1. */
-    zermio::BitField<0, 1, zermio::Permissions::Write> tx_watermark;
+    zermio::BitField<0, 1, zermio::Permissions::Write, uint32_t> tx_watermark;
     /* Write 1 to force !!INTR_STATE.rx_watermark to 1. */
-    zermio::BitField<1, 1, zermio::Permissions::Write> rx_watermark;
+    zermio::BitField<1, 1, zermio::Permissions::Write, uint32_t> rx_watermark;
     /* Write 1 to force !!INTR_STATE.tx_done to 1. */
-    zermio::BitField<2, 1, zermio::Permissions::Write> tx_done;
+    zermio::BitField<2, 1, zermio::Permissions::Write, uint32_t> tx_done;
     /* Write 1 to force !!INTR_STATE.rx_overflow to 1. */
-    zermio::BitField<3, 1, zermio::Permissions::Write> rx_overflow;
+    zermio::BitField<3, 1, zermio::Permissions::Write, uint32_t> rx_overflow;
     /* Write 1 to force !!INTR_STATE.rx_frame_err to 1. */
-    zermio::BitField<4, 1, zermio::Permissions::Write> rx_frame_err;
+    zermio::BitField<4, 1, zermio::Permissions::Write, uint32_t> rx_frame_err;
     /* Write 1 to force !!INTR_STATE.rx_break_err to 1. */
-    zermio::BitField<5, 1, zermio::Permissions::Write> rx_break_err;
+    zermio::BitField<5, 1, zermio::Permissions::Write, uint32_t> rx_break_err;
     /* Write 1 to force !!INTR_STATE.rx_timeout to 1. */
-    zermio::BitField<6, 1, zermio::Permissions::Write> rx_timeout;
+    zermio::BitField<6, 1, zermio::Permissions::Write, uint32_t> rx_timeout;
     /* Write 1 to force !!INTR_STATE.rx_parity_err to 1. */
-    zermio::BitField<7, 1, zermio::Permissions::Write> rx_parity_err;
+    zermio::BitField<7, 1, zermio::Permissions::Write, uint32_t> rx_parity_err;
     /* Write 1 to force !!INTR_STATE.tx_empty to 1. */
-    zermio::BitField<8, 1, zermio::Permissions::Write> tx_empty;
-    constexpr IntrTestReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 1, zermio::Permissions::Write, uint32_t> tx_empty;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntrTestReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrTestReg& fetch() {
         reg_.fetch();
         return *this;
@@ -118,34 +161,56 @@ union IntrTestReg {
 
 
 
+/* Enumerated values of the field RXBLVL. */
+enum class CtrlRxblvl: uint32_t {
+    /* 2 characters */
+    Break2 = 0x0,
+    /* 4 characters */
+    Break4 = 0x1,
+    /* 8 characters */
+    Break8 = 0x2,
+    /* 16 characters */
+    Break16 = 0x3,
+};
+
 /* UART control register */
 union CtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* TX enable */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> tx;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> tx;
     /* RX enable */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> rx;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx;
     /* RX noise filter enable.If the noise filter is enabled, RX line goes through the 3-taprepetition code. It ignores single IP clock period noise. */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> nf;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> nf;
     /* System loopback enable.If this bit is turned on, any outgoing bits to TX are received through RX.See Block Diagram. Note that the TX line goes 1 if System loopback is enabled. */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> slpbk;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> slpbk;
     /* Line loopback enable.If this bit is turned on, incoming bits are forwarded to TX for testing purpose.See Block Diagram. Note that the internal design sees RX value as 1 always if lineloopback is enabled. */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> llpbk;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> llpbk;
     /* If true, parity is enabled in both RX and TX directions. */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> parity_en;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> parity_en;
     /* If PARITY_EN is true, this determines the type, 1 for odd parity, 0 for even. */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> parity_odd;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> parity_odd;
     /* Trigger level for RX break detection. Sets the number of charactertimes the line must be low to detect a break. */
-    zermio::BitField<8, 2, zermio::Permissions::ReadWrite> rxblvl;
+    zermio::BitField<8, 2, zermio::Permissions::ReadWrite, uint32_t, CtrlRxblvl> rxblvl;
     /* BAUD clock rate control. */
-    zermio::BitField<16, 16, zermio::Permissions::ReadWrite> nco;
-    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> nco;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline CtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -157,25 +222,35 @@ union CtrlReg {
 /* UART live status register */
 union StatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* TX buffer is full */
-    zermio::BitField<0, 1, zermio::Permissions::Read> txfull;
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> txfull;
     /* RX buffer is full */
-    zermio::BitField<1, 1, zermio::Permissions::Read> rxfull;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rxfull;
     /* TX FIFO is empty */
-    zermio::BitField<2, 1, zermio::Permissions::Read> txempty;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> txempty;
     /* TX FIFO is empty and all bits have been transmitted */
-    zermio::BitField<3, 1, zermio::Permissions::Read> txidle;
+    zermio::BitField<3, 1, zermio::Permissions::Read, uint32_t> txidle;
     /* RX is idle */
-    zermio::BitField<4, 1, zermio::Permissions::Read> rxidle;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> rxidle;
     /* RX FIFO is empty */
-    zermio::BitField<5, 1, zermio::Permissions::Read> rxempty;
-    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> rxempty;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x3c;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline StatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -187,15 +262,25 @@ union StatusReg {
 /* UART read data */
 union RdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint8_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 8, zermio::Permissions::Read> rdata;
-    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint8_t> rdata;
+    /* Value of the register after a reset. */
+    static constexpr uint8_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint8_t reset_mask = 0xff;
+
+    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline RdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -207,15 +292,25 @@ union RdataReg {
 /* UART write data */
 union WdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint8_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 8, zermio::Permissions::Write> wdata;
-    constexpr WdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint8_t> wdata;
+    /* Value of the register after a reset. */
+    static constexpr uint8_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint8_t reset_mask = 0xff;
+
+    constexpr WdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline WdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -227,21 +322,31 @@ union WdataReg {
 /* UART FIFO control register */
 union FifoCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 */
-    zermio::BitField<0, 1, zermio::Permissions::Write> rxrst;
+    zermio::BitField<0, 1, zermio::Permissions::Write, uint32_t> rxrst;
     /* TX fifo reset. Write 1 to the register resets TX_FIFO. Read returns 0 */
-    zermio::BitField<1, 1, zermio::Permissions::Write> txrst;
+    zermio::BitField<1, 1, zermio::Permissions::Write, uint32_t> txrst;
     /* Trigger level for RX interrupts. If the FIFO depth is greater than or equal tothe setting, it raises rx_watermark interrupt. */
-    zermio::BitField<2, 3, zermio::Permissions::ReadWrite> rxilvl;
+    zermio::BitField<2, 3, zermio::Permissions::ReadWrite, uint32_t> rxilvl;
     /* Trigger level for TX interrupts. If the FIFO depth is less than the setting, itraises tx_watermark interrupt. */
-    zermio::BitField<5, 3, zermio::Permissions::ReadWrite> txilvl;
-    constexpr FifoCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<5, 3, zermio::Permissions::ReadWrite, uint32_t> txilvl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FifoCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FifoCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -253,17 +358,27 @@ union FifoCtrlReg {
 /* UART FIFO status register */
 union FifoStatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Current fill level of TX fifo */
-    zermio::BitField<0, 8, zermio::Permissions::Read> txlvl;
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> txlvl;
     /* Current fill level of RX fifo */
-    zermio::BitField<16, 8, zermio::Permissions::Read> rxlvl;
-    constexpr FifoStatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 8, zermio::Permissions::Read, uint32_t> rxlvl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FifoStatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FifoStatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -275,17 +390,27 @@ union FifoStatusReg {
 /* TX pin override control. Gives direct SW control over TX pin state */
 union OvrdReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Enable TX pin override control */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> txen;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> txen;
     /* Write to set the value of the TX pin */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> txval;
-    constexpr OvrdReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> txval;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr OvrdReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline OvrdReg& fetch() {
         reg_.fetch();
         return *this;
@@ -297,15 +422,25 @@ union OvrdReg {
 /* UART oversampled values */
 union ValReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Last 16 oversampled values of RX. Most recent bit is bit 0, oldest 15. */
-    zermio::BitField<0, 16, zermio::Permissions::Read> rx;
-    constexpr ValReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 16, zermio::Permissions::Read, uint32_t> rx;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr ValReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline ValReg& fetch() {
         reg_.fetch();
         return *this;
@@ -315,19 +450,32 @@ union ValReg {
 
 
 /* UART RX timeout control */
+/* Write enable: the hardware ignores the writes to this register while TIMEOUT_REGWEN.EN is clear. */
 union TimeoutCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t, true> reg_;
     public:
     /* RX timeout value in UART bit times */
-    zermio::BitField<0, 24, zermio::Permissions::ReadWrite> val;
+    zermio::BitField<0, 24, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::Modify, true> val;
     /* Enable RX timeout feature */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> en;
-    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::Modify, true> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
+    /* Shadowed register: every store instruction is run twice with the same value, as the
+     * hardware only takes it after two consecutive writes. */
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TimeoutCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -336,6 +484,81 @@ union TimeoutCtrlReg {
 
 
 
+/* Errors of the shadowed registers */
+union ErrCodeReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* The two writes to a shadowed register didn't match */
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> shadow_update_err;
+    /* The shadow copy of a shadowed register was corrupted */
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> shadow_storage_err;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr ErrCodeReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline ErrCodeReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Lock of the RX timeout configuration */
+union TimeoutRegwenReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* When cleared, TIMEOUT_CTRL can't be written until the next reset */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::ZeroToClear> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x1;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x1;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x1;
+
+    constexpr TimeoutRegwenReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline TimeoutRegwenReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline TimeoutRegwenReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+};
+
+
+
 /* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
 struct Uart {
     IntrStateReg intr_state;
@@ -350,6 +573,16 @@ struct Uart {
     OvrdReg ovrd;
     ValReg val;
     TimeoutCtrlReg timeout_ctrl;
+    ErrCodeReg err_code;
+    TimeoutRegwenReg timeout_regwen;
+    /* The window TX_BUF, 16 words of 32 bits.*/
+    zermio::Window<uint32_t, 16, zermio::Write> tx_buf;
+    /* Hardware parameter RX_FIFO_DEPTH. Number of entries of the RX FIFO*/
+    static constexpr uint32_t RxFifoDepth = 8;
+    /* Hardware parameter TX_FIFO_DEPTH.*/
+    static constexpr uint32_t TxFifoDepth = 8;
+    /* Hardware parameter NUM_ALERTS.*/
+    static constexpr uint32_t NumAlerts = 1;
     
     constexpr Uart (platform::Uart addr):
         intr_state(addr + 0x0), 
@@ -363,7 +596,23 @@ struct Uart {
         fifo_status(addr + 0x24), 
         ovrd(addr + 0x28), 
         val(addr + 0x2c), 
-        timeout_ctrl(addr + 0x30){}
+        timeout_ctrl(addr + 0x30), 
+        err_code(addr + 0x34), 
+        timeout_regwen(addr + 0x38), 
+        tx_buf(addr + 0x100){}
+
+    /* Fetch ERR_CODE and return whether SHADOW_UPDATE_ERR is set: The two writes to a shadowed register didn't match*/
+    inline bool shadow_update_err() { return err_code.fetch().shadow_update_err.is_set(); }
+
+    /* Fetch ERR_CODE and return whether SHADOW_STORAGE_ERR is set: The shadow copy of a shadowed register was corrupted*/
+    inline bool shadow_storage_err() { return err_code.fetch().shadow_storage_err.is_set(); }
+
+    /* Fetch TIMEOUT_REGWEN and only commit TIMEOUT_CTRL if EN is set, returns false otherwise. */
+    inline bool try_commit_timeout_ctrl() {
+        if (!timeout_regwen.fetch().en.is_set()) { return false; }
+        timeout_ctrl.commit();
+        return true;
+    }
 };
 
 
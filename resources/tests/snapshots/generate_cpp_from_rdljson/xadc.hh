@@ -13,15 +13,25 @@ namespace xadc {
 /*  */
 union ConfigReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint8_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> cfg;
-    constexpr ConfigReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint8_t> cfg;
+    /* Value of the register after a reset. */
+    static constexpr uint8_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint8_t reset_mask = 0xff;
+
+    constexpr ConfigReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline ConfigReg& fetch() {
         reg_.fetch();
         return *this;
@@ -33,15 +43,25 @@ union ConfigReg {
 /*  */
 union TestRegReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint8_t> reg_;
     public:
     /*  */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> tr;
-    constexpr TestRegReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint8_t> tr;
+    /* Value of the register after a reset. */
+    static constexpr uint8_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint8_t reset_mask = 0xff;
+
+    constexpr TestRegReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TestRegReg& fetch() {
         reg_.fetch();
         return *this;
@@ -52,22 +72,12 @@ union TestRegReg {
 
 /* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
 struct Xadc {
-    ConfigReg config0;
-    ConfigReg config1;
-    ConfigReg config2;
-    TestRegReg test_reg0;
-    TestRegReg test_reg1;
-    TestRegReg test_reg2;
-    TestRegReg test_reg3;
+    std::array<ConfigReg, 3> config;
+    std::array<TestRegReg, 4> test_reg;
     
     constexpr Xadc (platform::Xadc addr):
-        config0(addr + 0x40), 
-        config1(addr + 0x41), 
-        config2(addr + 0x42), 
-        test_reg0(addr + 0x43), 
-        test_reg1(addr + 0x44), 
-        test_reg2(addr + 0x45), 
-        test_reg3(addr + 0x46){}
+        config(zermio::make_array<ConfigReg, 3>(addr + 0x40, 0x1)), 
+        test_reg(zermio::make_array<TestRegReg, 4>(addr + 0x43, 0x1)){}
 };
 
 
@@ -0,0 +1,196 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace gpio {
+
+/* OUT */
+union OutReg { 
+    private:
+      zermio::Register<uint16_t> reg_;
+    public:
+    /* PINS */
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint16_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint16_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint16_t reset_mask = 0xffff;
+
+    constexpr OutReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline OutReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* OE */
+union OeReg { 
+    private:
+      zermio::Register<uint16_t> reg_;
+    public:
+    /* PINS */
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint16_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint16_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint16_t reset_mask = 0xffff;
+
+    constexpr OeReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline OeReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* DATA_IN0 */
+union DataInReg { 
+    private:
+      zermio::Register<uint16_t> reg_;
+    public:
+    /* PINS */
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint16_t> pins;
+    /* Value of the register after a reset. */
+    static constexpr uint16_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint16_t reset_mask = 0xffff;
+
+    constexpr DataInReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline DataInReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* CFG */
+union CfgReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Detect the rising edges. */
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> rising;
+    /* Detect the falling edges. */
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> falling;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CfgReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CfgReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* EVENTS */
+/* Reading this register has side effects:
+ * - EVENTS: Clear on read.
+ * Thus it can only be loaded explicitly with read_and_clear(). */
+union EventsReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* An edge was detected. */
+    zermio::BitField<0, 16, zermio::Permissions::Read, uint32_t> events;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr EventsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline EventsReg& read_and_clear() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Edge detection of a bank of pins. */
+struct BankCluster {
+    CfgReg cfg;
+    EventsReg events;
+    
+    constexpr BankCluster (uintptr_t addr):
+        cfg(addr + 0x0), 
+        events(addr + 0x4){}
+};
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Gpio {
+    OutReg out;
+    OeReg oe;
+    std::array<DataInReg, 2> data_in;
+    std::array<BankCluster, 2> bank;
+    
+    constexpr Gpio (platform::Gpio addr):
+        out(addr + 0x0), 
+        oe(addr + 0x2), 
+        data_in(zermio::make_array<DataInReg, 2>(addr + 0x8, 0x4)), 
+        bank(zermio::make_array<BankCluster, 2>(addr + 0x20, 0x10)){}
+};
+
+
+} // namespace gpio
+} // namespace mmio
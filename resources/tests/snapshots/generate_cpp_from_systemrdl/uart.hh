@@ -0,0 +1,258 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace uart {
+
+/* Interrupt state, write one to clear. */
+union IntrStateReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* The TX FIFO is empty. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> tx_empty;
+    /* The RX FIFO is full. */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_full;
+    /* A frame error was received. */
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> rx_frame_err;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0x7;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrStateReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntrStateReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrStateReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+};
+
+
+
+/* Enumerated values of the field PARITY. */
+enum class CtrlParity: uint32_t {
+    /* No parity bit. */
+    None = 0x0,
+    /* Even parity. */
+    Even = 0x1,
+    /* Odd parity. */
+    Odd = 0x2,
+};
+
+/* Control register. */
+union CtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Enable the transmitter. */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> tx_en;
+    /* Enable the receiver. */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> rx_en;
+    /* The parity of the frames. */
+    zermio::BitField<2, 2, zermio::Permissions::ReadWrite, uint32_t, CtrlParity> parity;
+    /* Clock divider of the baud rate. */
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> nco;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x680000;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Status register. */
+union StatusReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* The TX FIFO is full. */
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> txfull;
+    /* The RX FIFO is empty. */
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rxempty;
+    /* Number of bytes in the RX FIFO. */
+    zermio::BitField<8, 8, zermio::Permissions::Read, uint32_t> rxlvl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x2;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline StatusReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Received data, popped from the RX FIFO on read. */
+/* Reading this register has side effects:
+ * - DATA: Clear on read.
+ * Thus it can only be loaded explicitly with read_and_clear(). */
+union RdataReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* DATA */
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> data;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline RdataReg& read_and_clear() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Data to transmit. */
+union WdataReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* DATA */
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> data;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr WdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline WdataReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Configuration locked after the first write. */
+union LockReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* KEY */
+    zermio::BitField<0, 32, zermio::Permissions::WriteOnce, uint32_t> key;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr LockReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    /* Write the mmio unless this instance already did it, returns false otherwise. */
+    inline bool try_commit() { return reg_.try_commit(); }
+
+    /* Write the reset value unless this instance already wrote the mmio, returns false otherwise. */
+    inline bool reset() {
+        reg_.cache = reset_value;
+        return reg_.try_commit();
+    }
+
+    inline LockReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Uart {
+    IntrStateReg intr_state;
+    CtrlReg ctrl;
+    StatusReg status;
+    RdataReg rdata;
+    WdataReg wdata;
+    LockReg lock;
+    
+    constexpr Uart (platform::Uart addr):
+        intr_state(addr + 0x0), 
+        ctrl(addr + 0x4), 
+        status(addr + 0x8), 
+        rdata(addr + 0x10), 
+        wdata(addr + 0x14), 
+        lock(addr + 0x18){}
+};
+
+
+} // namespace uart
+} // namespace mmio
@@ -0,0 +1,43 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+
+MEMORY
+{
+  SRAM (rwx) : ORIGIN = 0x100000, LENGTH = 0x20000
+}
+
+/* Addresses for the devices of the type Gpio.*/
+PROVIDE(GPIO_BASE = 0x80000000);
+PROVIDE(GPIO_RPI_BASE = 0x80000010);
+PROVIDE(GPIO_ARDUINO_BASE = 0x80000020);
+PROVIDE(GPIO_PMOD_BASE = 0x80000030);
+PROVIDE(GPIO_PMODC_BASE = 0x80000040);
+
+/* Addresses for the devices of the type Pwm.*/
+PROVIDE(PWM_BASE = 0x80001000);
+
+/* Addresses for the devices of the type Xadc.*/
+PROVIDE(XADC_BASE = 0x8000b000);
+
+/* Addresses for the devices of the type Timer.*/
+PROVIDE(TIMER_BASE = 0x80040000);
+
+/* Addresses for the devices of the type Uart.*/
+PROVIDE(UART0_BASE = 0x80100000);
+PROVIDE(UART1_BASE = 0x80101000);
+PROVIDE(UART2_BASE = 0x80102000);
+
+/* Addresses for the devices of the type I2c.*/
+PROVIDE(I2C0_BASE = 0x80200000);
+PROVIDE(I2C1_BASE = 0x80201000);
+
+/* Addresses for the devices of the type Spi.*/
+PROVIDE(SPI_LCD_BASE = 0x80300000);
+PROVIDE(SPI_ETHMAC_BASE = 0x80301000);
+PROVIDE(SPI0_BASE = 0x80302000);
+PROVIDE(SPI1_BASE = 0x80303000);
+PROVIDE(SPI2_BASE = 0x80304000);
+
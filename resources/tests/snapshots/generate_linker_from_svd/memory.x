@@ -0,0 +1,30 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+
+MEMORY
+{
+  ROM (rx) : ORIGIN = 0x8000, LENGTH = 0x4000
+  SRAM (rwx) : ORIGIN = 0x10000000, LENGTH = 0x20000
+}
+
+/* Addresses for the devices of the type I2c.*/
+PROVIDE(I2C0_BASE = 0x80200000);
+PROVIDE(I2C1_BASE = 0x80200100);
+
+/* Addresses for the devices of the type Timer.*/
+PROVIDE(TIMER0_BASE = 0x80040000);
+PROVIDE(TIMER1_BASE = 0x80400100);
+
+/* Addresses for the devices of the type Prescaledtimer.*/
+PROVIDE(TIMER2_BASE = 0x80400200);
+
+/* Addresses for the devices of the type IoBank.*/
+PROVIDE(IO_BANK0_BASE = 0x40014000);
+
+/* Addresses for the devices of the type Pwm.*/
+PROVIDE(PWM0_BASE = 0x80500000);
+PROVIDE(PWM1_BASE = 0x80500100);
+
@@ -0,0 +1,1396 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the registers present in the gpio 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | INTR_STATE | Interrupt State Register | 0x0 | true |true |
+//! | INTR_ENABLE | Interrupt Enable Register | 0x4 | true |true |
+//! | INTR_TEST | Interrupt Test Register | 0x8 | true |false |
+//! | DATA_IN | GPIO Input data read value | 0xc | false |true |
+//! | INTR_CTRL_EN_RISING | GPIO interrupt enable for GPIO, rising edge. | 0x10 | true |true |
+//! | INP_PRD_CNT_CTRL0 | Control register of one input period counter. | 0x1c | true |true |
+//! | INP_PRD_CNT_CTRL1 | Control register of one input period counter. | 0x20 | true |true |
+//! | INP_PRD_CNT_VAL0 | Output value of the input period counter, cleared on read. | 0x24 | false |true |
+//! | INP_PRD_CNT_VAL1 | Output value of the input period counter, cleared on read. | 0x28 | false |true |
+
+use super::zermio;
+/// ``` rust
+/// gpio.intr_state.update(|intr_state| {
+///     if intr_state.gpio().read() == 1 {
+///        intr_state.gpio().write(0);
+///     }
+/// });
+/// ```
+pub struct Gpio {
+    /// Interrupt State Register  
+    pub intr_state : intr_state::IntrState,
+    /// Interrupt Enable Register  
+    pub intr_enable : intr_enable::IntrEnable,
+    /// Interrupt Test Register  
+    pub intr_test : intr_test::IntrTest,
+    /// GPIO Input data read value  
+    pub data_in : data_in::DataIn,
+    /// GPIO interrupt enable for GPIO, rising edge.  
+    pub intr_ctrl_en_rising : intr_ctrl_en_rising::IntrCtrlEnRising,
+    /// Control register of one input period counter.  
+    pub inp_prd_cnt_ctrl : [inp_prd_cnt_ctrl::InpPrdCntCtrl; 2],
+    /// Output value of the input period counter, cleared on read.  
+    pub inp_prd_cnt_val : [inp_prd_cnt_val::InpPrdCntVal; 2],
+}
+
+/// Hardware parameter NUM_IOS. Number of input/output pins.
+pub const NUM_IOS: u32 = 32;
+/// Hardware parameter NUM_INP_PERIOD_COUNTERS. Number of input period counters.
+pub const NUM_INP_PERIOD_COUNTERS: u32 = 2;
+
+impl Gpio {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        intr_state : intr_state::IntrState::new(addr + 0x0 ),
+        intr_enable : intr_enable::IntrEnable::new(addr + 0x4 ),
+        intr_test : intr_test::IntrTest::new(addr + 0x8 ),
+        data_in : data_in::DataIn::new(addr + 0xc ),
+        intr_ctrl_en_rising : intr_ctrl_en_rising::IntrCtrlEnRising::new(addr + 0x10 ),
+        inp_prd_cnt_ctrl : core::array::from_fn(|n| inp_prd_cnt_ctrl::InpPrdCntCtrl::new(addr + 0x1c + n as u32 * 0x4)),
+        inp_prd_cnt_val : core::array::from_fn(|n| inp_prd_cnt_val::InpPrdCntVal::new(addr + 0x24 + n as u32 * 0x4)),
+      }
+  }
+}
+
+
+
+/// Interrupt State Register.  
+pub use self::intr_state::*;
+mod intr_state {
+
+  use super::*;
+
+  /// # INTR_STATE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO | raised if any of GPIO pin detects configured interrupt mode | [31:0] | 0x0 | true |true |
+  pub struct IntrState {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0xffffffff;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: raised if any of GPIO pin detects configured interrupt mode
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio(&mut self) -> GpioField {
+        GpioField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type GpioField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+}
+
+
+
+/// Interrupt Enable Register.  
+pub use self::intr_enable::*;
+mod intr_enable {
+
+  use super::*;
+
+  /// # INTR_ENABLE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO | raised if any of GPIO pin detects configured interrupt mode | [31:0] | 0x0 | true |true |
+  pub struct IntrEnable {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrEnable {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: raised if any of GPIO pin detects configured interrupt mode
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio(&mut self) -> GpioField {
+        GpioField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type GpioField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt Test Register.  
+pub use self::intr_test::*;
+mod intr_test {
+
+  use super::*;
+
+  /// # INTR_TEST fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO | raised if any of GPIO pin detects configured interrupt mode | [31:0] | 0x0 | true |false |
+  pub struct IntrTest {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrTest {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: raised if any of GPIO pin detects configured interrupt mode
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio(&mut self) -> GpioField {
+        GpioField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type GpioField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::Write>;
+}
+
+
+
+/// GPIO Input data read value.  
+pub use self::data_in::*;
+mod data_in {
+
+  use super::*;
+
+  /// # DATA_IN fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | DATA_IN | DATA_IN | [31:0] | 0x0 | false |true |
+  pub struct DataIn {
+    reg: zermio::Register<u32>,
+  }
+
+  impl DataIn {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: DATA_IN
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn data_in(&mut self) -> DataInField {
+        DataInField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type DataInField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::Read>;
+}
+
+
+
+/// GPIO interrupt enable for GPIO, rising edge..  
+pub use self::intr_ctrl_en_rising::*;
+mod intr_ctrl_en_rising {
+
+  use super::*;
+
+  /// # INTR_CTRL_EN_RISING fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | EN_0 | Enable the rising edge interrupt of the pin. | [0:0] | 0x0 | true |true |
+  /// | EN_1 | Enable the rising edge interrupt of the pin. | [1:1] | 0x0 | true |true |
+  /// | EN_2 | Enable the rising edge interrupt of the pin. | [2:2] | 0x0 | true |true |
+  /// | EN_3 | Enable the rising edge interrupt of the pin. | [3:3] | 0x0 | true |true |
+  /// | EN_4 | Enable the rising edge interrupt of the pin. | [4:4] | 0x0 | true |true |
+  /// | EN_5 | Enable the rising edge interrupt of the pin. | [5:5] | 0x0 | true |true |
+  /// | EN_6 | Enable the rising edge interrupt of the pin. | [6:6] | 0x0 | true |true |
+  /// | EN_7 | Enable the rising edge interrupt of the pin. | [7:7] | 0x0 | true |true |
+  /// | EN_8 | Enable the rising edge interrupt of the pin. | [8:8] | 0x0 | true |true |
+  /// | EN_9 | Enable the rising edge interrupt of the pin. | [9:9] | 0x0 | true |true |
+  /// | EN_10 | Enable the rising edge interrupt of the pin. | [10:10] | 0x0 | true |true |
+  /// | EN_11 | Enable the rising edge interrupt of the pin. | [11:11] | 0x0 | true |true |
+  /// | EN_12 | Enable the rising edge interrupt of the pin. | [12:12] | 0x0 | true |true |
+  /// | EN_13 | Enable the rising edge interrupt of the pin. | [13:13] | 0x0 | true |true |
+  /// | EN_14 | Enable the rising edge interrupt of the pin. | [14:14] | 0x0 | true |true |
+  /// | EN_15 | Enable the rising edge interrupt of the pin. | [15:15] | 0x0 | true |true |
+  /// | EN_16 | Enable the rising edge interrupt of the pin. | [16:16] | 0x0 | true |true |
+  /// | EN_17 | Enable the rising edge interrupt of the pin. | [17:17] | 0x0 | true |true |
+  /// | EN_18 | Enable the rising edge interrupt of the pin. | [18:18] | 0x0 | true |true |
+  /// | EN_19 | Enable the rising edge interrupt of the pin. | [19:19] | 0x0 | true |true |
+  /// | EN_20 | Enable the rising edge interrupt of the pin. | [20:20] | 0x0 | true |true |
+  /// | EN_21 | Enable the rising edge interrupt of the pin. | [21:21] | 0x0 | true |true |
+  /// | EN_22 | Enable the rising edge interrupt of the pin. | [22:22] | 0x0 | true |true |
+  /// | EN_23 | Enable the rising edge interrupt of the pin. | [23:23] | 0x0 | true |true |
+  /// | EN_24 | Enable the rising edge interrupt of the pin. | [24:24] | 0x0 | true |true |
+  /// | EN_25 | Enable the rising edge interrupt of the pin. | [25:25] | 0x0 | true |true |
+  /// | EN_26 | Enable the rising edge interrupt of the pin. | [26:26] | 0x0 | true |true |
+  /// | EN_27 | Enable the rising edge interrupt of the pin. | [27:27] | 0x0 | true |true |
+  /// | EN_28 | Enable the rising edge interrupt of the pin. | [28:28] | 0x0 | true |true |
+  /// | EN_29 | Enable the rising edge interrupt of the pin. | [29:29] | 0x0 | true |true |
+  /// | EN_30 | Enable the rising edge interrupt of the pin. | [30:30] | 0x0 | true |true |
+  /// | EN_31 | Enable the rising edge interrupt of the pin. | [31:31] | 0x0 | true |true |
+  pub struct IntrCtrlEnRising {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrCtrlEnRising {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_0(&mut self) -> En0Field {
+        En0Field::new(&mut self.reg)
+    }
+
+  /// Field array EN: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns the element `n`, from 0 to 31, of the field array. It provides the same
+  /// functions of [`Self::en_0`], except for the ones to trigger write side
+  /// effects.
+  ///
+  /// # Panics
+  /// If `n` is out of range.
+    pub fn en(&mut self, n: usize) -> EnArray {
+        assert!(n < 32, "EN index out of range");
+        EnArray::new(&mut self.reg, 0 + n * 1)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_1(&mut self) -> En1Field {
+        En1Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_2(&mut self) -> En2Field {
+        En2Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_3(&mut self) -> En3Field {
+        En3Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_4(&mut self) -> En4Field {
+        En4Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_5(&mut self) -> En5Field {
+        En5Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_6(&mut self) -> En6Field {
+        En6Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_7(&mut self) -> En7Field {
+        En7Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_8(&mut self) -> En8Field {
+        En8Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_9(&mut self) -> En9Field {
+        En9Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_10(&mut self) -> En10Field {
+        En10Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_11(&mut self) -> En11Field {
+        En11Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_12(&mut self) -> En12Field {
+        En12Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_13(&mut self) -> En13Field {
+        En13Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_14(&mut self) -> En14Field {
+        En14Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_15(&mut self) -> En15Field {
+        En15Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_16(&mut self) -> En16Field {
+        En16Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_17(&mut self) -> En17Field {
+        En17Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_18(&mut self) -> En18Field {
+        En18Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_19(&mut self) -> En19Field {
+        En19Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_20(&mut self) -> En20Field {
+        En20Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_21(&mut self) -> En21Field {
+        En21Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_22(&mut self) -> En22Field {
+        En22Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_23(&mut self) -> En23Field {
+        En23Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_24(&mut self) -> En24Field {
+        En24Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_25(&mut self) -> En25Field {
+        En25Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_26(&mut self) -> En26Field {
+        En26Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_27(&mut self) -> En27Field {
+        En27Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_28(&mut self) -> En28Field {
+        En28Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_29(&mut self) -> En29Field {
+        En29Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_30(&mut self) -> En30Field {
+        En30Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the rising edge interrupt of the pin.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en_31(&mut self) -> En31Field {
+        En31Field::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type En0Field<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type En1Field<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type En2Field<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type En3Field<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type En4Field<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type En5Field<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type En6Field<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type En7Field<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type En8Field<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type En9Field<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type En10Field<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type En11Field<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type En12Field<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type En13Field<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type En14Field<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type En15Field<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type En16Field<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type En17Field<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type En18Field<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type En19Field<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type En20Field<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type En21Field<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type En22Field<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type En23Field<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type En24Field<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type En25Field<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type En26Field<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type En27Field<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type En28Field<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type En29Field<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type En30Field<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type En31Field<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type EnArray<'a> = zermio::IndexedBitField<'a, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Control register of one input period counter..  
+pub use self::inp_prd_cnt_ctrl::*;
+mod inp_prd_cnt_ctrl {
+
+  use super::*;
+
+  /// # INP_PRD_CNT_CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ENABLE | Enable the input period counter. | [0:0] | 0x0 | true |true |
+  /// | INPUT_SELECT | Index of the input whose period shall be counted. | [15:8] | 0x0 | true |true |
+  pub struct InpPrdCntCtrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl InpPrdCntCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Enable the input period counter.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn enable(&mut self) -> EnableField {
+        EnableField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Index of the input whose period shall be counted.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn input_select(&mut self) -> InputSelectField {
+        InputSelectField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type EnableField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type InputSelectField<'a> = zermio::BitField<'a, 8, 8, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Output value of the input period counter, cleared on read..  
+pub use self::inp_prd_cnt_val::*;
+mod inp_prd_cnt_val {
+
+  use super::*;
+
+  /// # INP_PRD_CNT_VAL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | VALUE | [31:0] | 0x0 | false |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * VALUE: Clear on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`InpPrdCntVal::read_and_clear`].
+  pub struct InpPrdCntVal {
+    reg: zermio::Register<u32>,
+  }
+
+  impl InpPrdCntVal {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: VALUE
+  ///
+  /// Side effect on read: Clear.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_and_clear(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::Read, (), zermio::effect::Modify, zermio::load::SideEffects>;
+}
@@ -0,0 +1,134 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+/* The best way to read and explor this documentation is by rendering it with:*/
+/*`cargo doc --open`*/
+
+#![no_std]
+
+//! # earlgrey Zermio
+//!
+//! `zermio` provides zero-cost abstractions for Memory-Mapped I/O (MMIO) access,
+//! focusing on performance and safety.
+//!
+//! ## Getting Started
+//!
+//! Following the Peripheral Access Crate (PAC) convention, you can take ownership 
+//! of hardware peripherals by calling **`take()`**. For contexts where ownership 
+//! is already managed or must be bypassed, the unsafe, infallible **`steal()`**
+//! function is available.
+//!
+//! ## The Register Model
+//!
+//! Peripherals are modeled as structs where each member represents a specific register.
+//! Access is controlled functions: a register provides specific methods only if it 
+//! is hardware-defined as readable or writable.
+//!
+//! Groups of registers, like the channels of a DMA, are modeled as cluster structs nested in the
+//! peripheral, and clusters with many instances as arrays of them, i.e `dma.ch[2].ctrl`.
+//!
+//! ### Lazy MMIO Operations
+//!
+//! To maximize performance and reduce unnecessary loads/stores, `zermio` employs 
+//! a lazy access pattern:
+//!
+//! * **`fetch()`**: Explicitly loads the value from physical MMIO into the local register struct.
+//! * **`commit()`**: Explicitly writes the current local value back to the physical MMIO.
+//!
+//! Registers with fields that are modified by a read, like clear on read status registers, don't
+//! provide `fetch()` and `update()`, nor do their fields. The load is only available through
+//! **`read_and_clear()`** (or **`read_with_side_effects()`** when the fields are not just
+//! cleared), so a debug read doesn't lose events by accident.
+//!
+//! ### Shorthand API: `write` and `update`
+//!
+//! For convenience, `zermio` provides higher-level methods that handle the fetch/commit 
+//! lifecycle using closures:
+//!
+//! * **`write(|reg| ...)`**: Creates a new value starting from the register reset value and
+//!     commits it to memory. Use **`write_with_zero(|reg| ...)`** to start from zero instead.
+//! * **`update(|reg| ...)`**: Fetches the current value, allows modification, and 
+//!     commits the result back to memory in a single logical block.
+//!
+//!   Fields with write side effects, like write one to clear interrupt flags, are written with a
+//!   neutral value by `update` and `write`, so only the fields modified by the closure take effect.
+//! * **`reset()`**: Commits the register reset value, also available as the `RESET_VALUE`
+//!     constant, to memory.
+//!
+//!   On the registers with write-once fields these methods return `AlreadyCommitted` instead of
+//!   running a second store, like **`try_commit()`**.
+//!
+//! ## Register Fields
+//!
+//! Additionally, every register provides access to specific **bitfields** through a function 
+//! with the same name as the field. Each bitfield is modeled as a generic `Bitfield` type, 
+//! with access strictly controlled by hardware-defined read/write permissions.
+//!
+//! The elements of a field array, i.e `PIN%s`, are available by name, like **`pin0()`**, and by
+//! index through a function named after the array, like **`pin(n)`**, which panics if `n` is out
+//! of range.
+//! 
+//!
+//! ### Bitfield API Reference
+//!
+//! These methods operate on the **local register buffer**. To reflect changes in the hardware, 
+//! **`commit()`** must be called (or the methods must be used within an **`update`** or **`write`** closure).
+//!
+//! | Method                | Description                                     | Type  | HW Access | 
+//! | :---                  | :---                                            | :---  | :-|
+//! | **`get()`**           | Returns the current value of the field.         | `u32` | R |
+//! | **`is_set()`**        | Returns `true` if the bit is 1.                 | `bool`| R |
+//! | **`write(val)`**      | Writes a multi-bit `val` to the field.          | `u32` | W |
+//! | **`clear()`**         | Sets all bits in the field to 0.                | -     | W |
+//! | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  | `u32` | W |
+//! | **`set()`**           | Sets a single-bit field to 1.                   | `bool`| W |
+//! | **`reset()`**         | Sets a single-bit field to 0.                   | `bool`| W |
+//! | **`toggle()`**        | Inverts the current state of a single-bit field.| `bool`| W |
+//! | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   | `bool`| W |
+//! | **`variant()`**       | Returns the field as its enumerated value, or the raw value if unknown. | `Result` | R |
+//! | **`write_variant(val)`** | Writes an enumerated value to the field.     | `enum`| W |
+//! | **`try_commit()`**    | Commits the register unless it was already committed. | `Result` | W once |
+//! | **`clear_flag()`**    | Writes only this field to clear it, i.e write one to clear. | - | W |
+//! | **`set_flag()`**      | Writes only this field to set it, i.e write one to set. | - | W |
+//! | **`toggle_flag()`**   | Writes only this field to toggle it, i.e write one to toggle. | - | W |
+//! | **`mask()`**          | Returns the bitmask for this field.             | `u32` | - |
+//! | **`max()`**           | Returns the maximum value this field can hold.  | `u32` | - |
+//!
+//! ## Memory Regions
+//!
+//! The memories of the platform, like a SRAM, are constants of the type `MemoryRegion` in the
+//! platform module. Unlike the registers they have no local buffer, **`read(n)`** and
+//! **`write(n, val)`** access the word `n` of the region and return `OutOfBounds` beyond its end.
+//! **`read_slice(n, buf)`** and **`write_slice(n, data)`** access several words starting at `n`.
+//!
+//! The windows of a device, like a FIFO or a buffer, are also `MemoryRegion` members of the
+//! device struct, with the width and access rights of the window.
+//!
+//! ## Example
+//! The following example is for the Opentitan Uart.
+//! ``` rust
+//! let peri = zermio::earlgrey::Peripherals::take().unwrap();
+//! let mut uart = peri.uart;
+//! let nco = (BAUD << 20) / CPU_CLOCK_HZ as u64;
+//! uart.ctrl.write(|ctrl| {
+//!     ctrl.nco().write(nco as u32);
+//!     ctrl.tx().set();
+//!     ctrl.rx().set();
+//! });
+//!   
+//! let txt = "Hello from EARLGREY";
+//! for c in txt.as_bytes(){
+//!     while uart.status.fetch().txfull().is_set() {}
+//!     uart.wdata.wdata().write(*c as u32).commit();
+//! }
+//! loop{}
+//! ```
+//! See the documentation at [`crate::earlgrey`] for all the peripherals available.
+
+pub mod zermio;
+pub mod earlgrey;
+pub mod uart;
+pub mod gpio;
+
@@ -0,0 +1,1262 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the windows present in the uart 
+//! | Window name        | Offset | Entries | Width | Write | Read| 
+//! | :---               | :---   | :---    | :---  | :--   | :-- |
+//! | TX_BUF | 0x40 | 8 | 32 | true |false |
+
+//! These are the registers present in the uart 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | INTR_STATE | Interrupt State Register | 0x0 | true |true |
+//! | INTR_ENABLE | Interrupt Enable Register | 0x4 | true |true |
+//! | INTR_TEST | Interrupt Test Register | 0x8 | true |false |
+//! | ALERT_TEST | Alert Test Register | 0xc | true |false |
+//! | CTRL | UART control register | 0x10 | true |true |
+//! | CTRL_REGWEN | Lock of the UART control register | 0x14 | true |true |
+//! | STATUS | UART live status register | 0x18 | false |true |
+//! | RDATA | UART read data | 0x1c | false |true |
+//! | WDATA | UART write data | 0x20 | true |false |
+//! | TIMEOUT_CTRL | UART RX timeout control | 0x24 | true |true |
+
+use super::zermio;
+/// ``` rust
+/// uart.intr_state.update(|intr_state| {
+///     if intr_state.tx_watermark().read() == 1 {
+///        intr_state.tx_watermark().write(0);
+///     }
+/// });
+/// ```
+pub struct Uart {
+    /// Interrupt State Register  
+    pub intr_state : intr_state::IntrState,
+    /// Interrupt Enable Register  
+    pub intr_enable : intr_enable::IntrEnable,
+    /// Interrupt Test Register  
+    pub intr_test : intr_test::IntrTest,
+    /// Alert Test Register  
+    pub alert_test : alert_test::AlertTest,
+    /// UART control register  
+    pub ctrl : ctrl::Ctrl,
+    /// Lock of the UART control register  
+    pub ctrl_regwen : ctrl_regwen::CtrlRegwen,
+    /// UART live status register  
+    pub status : status::Status,
+    /// UART read data  
+    pub rdata : rdata::Rdata,
+    /// UART write data  
+    pub wdata : wdata::Wdata,
+    /// UART RX timeout control  
+    pub timeout_ctrl : timeout_ctrl::TimeoutCtrl,
+    /// The window TX_BUF, 8 words of 32 bits.
+    pub tx_buf : zermio::MemoryRegion<u32, zermio::access::Write>,
+}
+
+/// Hardware parameter RX_FIFO_DEPTH. Number of bytes in the RX FIFO.
+pub const RX_FIFO_DEPTH: u32 = 64;
+/// Hardware parameter TX_FIFO_DEPTH. Number of bytes in the TX FIFO.
+pub const TX_FIFO_DEPTH: u32 = 32;
+
+impl Uart {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        intr_state : intr_state::IntrState::new(addr + 0x0 ),
+        intr_enable : intr_enable::IntrEnable::new(addr + 0x4 ),
+        intr_test : intr_test::IntrTest::new(addr + 0x8 ),
+        alert_test : alert_test::AlertTest::new(addr + 0xc ),
+        ctrl : ctrl::Ctrl::new(addr + 0x10 ),
+        ctrl_regwen : ctrl_regwen::CtrlRegwen::new(addr + 0x14 ),
+        status : status::Status::new(addr + 0x18 ),
+        rdata : rdata::Rdata::new(addr + 0x1c ),
+        wdata : wdata::Wdata::new(addr + 0x20 ),
+        timeout_ctrl : timeout_ctrl::TimeoutCtrl::new(addr + 0x24 ),
+        tx_buf : zermio::MemoryRegion::new((addr + 0x40) as usize, 0x20),
+      }
+  }
+
+  /// Fetch CTRL_REGWEN and only write CTRL if CTRL_REGWEN is set, as
+  /// the hardware silently ignores the writes otherwise.
+  pub fn try_write_ctrl<F>(&mut self, action: F) -> Result<(), zermio::Locked>
+  where
+    F: FnOnce(&mut ctrl::Ctrl),
+  {
+      if !self.ctrl_regwen.fetch().ctrl_regwen().is_set() {
+          return Err(zermio::Locked);
+      }
+      self.ctrl.write(action);
+      Ok(())
+  }
+}
+
+
+
+/// Interrupt State Register.  
+pub use self::intr_state::*;
+mod intr_state {
+
+  use super::*;
+
+  /// # INTR_STATE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_WATERMARK | raised if the transmit FIFO is past the high-water mark. | [0:0] | 0x0 | true |true |
+  /// | RX_WATERMARK | raised if the receive FIFO is past the high-water mark. | [1:1] | 0x0 | true |true |
+  /// | RX_OVERFLOW | raised if the receive FIFO has overflowed. | [2:2] | 0x0 | true |true |
+  pub struct IntrState {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x7;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: raised if the transmit FIFO is past the high-water mark.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_watermark(&mut self) -> TxWatermarkField {
+        TxWatermarkField::new(&mut self.reg)
+    }
+
+  /// Hardware description: raised if the receive FIFO is past the high-water mark.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_watermark(&mut self) -> RxWatermarkField {
+        RxWatermarkField::new(&mut self.reg)
+    }
+
+  /// Hardware description: raised if the receive FIFO has overflowed.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_overflow(&mut self) -> RxOverflowField {
+        RxOverflowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxWatermarkField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxOverflowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+}
+
+
+
+/// Interrupt Enable Register.  
+pub use self::intr_enable::*;
+mod intr_enable {
+
+  use super::*;
+
+  /// # INTR_ENABLE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_WATERMARK | raised if the transmit FIFO is past the high-water mark. | [0:0] | 0x0 | true |true |
+  /// | RX_WATERMARK | raised if the receive FIFO is past the high-water mark. | [1:1] | 0x0 | true |true |
+  /// | RX_OVERFLOW | raised if the receive FIFO has overflowed. | [2:2] | 0x0 | true |true |
+  pub struct IntrEnable {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrEnable {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: raised if the transmit FIFO is past the high-water mark.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_watermark(&mut self) -> TxWatermarkField {
+        TxWatermarkField::new(&mut self.reg)
+    }
+
+  /// Hardware description: raised if the receive FIFO is past the high-water mark.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_watermark(&mut self) -> RxWatermarkField {
+        RxWatermarkField::new(&mut self.reg)
+    }
+
+  /// Hardware description: raised if the receive FIFO has overflowed.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_overflow(&mut self) -> RxOverflowField {
+        RxOverflowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxWatermarkField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type RxOverflowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt Test Register.  
+pub use self::intr_test::*;
+mod intr_test {
+
+  use super::*;
+
+  /// # INTR_TEST fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_WATERMARK | raised if the transmit FIFO is past the high-water mark. | [0:0] | 0x0 | true |false |
+  /// | RX_WATERMARK | raised if the receive FIFO is past the high-water mark. | [1:1] | 0x0 | true |false |
+  /// | RX_OVERFLOW | raised if the receive FIFO has overflowed. | [2:2] | 0x0 | true |false |
+  pub struct IntrTest {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrTest {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: raised if the transmit FIFO is past the high-water mark.
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_watermark(&mut self) -> TxWatermarkField {
+        TxWatermarkField::new(&mut self.reg)
+    }
+
+  /// Hardware description: raised if the receive FIFO is past the high-water mark.
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_watermark(&mut self) -> RxWatermarkField {
+        RxWatermarkField::new(&mut self.reg)
+    }
+
+  /// Hardware description: raised if the receive FIFO has overflowed.
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_overflow(&mut self) -> RxOverflowField {
+        RxOverflowField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type TxWatermarkField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
+  type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Write>;
+  type RxOverflowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Write>;
+}
+
+
+
+/// Alert Test Register.  
+pub use self::alert_test::*;
+mod alert_test {
+
+  use super::*;
+
+  /// # ALERT_TEST fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FATAL_FAULT | This fatal alert is triggered when a fatal TL-UL bus integrity fault is detected. | [0:0] | 0x0 | true |false |
+  pub struct AlertTest {
+    reg: zermio::Register<u32>,
+  }
+
+  impl AlertTest {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: This fatal alert is triggered when a fatal TL-UL bus integrity fault is detected.
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fatal_fault(&mut self) -> FatalFaultField {
+        FatalFaultField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type FatalFaultField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
+}
+
+
+
+/// UART control register.  
+pub use self::ctrl::*;
+mod ctrl {
+
+  use super::*;
+
+  /// # CTRL fields.  
+  /// Reset value: 0x10000
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX | TX enable | [0:0] | 0x0 | true |true |
+  /// | RX | RX enable | [1:1] | 0x0 | true |true |
+  /// | RXBLVL | Trigger level for RX break detection. | [5:4] | 0x0 | true |true |
+  /// | NCO | BAUD clock rate control. | [31:16] | 0x1 | true |true |
+  ///
+  /// **Write enable:** the hardware ignores the writes to this register while
+  /// CTRL_REGWEN.CTRL_REGWEN is clear.
+  pub struct Ctrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x10000;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: TX enable
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx(&mut self) -> TxField {
+        TxField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RX enable
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx(&mut self) -> RxField {
+        RxField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Trigger level for RX break detection.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`CtrlRxblvl`]. |
+  /// | **`write_variant(val)`** | Writes a [`CtrlRxblvl`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxblvl(&mut self) -> RxblvlField {
+        RxblvlField::new(&mut self.reg)
+    }
+
+  /// Hardware description: BAUD clock rate control.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn nco(&mut self) -> NcoField {
+        NcoField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type RxField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type RxblvlField<'a> = zermio::BitField<'a, 4, 2, u32, zermio::access::ReadWrite, CtrlRxblvl>;
+  type NcoField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+
+  /// Enumerated values of the field RXBLVL: Trigger level for RX break detection.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum CtrlRxblvl {
+    /// 2 characters
+    Break2 = 0x0,
+    /// 4 characters
+    Break4 = 0x1,
+    /// 8 characters
+    Break8 = 0x2,
+    /// 16 characters
+    Break16 = 0x3,
+  }
+
+  impl From<CtrlRxblvl> for u32 {
+    fn from(variant: CtrlRxblvl) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for CtrlRxblvl {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Break2),
+        0x1 => Ok(Self::Break4),
+        0x2 => Ok(Self::Break8),
+        0x3 => Ok(Self::Break16),
+        _ => Err(value),
+      }
+    }
+  }
+}
+
+
+
+/// Lock of the UART control register.  
+pub use self::ctrl_regwen::*;
+mod ctrl_regwen {
+
+  use super::*;
+
+  /// # CTRL_REGWEN fields.  
+  /// Reset value: 0x1
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | CTRL_REGWEN | When cleared, CTRL can't be written until the next reset. | [0:0] | 0x1 | true |true |
+  pub struct CtrlRegwen {
+    reg: zermio::Register<u32>,
+  }
+
+  impl CtrlRegwen {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x1;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x1;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x1;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: When cleared, CTRL can't be written until the next reset.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (ZeroToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn ctrl_regwen(&mut self) -> CtrlRegwenField {
+        CtrlRegwenField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type CtrlRegwenField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::ZeroToClear>;
+}
+
+
+
+/// UART live status register.  
+pub use self::status::*;
+mod status {
+
+  use super::*;
+
+  /// # STATUS fields.  
+  /// Reset value: 0x14
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXFULL | TX buffer is full | [0:0] | 0x0 | false |true |
+  /// | RXFULL | RX buffer is full | [1:1] | 0x0 | false |true |
+  /// | TXEMPTY | TX FIFO is empty | [2:2] | 0x1 | false |true |
+  /// | RXIDLE | RX is idle | [4:4] | 0x1 | false |true |
+  pub struct Status {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x14;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: TX buffer is full
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txfull(&mut self) -> TxfullField {
+        TxfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RX buffer is full
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxfull(&mut self) -> RxfullField {
+        RxfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: TX FIFO is empty
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txempty(&mut self) -> TxemptyField {
+        TxemptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RX is idle
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxidle(&mut self) -> RxidleField {
+        RxidleField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type TxfullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+  type RxfullField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type TxemptyField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
+  type RxidleField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// UART read data.  
+pub use self::rdata::*;
+mod rdata {
+
+  use super::*;
+
+  /// # RDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RDATA | RDATA | [7:0] | 0x0 | false |true |
+  pub struct Rdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Rdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: RDATA
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rdata(&mut self) -> RdataField {
+        RdataField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type RdataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Read>;
+}
+
+
+
+/// UART write data.  
+pub use self::wdata::*;
+mod wdata {
+
+  use super::*;
+
+  /// # WDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | WDATA | WDATA | [7:0] | 0x0 | true |false |
+  pub struct Wdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Wdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: WDATA
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn wdata(&mut self) -> WdataField {
+        WdataField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type WdataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
+}
+
+
+
+/// UART RX timeout control.  
+pub use self::timeout_ctrl::*;
+mod timeout_ctrl {
+
+  use super::*;
+
+  /// # TIMEOUT_CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VAL | RX timeout value in UART bit times | [23:0] | 0x0 | true |true |
+  /// | EN | Enable RX timeout feature | [31:31] | 0x0 | true |true |
+  ///
+  /// **Shadowed register:** every store instruction is run twice with the same value, as the
+  /// hardware only takes it after two consecutive writes. A mismatch is reported as an update
+  /// error.
+  pub struct TimeoutCtrl {
+    reg: zermio::Register<u32, true>,
+  }
+
+  impl TimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32, true>::new(addr as usize),
+      }
+    }
+  /// Hardware description: RX timeout value in UART bit times
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn val(&mut self) -> ValField {
+        ValField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable RX timeout feature
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en(&mut self) -> EnField {
+        EnField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run two store instructions to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ValField<'a> = zermio::BitField<'a, 0, 24, u32, zermio::access::ReadWrite, (), zermio::effect::Modify, zermio::load::Plain, true>;
+  type EnField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite, (), zermio::effect::Modify, zermio::load::Plain, true>;
+}
@@ -0,0 +1,60 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+
+use super::uart;
+use super::gpio;
+
+pub enum UartOffset{
+  Uart0 = 0x41000000,
+  Uart1 = 0x40010000,
+  Uart2 = 0x40020000,
+}
+
+pub enum GpioOffset{
+  Gpio0 = 0x40040000,
+}
+
+
+pub enum Interrupt{
+  Uart0TxWatermark = 1,
+  Uart0RxWatermark = 2,
+  Uart0RxOverflow = 3,
+  Uart1TxWatermark = 4,
+  Uart1RxWatermark = 5,
+  Uart1RxOverflow = 6,
+  Uart2Irq = 7,
+  Gpio00 = 40,
+  Gpio01 = 41,
+}
+
+#[no_mangle]
+static mut DEVICE_PERIPHERALS: bool = false;
+
+pub struct Peripherals {
+  pub uart0: uart::Uart,
+  pub uart1: uart::Uart,
+  pub uart2: uart::Uart,
+  pub gpio0: gpio::Gpio,}
+
+impl Peripherals {
+  #[inline]
+  pub fn take() -> Option<Self> {
+    //TODO: Critical section
+    if unsafe { DEVICE_PERIPHERALS } {
+        return None;
+    }
+    Some(unsafe { Peripherals::steal() })
+  }
+  #[inline]
+  pub unsafe fn steal() -> Self {
+    DEVICE_PERIPHERALS = true;
+    Self {
+        uart0: uart::Uart::new(UartOffset::Uart0 as u32),
+        uart1: uart::Uart::new(UartOffset::Uart1 as u32),
+        uart2: uart::Uart::new(UartOffset::Uart2 as u32),
+        gpio0: gpio::Gpio::new(GpioOffset::Gpio0 as u32),}
+  }
+}
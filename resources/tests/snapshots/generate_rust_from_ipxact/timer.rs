@@ -0,0 +1,415 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the clusters present in the timer 
+//! | Cluster name       | Description  | Offset | Instances |
+//! | :---               | :---         | :---   | :--       |
+//! | CH | Compare channel. | 0x10 | 2 |
+
+//! These are the registers present in the timer 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | CTRL | Control register. | 0x0 | true |true |
+//! | COUNT | Current value of the counter. | 0x4 | false |true |
+
+use super::zermio;
+/// ``` rust
+/// timer.ctrl.update(|ctrl| {
+///     if ctrl.en().read() == 1 {
+///        ctrl.en().write(0);
+///     }
+/// });
+/// ```
+pub struct Timer {
+    /// Control register.  
+    pub ctrl : ctrl::Ctrl,
+    /// Current value of the counter.  
+    pub count : count::Count,
+    /// Compare channel.
+    pub ch : [ChCluster; 2],
+}
+
+impl Timer {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        ctrl : ctrl::Ctrl::new(addr + 0x0 ),
+        count : count::Count::new(addr + 0x4 ),
+        ch : core::array::from_fn(|n| ChCluster::new(addr + 0x10 + n as u32 * 0x8)),
+      }
+  }
+}
+
+/// Compare channel..  
+pub struct ChCluster {
+    /// Value compared with the counter.  
+    pub cmp : cmp::Cmp,
+    /// Match status of the channel.  
+    pub status : status::Status,
+}
+
+impl ChCluster {
+    pub fn new(addr: u32) -> Self {
+      Self {
+        cmp : cmp::Cmp::new(addr + 0x0 ),
+        status : status::Status::new(addr + 0x4 ),
+      }
+  }
+}
+
+
+
+/// Control register..  
+pub use self::ctrl::*;
+mod ctrl {
+
+  use super::*;
+
+  /// # CTRL fields.  
+  /// Reset value: 0x100
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | EN | EN | [0:0] | 0x0 | true |true |
+  /// | PRESCALER | Divider of the clock. | [15:8] | 0x1 | true |true |
+  /// | LOCK | Lock the configuration until the next reset. | [31:31] | 0x0 | true |false |
+  pub struct Ctrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x100;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: EN
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en(&mut self) -> EnField {
+        EnField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Divider of the clock.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn prescaler(&mut self) -> PrescalerField {
+        PrescalerField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Lock the configuration until the next reset.
+  ///
+  /// Returns a BitField with WriteOnce permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`try_commit()`**    | Writes the register unless it was already written. |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn lock(&mut self) -> LockField {
+        LockField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Run a store instruction to write the MMIO, unless this instance already did it. The
+    /// write-once fields of this register ignore any write after the first one.
+    pub fn try_commit(&mut self) -> Result<(), zermio::AlreadyCommitted> {
+      self.reg.try_commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO, unless this instance already did it.
+    pub fn write<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.try_commit()
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO, unless this instance already did it.
+    pub fn write_with_zero<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.try_commit()
+    }
+    /// Run a store instruction to write the reset value to the MMIO, unless this instance already
+    /// did it.
+    pub fn reset(&mut self) -> Result<(), zermio::AlreadyCommitted> {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.try_commit()
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The store is skipped if this instance already ran one, see [`Self::try_commit`].
+    pub fn update<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.try_commit()
+    }
+  }
+  type EnField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type PrescalerField<'a> = zermio::BitField<'a, 8, 8, u32, zermio::access::ReadWrite>;
+  type LockField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::WriteOnce>;
+}
+
+
+
+/// Current value of the counter..  
+pub use self::count::*;
+mod count {
+
+  use super::*;
+
+  /// # COUNT fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | VALUE | [31:0] | 0x0 | false |true |
+  pub struct Count {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Count {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: VALUE
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::Read>;
+}
+
+
+
+/// Value compared with the counter..  
+pub use self::cmp::*;
+mod cmp {
+
+  use super::*;
+
+  /// # CMP fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | VALUE | [31:0] | 0x0 | true |true |
+  pub struct Cmp {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Cmp {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: VALUE
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Match status of the channel..  
+pub use self::status::*;
+mod status {
+
+  use super::*;
+
+  /// # STATUS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | MATCHED | MATCHED | [0:0] | 0x0 | false |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * MATCHED: Clear on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`Status::read_and_clear`].
+  pub struct Status {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: MATCHED
+  ///
+  /// Side effect on read: Clear.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn matched(&mut self) -> MatchedField {
+        MatchedField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_and_clear(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type MatchedField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read, (), zermio::effect::Modify, zermio::load::SideEffects>;
+}
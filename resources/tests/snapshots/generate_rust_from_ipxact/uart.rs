@@ -0,0 +1,714 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the windows present in the uart 
+//! | Window name        | Offset | Entries | Width | Write | Read| 
+//! | :---               | :---   | :---    | :---  | :--   | :-- |
+//! | TX_FIFO | 0x800 | 16 | 32 | true |false |
+
+//! These are the registers present in the uart 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | CTRL | Control register. | 0x0 | true |true |
+//! | STATUS | Status register. | 0x4 | false |true |
+//! | INTR_STATE | Interrupt status, cleared by writing 1. | 0x8 | true |true |
+//! | RDATA | Received byte, popped from the FIFO when read. | 0xc | false |true |
+//! | WDATA | Byte to transmit. | 0x10 | true |false |
+//! | PATTERN0 | Characters that raise an event when received. | 0x20 | true |true |
+//! | PATTERN1 | Characters that raise an event when received. | 0x24 | true |true |
+//! | PATTERN2 | Characters that raise an event when received. | 0x28 | true |true |
+//! | PATTERN3 | Characters that raise an event when received. | 0x2c | true |true |
+
+use super::zermio;
+/// ``` rust
+/// uart.ctrl.update(|ctrl| {
+///     if ctrl.tx_en().read() == 1 {
+///        ctrl.tx_en().write(0);
+///     }
+/// });
+/// ```
+pub struct Uart {
+    /// Control register.  
+    pub ctrl : ctrl::Ctrl,
+    /// Status register.  
+    pub status : status::Status,
+    /// Interrupt status, cleared by writing 1.  
+    pub intr_state : intr_state::IntrState,
+    /// Received byte, popped from the FIFO when read.  
+    pub rdata : rdata::Rdata,
+    /// Byte to transmit.  
+    pub wdata : wdata::Wdata,
+    /// Characters that raise an event when received.  
+    pub pattern : [pattern::Pattern; 4],
+    /// The window TX_FIFO, 16 words of 32 bits.
+    pub tx_fifo : zermio::MemoryRegion<u32, zermio::access::Write>,
+}
+
+impl Uart {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        ctrl : ctrl::Ctrl::new(addr + 0x0 ),
+        status : status::Status::new(addr + 0x4 ),
+        intr_state : intr_state::IntrState::new(addr + 0x8 ),
+        rdata : rdata::Rdata::new(addr + 0xc ),
+        wdata : wdata::Wdata::new(addr + 0x10 ),
+        pattern : core::array::from_fn(|n| pattern::Pattern::new(addr + 0x20 + n as u32 * 0x4)),
+        tx_fifo : zermio::MemoryRegion::new((addr + 0x800) as usize, 0x40),
+      }
+  }
+}
+
+
+
+/// Control register..  
+pub use self::ctrl::*;
+mod ctrl {
+
+  use super::*;
+
+  /// # CTRL fields.  
+  /// Reset value: 0x1b20002
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_EN | Enable the transmitter. | [0:0] | 0x0 | true |true |
+  /// | RX_EN | Enable the receiver. | [1:1] | 0x1 | true |true |
+  /// | PARITY | Parity of the frames. | [5:4] | 0x0 | true |true |
+  /// | NCO | Baud rate of the bus. | [31:16] | 0x1b2 | true |true |
+  pub struct Ctrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x1b20002;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Enable the transmitter.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_en(&mut self) -> TxEnField {
+        TxEnField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the receiver.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_en(&mut self) -> RxEnField {
+        RxEnField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Parity of the frames.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`CtrlParity`]. |
+  /// | **`write_variant(val)`** | Writes a [`CtrlParity`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn parity(&mut self) -> ParityField {
+        ParityField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Baud rate of the bus.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn nco(&mut self) -> NcoField {
+        NcoField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxEnField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type RxEnField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type ParityField<'a> = zermio::BitField<'a, 4, 2, u32, zermio::access::ReadWrite, CtrlParity>;
+  type NcoField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+
+  /// Enumerated values of the field PARITY: Parity of the frames.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum CtrlParity {
+    /// NONE
+    None = 0x0,
+    /// Even parity.
+    Even = 0x1,
+    /// Odd parity.
+    Odd = 0x2,
+  }
+
+  impl From<CtrlParity> for u32 {
+    fn from(variant: CtrlParity) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for CtrlParity {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::None),
+        0x1 => Ok(Self::Even),
+        0x2 => Ok(Self::Odd),
+        _ => Err(value),
+      }
+    }
+  }
+}
+
+
+
+/// Status register..  
+pub use self::status::*;
+mod status {
+
+  use super::*;
+
+  /// # STATUS fields.  
+  /// Reset value: 0x2
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXFULL | TXFULL | [0:0] | 0x0 | false |true |
+  /// | RXEMPTY | RXEMPTY | [1:1] | 0x1 | false |true |
+  pub struct Status {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x2;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: TXFULL
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txfull(&mut self) -> TxfullField {
+        TxfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RXEMPTY
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxempty(&mut self) -> RxemptyField {
+        RxemptyField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type TxfullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+  type RxemptyField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// Interrupt status, cleared by writing 1..  
+pub use self::intr_state::*;
+mod intr_state {
+
+  use super::*;
+
+  /// # INTR_STATE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_EMPTY | TX_EMPTY | [0:0] | 0x0 | true |true |
+  /// | RX_FULL | RX_FULL | [1:1] | 0x0 | true |true |
+  pub struct IntrState {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x3;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: TX_EMPTY
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_empty(&mut self) -> TxEmptyField {
+        TxEmptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RX_FULL
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_full(&mut self) -> RxFullField {
+        RxFullField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxEmptyField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxFullField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+}
+
+
+
+/// Received byte, popped from the FIFO when read..  
+pub use self::rdata::*;
+mod rdata {
+
+  use super::*;
+
+  /// # RDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | VALUE | [7:0] | 0x0 | false |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * VALUE: Modify on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`Rdata::read_with_side_effects`].
+  pub struct Rdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Rdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: VALUE
+  ///
+  /// Side effect on read: Modify.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_with_side_effects(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Read, (), zermio::effect::Modify, zermio::load::SideEffects>;
+}
+
+
+
+/// Byte to transmit..  
+pub use self::wdata::*;
+mod wdata {
+
+  use super::*;
+
+  /// # WDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | VALUE | [7:0] | 0x0 | true |false |
+  pub struct Wdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Wdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: VALUE
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
+}
+
+
+
+/// Characters that raise an event when received..  
+pub use self::pattern::*;
+mod pattern {
+
+  use super::*;
+
+  /// # PATTERN fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | CHARACTER | CHARACTER | [7:0] | 0x0 | true |true |
+  /// | EN | EN | [31:31] | 0x0 | true |true |
+  pub struct Pattern {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Pattern {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: CHARACTER
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn character(&mut self) -> CharacterField {
+        CharacterField::new(&mut self.reg)
+    }
+
+  /// Hardware description: EN
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en(&mut self) -> EnField {
+        EnField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type CharacterField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::ReadWrite>;
+  type EnField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+}
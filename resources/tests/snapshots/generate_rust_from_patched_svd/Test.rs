@@ -0,0 +1,94 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+/*
+ * The svd was patched with i2c.toml:
+ *  - field I2C0.CTRL.HOSTRST: added at [3:3]
+ *  - field I2C0.CTRL.LLPBK: renamed to LOOPBACK, description, values DISABLED, ENABLED
+ *  - field I2C0.STATUS.*EMPTY: reset 0x1
+ *  - field I2C0.FDATA.NAKOK: deleted
+ *  - field TIMER2.CTRL.ONESHOT,PRESCALE: access read-only
+ *  - register I2C*.RDATA: renamed to RXDATA
+ *  - register TIMER*.MTIMECMP?: reset value 0xffffffff
+ *  - peripheral I2C1: renamed to I2C_AON
+ *  - peripheral PWM*: deleted
+ */
+
+
+use super::i2c;
+use super::timer;
+use super::prescaledtimer;
+use super::io_bank;
+use super::zermio;
+
+pub enum I2cOffset{
+  I2c0 = 0x80200000,
+  I2cAon = 0x80200100,
+}
+
+pub enum TimerOffset{
+  Timer0 = 0x80040000,
+  Timer1 = 0x80400100,
+}
+
+pub enum PrescaledtimerOffset{
+  Timer2 = 0x80400200,
+}
+
+pub enum IoBankOffset{
+  IoBank0 = 0x40014000,
+}
+
+
+pub enum Interrupt{
+  I2c0EventIrq = 2,
+  I2c0ErrorIrq = 3,
+  I2cAonEventIrq = 3,
+  Timer0FinishIrq = 10,
+  Timer1FinishIrq = 10,
+  Timer2FinishIrq = 11,
+  IoBank0IoIrqBank0 = 13,
+}
+
+/// ROM, 4096 words of 32 bits.
+pub const ROM: zermio::MemoryRegion<u32, zermio::access::Read> =
+  zermio::MemoryRegion::new(0x8000, 0x4000);
+
+/// SRAM, 32768 words of 32 bits.
+pub const SRAM: zermio::MemoryRegion<u32, zermio::access::ReadWrite> =
+  zermio::MemoryRegion::new(0x10000000, 0x20000);
+
+#[no_mangle]
+static mut DEVICE_PERIPHERALS: bool = false;
+
+pub struct Peripherals {
+  pub i2c0: i2c::I2c,
+  pub i2c_aon: i2c::I2c,
+  pub timer0: timer::Timer,
+  pub timer1: timer::Timer,
+  pub timer2: prescaledtimer::Prescaledtimer,
+  pub io_bank0: io_bank::IoBank,}
+
+impl Peripherals {
+  #[inline]
+  pub fn take() -> Option<Self> {
+    //TODO: Critical section
+    if unsafe { DEVICE_PERIPHERALS } {
+        return None;
+    }
+    Some(unsafe { Peripherals::steal() })
+  }
+  #[inline]
+  pub unsafe fn steal() -> Self {
+    DEVICE_PERIPHERALS = true;
+    Self {
+        i2c0: i2c::I2c::new(I2cOffset::I2c0 as u32),
+        i2c_aon: i2c::I2c::new(I2cOffset::I2cAon as u32),
+        timer0: timer::Timer::new(TimerOffset::Timer0 as u32),
+        timer1: timer::Timer::new(TimerOffset::Timer1 as u32),
+        timer2: prescaledtimer::Prescaledtimer::new(PrescaledtimerOffset::Timer2 as u32),
+        io_bank0: io_bank::IoBank::new(IoBankOffset::IoBank0 as u32),}
+  }
+}
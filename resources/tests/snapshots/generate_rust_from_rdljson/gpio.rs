@@ -54,14 +54,21 @@ mod out {
   use super::*;
 
   /// # OUT fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | PINS | Pins. | [31:0] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | Pins. | [31:0] | 0x0 | true |false |
   pub struct Out {
     reg: zermio::Register<u32>,
   }
 
   impl Out {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -86,14 +93,31 @@ mod out {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type PinsField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::Write>;
 }
@@ -107,14 +131,21 @@ mod in {
   use super::*;
 
   /// # IN fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | PINS | Pins. | [31:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | Pins. | [31:0] | 0x0 | false |true |
   pub struct In {
     reg: zermio::Register<u32>,
   }
 
   impl In {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -151,14 +182,21 @@ mod in_dbnc {
   use super::*;
 
   /// # IN_DBNC fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | PINS | DBNC. | [31:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | DBNC. | [31:0] | 0x0 | false |true |
   pub struct InDbnc {
     reg: zermio::Register<u32>,
   }
 
   impl InDbnc {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -195,14 +233,21 @@ mod output_enable {
   use super::*;
 
   /// # OUTPUT_ENABLE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | PINS | val | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | val | [31:0] | 0x0 | true |true |
   pub struct OutputEnable {
     reg: zermio::Register<u32>,
   }
 
   impl OutputEnable {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -233,14 +278,31 @@ mod output_enable {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -248,7 +310,8 @@ mod output_enable {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type PinsField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
@@ -6,6 +6,11 @@
 //zermio
 
 
+//! These are the windows present in the i2c 
+//! | Window name        | Offset | Entries | Width | Write | Read| 
+//! | :---               | :---   | :---    | :---  | :--   | :-- |
+//! | RX_BUF | 0x200 | 64 | 8 | false |true |
+
 //! These are the registers present in the i2c 
 //! | Register name      | Description  | Offset | Write | Read| 
 //! | :---               | :---         | :---   | :--   | :-- |
@@ -112,8 +117,17 @@ pub struct I2c {
     pub controller_events : controller_events::ControllerEvents,
     /// Latched events that can cause the target module to stretch the clock at the beginning of a read transfer.These events cause TX FIFO-related stretching even when the TX FIFO has data available.Any bits that are set must be written (with a 1) to clear the tx_stretch interrupt.This CSR serves as a gate to prevent the Target module from responding to a read command with unrelated, leftover data.  
     pub target_events : target_events::TargetEvents,
+    /// The window RX_BUF, 64 words of 8 bits.
+    pub rx_buf : zermio::MemoryRegion<u8, zermio::access::Read>,
 }
 
+/// Hardware parameter FIFO_DEPTH.
+pub const FIFO_DEPTH: u32 = 8;
+/// Hardware parameter ACQ_FIFO_DEPTH.
+pub const ACQ_FIFO_DEPTH: u32 = 8;
+/// Hardware parameter NUM_ALERTS.
+pub const NUM_ALERTS: u32 = 1;
+
 impl I2c {
     pub fn new(instance: u32) -> Self {
     let addr = instance as u32;
@@ -149,6 +163,7 @@ impl I2c {
         host_nack_handler_timeout : host_nack_handler_timeout::HostNackHandlerTimeout::new(addr + 0x74 ),
         controller_events : controller_events::ControllerEvents::new(addr + 0x78 ),
         target_events : target_events::TargetEvents::new(addr + 0x7c ),
+        rx_buf : zermio::MemoryRegion::new((addr + 0x200) as usize, 0x40),
       }
   }
 }
@@ -162,31 +177,46 @@ mod intr_state {
   use super::*;
 
   /// # INTR_STATE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | FMT_THRESHOLD | host mode interrupt: asserted whilst the FMT FIFO level is below the low threshold. This is a level status interrupt. | [0:0] | false |true |
-  /// | RX_THRESHOLD | host mode interrupt: asserted whilst the RX FIFO level is above the high threshold. This is a level status interrupt. | [1:1] | false |true |
-  /// | ACQ_THRESHOLD | target mode interrupt: asserted whilst the ACQ FIFO level is above the high threshold. This is a level status interrupt. | [2:2] | false |true |
-  /// | RX_OVERFLOW | host mode interrupt: raised if the RX FIFO has overflowed. | [3:3] | true |true |
-  /// | CONTROLLER_HALT | host mode interrupt: raised if the controller FSM is halted, such as on an unexpected NACK or lost arbitration.Check !!CONTROLLER_EVENTS for the reason.The interrupt will be released when the bits in !!CONTROLLER_EVENTS are cleared. | [4:4] | false |true |
-  /// | SCL_INTERFERENCE | host mode interrupt: raised if the SCL line drops early (not supported without clock synchronization). | [5:5] | true |true |
-  /// | SDA_INTERFERENCE | host mode interrupt: raised if the SDA line goes low when host is trying to assert high | [6:6] | true |true |
-  /// | STRETCH_TIMEOUT | host mode interrupt: raised if target stretches the clock beyond the allowed timeout period | [7:7] | true |true |
-  /// | SDA_UNSTABLE | host mode interrupt: raised if the target does not assert a constant value of SDA during transmission. | [8:8] | true |true |
-  /// | CMD_COMPLETE | host and target mode interrupt.In host mode, raised if the host issues a repeated START or terminates the transaction by issuing STOP.In target mode, raised if the external host issues a STOP or repeated START. | [9:9] | true |true |
-  /// | TX_STRETCH | target mode interrupt: raised if the target is stretching clocks for a read command. This is a level status interrupt. | [10:10] | false |true |
-  /// | TX_THRESHOLD | target mode interrupt: asserted whilst the TX FIFO level is below the low threshold. This is a level status interrupt. | [11:11] | false |true |
-  /// | ACQ_STRETCH | target mode interrupt: raised if the target is stretching clocks due to full ACQ FIFO or zero count in !!TARGET_ACK_CTRL.NBYTES (if enabled). This is a level status interrupt. | [12:12] | false |true |
-  /// | UNEXP_STOP | target mode interrupt: raised if STOP is received without a preceding NACK during an external host read. | [13:13] | true |true |
-  /// | HOST_TIMEOUT | target mode interrupt: raised if the host stops sending the clock during an ongoing transaction. | [14:14] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMT_THRESHOLD | host mode interrupt: asserted whilst the FMT FIFO level is below the low threshold. This is a level status interrupt. | [0:0] | 0x0 | false |true |
+  /// | RX_THRESHOLD | host mode interrupt: asserted whilst the RX FIFO level is above the high threshold. This is a level status interrupt. | [1:1] | 0x0 | false |true |
+  /// | ACQ_THRESHOLD | target mode interrupt: asserted whilst the ACQ FIFO level is above the high threshold. This is a level status interrupt. | [2:2] | 0x0 | false |true |
+  /// | RX_OVERFLOW | host mode interrupt: raised if the RX FIFO has overflowed. | [3:3] | 0x0 | true |true |
+  /// | CONTROLLER_HALT | host mode interrupt: raised if the controller FSM is halted, such as on an unexpected NACK or lost arbitration.Check !!CONTROLLER_EVENTS for the reason.The interrupt will be released when the bits in !!CONTROLLER_EVENTS are cleared. | [4:4] | 0x0 | false |true |
+  /// | SCL_INTERFERENCE | host mode interrupt: raised if the SCL line drops early (not supported without clock synchronization). | [5:5] | 0x0 | true |true |
+  /// | SDA_INTERFERENCE | host mode interrupt: raised if the SDA line goes low when host is trying to assert high | [6:6] | 0x0 | true |true |
+  /// | STRETCH_TIMEOUT | host mode interrupt: raised if target stretches the clock beyond the allowed timeout period | [7:7] | 0x0 | true |true |
+  /// | SDA_UNSTABLE | host mode interrupt: raised if the target does not assert a constant value of SDA during transmission. | [8:8] | 0x0 | true |true |
+  /// | CMD_COMPLETE | host and target mode interrupt.In host mode, raised if the host issues a repeated START or terminates the transaction by issuing STOP.In target mode, raised if the external host issues a STOP or repeated START. | [9:9] | 0x0 | true |true |
+  /// | TX_STRETCH | target mode interrupt: raised if the target is stretching clocks for a read command. This is a level status interrupt. | [10:10] | 0x0 | false |true |
+  /// | TX_THRESHOLD | target mode interrupt: asserted whilst the TX FIFO level is below the low threshold. This is a level status interrupt. | [11:11] | 0x0 | false |true |
+  /// | ACQ_STRETCH | target mode interrupt: raised if the target is stretching clocks due to full ACQ FIFO or zero count in !!TARGET_ACK_CTRL.NBYTES (if enabled). This is a level status interrupt. | [12:12] | 0x0 | false |true |
+  /// | UNEXP_STOP | target mode interrupt: raised if STOP is received without a preceding NACK during an external host read. | [13:13] | 0x0 | true |true |
+  /// | HOST_TIMEOUT | target mode interrupt: raised if the host stops sending the clock during an ongoing transaction. | [14:14] | 0x0 | true |true |
   pub struct IntrState {
     reg: zermio::Register<u32>,
   }
 
   impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x63e8;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
       }
     }
   /// Hardware description: host mode interrupt: asserted whilst the FMT FIFO level is below the low threshold. This is a level status interrupt.
@@ -245,6 +275,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -280,6 +311,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -301,6 +333,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -322,6 +355,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -343,6 +377,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -364,6 +399,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -427,6 +463,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -448,6 +485,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -464,39 +502,63 @@ mod intr_state {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
     pub fn update<F>(&mut self, action: F)
     where
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
     }
   }
   type FmtThresholdField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
   type RxThresholdField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
   type AcqThresholdField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
-  type RxOverflowField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type RxOverflowField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
   type ControllerHaltField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
-  type SclInterferenceField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
-  type SdaInterferenceField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
-  type StretchTimeoutField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
-  type SdaUnstableField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
-  type CmdCompleteField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type SclInterferenceField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type SdaInterferenceField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type StretchTimeoutField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type SdaUnstableField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type CmdCompleteField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
   type TxStretchField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::Read>;
   type TxThresholdField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::Read>;
   type AcqStretchField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Read>;
-  type UnexpStopField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
-  type HostTimeoutField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type UnexpStopField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type HostTimeoutField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
 }
 
 
@@ -508,28 +570,35 @@ mod intr_enable {
   use super::*;
 
   /// # INTR_ENABLE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | FMT_THRESHOLD | Enable interrupt when !!INTR_STATE.fmt_threshold is set. | [0:0] | true |true |
-  /// | RX_THRESHOLD | Enable interrupt when !!INTR_STATE.rx_threshold is set. | [1:1] | true |true |
-  /// | ACQ_THRESHOLD | Enable interrupt when !!INTR_STATE.acq_threshold is set. | [2:2] | true |true |
-  /// | RX_OVERFLOW | Enable interrupt when !!INTR_STATE.rx_overflow is set. | [3:3] | true |true |
-  /// | CONTROLLER_HALT | Enable interrupt when !!INTR_STATE.controller_halt is set. | [4:4] | true |true |
-  /// | SCL_INTERFERENCE | Enable interrupt when !!INTR_STATE.scl_interference is set. | [5:5] | true |true |
-  /// | SDA_INTERFERENCE | Enable interrupt when !!INTR_STATE.sda_interference is set. | [6:6] | true |true |
-  /// | STRETCH_TIMEOUT | Enable interrupt when !!INTR_STATE.stretch_timeout is set. | [7:7] | true |true |
-  /// | SDA_UNSTABLE | Enable interrupt when !!INTR_STATE.sda_unstable is set. | [8:8] | true |true |
-  /// | CMD_COMPLETE | Enable interrupt when !!INTR_STATE.cmd_complete is set. | [9:9] | true |true |
-  /// | TX_STRETCH | Enable interrupt when !!INTR_STATE.tx_stretch is set. | [10:10] | true |true |
-  /// | TX_THRESHOLD | Enable interrupt when !!INTR_STATE.tx_threshold is set. | [11:11] | true |true |
-  /// | ACQ_STRETCH | Enable interrupt when !!INTR_STATE.acq_stretch is set. | [12:12] | true |true |
-  /// | UNEXP_STOP | Enable interrupt when !!INTR_STATE.unexp_stop is set. | [13:13] | true |true |
-  /// | HOST_TIMEOUT | Enable interrupt when !!INTR_STATE.host_timeout is set. | [14:14] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMT_THRESHOLD | Enable interrupt when !!INTR_STATE.fmt_threshold is set. | [0:0] | 0x0 | true |true |
+  /// | RX_THRESHOLD | Enable interrupt when !!INTR_STATE.rx_threshold is set. | [1:1] | 0x0 | true |true |
+  /// | ACQ_THRESHOLD | Enable interrupt when !!INTR_STATE.acq_threshold is set. | [2:2] | 0x0 | true |true |
+  /// | RX_OVERFLOW | Enable interrupt when !!INTR_STATE.rx_overflow is set. | [3:3] | 0x0 | true |true |
+  /// | CONTROLLER_HALT | Enable interrupt when !!INTR_STATE.controller_halt is set. | [4:4] | 0x0 | true |true |
+  /// | SCL_INTERFERENCE | Enable interrupt when !!INTR_STATE.scl_interference is set. | [5:5] | 0x0 | true |true |
+  /// | SDA_INTERFERENCE | Enable interrupt when !!INTR_STATE.sda_interference is set. | [6:6] | 0x0 | true |true |
+  /// | STRETCH_TIMEOUT | Enable interrupt when !!INTR_STATE.stretch_timeout is set. | [7:7] | 0x0 | true |true |
+  /// | SDA_UNSTABLE | Enable interrupt when !!INTR_STATE.sda_unstable is set. | [8:8] | 0x0 | true |true |
+  /// | CMD_COMPLETE | Enable interrupt when !!INTR_STATE.cmd_complete is set. | [9:9] | 0x0 | true |true |
+  /// | TX_STRETCH | Enable interrupt when !!INTR_STATE.tx_stretch is set. | [10:10] | 0x0 | true |true |
+  /// | TX_THRESHOLD | Enable interrupt when !!INTR_STATE.tx_threshold is set. | [11:11] | 0x0 | true |true |
+  /// | ACQ_STRETCH | Enable interrupt when !!INTR_STATE.acq_stretch is set. | [12:12] | 0x0 | true |true |
+  /// | UNEXP_STOP | Enable interrupt when !!INTR_STATE.unexp_stop is set. | [13:13] | 0x0 | true |true |
+  /// | HOST_TIMEOUT | Enable interrupt when !!INTR_STATE.host_timeout is set. | [14:14] | 0x0 | true |true |
   pub struct IntrEnable {
     reg: zermio::Register<u32>,
   }
 
   impl IntrEnable {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -859,14 +928,31 @@ mod intr_enable {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -874,7 +960,8 @@ mod intr_enable {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type FmtThresholdField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -903,28 +990,35 @@ mod intr_test {
   use super::*;
 
   /// # INTR_TEST fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | FMT_THRESHOLD | Write 1 to force !!INTR_STATE.fmt_threshold to 1. | [0:0] | true |false |
-  /// | RX_THRESHOLD | Write 1 to force !!INTR_STATE.rx_threshold to 1. | [1:1] | true |false |
-  /// | ACQ_THRESHOLD | Write 1 to force !!INTR_STATE.acq_threshold to 1. | [2:2] | true |false |
-  /// | RX_OVERFLOW | Write 1 to force !!INTR_STATE.rx_overflow to 1. | [3:3] | true |false |
-  /// | CONTROLLER_HALT | Write 1 to force !!INTR_STATE.controller_halt to 1. | [4:4] | true |false |
-  /// | SCL_INTERFERENCE | Write 1 to force !!INTR_STATE.scl_interference to 1. | [5:5] | true |false |
-  /// | SDA_INTERFERENCE | Write 1 to force !!INTR_STATE.sda_interference to 1. | [6:6] | true |false |
-  /// | STRETCH_TIMEOUT | Write 1 to force !!INTR_STATE.stretch_timeout to 1. | [7:7] | true |false |
-  /// | SDA_UNSTABLE | Write 1 to force !!INTR_STATE.sda_unstable to 1. | [8:8] | true |false |
-  /// | CMD_COMPLETE | Write 1 to force !!INTR_STATE.cmd_complete to 1. | [9:9] | true |false |
-  /// | TX_STRETCH | Write 1 to force !!INTR_STATE.tx_stretch to 1. | [10:10] | true |false |
-  /// | TX_THRESHOLD | Write 1 to force !!INTR_STATE.tx_threshold to 1. | [11:11] | true |false |
-  /// | ACQ_STRETCH | Write 1 to force !!INTR_STATE.acq_stretch to 1. | [12:12] | true |false |
-  /// | UNEXP_STOP | Write 1 to force !!INTR_STATE.unexp_stop to 1. | [13:13] | true |false |
-  /// | HOST_TIMEOUT | Write 1 to force !!INTR_STATE.host_timeout to 1. | [14:14] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMT_THRESHOLD | Write 1 to force !!INTR_STATE.fmt_threshold to 1. | [0:0] | 0x0 | true |false |
+  /// | RX_THRESHOLD | Write 1 to force !!INTR_STATE.rx_threshold to 1. | [1:1] | 0x0 | true |false |
+  /// | ACQ_THRESHOLD | Write 1 to force !!INTR_STATE.acq_threshold to 1. | [2:2] | 0x0 | true |false |
+  /// | RX_OVERFLOW | Write 1 to force !!INTR_STATE.rx_overflow to 1. | [3:3] | 0x0 | true |false |
+  /// | CONTROLLER_HALT | Write 1 to force !!INTR_STATE.controller_halt to 1. | [4:4] | 0x0 | true |false |
+  /// | SCL_INTERFERENCE | Write 1 to force !!INTR_STATE.scl_interference to 1. | [5:5] | 0x0 | true |false |
+  /// | SDA_INTERFERENCE | Write 1 to force !!INTR_STATE.sda_interference to 1. | [6:6] | 0x0 | true |false |
+  /// | STRETCH_TIMEOUT | Write 1 to force !!INTR_STATE.stretch_timeout to 1. | [7:7] | 0x0 | true |false |
+  /// | SDA_UNSTABLE | Write 1 to force !!INTR_STATE.sda_unstable to 1. | [8:8] | 0x0 | true |false |
+  /// | CMD_COMPLETE | Write 1 to force !!INTR_STATE.cmd_complete to 1. | [9:9] | 0x0 | true |false |
+  /// | TX_STRETCH | Write 1 to force !!INTR_STATE.tx_stretch to 1. | [10:10] | 0x0 | true |false |
+  /// | TX_THRESHOLD | Write 1 to force !!INTR_STATE.tx_threshold to 1. | [11:11] | 0x0 | true |false |
+  /// | ACQ_STRETCH | Write 1 to force !!INTR_STATE.acq_stretch to 1. | [12:12] | 0x0 | true |false |
+  /// | UNEXP_STOP | Write 1 to force !!INTR_STATE.unexp_stop to 1. | [13:13] | 0x0 | true |false |
+  /// | HOST_TIMEOUT | Write 1 to force !!INTR_STATE.host_timeout to 1. | [14:14] | 0x0 | true |false |
   pub struct IntrTest {
     reg: zermio::Register<u32>,
   }
 
   impl IntrTest {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1219,14 +1313,31 @@ mod intr_test {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type FmtThresholdField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
   type RxThresholdField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Write>;
@@ -1254,20 +1365,27 @@ mod ctrl {
   use super::*;
 
   /// # CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | ENABLEHOST | Enable Host I2C functionality | [0:0] | true |true |
-  /// | ENABLETARGET | Enable Target I2C functionality | [1:1] | true |true |
-  /// | LLPBK | Enable I2C line loopback testIf line loopback is enabled, the internal design sees ACQ and RX data as "1" | [2:2] | true |true |
-  /// | NACK_ADDR_AFTER_TIMEOUT | Enable NACKing the address on a stretch timeout.This is a Target mode feature.If enabled (1), a stretch timeout will cause the device to NACK the address byte.If disabled (0), a stretch timeout will cause the device to ACK the address byte.SMBus requires that devices always ACK their address, even for read commands.However, non-SMBus protocols may have a different approach and can choose to NACK instead.Note that both cases handle data bytes the same way.For writes, the Target module will NACK all subsequent data bytes until it receives a Stop.For reads, the Target module will release SDA, causing 0xff to be returned for all data bytes until it receives a Stop. | [3:3] | true |true |
-  /// | ACK_CTRL_EN | Enable I2C Target ACK Control Mode.ACK Control Mode works together with !!TARGET_ACK_CTRL.NBYTES to allow software to control upper-layer protocol (N)ACKing (e.g. as in SMBus).This bit enables the mode when 1, and !!TARGET_ACK_CTRL.NBYTES limits how many bytes may be automatically ACK'd while the ACQ FIFO has space.If it is 0, the decision to ACK or NACK is made only from stretching timeouts and !!CTRL.NACK_ADDR_AFTER_TIMEOUT. | [4:4] | true |true |
-  /// | MULTI_CONTROLLER_MONITOR_EN | Enable the bus monitor in multi-controller mode.If a 0->1 transition happens while !!CTRL.ENABLEHOST and !!CTRL.ENABLETARGET are both 0, the bus monitor will enable and begin in the "bus busy" state.To transition to a bus free state, !!HOST_TIMEOUT_CTRL must be nonzero, so the bus monitor may count out idle cycles to confirm the freedom to transmit.In addition, the bus monitor will track whether the bus is free based on the enabled timeouts and detected Stop symbols.For multi-controller mode, ensure !!CTRL.MULTI_CONTROLLER_MONITOR_EN becomes 1 no later than !!CTRL.ENABLEHOST or !!CTRL.ENABLETARGET.This bit can be set at the same time as either or both of the other two, though.Note that if !!CTRL.MULTI_CONTROLLER_MONITOR_EN is set after !!CTRL.ENABLEHOST or !!CTRL.ENABLETARGET, the bus monitor will begin in the "bus free" state instead.This would violate the proper protocol for a controller to join a multi-controller environment.However, if this controller is known to be the first to join, this ordering will enable skipping the idle wait.When 0, the bus monitor will report that the bus is always free, so the controller FSM is never blocked from transmitting. | [5:5] | true |true |
-  /// | TX_STRETCH_CTRL_EN | If set to 1, this bit causes a read transfer addressed to this target to set the corresponding bit in !!TARGET_EVENTS.While !!TARGET_EVENTS.TX_PENDING is 1, subsequent read transactions will stretch the clock, even if there is data in the TX FIFO.If enabled, this function allows software to confirm the data in the TX FIFO should be released for the current read.This may be useful for cases where the TX FIFO has data that does not apply to the current transfer.For example, the transaction could've targeted an alternate function via another address. | [6:6] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ENABLEHOST | Enable Host I2C functionality | [0:0] | 0x0 | true |true |
+  /// | ENABLETARGET | Enable Target I2C functionality | [1:1] | 0x0 | true |true |
+  /// | LLPBK | Enable I2C line loopback testIf line loopback is enabled, the internal design sees ACQ and RX data as "1" | [2:2] | 0x0 | true |true |
+  /// | NACK_ADDR_AFTER_TIMEOUT | Enable NACKing the address on a stretch timeout.This is a Target mode feature.If enabled (1), a stretch timeout will cause the device to NACK the address byte.If disabled (0), a stretch timeout will cause the device to ACK the address byte.SMBus requires that devices always ACK their address, even for read commands.However, non-SMBus protocols may have a different approach and can choose to NACK instead.Note that both cases handle data bytes the same way.For writes, the Target module will NACK all subsequent data bytes until it receives a Stop.For reads, the Target module will release SDA, causing 0xff to be returned for all data bytes until it receives a Stop. | [3:3] | 0x0 | true |true |
+  /// | ACK_CTRL_EN | Enable I2C Target ACK Control Mode.ACK Control Mode works together with !!TARGET_ACK_CTRL.NBYTES to allow software to control upper-layer protocol (N)ACKing (e.g. as in SMBus).This bit enables the mode when 1, and !!TARGET_ACK_CTRL.NBYTES limits how many bytes may be automatically ACK'd while the ACQ FIFO has space.If it is 0, the decision to ACK or NACK is made only from stretching timeouts and !!CTRL.NACK_ADDR_AFTER_TIMEOUT. | [4:4] | 0x0 | true |true |
+  /// | MULTI_CONTROLLER_MONITOR_EN | Enable the bus monitor in multi-controller mode.If a 0->1 transition happens while !!CTRL.ENABLEHOST and !!CTRL.ENABLETARGET are both 0, the bus monitor will enable and begin in the "bus busy" state.To transition to a bus free state, !!HOST_TIMEOUT_CTRL must be nonzero, so the bus monitor may count out idle cycles to confirm the freedom to transmit.In addition, the bus monitor will track whether the bus is free based on the enabled timeouts and detected Stop symbols.For multi-controller mode, ensure !!CTRL.MULTI_CONTROLLER_MONITOR_EN becomes 1 no later than !!CTRL.ENABLEHOST or !!CTRL.ENABLETARGET.This bit can be set at the same time as either or both of the other two, though.Note that if !!CTRL.MULTI_CONTROLLER_MONITOR_EN is set after !!CTRL.ENABLEHOST or !!CTRL.ENABLETARGET, the bus monitor will begin in the "bus free" state instead.This would violate the proper protocol for a controller to join a multi-controller environment.However, if this controller is known to be the first to join, this ordering will enable skipping the idle wait.When 0, the bus monitor will report that the bus is always free, so the controller FSM is never blocked from transmitting. | [5:5] | 0x0 | true |true |
+  /// | TX_STRETCH_CTRL_EN | If set to 1, this bit causes a read transfer addressed to this target to set the corresponding bit in !!TARGET_EVENTS.While !!TARGET_EVENTS.TX_PENDING is 1, subsequent read transactions will stretch the clock, even if there is data in the TX FIFO.If enabled, this function allows software to confirm the data in the TX FIFO should be released for the current read.This may be useful for cases where the TX FIFO has data that does not apply to the current transfer.For example, the transaction could've targeted an alternate function via another address. | [6:6] | 0x0 | true |true |
   pub struct Ctrl {
     reg: zermio::Register<u32>,
   }
 
   impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1429,14 +1547,31 @@ mod ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -1444,7 +1579,8 @@ mod ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type EnablehostField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -1465,24 +1601,31 @@ mod status {
   use super::*;
 
   /// # STATUS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | FMTFULL | Host mode FMT FIFO is full | [0:0] | false |true |
-  /// | RXFULL | Host mode RX FIFO is full | [1:1] | false |true |
-  /// | FMTEMPTY | Host mode FMT FIFO is empty | [2:2] | false |true |
-  /// | HOSTIDLE | Host functionality is idle. No Host transaction is in progress | [3:3] | false |true |
-  /// | TARGETIDLE | Target functionality is idle. No Target transaction is in progress | [4:4] | false |true |
-  /// | RXEMPTY | Host mode RX FIFO is empty | [5:5] | false |true |
-  /// | TXFULL | Target mode TX FIFO is full | [6:6] | false |true |
-  /// | ACQFULL | Target mode receive FIFO is full | [7:7] | false |true |
-  /// | TXEMPTY | Target mode TX FIFO is empty | [8:8] | false |true |
-  /// | ACQEMPTY | Target mode receive FIFO is empty | [9:9] | false |true |
-  /// | ACK_CTRL_STRETCH | Target mode stretching at (N)ACK phase due to zero count in !!TARGET_ACK_CTRL.NBYTES | [10:10] | false |true |
+  /// Reset value: 0x33c
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMTFULL | Host mode FMT FIFO is full | [0:0] | 0x0 | false |true |
+  /// | RXFULL | Host mode RX FIFO is full | [1:1] | 0x0 | false |true |
+  /// | FMTEMPTY | Host mode FMT FIFO is empty | [2:2] | 0x1 | false |true |
+  /// | HOSTIDLE | Host functionality is idle. No Host transaction is in progress | [3:3] | 0x1 | false |true |
+  /// | TARGETIDLE | Target functionality is idle. No Target transaction is in progress | [4:4] | 0x1 | false |true |
+  /// | RXEMPTY | Host mode RX FIFO is empty | [5:5] | 0x1 | false |true |
+  /// | TXFULL | Target mode TX FIFO is full | [6:6] | 0x0 | false |true |
+  /// | ACQFULL | Target mode receive FIFO is full | [7:7] | 0x0 | false |true |
+  /// | TXEMPTY | Target mode TX FIFO is empty | [8:8] | 0x1 | false |true |
+  /// | ACQEMPTY | Target mode receive FIFO is empty | [9:9] | 0x1 | false |true |
+  /// | ACK_CTRL_STRETCH | Target mode stretching at (N)ACK phase due to zero count in !!TARGET_ACK_CTRL.NBYTES | [10:10] | 0x0 | false |true |
   pub struct Status {
     reg: zermio::Register<u32>,
   }
 
   impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x33c;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1670,14 +1813,21 @@ mod rdata {
   use super::*;
 
   /// # RDATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RDATA |  | [7:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RDATA |  | [7:0] | 0x0 | false |true |
   pub struct Rdata {
     reg: zermio::Register<u32>,
   }
 
   impl Rdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1714,19 +1864,26 @@ mod fdata {
   use super::*;
 
   /// # FDATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | FBYTE | Format Byte.If no flags are set, hardware will transmit this byte directly.If READB is set, this field becomes the number of bytes hardware will automaticallyread from the bus. | [7:0] | true |false |
-  /// | START | Issue a START condition before transmitting FBYTE. | [8:8] | true |false |
-  /// | STOP | Issue a STOP condition after transmitting FBYTE. | [9:9] | true |false |
-  /// | READB | Transfer Direction Indicator.If unset, this write to FDATA defines a controller-transmitter operation (WRITE).A single byte of data (FBYTE) is written to the bus.If set, this write to FDATA defines a controller-receiver operation (READ).The value of FBYTE defines the number of bytes read from the bus. (256 if FBYTE==0)"After this number of bytes are read, the final byte will be NACKed to end the transferunless RCONT is also set. | [10:10] | true |false |
-  /// | RCONT | Do not NACK the last byte read, let the read operation continue. | [11:11] | true |false |
-  /// | NAKOK | For the currrent controller-transmitter byte (WRITE), do not halt via CONTROLLER_EVENTSor assert the 'controller_halt' interrupt if the current byte is not ACK'd. | [12:12] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FBYTE | Format Byte.If no flags are set, hardware will transmit this byte directly.If READB is set, this field becomes the number of bytes hardware will automaticallyread from the bus. | [7:0] | 0x0 | true |false |
+  /// | START | Issue a START condition before transmitting FBYTE. | [8:8] | 0x0 | true |false |
+  /// | STOP | Issue a STOP condition after transmitting FBYTE. | [9:9] | 0x0 | true |false |
+  /// | READB | Transfer Direction Indicator.If unset, this write to FDATA defines a controller-transmitter operation (WRITE).A single byte of data (FBYTE) is written to the bus.If set, this write to FDATA defines a controller-receiver operation (READ).The value of FBYTE defines the number of bytes read from the bus. (256 if FBYTE==0)"After this number of bytes are read, the final byte will be NACKed to end the transferunless RCONT is also set. | [10:10] | 0x0 | true |false |
+  /// | RCONT | Do not NACK the last byte read, let the read operation continue. | [11:11] | 0x0 | true |false |
+  /// | NAKOK | For the currrent controller-transmitter byte (WRITE), do not halt via CONTROLLER_EVENTSor assert the 'controller_halt' interrupt if the current byte is not ACK'd. | [12:12] | 0x0 | true |false |
   pub struct Fdata {
     reg: zermio::Register<u32>,
   }
 
   impl Fdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1846,14 +2003,31 @@ mod fdata {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type FbyteField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
   type StartField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Write>;
@@ -1872,17 +2046,24 @@ mod fifo_ctrl {
   use super::*;
 
   /// # FIFO_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RXRST | RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 | [0:0] | true |false |
-  /// | FMTRST | FMT fifo reset. Write 1 to the register resets FMT_FIFO. Read returns 0 | [1:1] | true |false |
-  /// | ACQRST | ACQ FIFO reset. Write 1 to the register resets it. Read returns 0 | [7:7] | true |false |
-  /// | TXRST | TX FIFO reset. Write 1 to the register resets it. Read returns 0 | [8:8] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RXRST | RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 | [0:0] | 0x0 | true |false |
+  /// | FMTRST | FMT fifo reset. Write 1 to the register resets FMT_FIFO. Read returns 0 | [1:1] | 0x0 | true |false |
+  /// | ACQRST | ACQ FIFO reset. Write 1 to the register resets it. Read returns 0 | [7:7] | 0x0 | true |false |
+  /// | TXRST | TX FIFO reset. Write 1 to the register resets it. Read returns 0 | [8:8] | 0x0 | true |false |
   pub struct FifoCtrl {
     reg: zermio::Register<u32>,
   }
 
   impl FifoCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1968,14 +2149,31 @@ mod fifo_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type RxrstField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
   type FmtrstField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Write>;
@@ -1992,15 +2190,22 @@ mod host_fifo_config {
   use super::*;
 
   /// # HOST_FIFO_CONFIG fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RX_THRESH | Threshold level for RX interrupts. Whilst the level of data in the RX FIFOis above this setting, the rx_threshold interrupt will be asserted. | [11:0] | true |true |
-  /// | FMT_THRESH | Threshold level for FMT interrupts. Whilst the number of used entries in theFMT FIFO is below this setting, the fmt_threshold interrupt will be asserted. | [27:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RX_THRESH | Threshold level for RX interrupts. Whilst the level of data in the RX FIFOis above this setting, the rx_threshold interrupt will be asserted. | [11:0] | 0x0 | true |true |
+  /// | FMT_THRESH | Threshold level for FMT interrupts. Whilst the number of used entries in theFMT FIFO is below this setting, the fmt_threshold interrupt will be asserted. | [27:16] | 0x0 | true |true |
   pub struct HostFifoConfig {
     reg: zermio::Register<u32>,
   }
 
   impl HostFifoConfig {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2047,14 +2252,31 @@ mod host_fifo_config {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2062,7 +2284,8 @@ mod host_fifo_config {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type RxThreshField<'a> = zermio::BitField<'a, 0, 12, u32, zermio::access::ReadWrite>;
@@ -2078,15 +2301,22 @@ mod target_fifo_config {
   use super::*;
 
   /// # TARGET_FIFO_CONFIG fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_THRESH | Threshold level for TX interrupts. Whilst the number of used entries in theTX FIFO is below this setting, the tx_threshold interrupt will be asserted. | [11:0] | true |true |
-  /// | ACQ_THRESH | Threshold level for ACQ interrupts. Whilst the level of data in the ACQ FIFOis above this setting, the acq_threshold interrupt will be asserted. | [27:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_THRESH | Threshold level for TX interrupts. Whilst the number of used entries in theTX FIFO is below this setting, the tx_threshold interrupt will be asserted. | [11:0] | 0x0 | true |true |
+  /// | ACQ_THRESH | Threshold level for ACQ interrupts. Whilst the level of data in the ACQ FIFOis above this setting, the acq_threshold interrupt will be asserted. | [27:16] | 0x0 | true |true |
   pub struct TargetFifoConfig {
     reg: zermio::Register<u32>,
   }
 
   impl TargetFifoConfig {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2133,14 +2363,31 @@ mod target_fifo_config {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2148,7 +2395,8 @@ mod target_fifo_config {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TxThreshField<'a> = zermio::BitField<'a, 0, 12, u32, zermio::access::ReadWrite>;
@@ -2164,15 +2412,22 @@ mod host_fifo_status {
   use super::*;
 
   /// # HOST_FIFO_STATUS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | FMTLVL | Current fill level of FMT fifo | [11:0] | false |true |
-  /// | RXLVL | Current fill level of RX fifo | [27:16] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMTLVL | Current fill level of FMT fifo | [11:0] | 0x0 | false |true |
+  /// | RXLVL | Current fill level of RX fifo | [27:16] | 0x0 | false |true |
   pub struct HostFifoStatus {
     reg: zermio::Register<u32>,
   }
 
   impl HostFifoStatus {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2223,15 +2478,22 @@ mod target_fifo_status {
   use super::*;
 
   /// # TARGET_FIFO_STATUS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TXLVL | Current fill level of TX fifo | [11:0] | false |true |
-  /// | ACQLVL | Current fill level of ACQ fifo | [27:16] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXLVL | Current fill level of TX fifo | [11:0] | 0x0 | false |true |
+  /// | ACQLVL | Current fill level of ACQ fifo | [27:16] | 0x0 | false |true |
   pub struct TargetFifoStatus {
     reg: zermio::Register<u32>,
   }
 
   impl TargetFifoStatus {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2282,16 +2544,23 @@ mod ovrd {
   use super::*;
 
   /// # OVRD fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TXOVRDEN | Override the SDA and SCL TX signals. | [0:0] | true |true |
-  /// | SCLVAL | Value for SCL Override. Set to 0 to drive TX Low, and set to 1 for high-Z | [1:1] | true |true |
-  /// | SDAVAL | Value for SDA Override. Set to 0 to drive TX Low, and set to 1 for high-Z | [2:2] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXOVRDEN | Override the SDA and SCL TX signals. | [0:0] | 0x0 | true |true |
+  /// | SCLVAL | Value for SCL Override. Set to 0 to drive TX Low, and set to 1 for high-Z | [1:1] | 0x0 | true |true |
+  /// | SDAVAL | Value for SDA Override. Set to 0 to drive TX Low, and set to 1 for high-Z | [2:2] | 0x0 | true |true |
   pub struct Ovrd {
     reg: zermio::Register<u32>,
   }
 
   impl Ovrd {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2369,14 +2638,31 @@ mod ovrd {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2384,7 +2670,8 @@ mod ovrd {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TxovrdenField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -2401,15 +2688,22 @@ mod val {
   use super::*;
 
   /// # VAL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | SCL_RX | Last 16 oversampled values of SCL. Most recent bit is bit 0, oldest 15. | [15:0] | false |true |
-  /// | SDA_RX | Last 16 oversampled values of SDA. Most recent bit is bit 16, oldest 31. | [31:16] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | SCL_RX | Last 16 oversampled values of SCL. Most recent bit is bit 0, oldest 15. | [15:0] | 0x0 | false |true |
+  /// | SDA_RX | Last 16 oversampled values of SDA. Most recent bit is bit 16, oldest 31. | [31:16] | 0x0 | false |true |
   pub struct Val {
     reg: zermio::Register<u32>,
   }
 
   impl Val {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2460,15 +2754,22 @@ mod timing0 {
   use super::*;
 
   /// # TIMING0 fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | THIGH | The actual time to hold SCL high in a given pulse.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. | [12:0] | true |true |
-  /// | TLOW | The actual time to hold SCL low between any two SCL pulses.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. | [28:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | THIGH | The actual time to hold SCL high in a given pulse.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. | [12:0] | 0x0 | true |true |
+  /// | TLOW | The actual time to hold SCL low between any two SCL pulses.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. | [28:16] | 0x0 | true |true |
   pub struct Timing0 {
     reg: zermio::Register<u32>,
   }
 
   impl Timing0 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2515,14 +2816,31 @@ mod timing0 {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2530,7 +2848,8 @@ mod timing0 {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ThighField<'a> = zermio::BitField<'a, 0, 13, u32, zermio::access::ReadWrite>;
@@ -2546,15 +2865,22 @@ mod timing1 {
   use super::*;
 
   /// # TIMING1 fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | T_R | The nominal rise time to anticipate for the bus (depends on capacitance).This field is sized to have a range of at least Standard Mode's 1000 ns max with a core clock at 1 GHz. | [9:0] | true |true |
-  /// | T_F | The nominal fall time to anticipate for the bus (influences SDA hold times).This field is sized to have a range of at least Standard Mode's 300 ns max with a core clock at 1 GHz. | [24:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | T_R | The nominal rise time to anticipate for the bus (depends on capacitance).This field is sized to have a range of at least Standard Mode's 1000 ns max with a core clock at 1 GHz. | [9:0] | 0x0 | true |true |
+  /// | T_F | The nominal fall time to anticipate for the bus (influences SDA hold times).This field is sized to have a range of at least Standard Mode's 300 ns max with a core clock at 1 GHz. | [24:16] | 0x0 | true |true |
   pub struct Timing1 {
     reg: zermio::Register<u32>,
   }
 
   impl Timing1 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2601,14 +2927,31 @@ mod timing1 {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2616,7 +2959,8 @@ mod timing1 {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TRField<'a> = zermio::BitField<'a, 0, 10, u32, zermio::access::ReadWrite>;
@@ -2632,15 +2976,22 @@ mod timing2 {
   use super::*;
 
   /// # TIMING2 fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TSU_STA | Actual setup time for repeated start signals.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. | [12:0] | true |true |
-  /// | THD_STA | Actual hold time for start signals.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. | [28:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TSU_STA | Actual setup time for repeated start signals.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. | [12:0] | 0x0 | true |true |
+  /// | THD_STA | Actual hold time for start signals.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. | [28:16] | 0x0 | true |true |
   pub struct Timing2 {
     reg: zermio::Register<u32>,
   }
 
   impl Timing2 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2687,14 +3038,31 @@ mod timing2 {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2702,7 +3070,8 @@ mod timing2 {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TsuStaField<'a> = zermio::BitField<'a, 0, 13, u32, zermio::access::ReadWrite>;
@@ -2718,15 +3087,22 @@ mod timing3 {
   use super::*;
 
   /// # TIMING3 fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TSU_DAT | Actual setup time for data (or ack) bits.This field is sized to have a range of at least Standard Mode's 250 ns max with a core clock at 1 GHz. | [8:0] | true |true |
-  /// | THD_DAT | Actual hold time for data (or ack) bits.(Note, where required, the parameters TVD_DAT is taken to be THD_DAT+T_F)This field is sized to have a range that accommodates Standard Mode's 3.45 us max for TVD_DAT with a core clock at 1 GHz.However, this field is generally expected to represent a time substantially shorter than that.It should be long enough to cover the maximum round-trip latency from output pins, through pads and voltage transitions on the board, and back to the input pins, but it should not be substantially greater. | [28:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TSU_DAT | Actual setup time for data (or ack) bits.This field is sized to have a range of at least Standard Mode's 250 ns max with a core clock at 1 GHz. | [8:0] | 0x0 | true |true |
+  /// | THD_DAT | Actual hold time for data (or ack) bits.(Note, where required, the parameters TVD_DAT is taken to be THD_DAT+T_F)This field is sized to have a range that accommodates Standard Mode's 3.45 us max for TVD_DAT with a core clock at 1 GHz.However, this field is generally expected to represent a time substantially shorter than that.It should be long enough to cover the maximum round-trip latency from output pins, through pads and voltage transitions on the board, and back to the input pins, but it should not be substantially greater. | [28:16] | 0x0 | true |true |
   pub struct Timing3 {
     reg: zermio::Register<u32>,
   }
 
   impl Timing3 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2773,14 +3149,31 @@ mod timing3 {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2788,7 +3181,8 @@ mod timing3 {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TsuDatField<'a> = zermio::BitField<'a, 0, 9, u32, zermio::access::ReadWrite>;
@@ -2804,15 +3198,22 @@ mod timing4 {
   use super::*;
 
   /// # TIMING4 fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TSU_STO | Actual setup time for stop signals.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. | [12:0] | true |true |
-  /// | T_BUF | Actual time between each STOP signal and the following START signal.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. | [28:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TSU_STO | Actual setup time for stop signals.This field is sized to have a range of at least Standard Mode's 4.0 us max with a core clock at 1 GHz. | [12:0] | 0x0 | true |true |
+  /// | T_BUF | Actual time between each STOP signal and the following START signal.This field is sized to have a range of at least Standard Mode's 4.7 us max with a core clock at 1 GHz. | [28:16] | 0x0 | true |true |
   pub struct Timing4 {
     reg: zermio::Register<u32>,
   }
 
   impl Timing4 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2859,14 +3260,31 @@ mod timing4 {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2874,7 +3292,8 @@ mod timing4 {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TsuStoField<'a> = zermio::BitField<'a, 0, 13, u32, zermio::access::ReadWrite>;
@@ -2890,16 +3309,23 @@ mod timeout_ctrl {
   use super::*;
 
   /// # TIMEOUT_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | VAL | Clock stretching timeout value (in units of input clock frequency) | [29:0] | true |true |
-  /// | MODE | Selects the timeout mode, between a stretch timeout and a bus timeout.Between the two modes, the primary difference is how much of the clock low period is counted.For a stretch timeout, only the time that another device holds the clock low will be counted.For a bus timeout, the entire clock low time is counted, consistent with the SMBus tTIMEOUT type.!!TIMEOUT_CTRL.EN must be 1 for either of these features to be enabled. | [30:30] | true |true |
-  /// | EN | Enable stretch timeout or bus timeout feature | [31:31] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VAL | Clock stretching timeout value (in units of input clock frequency) | [29:0] | 0x0 | true |true |
+  /// | MODE | Selects the timeout mode, between a stretch timeout and a bus timeout.Between the two modes, the primary difference is how much of the clock low period is counted.For a stretch timeout, only the time that another device holds the clock low will be counted.For a bus timeout, the entire clock low time is counted, consistent with the SMBus tTIMEOUT type.!!TIMEOUT_CTRL.EN must be 1 for either of these features to be enabled. | [30:30] | 0x0 | true |true |
+  /// | EN | Enable stretch timeout or bus timeout feature | [31:31] | 0x0 | true |true |
   pub struct TimeoutCtrl {
     reg: zermio::Register<u32>,
   }
 
   impl TimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -2972,14 +3398,31 @@ mod timeout_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -2987,7 +3430,8 @@ mod timeout_ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValField<'a> = zermio::BitField<'a, 0, 30, u32, zermio::access::ReadWrite>;
@@ -3004,17 +3448,24 @@ mod target_id {
   use super::*;
 
   /// # TARGET_ID fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | ADDRESS0 | I2C target address number 0 | [6:0] | true |true |
-  /// | MASK0 | I2C target mask number 0.At least one bit in MASK0 must be set to 1 for ADDRESS0 to be used. | [13:7] | true |true |
-  /// | ADDRESS1 | I2C target address number 1 | [20:14] | true |true |
-  /// | MASK1 | I2C target mask number 1.At least one bit in MASK1 must be set to 1 for ADDRESS1 to be used. | [27:21] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ADDRESS0 | I2C target address number 0 | [6:0] | 0x0 | true |true |
+  /// | MASK0 | I2C target mask number 0.At least one bit in MASK0 must be set to 1 for ADDRESS0 to be used. | [13:7] | 0x0 | true |true |
+  /// | ADDRESS1 | I2C target address number 1 | [20:14] | 0x0 | true |true |
+  /// | MASK1 | I2C target mask number 1.At least one bit in MASK1 must be set to 1 for ADDRESS1 to be used. | [27:21] | 0x0 | true |true |
   pub struct TargetId {
     reg: zermio::Register<u32>,
   }
 
   impl TargetId {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3093,14 +3544,31 @@ mod target_id {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -3108,7 +3576,8 @@ mod target_id {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type Address0Field<'a> = zermio::BitField<'a, 0, 7, u32, zermio::access::ReadWrite>;
@@ -3126,15 +3595,22 @@ mod acqdata {
   use super::*;
 
   /// # ACQDATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | ABYTE | Address for accepted transaction or acquired byte | [7:0] | false |true |
-  /// | SIGNAL | Indicates any control symbols associated with the ABYTE.For the STOP symbol, a stretch timeout or other unexpected events will cause a NACK_STOP to appear in the ACQ FIFO.If the ACQ FIFO doesn't have enough space to record a START and a STOP, the transaction will be dropped entirely on a stretch timeout.In that case, the START byte will not appear (neither as START nor NACK_START), but a standalone NACK_STOP may, if there was space.Software can discard any standalone NACK_STOP that appears.See the associated values for more information about the contents. | [10:8] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ABYTE | Address for accepted transaction or acquired byte | [7:0] | 0x0 | false |true |
+  /// | SIGNAL | Indicates any control symbols associated with the ABYTE.For the STOP symbol, a stretch timeout or other unexpected events will cause a NACK_STOP to appear in the ACQ FIFO.If the ACQ FIFO doesn't have enough space to record a START and a STOP, the transaction will be dropped entirely on a stretch timeout.In that case, the START byte will not appear (neither as START nor NACK_START), but a standalone NACK_STOP may, if there was space.Software can discard any standalone NACK_STOP that appears.See the associated values for more information about the contents. | [10:8] | 0x0 | false |true |
   pub struct Acqdata {
     reg: zermio::Register<u32>,
   }
 
   impl Acqdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3185,14 +3661,21 @@ mod txdata {
   use super::*;
 
   /// # TXDATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TXDATA |  | [7:0] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXDATA |  | [7:0] | 0x0 | true |false |
   pub struct Txdata {
     reg: zermio::Register<u32>,
   }
 
   impl Txdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3217,14 +3700,31 @@ mod txdata {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type TxdataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
 }
@@ -3238,14 +3738,21 @@ mod host_timeout_ctrl {
   use super::*;
 
   /// # HOST_TIMEOUT_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | HOST_TIMEOUT_CTRL |  | [19:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | HOST_TIMEOUT_CTRL |  | [19:0] | 0x0 | true |true |
   pub struct HostTimeoutCtrl {
     reg: zermio::Register<u32>,
   }
 
   impl HostTimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3276,14 +3783,31 @@ mod host_timeout_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -3291,7 +3815,8 @@ mod host_timeout_ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type HostTimeoutCtrlField<'a> = zermio::BitField<'a, 0, 20, u32, zermio::access::ReadWrite>;
@@ -3306,15 +3831,22 @@ mod target_timeout_ctrl {
   use super::*;
 
   /// # TARGET_TIMEOUT_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | VAL | Clock stretching timeout value (in units of input clock frequency) | [30:0] | true |true |
-  /// | EN | Enable timeout feature and send NACK once the timeout has been reached | [31:31] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VAL | Clock stretching timeout value (in units of input clock frequency) | [30:0] | 0x0 | true |true |
+  /// | EN | Enable timeout feature and send NACK once the timeout has been reached | [31:31] | 0x0 | true |true |
   pub struct TargetTimeoutCtrl {
     reg: zermio::Register<u32>,
   }
 
   impl TargetTimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3366,14 +3898,31 @@ mod target_timeout_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -3381,7 +3930,8 @@ mod target_timeout_ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValField<'a> = zermio::BitField<'a, 0, 31, u32, zermio::access::ReadWrite>;
@@ -3397,14 +3947,28 @@ mod target_nack_count {
   use super::*;
 
   /// # TARGET_NACK_COUNT fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TARGET_NACK_COUNT |  | [7:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TARGET_NACK_COUNT |  | [7:0] | 0x0 | true |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * TARGET_NACK_COUNT: Clear on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`TargetNackCount::read_and_clear`].
   pub struct TargetNackCount {
     reg: zermio::Register<u32>,
   }
 
   impl TargetNackCount {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3412,6 +3976,8 @@ mod target_nack_count {
     }
   /// Hardware description: 
   ///
+  /// Side effect on read: Clear.
+  ///
   /// Returns a BitField with ReadWrite permissions, providing the following functions:
   /// | Method                | Description                                     |
   /// | :---                  | :---                                            |
@@ -3426,8 +3992,9 @@ mod target_nack_count {
         TargetNackCountField::new(&mut self.reg)
     }
 
-    /// Run a load instruction to read from MMIO
-    pub fn fetch(&mut self) -> &mut Self {
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_and_clear(&mut self) -> &mut Self {
       self.reg.fetch();
       self
     }
@@ -3435,25 +4002,33 @@ mod target_nack_count {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
-    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
-    /// to write it to the MMIO
-    pub fn update<F>(&mut self, action: F)
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
     where
-        F: FnOnce(&mut Self),
+      F: FnOnce(&mut Self),
     {
-      self.reg.fetch();
-      self.write(action);
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
     }
   }
-  type TargetNackCountField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::ReadWrite>;
+  type TargetNackCountField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::ReadWrite, (), zermio::effect::Modify, zermio::load::SideEffects>;
 }
 
 
@@ -3465,15 +4040,22 @@ mod target_ack_ctrl {
   use super::*;
 
   /// # TARGET_ACK_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | NBYTES | Remaining number of bytes the Target module may ACK automatically.If !!CTRL.ACK_CTRL_EN is set to 1, the Target module will stretch the clock at the (N)ACK phase of a byte if this CSR is 0, awaiting software's instructions.At the beginning of each Write transfer, this byte count is reset to 0.Writes to this CSR also are only accepted while the Target module is stretching the clock.The Target module will always ACK its address if the ACQ FIFO has space.For data bytes afterwards, it will stop at the (N)ACK phase and stretch the clock when this CSR is 0.For each data byte that is ACK'd in a transaction, the byte count will decrease by 1.Note that a full ACQ FIFO can still cause the Target module to halt at the beginning of a new byte.The ACK Control Mode provides an additional synchronization point, during the (N)ACK phase instead of after.For both cases, !!TARGET_TIMEOUT_CTRL applies, and stretching past the timeout will produce an automatic NACK.This mode can be used to implement the mid-transfer (N)ACK responses required by various SMBus protocols. | [8:0] | true |true |
-  /// | NACK | When the Target module stretches on the (N)ACK phase of a Write due to !!TARGET_ACK_CTRL.NBYTES being 0, writing a 1 here will cause it to send a NACK.If software chooses to NACK, note that the NACKing behavior is the same as if a stretch timeout occurred.The rest of the transaction will be NACK'd, including subsequent transfers.For the address byte, the (N)ACK phase of subsequent transfers will follow the behavior specified by !!CTRL.NACK_ADDR_AFTER_TIMEOUT.Automatically clears to 0. | [31:31] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | NBYTES | Remaining number of bytes the Target module may ACK automatically.If !!CTRL.ACK_CTRL_EN is set to 1, the Target module will stretch the clock at the (N)ACK phase of a byte if this CSR is 0, awaiting software's instructions.At the beginning of each Write transfer, this byte count is reset to 0.Writes to this CSR also are only accepted while the Target module is stretching the clock.The Target module will always ACK its address if the ACQ FIFO has space.For data bytes afterwards, it will stop at the (N)ACK phase and stretch the clock when this CSR is 0.For each data byte that is ACK'd in a transaction, the byte count will decrease by 1.Note that a full ACQ FIFO can still cause the Target module to halt at the beginning of a new byte.The ACK Control Mode provides an additional synchronization point, during the (N)ACK phase instead of after.For both cases, !!TARGET_TIMEOUT_CTRL applies, and stretching past the timeout will produce an automatic NACK.This mode can be used to implement the mid-transfer (N)ACK responses required by various SMBus protocols. | [8:0] | 0x0 | true |true |
+  /// | NACK | When the Target module stretches on the (N)ACK phase of a Write due to !!TARGET_ACK_CTRL.NBYTES being 0, writing a 1 here will cause it to send a NACK.If software chooses to NACK, note that the NACKing behavior is the same as if a stretch timeout occurred.The rest of the transaction will be NACK'd, including subsequent transfers.For the address byte, the (N)ACK phase of subsequent transfers will follow the behavior specified by !!CTRL.NACK_ADDR_AFTER_TIMEOUT.Automatically clears to 0. | [31:31] | 0x0 | true |false |
   pub struct TargetAckCtrl {
     reg: zermio::Register<u32>,
   }
 
   impl TargetAckCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3523,14 +4105,31 @@ mod target_ack_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -3538,7 +4137,8 @@ mod target_ack_ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type NbytesField<'a> = zermio::BitField<'a, 0, 9, u32, zermio::access::ReadWrite>;
@@ -3554,14 +4154,21 @@ mod acq_fifo_next_data {
   use super::*;
 
   /// # ACQ_FIFO_NEXT_DATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | ACQ_FIFO_NEXT_DATA |  | [7:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ACQ_FIFO_NEXT_DATA |  | [7:0] | 0x0 | false |true |
   pub struct AcqFifoNextData {
     reg: zermio::Register<u32>,
   }
 
   impl AcqFifoNextData {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3598,15 +4205,22 @@ mod host_nack_handler_timeout {
   use super::*;
 
   /// # HOST_NACK_HANDLER_TIMEOUT fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | VAL | Unhandled NAK timeout value (in units of input clock frequency) | [30:0] | true |true |
-  /// | EN | Timeout enable | [31:31] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VAL | Unhandled NAK timeout value (in units of input clock frequency) | [30:0] | 0x0 | true |true |
+  /// | EN | Timeout enable | [31:31] | 0x0 | true |true |
   pub struct HostNackHandlerTimeout {
     reg: zermio::Register<u32>,
   }
 
   impl HostNackHandlerTimeout {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -3658,14 +4272,31 @@ mod host_nack_handler_timeout {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -3673,7 +4304,8 @@ mod host_nack_handler_timeout {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValField<'a> = zermio::BitField<'a, 0, 31, u32, zermio::access::ReadWrite>;
@@ -3689,20 +4321,35 @@ mod controller_events {
   use super::*;
 
   /// # CONTROLLER_EVENTS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | NACK | Received an unexpected NACK | [0:0] | true |true |
-  /// | UNHANDLED_NACK_TIMEOUT | A Host-Mode active transaction has been ended by the !!HOST_NACK_HANDLER_TIMEOUT mechanism. | [1:1] | true |true |
-  /// | BUS_TIMEOUT | A Host-Mode active transaction has terminated due to a bus timeout activated by !!TIMEOUT_CTRL. | [2:2] | true |true |
-  /// | ARBITRATION_LOST | A Host-Mode active transaction has terminated due to lost arbitration. | [3:3] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | NACK | Received an unexpected NACK | [0:0] | 0x0 | true |true |
+  /// | UNHANDLED_NACK_TIMEOUT | A Host-Mode active transaction has been ended by the !!HOST_NACK_HANDLER_TIMEOUT mechanism. | [1:1] | 0x0 | true |true |
+  /// | BUS_TIMEOUT | A Host-Mode active transaction has terminated due to a bus timeout activated by !!TIMEOUT_CTRL. | [2:2] | 0x0 | true |true |
+  /// | ARBITRATION_LOST | A Host-Mode active transaction has terminated due to lost arbitration. | [3:3] | 0x0 | true |true |
   pub struct ControllerEvents {
     reg: zermio::Register<u32>,
   }
 
   impl ControllerEvents {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0xf;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
       }
     }
   /// Hardware description: Received an unexpected NACK
@@ -3719,6 +4366,7 @@ mod controller_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3740,6 +4388,7 @@ mod controller_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3761,6 +4410,7 @@ mod controller_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3782,6 +4432,7 @@ mod controller_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3798,28 +4449,52 @@ mod controller_events {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
     pub fn update<F>(&mut self, action: F)
     where
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
     }
   }
-  type NackField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
-  type UnhandledNackTimeoutField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
-  type BusTimeoutField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
-  type ArbitrationLostField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type NackField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type UnhandledNackTimeoutField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type BusTimeoutField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type ArbitrationLostField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
 }
 
 
@@ -3831,19 +4506,34 @@ mod target_events {
   use super::*;
 
   /// # TARGET_EVENTS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_PENDING | A new Target-Mode read transfer has arrived that addressed this target.This bit is used by software to confirm the release of the contents in the TX FIFO.If the contents do not apply, software should first reset the TX FIFO, then load it with the correct data, then clear this bit.Optionally enabled by !!CTRL.TX_STRETCH_CTRL_EN. | [0:0] | true |true |
-  /// | BUS_TIMEOUT | A Target-Mode read transfer has terminated due to a bus timeout activated by !!TIMEOUT_CTRL. | [1:1] | true |true |
-  /// | ARBITRATION_LOST | A Target-Mode read transfer has terminated due to lost arbitration. | [2:2] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_PENDING | A new Target-Mode read transfer has arrived that addressed this target.This bit is used by software to confirm the release of the contents in the TX FIFO.If the contents do not apply, software should first reset the TX FIFO, then load it with the correct data, then clear this bit.Optionally enabled by !!CTRL.TX_STRETCH_CTRL_EN. | [0:0] | 0x0 | true |true |
+  /// | BUS_TIMEOUT | A Target-Mode read transfer has terminated due to a bus timeout activated by !!TIMEOUT_CTRL. | [1:1] | 0x0 | true |true |
+  /// | ARBITRATION_LOST | A Target-Mode read transfer has terminated due to lost arbitration. | [2:2] | 0x0 | true |true |
   pub struct TargetEvents {
     reg: zermio::Register<u32>,
   }
 
   impl TargetEvents {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x7;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
       }
     }
   /// Hardware description: A new Target-Mode read transfer has arrived that addressed this target.This bit is used by software to confirm the release of the contents in the TX FIFO.If the contents do not apply, software should first reset the TX FIFO, then load it with the correct data, then clear this bit.Optionally enabled by !!CTRL.TX_STRETCH_CTRL_EN.
@@ -3860,6 +4550,7 @@ mod target_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3881,6 +4572,7 @@ mod target_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3902,6 +4594,7 @@ mod target_events {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -3918,25 +4611,49 @@ mod target_events {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
     pub fn update<F>(&mut self, action: F)
     where
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
     }
   }
-  type TxPendingField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
-  type BusTimeoutField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
-  type ArbitrationLostField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type TxPendingField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type BusTimeoutField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type ArbitrationLostField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
 }
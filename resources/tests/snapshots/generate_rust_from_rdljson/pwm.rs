@@ -46,14 +46,21 @@ mod width {
   use super::*;
 
   /// # WIDTH fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | value | Pwm width. | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | value | Pwm width. | [31:0] | 0x0 | true |true |
   pub struct Width {
     reg: zermio::Register<u32>,
   }
 
   impl Width {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -84,14 +91,31 @@ mod width {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -99,7 +123,8 @@ mod width {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
@@ -114,14 +139,21 @@ mod counter {
   use super::*;
 
   /// # COUNTER fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | value | Pwm counter. | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | value | Pwm counter. | [31:0] | 0x0 | true |true |
   pub struct Counter {
     reg: zermio::Register<u32>,
   }
 
   impl Counter {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -152,14 +184,31 @@ mod counter {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -167,7 +216,8 @@ mod counter {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
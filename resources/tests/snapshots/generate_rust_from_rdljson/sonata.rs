@@ -11,6 +11,7 @@ use super::timer;
 use super::uart;
 use super::i2c;
 use super::spi;
+use super::zermio;
 
 pub enum GpioOffset{
   Gpio = 0x80000000,
@@ -53,8 +54,17 @@ pub enum SpiOffset{
 
 
 pub enum Interrupt{
+  Uart0Irq = 1,
+  Uart1Irq = 2,
+  Uart2Irq = 3,
+  I2c0Irq = 4,
+  I2c1Irq = 5,
 }
 
+/// SRAM, 131072 words of 8 bits.
+pub const SRAM: zermio::MemoryRegion<u8, zermio::access::ReadWrite> =
+  zermio::MemoryRegion::new(0x100000, 0x20000);
+
 #[no_mangle]
 static mut DEVICE_PERIPHERALS: bool = false;
 
@@ -54,6 +54,9 @@ pub struct Spi {
     pub cs : cs::Cs,
 }
 
+/// Hardware parameter MAX_PERIPHERALS.
+pub const MAX_PERIPHERALS: u32 = 4;
+
 impl Spi {
     pub fn new(instance: u32) -> Self {
     let addr = instance as u32;
@@ -82,21 +85,36 @@ mod intr_state {
   use super::*;
 
   /// # INTR_STATE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RX_FULL | Receive FIFO is full | [0:0] | false |true |
-  /// | RX_WATERMARK | Receive FIFO level is at or above watermark | [1:1] | false |true |
-  /// | TX_EMPTY | Transmit FIFO is empty | [2:2] | false |true |
-  /// | TX_WATERMARK | Transmit FIFO level is at or below watermark | [3:3] | false |true |
-  /// | COMPLETE | On-going SPI operation has completed and the block is now idle | [4:4] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RX_FULL | Receive FIFO is full | [0:0] | 0x0 | false |true |
+  /// | RX_WATERMARK | Receive FIFO level is at or above watermark | [1:1] | 0x0 | false |true |
+  /// | TX_EMPTY | Transmit FIFO is empty | [2:2] | 0x0 | false |true |
+  /// | TX_WATERMARK | Transmit FIFO level is at or below watermark | [3:3] | 0x0 | false |true |
+  /// | COMPLETE | On-going SPI operation has completed and the block is now idle | [4:4] | 0x0 | true |true |
   pub struct IntrState {
     reg: zermio::Register<u32>,
   }
 
   impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x10;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
       }
     }
   /// Hardware description: Receive FIFO is full
@@ -169,6 +187,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -185,29 +204,53 @@ mod intr_state {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
     pub fn update<F>(&mut self, action: F)
     where
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
     }
   }
   type RxFullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
   type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
   type TxEmptyField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
   type TxWatermarkField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::Read>;
-  type CompleteField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type CompleteField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
 }
 
 
@@ -219,18 +262,25 @@ mod intr_enable {
   use super::*;
 
   /// # INTR_ENABLE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RX_FULL | Enable interrupt when !!INTR_STATE.rx_full is set. | [0:0] | true |true |
-  /// | RX_WATERMARK | Enable interrupt when !!INTR_STATE.rx_watermark is set. | [1:1] | true |true |
-  /// | TX_EMPTY | Enable interrupt when !!INTR_STATE.tx_empty is set. | [2:2] | true |true |
-  /// | TX_WATERMARK | Enable interrupt when !!INTR_STATE.tx_watermark is set. | [3:3] | true |true |
-  /// | COMPLETE | Enable interrupt when !!INTR_STATE.complete is set. | [4:4] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RX_FULL | Enable interrupt when !!INTR_STATE.rx_full is set. | [0:0] | 0x0 | true |true |
+  /// | RX_WATERMARK | Enable interrupt when !!INTR_STATE.rx_watermark is set. | [1:1] | 0x0 | true |true |
+  /// | TX_EMPTY | Enable interrupt when !!INTR_STATE.tx_empty is set. | [2:2] | 0x0 | true |true |
+  /// | TX_WATERMARK | Enable interrupt when !!INTR_STATE.tx_watermark is set. | [3:3] | 0x0 | true |true |
+  /// | COMPLETE | Enable interrupt when !!INTR_STATE.complete is set. | [4:4] | 0x0 | true |true |
   pub struct IntrEnable {
     reg: zermio::Register<u32>,
   }
 
   impl IntrEnable {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -350,14 +400,31 @@ mod intr_enable {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -365,7 +432,8 @@ mod intr_enable {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type RxFullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -384,18 +452,25 @@ mod intr_test {
   use super::*;
 
   /// # INTR_TEST fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RX_FULL | Write 1 to force !!INTR_STATE.rx_full to 1. | [0:0] | true |false |
-  /// | RX_WATERMARK | Write 1 to force !!INTR_STATE.rx_watermark to 1. | [1:1] | true |false |
-  /// | TX_EMPTY | Write 1 to force !!INTR_STATE.tx_empty to 1. | [2:2] | true |false |
-  /// | TX_WATERMARK | Write 1 to force !!INTR_STATE.tx_watermark to 1. | [3:3] | true |false |
-  /// | COMPLETE | Write 1 to force !!INTR_STATE.complete to 1. | [4:4] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RX_FULL | Write 1 to force !!INTR_STATE.rx_full to 1. | [0:0] | 0x0 | true |false |
+  /// | RX_WATERMARK | Write 1 to force !!INTR_STATE.rx_watermark to 1. | [1:1] | 0x0 | true |false |
+  /// | TX_EMPTY | Write 1 to force !!INTR_STATE.tx_empty to 1. | [2:2] | 0x0 | true |false |
+  /// | TX_WATERMARK | Write 1 to force !!INTR_STATE.tx_watermark to 1. | [3:3] | 0x0 | true |false |
+  /// | COMPLETE | Write 1 to force !!INTR_STATE.complete to 1. | [4:4] | 0x0 | true |false |
   pub struct IntrTest {
     reg: zermio::Register<u32>,
   }
 
   impl IntrTest {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -500,14 +575,31 @@ mod intr_test {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type RxFullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
   type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Write>;
@@ -525,18 +617,25 @@ mod cfg {
   use super::*;
 
   /// # CFG fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | HALF_CLK_PERIOD | The length of a half period (i.e. positive edge to negative   edge) of the SPI clock, measured in system clock cycles   reduced by 1. At the standard Sonata 50 MHz system clock a   value of 0 gives a 25 MHz SPI clock, a value of 1 gives a   12.5 MHz SPI clock, a value of 2 gives a 8.33 MHz SPI clock   and so on. | [15:0] | true |true |
-  /// | COPI_IDLE | State of the controller output line (COPI) when not transmitting. | [28:28] | true |true |
-  /// | MSB_FIRST | When set the most significant bit (MSB) is the first bit   sent and received with each byte | [29:29] | true |true |
-  /// | CPHA | The phase of the spi_clk signal. When CPHA is 0 data is   sampled on the leading edge and changes on the trailing   edge. The first data bit is immediately available before   the first leading edge of the clock when transmission   begins. When CPHA is 1 data is sampled on the trailing edge   and change on the leading edge. | [30:30] | true |true |
-  /// | CPOL | The polarity of the spi_clk signal. When CPOL is 0 clock is   low when idle and the leading edge is positive. When CPOL   is 1 clock is high when idle and the leading edge is   negative | [31:31] | true |true |
+  /// Reset value: 0x20000000
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | HALF_CLK_PERIOD | The length of a half period (i.e. positive edge to negative   edge) of the SPI clock, measured in system clock cycles   reduced by 1. At the standard Sonata 50 MHz system clock a   value of 0 gives a 25 MHz SPI clock, a value of 1 gives a   12.5 MHz SPI clock, a value of 2 gives a 8.33 MHz SPI clock   and so on. | [15:0] | 0x0 | true |true |
+  /// | COPI_IDLE | State of the controller output line (COPI) when not transmitting. | [28:28] | 0x0 | true |true |
+  /// | MSB_FIRST | When set the most significant bit (MSB) is the first bit   sent and received with each byte | [29:29] | 0x1 | true |true |
+  /// | CPHA | The phase of the spi_clk signal. When CPHA is 0 data is   sampled on the leading edge and changes on the trailing   edge. The first data bit is immediately available before   the first leading edge of the clock when transmission   begins. When CPHA is 1 data is sampled on the trailing edge   and change on the leading edge. | [30:30] | 0x0 | true |true |
+  /// | CPOL | The polarity of the spi_clk signal. When CPOL is 0 clock is   low when idle and the leading edge is positive. When CPOL   is 1 clock is high when idle and the leading edge is   negative | [31:31] | 0x0 | true |true |
   pub struct Cfg {
     reg: zermio::Register<u32>,
   }
 
   impl Cfg {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x20000000;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -651,14 +750,31 @@ mod cfg {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -666,7 +782,8 @@ mod cfg {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type HalfClkPeriodField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
@@ -685,21 +802,28 @@ mod control {
   use super::*;
 
   /// # CONTROL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_CLEAR | Write 1 to clear the transmit FIFO | [0:0] | true |false |
-  /// | RX_CLEAR | Write 1 to clear the receive FIFO | [1:1] | true |false |
-  /// | TX_ENABLE | When set bytes from the transmit FIFO are sent. When clear   the state of the outgoing spi_copi is undefined whilst the   SPI clock is running. | [2:2] | true |true |
-  /// | RX_ENABLE | When set incoming bits are written to the receive FIFO.   When clear incoming bits are ignored. | [3:3] | true |true |
-  /// | TX_WATERMARK | The watermark level for the transmit FIFO, depending on the   value the interrupt will trigger at different points:    * 0 - 1 or fewer items in the FIFO    * 1 - 2 or fewer items in the FIFO    * 2 - 4 or fewer items in the FIFO    * 3 - 8 or fewer items in the FIFO    * 4 - 16 or fewer items in the FIFO | [7:4] | true |true |
-  /// | RX_WATERMARK | The watermark level for the receive FIFO, depending on the   value the interrupt will trigger at different points:    * 0 - 1 or more items in the FIFO    * 1 - 2 or more items in the FIFO    * 2 - 4 or more items in the FIFO    * 3 - 8 or more items in the FIFO    * 4 - 16 or more items in the FIFO    * 5 - 32 or more items in the FIFO    * 6 - 56 or more items in the FIFO | [11:8] | true |true |
-  /// | INT_LOOPBACK | When set the CIPO line is internally connected to the COPI line,   providing loopback functionality which may be useful in testing.   Note that the COPI line is unaffected and still carries data so   test software should normally leave the CS lines deasserted.   This bit shall be changed only when the SPI core is idle. | [30:30] | true |true |
-  /// | SW_RESET | When a 1 is written to this field a reset of the controller logic   is performed.   This shall be used only to recover from error conditions.   The TX FIFO shall be cleared before resetting the controller logic;   then clear the RX FIFO after the controller reset.   The bit self-clears and always reads as zero. | [31:31] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_CLEAR | Write 1 to clear the transmit FIFO | [0:0] | 0x0 | true |false |
+  /// | RX_CLEAR | Write 1 to clear the receive FIFO | [1:1] | 0x0 | true |false |
+  /// | TX_ENABLE | When set bytes from the transmit FIFO are sent. When clear   the state of the outgoing spi_copi is undefined whilst the   SPI clock is running. | [2:2] | 0x0 | true |true |
+  /// | RX_ENABLE | When set incoming bits are written to the receive FIFO.   When clear incoming bits are ignored. | [3:3] | 0x0 | true |true |
+  /// | TX_WATERMARK | The watermark level for the transmit FIFO, depending on the   value the interrupt will trigger at different points:    * 0 - 1 or fewer items in the FIFO    * 1 - 2 or fewer items in the FIFO    * 2 - 4 or fewer items in the FIFO    * 3 - 8 or fewer items in the FIFO    * 4 - 16 or fewer items in the FIFO | [7:4] | 0x0 | true |true |
+  /// | RX_WATERMARK | The watermark level for the receive FIFO, depending on the   value the interrupt will trigger at different points:    * 0 - 1 or more items in the FIFO    * 1 - 2 or more items in the FIFO    * 2 - 4 or more items in the FIFO    * 3 - 8 or more items in the FIFO    * 4 - 16 or more items in the FIFO    * 5 - 32 or more items in the FIFO    * 6 - 56 or more items in the FIFO | [11:8] | 0x0 | true |true |
+  /// | INT_LOOPBACK | When set the CIPO line is internally connected to the COPI line,   providing loopback functionality which may be useful in testing.   Note that the COPI line is unaffected and still carries data so   test software should normally leave the CS lines deasserted.   This bit shall be changed only when the SPI core is idle. | [30:30] | 0x0 | true |true |
+  /// | SW_RESET | When a 1 is written to this field a reset of the controller logic   is performed.   This shall be used only to recover from error conditions.   The TX FIFO shall be cleared before resetting the controller logic;   then clear the RX FIFO after the controller reset.   The bit self-clears and always reads as zero. | [31:31] | 0x0 | true |false |
   pub struct Control {
     reg: zermio::Register<u32>,
   }
 
   impl Control {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -866,14 +990,31 @@ mod control {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -881,7 +1022,8 @@ mod control {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TxClearField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
@@ -903,18 +1045,25 @@ mod status {
   use super::*;
 
   /// # STATUS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_FIFO_LEVEL | Number of items in the transmit FIFO | [7:0] | false |true |
-  /// | RX_FIFO_LEVEL | Number of items in the receive FIFO | [15:8] | false |true |
-  /// | TX_FIFO_FULL | When set the transmit FIFO is full and any data written to it   will be ignored. | [16:16] | false |true |
-  /// | RX_FIFO_EMPTY | When set the receive FIFO is empty and any data read from it   will be undefined. | [17:17] | false |true |
-  /// | IDLE | When set the SPI block is idle and can accept a new start   command. | [18:18] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_FIFO_LEVEL | Number of items in the transmit FIFO | [7:0] | 0x0 | false |true |
+  /// | RX_FIFO_LEVEL | Number of items in the receive FIFO | [15:8] | 0x0 | false |true |
+  /// | TX_FIFO_FULL | When set the transmit FIFO is full and any data written to it   will be ignored. | [16:16] | 0x0 | false |true |
+  /// | RX_FIFO_EMPTY | When set the receive FIFO is empty and any data read from it   will be undefined. | [17:17] | 0x0 | false |true |
+  /// | IDLE | When set the SPI block is idle and can accept a new start   command. | [18:18] | 0x0 | false |true |
   pub struct Status {
     reg: zermio::Register<u32>,
   }
 
   impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1010,14 +1159,21 @@ mod start {
   use super::*;
 
   /// # START fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | BYTE_COUNT | Number of bytes to receive/transmit in the SPI operation | [10:0] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | BYTE_COUNT | Number of bytes to receive/transmit in the SPI operation | [10:0] | 0x0 | true |false |
   pub struct Start {
     reg: zermio::Register<u32>,
   }
 
   impl Start {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1042,14 +1198,31 @@ mod start {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type ByteCountField<'a> = zermio::BitField<'a, 0, 11, u32, zermio::access::Write>;
 }
@@ -1063,14 +1236,21 @@ mod rx_fifo {
   use super::*;
 
   /// # RX_FIFO fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | DATA | Byte popped from the FIFO | [7:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | DATA | Byte popped from the FIFO | [7:0] | 0x0 | false |true |
   pub struct RxFifo {
     reg: zermio::Register<u32>,
   }
 
   impl RxFifo {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1107,14 +1287,21 @@ mod tx_fifo {
   use super::*;
 
   /// # TX_FIFO fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | DATA | Byte to push to the FIFO | [7:0] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | DATA | Byte to push to the FIFO | [7:0] | 0x0 | true |false |
   pub struct TxFifo {
     reg: zermio::Register<u32>,
   }
 
   impl TxFifo {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1139,14 +1326,31 @@ mod tx_fifo {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type DataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
 }
@@ -1160,15 +1364,22 @@ mod info {
   use super::*;
 
   /// # INFO fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_FIFO_DEPTH | Maximum number of items in the transmit FIFO. | [7:0] | false |true |
-  /// | RX_FIFO_DEPTH | Maximum number of items in the receive FIFO. | [15:8] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_FIFO_DEPTH | Maximum number of items in the transmit FIFO. | [7:0] | 0x0 | false |true |
+  /// | RX_FIFO_DEPTH | Maximum number of items in the receive FIFO. | [15:8] | 0x0 | false |true |
   pub struct Info {
     reg: zermio::Register<u32>,
   }
 
   impl Info {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1219,17 +1430,24 @@ mod cs {
   use super::*;
 
   /// # CS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | CS_0 | If this bit is clear the peripheral is selected for transmit/receive operations. | [0:0] | true |true |
-  /// | CS_1 | If this bit is clear the peripheral is selected for transmit/receive operations. | [1:1] | true |true |
-  /// | CS_2 | If this bit is clear the peripheral is selected for transmit/receive operations. | [2:2] | true |true |
-  /// | CS_3 | If this bit is clear the peripheral is selected for transmit/receive operations. | [3:3] | true |true |
+  /// Reset value: 0xf
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | CS_0 | If this bit is clear the peripheral is selected for transmit/receive operations. | [0:0] | 0x1 | true |true |
+  /// | CS_1 | If this bit is clear the peripheral is selected for transmit/receive operations. | [1:1] | 0x1 | true |true |
+  /// | CS_2 | If this bit is clear the peripheral is selected for transmit/receive operations. | [2:2] | 0x1 | true |true |
+  /// | CS_3 | If this bit is clear the peripheral is selected for transmit/receive operations. | [3:3] | 0x1 | true |true |
   pub struct Cs {
     reg: zermio::Register<u32>,
   }
 
   impl Cs {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0xf;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1328,14 +1546,31 @@ mod cs {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -1343,7 +1578,8 @@ mod cs {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type Cs0Field<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -54,14 +54,21 @@ mod mtimel {
   use super::*;
 
   /// # MTIMEL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | VALUE | mtime low | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | mtime low | [31:0] | 0x0 | true |true |
   pub struct Mtimel {
     reg: zermio::Register<u32>,
   }
 
   impl Mtimel {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -92,14 +99,31 @@ mod mtimel {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -107,7 +131,8 @@ mod mtimel {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
@@ -122,14 +147,21 @@ mod mtimeh {
   use super::*;
 
   /// # MTIMEH fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | VALUE | mtime high | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VALUE | mtime high | [31:0] | 0x0 | true |true |
   pub struct Mtimeh {
     reg: zermio::Register<u32>,
   }
 
   impl Mtimeh {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -160,14 +192,31 @@ mod mtimeh {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -175,7 +224,8 @@ mod mtimeh {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
@@ -190,14 +240,21 @@ mod mtimecmpl {
   use super::*;
 
   /// # MTIMECMPL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | value | mtime cmp low | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | value | mtime cmp low | [31:0] | 0x0 | true |true |
   pub struct Mtimecmpl {
     reg: zermio::Register<u32>,
   }
 
   impl Mtimecmpl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -228,14 +285,31 @@ mod mtimecmpl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -243,7 +317,8 @@ mod mtimecmpl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
@@ -258,14 +333,21 @@ mod mtimecmph {
   use super::*;
 
   /// # MTIMECMPH fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | value | mtime cmp high | [31:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | value | mtime cmp high | [31:0] | 0x0 | true |true |
   pub struct Mtimecmph {
     reg: zermio::Register<u32>,
   }
 
   impl Mtimecmph {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -296,14 +378,31 @@ mod mtimecmph {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -311,7 +410,8 @@ mod mtimecmph {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
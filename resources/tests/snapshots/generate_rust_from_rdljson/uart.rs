@@ -6,6 +6,11 @@
 //zermio
 
 
+//! These are the windows present in the uart 
+//! | Window name        | Offset | Entries | Width | Write | Read| 
+//! | :---               | :---   | :---    | :---  | :--   | :-- |
+//! | TX_BUF | 0x100 | 16 | 32 | true |false |
+
 //! These are the registers present in the uart 
 //! | Register name      | Description  | Offset | Write | Read| 
 //! | :---               | :---         | :---   | :--   | :-- |
@@ -21,6 +26,8 @@
 //! | OVRD | TX pin override control. Gives direct SW control over TX pin state | 0x28 | true |true |
 //! | VAL | UART oversampled values | 0x2c | false |true |
 //! | TIMEOUT_CTRL | UART RX timeout control | 0x30 | true |true |
+//! | ERR_CODE | Errors of the shadowed registers | 0x34 | false |true |
+//! | TIMEOUT_REGWEN | Lock of the RX timeout configuration | 0x38 | true |true |
 
 use super::zermio;
 /// ``` rust
@@ -55,8 +62,21 @@ pub struct Uart {
     pub val : val::Val,
     /// UART RX timeout control  
     pub timeout_ctrl : timeout_ctrl::TimeoutCtrl,
+    /// Errors of the shadowed registers  
+    pub err_code : err_code::ErrCode,
+    /// Lock of the RX timeout configuration  
+    pub timeout_regwen : timeout_regwen::TimeoutRegwen,
+    /// The window TX_BUF, 16 words of 32 bits.
+    pub tx_buf : zermio::MemoryRegion<u32, zermio::access::Write>,
 }
 
+/// Hardware parameter RX_FIFO_DEPTH. Number of entries of the RX FIFO
+pub const RX_FIFO_DEPTH: u32 = 8;
+/// Hardware parameter TX_FIFO_DEPTH.
+pub const TX_FIFO_DEPTH: u32 = 8;
+/// Hardware parameter NUM_ALERTS.
+pub const NUM_ALERTS: u32 = 1;
+
 impl Uart {
     pub fn new(instance: u32) -> Self {
     let addr = instance as u32;
@@ -73,7 +93,33 @@ impl Uart {
         ovrd : ovrd::Ovrd::new(addr + 0x28 ),
         val : val::Val::new(addr + 0x2c ),
         timeout_ctrl : timeout_ctrl::TimeoutCtrl::new(addr + 0x30 ),
+        err_code : err_code::ErrCode::new(addr + 0x34 ),
+        timeout_regwen : timeout_regwen::TimeoutRegwen::new(addr + 0x38 ),
+        tx_buf : zermio::MemoryRegion::new((addr + 0x100) as usize, 0x40),
+      }
+  }
+
+  /// Fetch ERR_CODE and return whether SHADOW_UPDATE_ERR is set: The two writes to a shadowed register didn't match
+  pub fn shadow_update_err(&mut self) -> bool {
+      self.err_code.fetch().shadow_update_err().is_set()
+  }
+
+  /// Fetch ERR_CODE and return whether SHADOW_STORAGE_ERR is set: The shadow copy of a shadowed register was corrupted
+  pub fn shadow_storage_err(&mut self) -> bool {
+      self.err_code.fetch().shadow_storage_err().is_set()
+  }
+
+  /// Fetch TIMEOUT_REGWEN and only write TIMEOUT_CTRL if EN is set, as
+  /// the hardware silently ignores the writes otherwise.
+  pub fn try_write_timeout_ctrl<F>(&mut self, action: F) -> Result<(), zermio::Locked>
+  where
+    F: FnOnce(&mut timeout_ctrl::TimeoutCtrl),
+  {
+      if !self.timeout_regwen.fetch().en().is_set() {
+          return Err(zermio::Locked);
       }
+      self.timeout_ctrl.write(action);
+      Ok(())
   }
 }
 
@@ -86,25 +132,40 @@ mod intr_state {
   use super::*;
 
   /// # INTR_STATE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_WATERMARK | raised if the transmit FIFO is past the high-water mark. | [0:0] | false |true |
-  /// | RX_WATERMARK | raised if the receive FIFO is past the high-water mark. | [1:1] | false |true |
-  /// | TX_DONE | raised if the transmit FIFO has emptied and no transmit is ongoing. | [2:2] | true |true |
-  /// | RX_OVERFLOW | raised if the receive FIFO has overflowed. | [3:3] | true |true |
-  /// | RX_FRAME_ERR | raised if a framing error has been detected on receive. | [4:4] | true |true |
-  /// | RX_BREAK_ERR | raised if break condition has been detected on receive. | [5:5] | true |true |
-  /// | RX_TIMEOUT | raised if RX FIFO has characters remaining in the FIFO without beingretrieved for the programmed time period. | [6:6] | true |true |
-  /// | RX_PARITY_ERR | raised if the receiver has detected a parity error. | [7:7] | true |true |
-  /// | TX_EMPTY | raised if the transmit FIFO is empty. | [8:8] | false |true |
+  /// Reset value: 0x101
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_WATERMARK | raised if the transmit FIFO is past the high-water mark. | [0:0] | 0x1 | false |true |
+  /// | RX_WATERMARK | raised if the receive FIFO is past the high-water mark. | [1:1] | 0x0 | false |true |
+  /// | TX_DONE | raised if the transmit FIFO has emptied and no transmit is ongoing. | [2:2] | 0x0 | true |true |
+  /// | RX_OVERFLOW | raised if the receive FIFO has overflowed. | [3:3] | 0x0 | true |true |
+  /// | RX_FRAME_ERR | raised if a framing error has been detected on receive. | [4:4] | 0x0 | true |true |
+  /// | RX_BREAK_ERR | raised if break condition has been detected on receive. | [5:5] | 0x0 | true |true |
+  /// | RX_TIMEOUT | raised if RX FIFO has characters remaining in the FIFO without beingretrieved for the programmed time period. | [6:6] | 0x0 | true |true |
+  /// | RX_PARITY_ERR | raised if the receiver has detected a parity error. | [7:7] | 0x0 | true |true |
+  /// | TX_EMPTY | raised if the transmit FIFO is empty. | [8:8] | 0x1 | false |true |
   pub struct IntrState {
     reg: zermio::Register<u32>,
   }
 
   impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x101;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0xfc;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
       }
     }
   /// Hardware description: raised if the transmit FIFO is past the high-water mark.
@@ -149,6 +210,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -170,6 +232,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -191,6 +254,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -212,6 +276,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -233,6 +298,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -254,6 +320,7 @@ mod intr_state {
   /// | **`reset()`**         | Sets a single-bit field to 0.                   |
   /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
   /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -284,32 +351,56 @@ mod intr_state {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
     pub fn update<F>(&mut self, action: F)
     where
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
     }
   }
   type TxWatermarkField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
   type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
-  type TxDoneField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
-  type RxOverflowField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
-  type RxFrameErrField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
-  type RxBreakErrField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
-  type RxTimeoutField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
-  type RxParityErrField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type TxDoneField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxOverflowField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxFrameErrField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxBreakErrField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxTimeoutField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxParityErrField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
   type TxEmptyField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
 }
 
@@ -322,22 +413,29 @@ mod intr_enable {
   use super::*;
 
   /// # INTR_ENABLE fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_WATERMARK | Enable interrupt when !!INTR_STATE.tx_watermark is set. | [0:0] | true |true |
-  /// | RX_WATERMARK | Enable interrupt when !!INTR_STATE.rx_watermark is set. | [1:1] | true |true |
-  /// | TX_DONE | Enable interrupt when !!INTR_STATE.tx_done is set. | [2:2] | true |true |
-  /// | RX_OVERFLOW | Enable interrupt when !!INTR_STATE.rx_overflow is set. | [3:3] | true |true |
-  /// | RX_FRAME_ERR | Enable interrupt when !!INTR_STATE.rx_frame_err is set. | [4:4] | true |true |
-  /// | RX_BREAK_ERR | Enable interrupt when !!INTR_STATE.rx_break_err is set. | [5:5] | true |true |
-  /// | RX_TIMEOUT | Enable interrupt when !!INTR_STATE.rx_timeout is set. | [6:6] | true |true |
-  /// | RX_PARITY_ERR | Enable interrupt when !!INTR_STATE.rx_parity_err is set. | [7:7] | true |true |
-  /// | TX_EMPTY | Enable interrupt when !!INTR_STATE.tx_empty is set. | [8:8] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_WATERMARK | Enable interrupt when !!INTR_STATE.tx_watermark is set. | [0:0] | 0x0 | true |true |
+  /// | RX_WATERMARK | Enable interrupt when !!INTR_STATE.rx_watermark is set. | [1:1] | 0x0 | true |true |
+  /// | TX_DONE | Enable interrupt when !!INTR_STATE.tx_done is set. | [2:2] | 0x0 | true |true |
+  /// | RX_OVERFLOW | Enable interrupt when !!INTR_STATE.rx_overflow is set. | [3:3] | 0x0 | true |true |
+  /// | RX_FRAME_ERR | Enable interrupt when !!INTR_STATE.rx_frame_err is set. | [4:4] | 0x0 | true |true |
+  /// | RX_BREAK_ERR | Enable interrupt when !!INTR_STATE.rx_break_err is set. | [5:5] | 0x0 | true |true |
+  /// | RX_TIMEOUT | Enable interrupt when !!INTR_STATE.rx_timeout is set. | [6:6] | 0x0 | true |true |
+  /// | RX_PARITY_ERR | Enable interrupt when !!INTR_STATE.rx_parity_err is set. | [7:7] | 0x0 | true |true |
+  /// | TX_EMPTY | Enable interrupt when !!INTR_STATE.tx_empty is set. | [8:8] | 0x0 | true |true |
   pub struct IntrEnable {
     reg: zermio::Register<u32>,
   }
 
   impl IntrEnable {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -541,14 +639,31 @@ mod intr_enable {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -556,7 +671,8 @@ mod intr_enable {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TxWatermarkField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -579,22 +695,29 @@ mod intr_test {
   use super::*;
 
   /// # INTR_TEST fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX_WATERMARK | Write 1 to force !!INTR_STATE.tx_watermark to 1. | [0:0] | true |false |
-  /// | RX_WATERMARK | Write 1 to force !!INTR_STATE.rx_watermark to 1. | [1:1] | true |false |
-  /// | TX_DONE | Write 1 to force !!INTR_STATE.tx_done to 1. | [2:2] | true |false |
-  /// | RX_OVERFLOW | Write 1 to force !!INTR_STATE.rx_overflow to 1. | [3:3] | true |false |
-  /// | RX_FRAME_ERR | Write 1 to force !!INTR_STATE.rx_frame_err to 1. | [4:4] | true |false |
-  /// | RX_BREAK_ERR | Write 1 to force !!INTR_STATE.rx_break_err to 1. | [5:5] | true |false |
-  /// | RX_TIMEOUT | Write 1 to force !!INTR_STATE.rx_timeout to 1. | [6:6] | true |false |
-  /// | RX_PARITY_ERR | Write 1 to force !!INTR_STATE.rx_parity_err to 1. | [7:7] | true |false |
-  /// | TX_EMPTY | Write 1 to force !!INTR_STATE.tx_empty to 1. | [8:8] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_WATERMARK | Write 1 to force !!INTR_STATE.tx_watermark to 1. | [0:0] | 0x0 | true |false |
+  /// | RX_WATERMARK | Write 1 to force !!INTR_STATE.rx_watermark to 1. | [1:1] | 0x0 | true |false |
+  /// | TX_DONE | Write 1 to force !!INTR_STATE.tx_done to 1. | [2:2] | 0x0 | true |false |
+  /// | RX_OVERFLOW | Write 1 to force !!INTR_STATE.rx_overflow to 1. | [3:3] | 0x0 | true |false |
+  /// | RX_FRAME_ERR | Write 1 to force !!INTR_STATE.rx_frame_err to 1. | [4:4] | 0x0 | true |false |
+  /// | RX_BREAK_ERR | Write 1 to force !!INTR_STATE.rx_break_err to 1. | [5:5] | 0x0 | true |false |
+  /// | RX_TIMEOUT | Write 1 to force !!INTR_STATE.rx_timeout to 1. | [6:6] | 0x0 | true |false |
+  /// | RX_PARITY_ERR | Write 1 to force !!INTR_STATE.rx_parity_err to 1. | [7:7] | 0x0 | true |false |
+  /// | TX_EMPTY | Write 1 to force !!INTR_STATE.tx_empty to 1. | [8:8] | 0x0 | true |false |
   pub struct IntrTest {
     reg: zermio::Register<u32>,
   }
 
   impl IntrTest {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -775,14 +898,31 @@ mod intr_test {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
   type TxWatermarkField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
   type RxWatermarkField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Write>;
@@ -804,22 +944,29 @@ mod ctrl {
   use super::*;
 
   /// # CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TX | TX enable | [0:0] | true |true |
-  /// | RX | RX enable | [1:1] | true |true |
-  /// | NF | RX noise filter enable.If the noise filter is enabled, RX line goes through the 3-taprepetition code. It ignores single IP clock period noise. | [2:2] | true |true |
-  /// | SLPBK | System loopback enable.If this bit is turned on, any outgoing bits to TX are received through RX.See Block Diagram. Note that the TX line goes 1 if System loopback is enabled. | [4:4] | true |true |
-  /// | LLPBK | Line loopback enable.If this bit is turned on, incoming bits are forwarded to TX for testing purpose.See Block Diagram. Note that the internal design sees RX value as 1 always if lineloopback is enabled. | [5:5] | true |true |
-  /// | PARITY_EN | If true, parity is enabled in both RX and TX directions. | [6:6] | true |true |
-  /// | PARITY_ODD | If PARITY_EN is true, this determines the type, 1 for odd parity, 0 for even. | [7:7] | true |true |
-  /// | RXBLVL | Trigger level for RX break detection. Sets the number of charactertimes the line must be low to detect a break. | [9:8] | true |true |
-  /// | NCO | BAUD clock rate control. | [31:16] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX | TX enable | [0:0] | 0x0 | true |true |
+  /// | RX | RX enable | [1:1] | 0x0 | true |true |
+  /// | NF | RX noise filter enable.If the noise filter is enabled, RX line goes through the 3-taprepetition code. It ignores single IP clock period noise. | [2:2] | 0x0 | true |true |
+  /// | SLPBK | System loopback enable.If this bit is turned on, any outgoing bits to TX are received through RX.See Block Diagram. Note that the TX line goes 1 if System loopback is enabled. | [4:4] | 0x0 | true |true |
+  /// | LLPBK | Line loopback enable.If this bit is turned on, incoming bits are forwarded to TX for testing purpose.See Block Diagram. Note that the internal design sees RX value as 1 always if lineloopback is enabled. | [5:5] | 0x0 | true |true |
+  /// | PARITY_EN | If true, parity is enabled in both RX and TX directions. | [6:6] | 0x0 | true |true |
+  /// | PARITY_ODD | If PARITY_EN is true, this determines the type, 1 for odd parity, 0 for even. | [7:7] | 0x0 | true |true |
+  /// | RXBLVL | Trigger level for RX break detection. Sets the number of charactertimes the line must be low to detect a break. | [9:8] | 0x0 | true |true |
+  /// | NCO | BAUD clock rate control. | [31:16] | 0x0 | true |true |
   pub struct Ctrl {
     reg: zermio::Register<u32>,
   }
 
   impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -981,6 +1128,8 @@ mod ctrl {
   /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
   /// | **`clear()`**         | Sets all bits in the field to 0.                |
   /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`CtrlRxblvl`]. |
+  /// | **`write_variant(val)`** | Writes a [`CtrlRxblvl`] to the field. |
   /// | **`mask()`**          | Returns the bitmask for this field.             |
   /// | **`max()`**           | Returns the maximum value this field can hold.  |
   ///
@@ -1013,14 +1162,31 @@ mod ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -1028,7 +1194,8 @@ mod ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TxField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -1038,8 +1205,42 @@ mod ctrl {
   type LlpbkField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
   type ParityEnField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
   type ParityOddField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
-  type RxblvlField<'a> = zermio::BitField<'a, 8, 2, u32, zermio::access::ReadWrite>;
+  type RxblvlField<'a> = zermio::BitField<'a, 8, 2, u32, zermio::access::ReadWrite, CtrlRxblvl>;
   type NcoField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+
+  /// Enumerated values of the field RXBLVL: Trigger level for RX break detection. Sets the number of charactertimes the line must be low to detect a break.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum CtrlRxblvl {
+    /// 2 characters
+    Break2 = 0x0,
+    /// 4 characters
+    Break4 = 0x1,
+    /// 8 characters
+    Break8 = 0x2,
+    /// 16 characters
+    Break16 = 0x3,
+  }
+
+  impl From<CtrlRxblvl> for u32 {
+    fn from(variant: CtrlRxblvl) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for CtrlRxblvl {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Break2),
+        0x1 => Ok(Self::Break4),
+        0x2 => Ok(Self::Break8),
+        0x3 => Ok(Self::Break16),
+        _ => Err(value),
+      }
+    }
+  }
 }
 
 
@@ -1051,19 +1252,26 @@ mod status {
   use super::*;
 
   /// # STATUS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TXFULL | TX buffer is full | [0:0] | false |true |
-  /// | RXFULL | RX buffer is full | [1:1] | false |true |
-  /// | TXEMPTY | TX FIFO is empty | [2:2] | false |true |
-  /// | TXIDLE | TX FIFO is empty and all bits have been transmitted | [3:3] | false |true |
-  /// | RXIDLE | RX is idle | [4:4] | false |true |
-  /// | RXEMPTY | RX FIFO is empty | [5:5] | false |true |
+  /// Reset value: 0x3c
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXFULL | TX buffer is full | [0:0] | 0x0 | false |true |
+  /// | RXFULL | RX buffer is full | [1:1] | 0x0 | false |true |
+  /// | TXEMPTY | TX FIFO is empty | [2:2] | 0x1 | false |true |
+  /// | TXIDLE | TX FIFO is empty and all bits have been transmitted | [3:3] | 0x1 | false |true |
+  /// | RXIDLE | RX is idle | [4:4] | 0x1 | false |true |
+  /// | RXEMPTY | RX FIFO is empty | [5:5] | 0x1 | false |true |
   pub struct Status {
     reg: zermio::Register<u32>,
   }
 
   impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x3c;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1176,17 +1384,24 @@ mod rdata {
   use super::*;
 
   /// # RDATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RDATA |  | [7:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RDATA |  | [7:0] | 0x0 | false |true |
   pub struct Rdata {
-    reg: zermio::Register<u32>,
+    reg: zermio::Register<u8>,
   }
 
   impl Rdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u8 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u8 = 0xff;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u8>::new(addr as usize),
       }
     }
   /// Hardware description: 
@@ -1208,7 +1423,7 @@ mod rdata {
       self
     }
   }
-  type RdataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Read>;
+  type RdataField<'a> = zermio::BitField<'a, 0, 8, u8, zermio::access::Read>;
 }
 
 
@@ -1220,17 +1435,24 @@ mod wdata {
   use super::*;
 
   /// # WDATA fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | WDATA |  | [7:0] | true |false |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | WDATA |  | [7:0] | 0x0 | true |false |
   pub struct Wdata {
-    reg: zermio::Register<u32>,
+    reg: zermio::Register<u8>,
   }
 
   impl Wdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u8 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u8 = 0xff;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u8>::new(addr as usize),
       }
     }
   /// Hardware description: 
@@ -1252,16 +1474,33 @@ mod wdata {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
   }
-  type WdataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
+  type WdataField<'a> = zermio::BitField<'a, 0, 8, u8, zermio::access::Write>;
 }
 
 
@@ -1273,17 +1512,24 @@ mod fifo_ctrl {
   use super::*;
 
   /// # FIFO_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RXRST | RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 | [0:0] | true |false |
-  /// | TXRST | TX fifo reset. Write 1 to the register resets TX_FIFO. Read returns 0 | [1:1] | true |false |
-  /// | RXILVL | Trigger level for RX interrupts. If the FIFO depth is greater than or equal tothe setting, it raises rx_watermark interrupt. | [4:2] | true |true |
-  /// | TXILVL | Trigger level for TX interrupts. If the FIFO depth is less than the setting, itraises tx_watermark interrupt. | [7:5] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RXRST | RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 | [0:0] | 0x0 | true |false |
+  /// | TXRST | TX fifo reset. Write 1 to the register resets TX_FIFO. Read returns 0 | [1:1] | 0x0 | true |false |
+  /// | RXILVL | Trigger level for RX interrupts. If the FIFO depth is greater than or equal tothe setting, it raises rx_watermark interrupt. | [4:2] | 0x0 | true |true |
+  /// | TXILVL | Trigger level for TX interrupts. If the FIFO depth is less than the setting, itraises tx_watermark interrupt. | [7:5] | 0x0 | true |true |
   pub struct FifoCtrl {
     reg: zermio::Register<u32>,
   }
 
   impl FifoCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1368,14 +1614,31 @@ mod fifo_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -1383,7 +1646,8 @@ mod fifo_ctrl {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type RxrstField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Write>;
@@ -1401,15 +1665,22 @@ mod fifo_status {
   use super::*;
 
   /// # FIFO_STATUS fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TXLVL | Current fill level of TX fifo | [7:0] | false |true |
-  /// | RXLVL | Current fill level of RX fifo | [23:16] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXLVL | Current fill level of TX fifo | [7:0] | 0x0 | false |true |
+  /// | RXLVL | Current fill level of RX fifo | [23:16] | 0x0 | false |true |
   pub struct FifoStatus {
     reg: zermio::Register<u32>,
   }
 
   impl FifoStatus {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1460,15 +1731,22 @@ mod ovrd {
   use super::*;
 
   /// # OVRD fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | TXEN | Enable TX pin override control | [0:0] | true |true |
-  /// | TXVAL | Write to set the value of the TX pin | [1:1] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXEN | Enable TX pin override control | [0:0] | 0x0 | true |true |
+  /// | TXVAL | Write to set the value of the TX pin | [1:1] | 0x0 | true |true |
   pub struct Ovrd {
     reg: zermio::Register<u32>,
   }
 
   impl Ovrd {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1525,14 +1803,31 @@ mod ovrd {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -1540,7 +1835,8 @@ mod ovrd {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
   type TxenField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
@@ -1556,14 +1852,21 @@ mod val {
   use super::*;
 
   /// # VAL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | RX | Last 16 oversampled values of RX. Most recent bit is bit 0, oldest 15. | [15:0] | false |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RX | Last 16 oversampled values of RX. Most recent bit is bit 0, oldest 15. | [15:0] | 0x0 | false |true |
   pub struct Val {
     reg: zermio::Register<u32>,
   }
 
   impl Val {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
         reg: zermio::Register::<u32>::new(addr as usize),
@@ -1600,18 +1903,32 @@ mod timeout_ctrl {
   use super::*;
 
   /// # TIMEOUT_CTRL fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | VAL | RX timeout value in UART bit times | [23:0] | true |true |
-  /// | EN | Enable RX timeout feature | [31:31] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VAL | RX timeout value in UART bit times | [23:0] | 0x0 | true |true |
+  /// | EN | Enable RX timeout feature | [31:31] | 0x0 | true |true |
+  ///
+  /// **Shadowed register:** every store instruction is run twice with the same value, as the
+  /// hardware only takes it after two consecutive writes. A mismatch is reported as an update
+  /// error.
+  ///
+  /// **Write enable:** the hardware ignores the writes to this register while
+  /// TIMEOUT_REGWEN.EN is clear.
   pub struct TimeoutCtrl {
-    reg: zermio::Register<u32>,
+    reg: zermio::Register<u32, true>,
   }
 
   impl TimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u32, true>::new(addr as usize),
       }
     }
   /// Hardware description: RX timeout value in UART bit times
@@ -1651,6 +1968,182 @@ mod timeout_ctrl {
         EnField::new(&mut self.reg)
     }
 
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run two store instructions to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ValField<'a> = zermio::BitField<'a, 0, 24, u32, zermio::access::ReadWrite, (), zermio::effect::Modify, zermio::load::Plain, true>;
+  type EnField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite, (), zermio::effect::Modify, zermio::load::Plain, true>;
+}
+
+
+
+/// Errors of the shadowed registers.  
+pub use self::err_code::*;
+mod err_code {
+
+  use super::*;
+
+  /// # ERR_CODE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | SHADOW_UPDATE_ERR | The two writes to a shadowed register didn't match | [0:0] | 0x0 | false |true |
+  /// | SHADOW_STORAGE_ERR | The shadow copy of a shadowed register was corrupted | [1:1] | 0x0 | false |true |
+  pub struct ErrCode {
+    reg: zermio::Register<u32>,
+  }
+
+  impl ErrCode {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: The two writes to a shadowed register didn't match
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn shadow_update_err(&mut self) -> ShadowUpdateErrField {
+        ShadowUpdateErrField::new(&mut self.reg)
+    }
+
+  /// Hardware description: The shadow copy of a shadowed register was corrupted
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn shadow_storage_err(&mut self) -> ShadowStorageErrField {
+        ShadowStorageErrField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type ShadowUpdateErrField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+  type ShadowStorageErrField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// Lock of the RX timeout configuration.  
+pub use self::timeout_regwen::*;
+mod timeout_regwen {
+
+  use super::*;
+
+  /// # TIMEOUT_REGWEN fields.  
+  /// Reset value: 0x1
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | EN | When cleared, TIMEOUT_CTRL can't be written until the next reset | [0:0] | 0x1 | true |true |
+  pub struct TimeoutRegwen {
+    reg: zermio::Register<u32>,
+  }
+
+  impl TimeoutRegwen {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x1;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x1;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x1;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: When cleared, TIMEOUT_CTRL can't be written until the next reset
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (ZeroToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en(&mut self) -> EnField {
+        EnField::new(&mut self.reg)
+    }
+
     /// Run a load instruction to read from MMIO
     pub fn fetch(&mut self) -> &mut Self {
       self.reg.fetch();
@@ -1660,24 +2153,47 @@ mod timeout_ctrl {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
     pub fn update<F>(&mut self, action: F)
     where
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
     }
   }
-  type ValField<'a> = zermio::BitField<'a, 0, 24, u32, zermio::access::ReadWrite>;
-  type EnField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type EnField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::ZeroToClear>;
 }
@@ -19,7 +19,7 @@
 
 use super::zermio;
 /// ``` rust
-/// xadc.config0.update(|config0| {
+/// xadc.config[0].update(|config0| {
 ///     if config0.cfg().read() == 1 {
 ///        config0.cfg().write(0);
 ///     }
@@ -27,32 +27,17 @@ use super::zermio;
 /// ```
 pub struct Xadc {
     ///   
-    pub config0 : config::Config,
+    pub config : [config::Config; 3],
     ///   
-    pub config1 : config::Config,
-    ///   
-    pub config2 : config::Config,
-    ///   
-    pub test_reg0 : test_reg::TestReg,
-    ///   
-    pub test_reg1 : test_reg::TestReg,
-    ///   
-    pub test_reg2 : test_reg::TestReg,
-    ///   
-    pub test_reg3 : test_reg::TestReg,
+    pub test_reg : [test_reg::TestReg; 4],
 }
 
 impl Xadc {
     pub fn new(instance: u32) -> Self {
     let addr = instance as u32;
       Self {
-        config0 : config0::Config0::new(addr + 0x40 ),
-        config1 : config1::Config1::new(addr + 0x41 ),
-        config2 : config2::Config2::new(addr + 0x42 ),
-        test_reg0 : test_reg0::TestReg0::new(addr + 0x43 ),
-        test_reg1 : test_reg1::TestReg1::new(addr + 0x44 ),
-        test_reg2 : test_reg2::TestReg2::new(addr + 0x45 ),
-        test_reg3 : test_reg3::TestReg3::new(addr + 0x46 ),
+        config : core::array::from_fn(|n| config::Config::new(addr + 0x40 + n as u32 * 0x1)),
+        test_reg : core::array::from_fn(|n| test_reg::TestReg::new(addr + 0x43 + n as u32 * 0x1)),
       }
   }
 }
@@ -66,17 +51,24 @@ mod config {
   use super::*;
 
   /// # CONFIG fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | cfg |  | [0:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | cfg |  | [0:0] | 0x0 | true |true |
   pub struct Config {
-    reg: zermio::Register<u32>,
+    reg: zermio::Register<u8>,
   }
 
   impl Config {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u8 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u8 = 0xff;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u8>::new(addr as usize),
       }
     }
   /// Hardware description: 
@@ -109,14 +101,31 @@ mod config {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
       action(self);
       self.reg.commit();
     }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -124,10 +133,11 @@ mod config {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
-  type CfgField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type CfgField<'a> = zermio::BitField<'a, 0, 1, u8, zermio::access::ReadWrite>;
 }
 
 
@@ -139,17 +149,24 @@ mod test_reg {
   use super::*;
 
   /// # TEST_REG fields.  
-  /// | Field name      | Description  | Offset | Write | Read| 
-  /// | :---               | :---         | :---   | :--   | :-- |
-  /// | tr |  | [0:0] | true |true |
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | tr |  | [0:0] | 0x0 | true |true |
   pub struct TestReg {
-    reg: zermio::Register<u32>,
+    reg: zermio::Register<u8>,
   }
 
   impl TestReg {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u8 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u8 = 0xff;
+
     pub fn new(addr: u32) -> Self {
       Self {
-        reg: zermio::Register::<u32>::new(addr as usize),
+        reg: zermio::Register::<u8>::new(addr as usize),
       }
     }
   /// Hardware description: 
@@ -182,14 +199,31 @@ mod test_reg {
     pub fn commit(&mut self) {
       self.reg.commit()
     }
-    /// Call action to update the inner self, then run a store instruction to write it to the MMIO
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
     pub fn write<F>(&mut self, action: F)
     where
       F: FnOnce(&mut Self),
     {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
       action(self);
       self.reg.commit();
     }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
     /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
     /// to write it to the MMIO
     pub fn update<F>(&mut self, action: F)
@@ -197,8 +231,9 @@ mod test_reg {
         F: FnOnce(&mut Self),
     {
       self.reg.fetch();
-      self.write(action);
+      action(self);
+      self.reg.commit();
     }
   }
-  type TrField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type TrField<'a> = zermio::BitField<'a, 0, 1, u8, zermio::access::ReadWrite>;
 }
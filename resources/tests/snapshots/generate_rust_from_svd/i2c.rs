@@ -0,0 +1,2736 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the registers present in the i2c 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | CTRL | I2C Control Register | 0x10 | true |true |
+//! | STATUS | I2C Live Status Register | 0x14 | false |true |
+//! | RDATA | I2C Read Data | 0x18 | false |true |
+//! | FDATA | I2C Format Data | 0x1c | true |false |
+//! | FIFO_CTRL | I2C FIFO control register | 0x20 | true |true |
+//! | FIFO_STATUS | I2C FIFO status register | 0x24 | false |true |
+//! | OVRD | I2C Override Control Register | 0x58 | true |true |
+//! | VAL | Oversampled RX values | 0x5c | false |true |
+//! | TIMING0 | Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.These must be greater than 2 in order for the change in SCL to propagate to the input of the FSM so that acknowledgements are detected correctly. | 0x60 | true |true |
+//! | TIMING1 | Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period. | 0x64 | true |true |
+//! | TIMING2 | Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period. | 0x68 | true |true |
+//! | TIMING3 | Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period. | 0x6c | true |true |
+//! | TIMING4 | Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period. | 0x70 | true |true |
+//! | TIMEOUT_CTRL | I2C clock stretching timeout control | 0x74 | true |true |
+//! | TARGET_ID | I2C target address and mask pairs | 0x78 | true |true |
+//! | ACQDATA | I2C target acquired data | 0x7c | false |true |
+//! | TXDATA | I2C target transmit data | 0x80 | true |false |
+//! | HOST_TIMEOUT_CTRL | I2C host clock generation timeout value (in units of input clock frequency) | 0x84 | true |true |
+//! | CFG_LOCK | I2C configuration lock, only the first write after reset takes effect | 0x88 | true |true |
+//! | PAD_CTRL | I2C pads configuration | 0x8c | true |true |
+
+use super::zermio;
+/// ``` rust
+/// i2c.ctrl.update(|ctrl| {
+///     if ctrl.enablehost().read() == 1 {
+///        ctrl.enablehost().write(0);
+///     }
+/// });
+/// ```
+pub struct I2c {
+    /// I2C Control Register  
+    pub ctrl : ctrl::Ctrl,
+    /// I2C Live Status Register  
+    pub status : status::Status,
+    /// I2C Read Data  
+    pub rdata : rdata::Rdata,
+    /// I2C Format Data  
+    pub fdata : fdata::Fdata,
+    /// I2C FIFO control register  
+    pub fifo_ctrl : fifo_ctrl::FifoCtrl,
+    /// I2C FIFO status register  
+    pub fifo_status : fifo_status::FifoStatus,
+    /// I2C Override Control Register  
+    pub ovrd : ovrd::Ovrd,
+    /// Oversampled RX values  
+    pub val : val::Val,
+    /// Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.These must be greater than 2 in order for the change in SCL to propagate to the input of the FSM so that acknowledgements are detected correctly.  
+    pub timing0 : timing0::Timing0,
+    /// Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.  
+    pub timing1 : timing1::Timing1,
+    /// Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.  
+    pub timing2 : timing2::Timing2,
+    /// Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period.  
+    pub timing3 : timing3::Timing3,
+    /// Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period.  
+    pub timing4 : timing4::Timing4,
+    /// I2C clock stretching timeout control  
+    pub timeout_ctrl : timeout_ctrl::TimeoutCtrl,
+    /// I2C target address and mask pairs  
+    pub target_id : target_id::TargetId,
+    /// I2C target acquired data  
+    pub acqdata : acqdata::Acqdata,
+    /// I2C target transmit data  
+    pub txdata : txdata::Txdata,
+    /// I2C host clock generation timeout value (in units of input clock frequency)  
+    pub host_timeout_ctrl : host_timeout_ctrl::HostTimeoutCtrl,
+    /// I2C configuration lock, only the first write after reset takes effect  
+    pub cfg_lock : cfg_lock::CfgLock,
+    /// I2C pads configuration  
+    pub pad_ctrl : pad_ctrl::PadCtrl,
+}
+
+impl I2c {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        ctrl : ctrl::Ctrl::new(addr + 0x10 ),
+        status : status::Status::new(addr + 0x14 ),
+        rdata : rdata::Rdata::new(addr + 0x18 ),
+        fdata : fdata::Fdata::new(addr + 0x1c ),
+        fifo_ctrl : fifo_ctrl::FifoCtrl::new(addr + 0x20 ),
+        fifo_status : fifo_status::FifoStatus::new(addr + 0x24 ),
+        ovrd : ovrd::Ovrd::new(addr + 0x58 ),
+        val : val::Val::new(addr + 0x5c ),
+        timing0 : timing0::Timing0::new(addr + 0x60 ),
+        timing1 : timing1::Timing1::new(addr + 0x64 ),
+        timing2 : timing2::Timing2::new(addr + 0x68 ),
+        timing3 : timing3::Timing3::new(addr + 0x6c ),
+        timing4 : timing4::Timing4::new(addr + 0x70 ),
+        timeout_ctrl : timeout_ctrl::TimeoutCtrl::new(addr + 0x74 ),
+        target_id : target_id::TargetId::new(addr + 0x78 ),
+        acqdata : acqdata::Acqdata::new(addr + 0x7c ),
+        txdata : txdata::Txdata::new(addr + 0x80 ),
+        host_timeout_ctrl : host_timeout_ctrl::HostTimeoutCtrl::new(addr + 0x84 ),
+        cfg_lock : cfg_lock::CfgLock::new(addr + 0x88 ),
+        pad_ctrl : pad_ctrl::PadCtrl::new(addr + 0x8c ),
+      }
+  }
+}
+
+
+
+/// I2C Control Register.  
+pub use self::ctrl::*;
+mod ctrl {
+
+  use super::*;
+
+  /// # CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ENABLEHOST | Enable Host I2C functionality | [0:0] | 0x0 | true |true |
+  /// | ENABLETARGET | Enable Target I2C functionality | [1:1] | 0x0 | true |true |
+  /// | LLPBK | Enable I2C line loopback testIf line loopback is enabled, the internal design sees ACQ and RX data as "1" | [2:2] | 0x0 | true |true |
+  pub struct Ctrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Enable Host I2C functionality
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn enablehost(&mut self) -> EnablehostField {
+        EnablehostField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable Target I2C functionality
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn enabletarget(&mut self) -> EnabletargetField {
+        EnabletargetField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable I2C line loopback testIf line loopback is enabled, the internal design sees ACQ and RX data as "1"
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn llpbk(&mut self) -> LlpbkField {
+        LlpbkField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type EnablehostField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type EnabletargetField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type LlpbkField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// I2C Live Status Register.  
+pub use self::status::*;
+mod status {
+
+  use super::*;
+
+  /// # STATUS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMTFULL | FMT FIFO is full | [0:0] | 0x0 | false |true |
+  /// | RXFULL | RX FIFO is full | [1:1] | 0x0 | false |true |
+  /// | FMTEMPTY | FMT FIFO is empty | [2:2] | 0x0 | false |true |
+  /// | RXEMPTY | RX FIFO is empty | [5:5] | 0x0 | false |true |
+  /// | HOSTIDLE | Host functionality is idle. No Host transaction is in progress | [3:3] | 0x0 | false |true |
+  /// | TARGETIDLE | Target functionality is idle. No Target transaction is in progress | [4:4] | 0x0 | false |true |
+  /// | TXFULL | TX FIFO is full | [6:6] | 0x0 | false |true |
+  /// | ACQFULL | ACQ FIFO is full | [7:7] | 0x0 | false |true |
+  /// | TXEMPTY | TX FIFO is empty | [8:8] | 0x0 | false |true |
+  /// | ACQEMPTY | ACQ FIFO is empty | [9:9] | 0x0 | false |true |
+  pub struct Status {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: FMT FIFO is full
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fmtfull(&mut self) -> FmtfullField {
+        FmtfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RX FIFO is full
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxfull(&mut self) -> RxfullField {
+        RxfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: FMT FIFO is empty
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fmtempty(&mut self) -> FmtemptyField {
+        FmtemptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: RX FIFO is empty
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxempty(&mut self) -> RxemptyField {
+        RxemptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Host functionality is idle. No Host transaction is in progress
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn hostidle(&mut self) -> HostidleField {
+        HostidleField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Target functionality is idle. No Target transaction is in progress
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn targetidle(&mut self) -> TargetidleField {
+        TargetidleField::new(&mut self.reg)
+    }
+
+  /// Hardware description: TX FIFO is full
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txfull(&mut self) -> TxfullField {
+        TxfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: ACQ FIFO is full
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn acqfull(&mut self) -> AcqfullField {
+        AcqfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: TX FIFO is empty
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txempty(&mut self) -> TxemptyField {
+        TxemptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: ACQ FIFO is empty
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn acqempty(&mut self) -> AcqemptyField {
+        AcqemptyField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type FmtfullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+  type RxfullField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type FmtemptyField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
+  type RxemptyField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::Read>;
+  type HostidleField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::Read>;
+  type TargetidleField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
+  type TxfullField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::Read>;
+  type AcqfullField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::Read>;
+  type TxemptyField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
+  type AcqemptyField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// I2C Read Data.  
+pub use self::rdata::*;
+mod rdata {
+
+  use super::*;
+
+  /// # RDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | Value | Value | [7:0] | 0x0 | false |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * Value: Modify on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`Rdata::read_with_side_effects`].
+  pub struct Rdata {
+    reg: zermio::Register<u8>,
+  }
+
+  impl Rdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u8 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u8 = 0xff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u8>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Value
+  ///
+  /// Side effect on read: Modify.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_with_side_effects(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 8, u8, zermio::access::Read, (), zermio::effect::Modify, zermio::load::SideEffects>;
+}
+
+
+
+/// I2C Format Data.  
+pub use self::fdata::*;
+mod fdata {
+
+  use super::*;
+
+  /// # FDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FBYTE | Format Byte. Directly transmitted if no flags are set. | [7:0] | 0x0 | true |false |
+  /// | START | Issue a START condition before transmitting BYTE. | [8:8] | 0x0 | true |false |
+  /// | STOP | Issue a STOP condition after this operation | [9:9] | 0x0 | true |false |
+  /// | READ | Read BYTE bytes from I2C. (256 if BYTE==0) | [10:10] | 0x0 | true |false |
+  /// | RCONT | Do not NACK the last byte read, let the read operation continue | [11:11] | 0x0 | true |false |
+  /// | NAKOK | Do not signal an exception if the current byte is not ACK'd | [12:12] | 0x0 | true |false |
+  pub struct Fdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Fdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Format Byte. Directly transmitted if no flags are set.
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fbyte(&mut self) -> FbyteField {
+        FbyteField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Issue a START condition before transmitting BYTE.
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn start(&mut self) -> StartField {
+        StartField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Issue a STOP condition after this operation
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn stop(&mut self) -> StopField {
+        StopField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Read BYTE bytes from I2C. (256 if BYTE==0)
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn read(&mut self) -> ReadField {
+        ReadField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Do not NACK the last byte read, let the read operation continue
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rcont(&mut self) -> RcontField {
+        RcontField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Do not signal an exception if the current byte is not ACK'd
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn nakok(&mut self) -> NakokField {
+        NakokField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type FbyteField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
+  type StartField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Write>;
+  type StopField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Write>;
+  type ReadField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::Write>;
+  type RcontField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::Write>;
+  type NakokField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Write>;
+}
+
+
+
+/// I2C FIFO control register.  
+pub use self::fifo_ctrl::*;
+mod fifo_ctrl {
+
+  use super::*;
+
+  /// # FIFO_CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RXRST | RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 | [0:0] | 0x0 | true |true |
+  /// | FMTRST | FMT fifo reset. Write 1 to the register resets FMT_FIFO. Read returns 0 | [1:1] | 0x0 | true |true |
+  /// | RXILVL | Trigger level for RX interrupts. If the FIFO depth exceedsthis setting, it raises rx_threshold interrupt. | [4:2] | 0x0 | true |true |
+  /// | FMTILVL | Trigger level for FMT interrupts. If the FIFO depth falls belowthis setting, it raises fmt_threshold interrupt. | [6:5] | 0x0 | true |true |
+  /// | ACQRST | ACQ FIFO reset. Write 1 to the register resets it. Read returns 0 | [7:7] | 0x0 | true |true |
+  /// | TXRST | TX FIFO reset. Write 1 to the register resets it. Read returns 0 | [8:8] | 0x0 | true |true |
+  pub struct FifoCtrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl FifoCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxrst(&mut self) -> RxrstField {
+        RxrstField::new(&mut self.reg)
+    }
+
+  /// Hardware description: FMT fifo reset. Write 1 to the register resets FMT_FIFO. Read returns 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fmtrst(&mut self) -> FmtrstField {
+        FmtrstField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Trigger level for RX interrupts. If the FIFO depth exceedsthis setting, it raises rx_threshold interrupt.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxilvl(&mut self) -> RxilvlField {
+        RxilvlField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Trigger level for FMT interrupts. If the FIFO depth falls belowthis setting, it raises fmt_threshold interrupt.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fmtilvl(&mut self) -> FmtilvlField {
+        FmtilvlField::new(&mut self.reg)
+    }
+
+  /// Hardware description: ACQ FIFO reset. Write 1 to the register resets it. Read returns 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn acqrst(&mut self) -> AcqrstField {
+        AcqrstField::new(&mut self.reg)
+    }
+
+  /// Hardware description: TX FIFO reset. Write 1 to the register resets it. Read returns 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txrst(&mut self) -> TxrstField {
+        TxrstField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type RxrstField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type FmtrstField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type RxilvlField<'a> = zermio::BitField<'a, 2, 3, u32, zermio::access::ReadWrite>;
+  type FmtilvlField<'a> = zermio::BitField<'a, 5, 2, u32, zermio::access::ReadWrite>;
+  type AcqrstField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type TxrstField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// I2C FIFO status register.  
+pub use self::fifo_status::*;
+mod fifo_status {
+
+  use super::*;
+
+  /// # FIFO_STATUS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FMTLVL | Current fill level of FMT fifo | [6:0] | 0x0 | false |true |
+  /// | RXLVL | Current fill level of RX fifo | [22:16] | 0x0 | false |true |
+  /// | TXLVL | Current fill level of TX fifo | [14:8] | 0x0 | false |true |
+  /// | ACQLVL | Current fill level of ACQ fifo | [30:24] | 0x0 | false |true |
+  pub struct FifoStatus {
+    reg: zermio::Register<u32>,
+  }
+
+  impl FifoStatus {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Current fill level of FMT fifo
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn fmtlvl(&mut self) -> FmtlvlField {
+        FmtlvlField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Current fill level of RX fifo
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxlvl(&mut self) -> RxlvlField {
+        RxlvlField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Current fill level of TX fifo
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txlvl(&mut self) -> TxlvlField {
+        TxlvlField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Current fill level of ACQ fifo
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn acqlvl(&mut self) -> AcqlvlField {
+        AcqlvlField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type FmtlvlField<'a> = zermio::BitField<'a, 0, 7, u32, zermio::access::Read>;
+  type RxlvlField<'a> = zermio::BitField<'a, 16, 7, u32, zermio::access::Read>;
+  type TxlvlField<'a> = zermio::BitField<'a, 8, 7, u32, zermio::access::Read>;
+  type AcqlvlField<'a> = zermio::BitField<'a, 24, 7, u32, zermio::access::Read>;
+}
+
+
+
+/// I2C Override Control Register.  
+pub use self::ovrd::*;
+mod ovrd {
+
+  use super::*;
+
+  /// # OVRD fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXOVRDEN | Override the SDA and SCL TX signals. | [0:0] | 0x0 | true |true |
+  /// | SCLVAL | Value for SCL Override. Set to 0 to drive TX Low, and set to 1 for high-Z | [1:1] | 0x0 | true |true |
+  /// | SDAVAL | Value for SDA Override. Set to 0 to drive TX Low, and set to 1 for high-Z | [2:2] | 0x0 | true |true |
+  pub struct Ovrd {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ovrd {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Override the SDA and SCL TX signals.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txovrden(&mut self) -> TxovrdenField {
+        TxovrdenField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Value for SCL Override. Set to 0 to drive TX Low, and set to 1 for high-Z
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn sclval(&mut self) -> SclvalField {
+        SclvalField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Value for SDA Override. Set to 0 to drive TX Low, and set to 1 for high-Z
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn sdaval(&mut self) -> SdavalField {
+        SdavalField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxovrdenField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type SclvalField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type SdavalField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Oversampled RX values.  
+pub use self::val::*;
+mod val {
+
+  use super::*;
+
+  /// # VAL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | SCL_RX | Last 16 oversampled values of SCL. Most recent bit is bit 0, oldest 15. | [15:0] | 0x0 | false |true |
+  /// | SDA_RX | Last 16 oversampled values of SDA. Most recent bit is bit 16, oldest 31. | [31:16] | 0x0 | false |true |
+  pub struct Val {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Val {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Last 16 oversampled values of SCL. Most recent bit is bit 0, oldest 15.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn scl_rx(&mut self) -> SclRxField {
+        SclRxField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Last 16 oversampled values of SDA. Most recent bit is bit 16, oldest 31.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn sda_rx(&mut self) -> SdaRxField {
+        SdaRxField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type SclRxField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::Read>;
+  type SdaRxField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::Read>;
+}
+
+
+
+/// Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.These must be greater than 2 in order for the change in SCL to propagate to the input of the FSM so that acknowledgements are detected correctly..  
+pub use self::timing0::*;
+mod timing0 {
+
+  use super::*;
+
+  /// # TIMING0 fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | THIGH | The actual time to hold SCL high in a given pulse: in host mode, when there is no stretching this value is 3 cycles longer as tracked in issue #18962 | [15:0] | 0x0 | true |true |
+  /// | TLOW | The actual time to hold SCL low between any two SCL pulses | [31:16] | 0x0 | true |true |
+  pub struct Timing0 {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Timing0 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: The actual time to hold SCL high in a given pulse: in host mode, when there is no stretching this value is 3 cycles longer as tracked in issue #18962
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn thigh(&mut self) -> ThighField {
+        ThighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: The actual time to hold SCL low between any two SCL pulses
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tlow(&mut self) -> TlowField {
+        TlowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ThighField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
+  type TlowField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period..  
+pub use self::timing1::*;
+mod timing1 {
+
+  use super::*;
+
+  /// # TIMING1 fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | T_R | The nominal rise time to anticipate for the bus (depends on capacitance) | [15:0] | 0x0 | true |true |
+  /// | T_F | The nominal fall time to anticipate for the bus (influences SDA hold times): this is currently counted twice in host mode as tracked in issue #18958 | [31:16] | 0x0 | true |true |
+  pub struct Timing1 {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Timing1 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: The nominal rise time to anticipate for the bus (depends on capacitance)
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn t_r(&mut self) -> TRField {
+        TRField::new(&mut self.reg)
+    }
+
+  /// Hardware description: The nominal fall time to anticipate for the bus (influences SDA hold times): this is currently counted twice in host mode as tracked in issue #18958
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn t_f(&mut self) -> TFField {
+        TFField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TRField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
+  type TFField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period..  
+pub use self::timing2::*;
+mod timing2 {
+
+  use super::*;
+
+  /// # TIMING2 fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TSU_STA | Actual setup time for repeated start signals | [15:0] | 0x0 | true |true |
+  /// | THD_STA | Actual hold time for start signals | [31:16] | 0x0 | true |true |
+  pub struct Timing2 {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Timing2 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Actual setup time for repeated start signals
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tsu_sta(&mut self) -> TsuStaField {
+        TsuStaField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Actual hold time for start signals
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn thd_sta(&mut self) -> ThdStaField {
+        ThdStaField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TsuStaField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
+  type ThdStaField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period..  
+pub use self::timing3::*;
+mod timing3 {
+
+  use super::*;
+
+  /// # TIMING3 fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TSU_DAT | Actual setup time for data (or ack) bits | [15:0] | 0x0 | true |true |
+  /// | THD_DAT | Actual hold time for data (or ack) bits(Note, where required, the parameters TVD_DAT is taken to be THD_DAT+T_F) | [31:16] | 0x0 | true |true |
+  pub struct Timing3 {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Timing3 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Actual setup time for data (or ack) bits
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tsu_dat(&mut self) -> TsuDatField {
+        TsuDatField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Actual hold time for data (or ack) bits(Note, where required, the parameters TVD_DAT is taken to be THD_DAT+T_F)
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn thd_dat(&mut self) -> ThdDatField {
+        ThdDatField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TsuDatField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
+  type ThdDatField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period..  
+pub use self::timing4::*;
+mod timing4 {
+
+  use super::*;
+
+  /// # TIMING4 fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TSU_STO | Actual setup time for stop signals | [15:0] | 0x0 | true |true |
+  /// | T_BUF | Actual time between each STOP signal and the following START signal | [31:16] | 0x0 | true |true |
+  pub struct Timing4 {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Timing4 {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Actual setup time for stop signals
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tsu_sto(&mut self) -> TsuStoField {
+        TsuStoField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Actual time between each STOP signal and the following START signal
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn t_buf(&mut self) -> TBufField {
+        TBufField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TsuStoField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
+  type TBufField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// I2C clock stretching timeout control.  
+pub use self::timeout_ctrl::*;
+mod timeout_ctrl {
+
+  use super::*;
+
+  /// # TIMEOUT_CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | VAL | Clock stretching timeout value (in units of input clock frequency) | [30:0] | 0x0 | true |true |
+  /// | EN | Enable timeout feature | [31:31] | 0x0 | true |true |
+  pub struct TimeoutCtrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl TimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Clock stretching timeout value (in units of input clock frequency)
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn val(&mut self) -> ValField {
+        ValField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable timeout feature
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn en(&mut self) -> EnField {
+        EnField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ValField<'a> = zermio::BitField<'a, 0, 31, u32, zermio::access::ReadWrite>;
+  type EnField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// I2C target address and mask pairs.  
+pub use self::target_id::*;
+mod target_id {
+
+  use super::*;
+
+  /// # TARGET_ID fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ADDRESS0 | I2C target address number 0 | [6:0] | 0x0 | true |true |
+  /// | MASK0 | I2C target mask number 0 | [13:7] | 0x0 | true |true |
+  /// | ADDRESS1 | I2C target address number 1 | [20:14] | 0x0 | true |true |
+  /// | MASK1 | I2C target mask number 1 | [27:21] | 0x0 | true |true |
+  pub struct TargetId {
+    reg: zermio::Register<u32>,
+  }
+
+  impl TargetId {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: I2C target address number 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn address0(&mut self) -> Address0Field {
+        Address0Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: I2C target mask number 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn mask0(&mut self) -> Mask0Field {
+        Mask0Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: I2C target address number 1
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn address1(&mut self) -> Address1Field {
+        Address1Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: I2C target mask number 1
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn mask1(&mut self) -> Mask1Field {
+        Mask1Field::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Address0Field<'a> = zermio::BitField<'a, 0, 7, u32, zermio::access::ReadWrite>;
+  type Mask0Field<'a> = zermio::BitField<'a, 7, 7, u32, zermio::access::ReadWrite>;
+  type Address1Field<'a> = zermio::BitField<'a, 14, 7, u32, zermio::access::ReadWrite>;
+  type Mask1Field<'a> = zermio::BitField<'a, 21, 7, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// I2C target acquired data.  
+pub use self::acqdata::*;
+mod acqdata {
+
+  use super::*;
+
+  /// # ACQDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ABYTE | Address for accepted transaction or acquired byte | [7:0] | 0x0 | false |true |
+  /// | SIGNAL | Host issued a START before transmitting ABYTE, a STOP or a RESTART after the preceeding ABYTE | [9:8] | 0x0 | false |true |
+  pub struct Acqdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Acqdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Address for accepted transaction or acquired byte
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn abyte(&mut self) -> AbyteField {
+        AbyteField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Host issued a START before transmitting ABYTE, a STOP or a RESTART after the preceeding ABYTE
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn signal(&mut self) -> SignalField {
+        SignalField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type AbyteField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Read>;
+  type SignalField<'a> = zermio::BitField<'a, 8, 2, u32, zermio::access::Read>;
+}
+
+
+
+/// I2C target transmit data.  
+pub use self::txdata::*;
+mod txdata {
+
+  use super::*;
+
+  /// # TXDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | Value | Value | [7:0] | 0x0 | true |false |
+  pub struct Txdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Txdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Value
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
+}
+
+
+
+/// I2C host clock generation timeout value (in units of input clock frequency).  
+pub use self::host_timeout_ctrl::*;
+mod host_timeout_ctrl {
+
+  use super::*;
+
+  /// # HOST_TIMEOUT_CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | Value | Value | [31:0] | 0x0 | true |true |
+  pub struct HostTimeoutCtrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl HostTimeoutCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Value
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn value(&mut self) -> ValueField {
+        ValueField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ValueField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// I2C configuration lock, only the first write after reset takes effect.  
+pub use self::cfg_lock::*;
+mod cfg_lock {
+
+  use super::*;
+
+  /// # CFG_LOCK fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | KEY | Key that unlocks the target configuration | [7:0] | 0x0 | true |false |
+  /// | LOCKED | Lock the timing configuration until the next reset | [8:8] | 0x0 | true |true |
+  pub struct CfgLock {
+    reg: zermio::Register<u32>,
+  }
+
+  impl CfgLock {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Key that unlocks the target configuration
+  ///
+  /// Returns a BitField with WriteOnce permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`try_commit()`**    | Writes the register unless it was already written. |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn key(&mut self) -> KeyField {
+        KeyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Lock the timing configuration until the next reset
+  ///
+  /// Returns a BitField with ReadWriteOnce permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`try_commit()`**    | Writes the register unless it was already written. |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn locked(&mut self) -> LockedField {
+        LockedField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Run a store instruction to write the MMIO, unless this instance already did it. The
+    /// write-once fields of this register ignore any write after the first one.
+    pub fn try_commit(&mut self) -> Result<(), zermio::AlreadyCommitted> {
+      self.reg.try_commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO, unless this instance already did it.
+    pub fn write<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.try_commit()
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO, unless this instance already did it.
+    pub fn write_with_zero<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.try_commit()
+    }
+    /// Run a store instruction to write the reset value to the MMIO, unless this instance already
+    /// did it.
+    pub fn reset(&mut self) -> Result<(), zermio::AlreadyCommitted> {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.try_commit()
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The store is skipped if this instance already ran one, see [`Self::try_commit`].
+    pub fn update<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.try_commit()
+    }
+  }
+  type KeyField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::WriteOnce>;
+  type LockedField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWriteOnce>;
+}
+
+
+
+/// I2C pads configuration.  
+pub use self::pad_ctrl::*;
+mod pad_ctrl {
+
+  use super::*;
+
+  /// # PAD_CTRL fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | MODE_SCL | Drive mode of the SCL pad | [1:0] | 0x0 | true |true |
+  /// | MODE_SDA | Drive mode of the SDA pad | [5:4] | 0x0 | true |true |
+  /// | PULLUP0 | Enable the internal pull-up of the pin 0 | [16:16] | 0x0 | true |true |
+  /// | PULLUP1 | Enable the internal pull-up of the pin 1 | [17:17] | 0x0 | true |true |
+  /// | PULLUP2 | Enable the internal pull-up of the pin 2 | [18:18] | 0x0 | true |true |
+  /// | PULLUP3 | Enable the internal pull-up of the pin 3 | [19:19] | 0x0 | true |true |
+  /// | PULLUP4 | Enable the internal pull-up of the pin 4 | [20:20] | 0x0 | true |true |
+  /// | PULLUP5 | Enable the internal pull-up of the pin 5 | [21:21] | 0x0 | true |true |
+  /// | PULLUP6 | Enable the internal pull-up of the pin 6 | [22:22] | 0x0 | true |true |
+  /// | PULLUP7 | Enable the internal pull-up of the pin 7 | [23:23] | 0x0 | true |true |
+  pub struct PadCtrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl PadCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Drive mode of the SCL pad
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`PadCtrlMode`]. |
+  /// | **`write_variant(val)`** | Writes a [`PadCtrlMode`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn mode_scl(&mut self) -> ModeSclField {
+        ModeSclField::new(&mut self.reg)
+    }
+
+  /// Field array MODE: Drive mode of the SCL pad
+  ///
+  /// Returns the element `n`, from 0 to 1, of the field array. It provides the same
+  /// functions of [`Self::mode_scl`], except for the ones to trigger write side
+  /// effects.
+  ///
+  /// # Panics
+  /// If `n` is out of range.
+    pub fn mode(&mut self, n: usize) -> ModeArray {
+        assert!(n < 2, "MODE index out of range");
+        ModeArray::new(&mut self.reg, 0 + n * 4)
+    }
+
+  /// Hardware description: Drive mode of the SDA pad
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`PadCtrlMode`]. |
+  /// | **`write_variant(val)`** | Writes a [`PadCtrlMode`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn mode_sda(&mut self) -> ModeSdaField {
+        ModeSdaField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 0
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup0(&mut self) -> Pullup0Field {
+        Pullup0Field::new(&mut self.reg)
+    }
+
+  /// Field array PULLUP: Enable the internal pull-up of the pin 0
+  ///
+  /// Returns the element `n`, from 0 to 7, of the field array. It provides the same
+  /// functions of [`Self::pullup0`], except for the ones to trigger write side
+  /// effects.
+  ///
+  /// # Panics
+  /// If `n` is out of range.
+    pub fn pullup(&mut self, n: usize) -> PullupArray {
+        assert!(n < 8, "PULLUP index out of range");
+        PullupArray::new(&mut self.reg, 16 + n * 1)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 1
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup1(&mut self) -> Pullup1Field {
+        Pullup1Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 2
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup2(&mut self) -> Pullup2Field {
+        Pullup2Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 3
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup3(&mut self) -> Pullup3Field {
+        Pullup3Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 4
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup4(&mut self) -> Pullup4Field {
+        Pullup4Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 5
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup5(&mut self) -> Pullup5Field {
+        Pullup5Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 6
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup6(&mut self) -> Pullup6Field {
+        Pullup6Field::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the internal pull-up of the pin 7
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pullup7(&mut self) -> Pullup7Field {
+        Pullup7Field::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ModeSclField<'a> = zermio::BitField<'a, 0, 2, u32, zermio::access::ReadWrite, PadCtrlMode>;
+  type ModeSdaField<'a> = zermio::BitField<'a, 4, 2, u32, zermio::access::ReadWrite, PadCtrlMode>;
+  type Pullup0Field<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Pullup1Field<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Pullup2Field<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Pullup3Field<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Pullup4Field<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Pullup5Field<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Pullup6Field<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Pullup7Field<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type ModeArray<'a> = zermio::IndexedBitField<'a, 2, u32, zermio::access::ReadWrite, PadCtrlMode>;
+  type PullupArray<'a> = zermio::IndexedBitField<'a, 1, u32, zermio::access::ReadWrite>;
+
+  /// Enumerated values of the field MODE: Drive mode of the SCL pad
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum PadCtrlMode {
+    /// Only drive the pad low
+    Opendrain = 0x0,
+    /// Drive the pad low and high
+    Pushpull = 0x1,
+  }
+
+  impl From<PadCtrlMode> for u32 {
+    fn from(variant: PadCtrlMode) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for PadCtrlMode {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Opendrain),
+        0x1 => Ok(Self::Pushpull),
+        _ => Err(value),
+      }
+    }
+  }
+}
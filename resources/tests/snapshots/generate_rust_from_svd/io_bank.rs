@@ -0,0 +1,8263 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the clusters present in the io_bank 
+//! | Cluster name       | Description  | Offset | Instances |
+//! | :---               | :---         | :---   | :--       |
+//! | GPIO | Cluster GPIO%s, containing GPIO*_STATUS, GPIO*_CTRL | 0x0 | 30 |
+//! | PROC0 | Interrupt control for the processor 0 | 0x100 | 1 |
+//! | PROC1 | Interrupt control for the processor 1 | 0x140 | 1 |
+
+//! These are the registers present in the io_bank 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | INTR0 | Raw Interrupts | 0xf0 | true |true |
+//! | INTR1 | INTR1 | 0xf4 | true |true |
+//! | INTR2 | INTR2 | 0xf8 | true |true |
+//! | INTR3 | INTR3 | 0xfc | true |true |
+//! | PROC0_INTE0 | Interrupt Enable for proc0 | 0x100 | true |true |
+//! | PROC0_INTE1 | PROC0_INTE1 | 0x104 | true |true |
+//! | PROC0_INTE2 | PROC0_INTE2 | 0x108 | true |true |
+//! | PROC0_INTE3 | PROC0_INTE3 | 0x10c | true |true |
+//! | PROC0_INTF0 | Interrupt Force for proc0 | 0x110 | true |true |
+//! | PROC0_INTF1 | PROC0_INTF1 | 0x114 | true |true |
+//! | PROC0_INTF2 | PROC0_INTF2 | 0x118 | true |true |
+//! | PROC0_INTF3 | PROC0_INTF3 | 0x11c | true |true |
+//! | PROC0_INTS0 | Interrupt status after masking & forcing for proc0 | 0x120 | false |true |
+//! | PROC0_INTS1 | PROC0_INTS1 | 0x124 | false |true |
+//! | PROC0_INTS2 | PROC0_INTS2 | 0x128 | false |true |
+//! | PROC0_INTS3 | PROC0_INTS3 | 0x12c | false |true |
+//! | PROC1_INTE0 | Interrupt Enable for proc1 | 0x130 | true |true |
+//! | PROC1_INTE1 | PROC1_INTE1 | 0x134 | true |true |
+//! | PROC1_INTE2 | PROC1_INTE2 | 0x138 | true |true |
+//! | PROC1_INTE3 | PROC1_INTE3 | 0x13c | true |true |
+//! | PROC1_INTF0 | Interrupt Force for proc1 | 0x140 | true |true |
+//! | PROC1_INTF1 | PROC1_INTF1 | 0x144 | true |true |
+//! | PROC1_INTF2 | PROC1_INTF2 | 0x148 | true |true |
+//! | PROC1_INTF3 | PROC1_INTF3 | 0x14c | true |true |
+//! | PROC1_INTS0 | Interrupt status after masking & forcing for proc1 | 0x150 | false |true |
+//! | PROC1_INTS1 | PROC1_INTS1 | 0x154 | false |true |
+//! | PROC1_INTS2 | PROC1_INTS2 | 0x158 | false |true |
+//! | PROC1_INTS3 | PROC1_INTS3 | 0x15c | false |true |
+//! | DORMANT_WAKE_INTE0 | Interrupt Enable for dormant_wake | 0x160 | true |true |
+//! | DORMANT_WAKE_INTE1 | DORMANT_WAKE_INTE1 | 0x164 | true |true |
+//! | DORMANT_WAKE_INTE2 | DORMANT_WAKE_INTE2 | 0x168 | true |true |
+//! | DORMANT_WAKE_INTE3 | DORMANT_WAKE_INTE3 | 0x16c | true |true |
+//! | DORMANT_WAKE_INTF0 | Interrupt Force for dormant_wake | 0x170 | true |true |
+//! | DORMANT_WAKE_INTF1 | DORMANT_WAKE_INTF1 | 0x174 | true |true |
+//! | DORMANT_WAKE_INTF2 | DORMANT_WAKE_INTF2 | 0x178 | true |true |
+//! | DORMANT_WAKE_INTF3 | DORMANT_WAKE_INTF3 | 0x17c | true |true |
+//! | DORMANT_WAKE_INTS0 | Interrupt status after masking & forcing for dormant_wake | 0x180 | false |true |
+//! | DORMANT_WAKE_INTS1 | DORMANT_WAKE_INTS1 | 0x184 | false |true |
+//! | DORMANT_WAKE_INTS2 | DORMANT_WAKE_INTS2 | 0x188 | false |true |
+//! | DORMANT_WAKE_INTS3 | DORMANT_WAKE_INTS3 | 0x18c | false |true |
+
+use super::zermio;
+/// ``` rust
+/// io_bank.intr0.update(|intr0| {
+///     if intr0.gpio7_edge_high().read() == 1 {
+///        intr0.gpio7_edge_high().write(0);
+///     }
+/// });
+/// ```
+pub struct IoBank {
+    /// Raw Interrupts  
+    pub intr0 : intr::Intr,
+    /// INTR1  
+    pub intr1 : intr::Intr,
+    /// INTR2  
+    pub intr2 : intr::Intr,
+    /// INTR3  
+    pub intr3 : intr::Intr,
+    /// Interrupt Enable for proc0  
+    pub proc0_inte0 : proc0_inte::Proc0Inte,
+    /// PROC0_INTE1  
+    pub proc0_inte1 : proc0_inte::Proc0Inte,
+    /// PROC0_INTE2  
+    pub proc0_inte2 : proc0_inte::Proc0Inte,
+    /// PROC0_INTE3  
+    pub proc0_inte3 : proc0_inte::Proc0Inte,
+    /// Interrupt Force for proc0  
+    pub proc0_intf0 : proc0_intf::Proc0Intf,
+    /// PROC0_INTF1  
+    pub proc0_intf1 : proc0_intf::Proc0Intf,
+    /// PROC0_INTF2  
+    pub proc0_intf2 : proc0_intf::Proc0Intf,
+    /// PROC0_INTF3  
+    pub proc0_intf3 : proc0_intf::Proc0Intf,
+    /// Interrupt status after masking & forcing for proc0  
+    pub proc0_ints0 : proc0_ints::Proc0Ints,
+    /// PROC0_INTS1  
+    pub proc0_ints1 : proc0_ints::Proc0Ints,
+    /// PROC0_INTS2  
+    pub proc0_ints2 : proc0_ints::Proc0Ints,
+    /// PROC0_INTS3  
+    pub proc0_ints3 : proc0_ints::Proc0Ints,
+    /// Interrupt Enable for proc1  
+    pub proc1_inte0 : proc1_inte::Proc1Inte,
+    /// PROC1_INTE1  
+    pub proc1_inte1 : proc1_inte::Proc1Inte,
+    /// PROC1_INTE2  
+    pub proc1_inte2 : proc1_inte::Proc1Inte,
+    /// PROC1_INTE3  
+    pub proc1_inte3 : proc1_inte::Proc1Inte,
+    /// Interrupt Force for proc1  
+    pub proc1_intf0 : proc1_intf::Proc1Intf,
+    /// PROC1_INTF1  
+    pub proc1_intf1 : proc1_intf::Proc1Intf,
+    /// PROC1_INTF2  
+    pub proc1_intf2 : proc1_intf::Proc1Intf,
+    /// PROC1_INTF3  
+    pub proc1_intf3 : proc1_intf::Proc1Intf,
+    /// Interrupt status after masking & forcing for proc1  
+    pub proc1_ints0 : proc1_ints::Proc1Ints,
+    /// PROC1_INTS1  
+    pub proc1_ints1 : proc1_ints::Proc1Ints,
+    /// PROC1_INTS2  
+    pub proc1_ints2 : proc1_ints::Proc1Ints,
+    /// PROC1_INTS3  
+    pub proc1_ints3 : proc1_ints::Proc1Ints,
+    /// Interrupt Enable for dormant_wake  
+    pub dormant_wake_inte0 : dormant_wake_inte::DormantWakeInte,
+    /// DORMANT_WAKE_INTE1  
+    pub dormant_wake_inte1 : dormant_wake_inte::DormantWakeInte,
+    /// DORMANT_WAKE_INTE2  
+    pub dormant_wake_inte2 : dormant_wake_inte::DormantWakeInte,
+    /// DORMANT_WAKE_INTE3  
+    pub dormant_wake_inte3 : dormant_wake_inte::DormantWakeInte,
+    /// Interrupt Force for dormant_wake  
+    pub dormant_wake_intf0 : dormant_wake_intf::DormantWakeIntf,
+    /// DORMANT_WAKE_INTF1  
+    pub dormant_wake_intf1 : dormant_wake_intf::DormantWakeIntf,
+    /// DORMANT_WAKE_INTF2  
+    pub dormant_wake_intf2 : dormant_wake_intf::DormantWakeIntf,
+    /// DORMANT_WAKE_INTF3  
+    pub dormant_wake_intf3 : dormant_wake_intf::DormantWakeIntf,
+    /// Interrupt status after masking & forcing for dormant_wake  
+    pub dormant_wake_ints0 : dormant_wake_ints::DormantWakeInts,
+    /// DORMANT_WAKE_INTS1  
+    pub dormant_wake_ints1 : dormant_wake_ints::DormantWakeInts,
+    /// DORMANT_WAKE_INTS2  
+    pub dormant_wake_ints2 : dormant_wake_ints::DormantWakeInts,
+    /// DORMANT_WAKE_INTS3  
+    pub dormant_wake_ints3 : dormant_wake_ints::DormantWakeInts,
+    /// Cluster GPIO%s, containing GPIO*_STATUS, GPIO*_CTRL
+    pub gpio : [GpioCluster; 30],
+    /// Interrupt control for the processor 0
+    pub proc0 : Proc0Cluster,
+    /// Interrupt control for the processor 1
+    pub proc1 : Proc0Cluster,
+}
+
+impl IoBank {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        intr0 : intr::Intr::new(addr + 0xf0 ),
+        intr1 : intr::Intr::new(addr + 0xf4 ),
+        intr2 : intr::Intr::new(addr + 0xf8 ),
+        intr3 : intr::Intr::new(addr + 0xfc ),
+        proc0_inte0 : proc0_inte::Proc0Inte::new(addr + 0x100 ),
+        proc0_inte1 : proc0_inte::Proc0Inte::new(addr + 0x104 ),
+        proc0_inte2 : proc0_inte::Proc0Inte::new(addr + 0x108 ),
+        proc0_inte3 : proc0_inte::Proc0Inte::new(addr + 0x10c ),
+        proc0_intf0 : proc0_intf::Proc0Intf::new(addr + 0x110 ),
+        proc0_intf1 : proc0_intf::Proc0Intf::new(addr + 0x114 ),
+        proc0_intf2 : proc0_intf::Proc0Intf::new(addr + 0x118 ),
+        proc0_intf3 : proc0_intf::Proc0Intf::new(addr + 0x11c ),
+        proc0_ints0 : proc0_ints::Proc0Ints::new(addr + 0x120 ),
+        proc0_ints1 : proc0_ints::Proc0Ints::new(addr + 0x124 ),
+        proc0_ints2 : proc0_ints::Proc0Ints::new(addr + 0x128 ),
+        proc0_ints3 : proc0_ints::Proc0Ints::new(addr + 0x12c ),
+        proc1_inte0 : proc1_inte::Proc1Inte::new(addr + 0x130 ),
+        proc1_inte1 : proc1_inte::Proc1Inte::new(addr + 0x134 ),
+        proc1_inte2 : proc1_inte::Proc1Inte::new(addr + 0x138 ),
+        proc1_inte3 : proc1_inte::Proc1Inte::new(addr + 0x13c ),
+        proc1_intf0 : proc1_intf::Proc1Intf::new(addr + 0x140 ),
+        proc1_intf1 : proc1_intf::Proc1Intf::new(addr + 0x144 ),
+        proc1_intf2 : proc1_intf::Proc1Intf::new(addr + 0x148 ),
+        proc1_intf3 : proc1_intf::Proc1Intf::new(addr + 0x14c ),
+        proc1_ints0 : proc1_ints::Proc1Ints::new(addr + 0x150 ),
+        proc1_ints1 : proc1_ints::Proc1Ints::new(addr + 0x154 ),
+        proc1_ints2 : proc1_ints::Proc1Ints::new(addr + 0x158 ),
+        proc1_ints3 : proc1_ints::Proc1Ints::new(addr + 0x15c ),
+        dormant_wake_inte0 : dormant_wake_inte::DormantWakeInte::new(addr + 0x160 ),
+        dormant_wake_inte1 : dormant_wake_inte::DormantWakeInte::new(addr + 0x164 ),
+        dormant_wake_inte2 : dormant_wake_inte::DormantWakeInte::new(addr + 0x168 ),
+        dormant_wake_inte3 : dormant_wake_inte::DormantWakeInte::new(addr + 0x16c ),
+        dormant_wake_intf0 : dormant_wake_intf::DormantWakeIntf::new(addr + 0x170 ),
+        dormant_wake_intf1 : dormant_wake_intf::DormantWakeIntf::new(addr + 0x174 ),
+        dormant_wake_intf2 : dormant_wake_intf::DormantWakeIntf::new(addr + 0x178 ),
+        dormant_wake_intf3 : dormant_wake_intf::DormantWakeIntf::new(addr + 0x17c ),
+        dormant_wake_ints0 : dormant_wake_ints::DormantWakeInts::new(addr + 0x180 ),
+        dormant_wake_ints1 : dormant_wake_ints::DormantWakeInts::new(addr + 0x184 ),
+        dormant_wake_ints2 : dormant_wake_ints::DormantWakeInts::new(addr + 0x188 ),
+        dormant_wake_ints3 : dormant_wake_ints::DormantWakeInts::new(addr + 0x18c ),
+        gpio : core::array::from_fn(|n| GpioCluster::new(addr + 0x0 + n as u32 * 0x8)),
+        proc0 : Proc0Cluster::new(addr + 0x100 ),
+        proc1 : Proc0Cluster::new(addr + 0x140 ),
+      }
+  }
+}
+
+/// Cluster GPIO%s, containing GPIO*_STATUS, GPIO*_CTRL.  
+pub struct GpioCluster {
+    /// GPIO status  
+    pub gpio_status : gpio_status::GpioStatus,
+    /// GPIO control including function select and overrides.  
+    pub gpio_ctrl : gpio_ctrl::GpioCtrl,
+}
+
+impl GpioCluster {
+    pub fn new(addr: u32) -> Self {
+      Self {
+        gpio_status : gpio_status::GpioStatus::new(addr + 0x0 ),
+        gpio_ctrl : gpio_ctrl::GpioCtrl::new(addr + 0x4 ),
+      }
+  }
+}
+
+/// Interrupt force and status of the bank %s.  
+pub struct IrqCluster {
+    /// Interrupt Force  
+    pub intf : intf::Intf,
+    /// Interrupt status after masking and forcing  
+    pub ints : ints::Ints,
+}
+
+impl IrqCluster {
+    pub fn new(addr: u32) -> Self {
+      Self {
+        intf : intf::Intf::new(addr + 0x0 ),
+        ints : ints::Ints::new(addr + 0x4 ),
+      }
+  }
+}
+
+/// Interrupt control for the processor 0.  
+pub struct Proc0Cluster {
+    /// Interrupt Enable for the processor 0  
+    pub inte : inte::Inte,
+    /// Interrupt force and status of the bank %s
+    pub irq : [IrqCluster; 2],
+}
+
+impl Proc0Cluster {
+    pub fn new(addr: u32) -> Self {
+      Self {
+        inte : inte::Inte::new(addr + 0x0 ),
+        irq : core::array::from_fn(|n| IrqCluster::new(addr + 0x10 + n as u32 * 0x8)),
+      }
+  }
+}
+
+
+
+/// Raw Interrupts.  
+pub use self::intr::*;
+mod intr {
+
+  use super::*;
+
+  /// # INTR fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | false |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | false |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | false |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | false |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | false |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | false |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | false |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | false |true |
+  pub struct Intr {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Intr {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0xcccccccc;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::Read>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::Read>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::Read>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::Read>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::Read>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Read>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::Read>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// Interrupt Enable for proc0.  
+pub use self::proc0_inte::*;
+mod proc0_inte {
+
+  use super::*;
+
+  /// # PROC0_INTE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | true |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | true |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | true |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | true |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | true |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | true |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | true |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | true |true |
+  pub struct Proc0Inte {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Proc0Inte {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt Force for proc0.  
+pub use self::proc0_intf::*;
+mod proc0_intf {
+
+  use super::*;
+
+  /// # PROC0_INTF fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | true |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | true |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | true |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | true |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | true |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | true |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | true |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | true |true |
+  pub struct Proc0Intf {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Proc0Intf {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt status after masking & forcing for proc0.  
+pub use self::proc0_ints::*;
+mod proc0_ints {
+
+  use super::*;
+
+  /// # PROC0_INTS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | false |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | false |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | false |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | false |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | false |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | false |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | false |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | false |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | false |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | false |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | false |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | false |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | false |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | false |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | false |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | false |true |
+  pub struct Proc0Ints {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Proc0Ints {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::Read>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::Read>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::Read>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::Read>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::Read>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::Read>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::Read>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::Read>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::Read>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::Read>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::Read>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::Read>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Read>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::Read>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::Read>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// Interrupt Enable for proc1.  
+pub use self::proc1_inte::*;
+mod proc1_inte {
+
+  use super::*;
+
+  /// # PROC1_INTE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | true |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | true |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | true |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | true |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | true |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | true |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | true |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | true |true |
+  pub struct Proc1Inte {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Proc1Inte {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt Force for proc1.  
+pub use self::proc1_intf::*;
+mod proc1_intf {
+
+  use super::*;
+
+  /// # PROC1_INTF fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | true |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | true |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | true |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | true |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | true |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | true |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | true |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | true |true |
+  pub struct Proc1Intf {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Proc1Intf {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt status after masking & forcing for proc1.  
+pub use self::proc1_ints::*;
+mod proc1_ints {
+
+  use super::*;
+
+  /// # PROC1_INTS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | false |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | false |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | false |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | false |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | false |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | false |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | false |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | false |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | false |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | false |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | false |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | false |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | false |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | false |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | false |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | false |true |
+  pub struct Proc1Ints {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Proc1Ints {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::Read>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::Read>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::Read>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::Read>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::Read>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::Read>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::Read>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::Read>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::Read>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::Read>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::Read>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::Read>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Read>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::Read>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::Read>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// Interrupt Enable for dormant_wake.  
+pub use self::dormant_wake_inte::*;
+mod dormant_wake_inte {
+
+  use super::*;
+
+  /// # DORMANT_WAKE_INTE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | true |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | true |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | true |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | true |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | true |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | true |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | true |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | true |true |
+  pub struct DormantWakeInte {
+    reg: zermio::Register<u32>,
+  }
+
+  impl DormantWakeInte {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt Force for dormant_wake.  
+pub use self::dormant_wake_intf::*;
+mod dormant_wake_intf {
+
+  use super::*;
+
+  /// # DORMANT_WAKE_INTF fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | true |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | true |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | true |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | true |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | true |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | true |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | true |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | true |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | true |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | true |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | true |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | true |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | true |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | true |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | true |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | true |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | true |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | true |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | true |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | true |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | true |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | true |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | true |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | true |true |
+  pub struct DormantWakeIntf {
+    reg: zermio::Register<u32>,
+  }
+
+  impl DormantWakeIntf {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::ReadWrite>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::ReadWrite>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::ReadWrite>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::ReadWrite>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::ReadWrite>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::ReadWrite>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::ReadWrite>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt status after masking & forcing for dormant_wake.  
+pub use self::dormant_wake_ints::*;
+mod dormant_wake_ints {
+
+  use super::*;
+
+  /// # DORMANT_WAKE_INTS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | GPIO7_EDGE_HIGH | GPIO7_EDGE_HIGH | [31:31] | 0x0 | false |true |
+  /// | GPIO7_EDGE_LOW | GPIO7_EDGE_LOW | [30:30] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_HIGH | GPIO7_LEVEL_HIGH | [29:29] | 0x0 | false |true |
+  /// | GPIO7_LEVEL_LOW | GPIO7_LEVEL_LOW | [28:28] | 0x0 | false |true |
+  /// | GPIO6_EDGE_HIGH | GPIO6_EDGE_HIGH | [27:27] | 0x0 | false |true |
+  /// | GPIO6_EDGE_LOW | GPIO6_EDGE_LOW | [26:26] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_HIGH | GPIO6_LEVEL_HIGH | [25:25] | 0x0 | false |true |
+  /// | GPIO6_LEVEL_LOW | GPIO6_LEVEL_LOW | [24:24] | 0x0 | false |true |
+  /// | GPIO5_EDGE_HIGH | GPIO5_EDGE_HIGH | [23:23] | 0x0 | false |true |
+  /// | GPIO5_EDGE_LOW | GPIO5_EDGE_LOW | [22:22] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_HIGH | GPIO5_LEVEL_HIGH | [21:21] | 0x0 | false |true |
+  /// | GPIO5_LEVEL_LOW | GPIO5_LEVEL_LOW | [20:20] | 0x0 | false |true |
+  /// | GPIO4_EDGE_HIGH | GPIO4_EDGE_HIGH | [19:19] | 0x0 | false |true |
+  /// | GPIO4_EDGE_LOW | GPIO4_EDGE_LOW | [18:18] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_HIGH | GPIO4_LEVEL_HIGH | [17:17] | 0x0 | false |true |
+  /// | GPIO4_LEVEL_LOW | GPIO4_LEVEL_LOW | [16:16] | 0x0 | false |true |
+  /// | GPIO3_EDGE_HIGH | GPIO3_EDGE_HIGH | [15:15] | 0x0 | false |true |
+  /// | GPIO3_EDGE_LOW | GPIO3_EDGE_LOW | [14:14] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_HIGH | GPIO3_LEVEL_HIGH | [13:13] | 0x0 | false |true |
+  /// | GPIO3_LEVEL_LOW | GPIO3_LEVEL_LOW | [12:12] | 0x0 | false |true |
+  /// | GPIO2_EDGE_HIGH | GPIO2_EDGE_HIGH | [11:11] | 0x0 | false |true |
+  /// | GPIO2_EDGE_LOW | GPIO2_EDGE_LOW | [10:10] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_HIGH | GPIO2_LEVEL_HIGH | [9:9] | 0x0 | false |true |
+  /// | GPIO2_LEVEL_LOW | GPIO2_LEVEL_LOW | [8:8] | 0x0 | false |true |
+  /// | GPIO1_EDGE_HIGH | GPIO1_EDGE_HIGH | [7:7] | 0x0 | false |true |
+  /// | GPIO1_EDGE_LOW | GPIO1_EDGE_LOW | [6:6] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_HIGH | GPIO1_LEVEL_HIGH | [5:5] | 0x0 | false |true |
+  /// | GPIO1_LEVEL_LOW | GPIO1_LEVEL_LOW | [4:4] | 0x0 | false |true |
+  /// | GPIO0_EDGE_HIGH | GPIO0_EDGE_HIGH | [3:3] | 0x0 | false |true |
+  /// | GPIO0_EDGE_LOW | GPIO0_EDGE_LOW | [2:2] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_HIGH | GPIO0_LEVEL_HIGH | [1:1] | 0x0 | false |true |
+  /// | GPIO0_LEVEL_LOW | GPIO0_LEVEL_LOW | [0:0] | 0x0 | false |true |
+  pub struct DormantWakeInts {
+    reg: zermio::Register<u32>,
+  }
+
+  impl DormantWakeInts {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: GPIO7_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_high(&mut self) -> Gpio7EdgeHighField {
+        Gpio7EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_edge_low(&mut self) -> Gpio7EdgeLowField {
+        Gpio7EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_high(&mut self) -> Gpio7LevelHighField {
+        Gpio7LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO7_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio7_level_low(&mut self) -> Gpio7LevelLowField {
+        Gpio7LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_high(&mut self) -> Gpio6EdgeHighField {
+        Gpio6EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_edge_low(&mut self) -> Gpio6EdgeLowField {
+        Gpio6EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_high(&mut self) -> Gpio6LevelHighField {
+        Gpio6LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO6_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio6_level_low(&mut self) -> Gpio6LevelLowField {
+        Gpio6LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_high(&mut self) -> Gpio5EdgeHighField {
+        Gpio5EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_edge_low(&mut self) -> Gpio5EdgeLowField {
+        Gpio5EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_high(&mut self) -> Gpio5LevelHighField {
+        Gpio5LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO5_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio5_level_low(&mut self) -> Gpio5LevelLowField {
+        Gpio5LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_high(&mut self) -> Gpio4EdgeHighField {
+        Gpio4EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_edge_low(&mut self) -> Gpio4EdgeLowField {
+        Gpio4EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_high(&mut self) -> Gpio4LevelHighField {
+        Gpio4LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO4_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio4_level_low(&mut self) -> Gpio4LevelLowField {
+        Gpio4LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_high(&mut self) -> Gpio3EdgeHighField {
+        Gpio3EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_edge_low(&mut self) -> Gpio3EdgeLowField {
+        Gpio3EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_high(&mut self) -> Gpio3LevelHighField {
+        Gpio3LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO3_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio3_level_low(&mut self) -> Gpio3LevelLowField {
+        Gpio3LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_high(&mut self) -> Gpio2EdgeHighField {
+        Gpio2EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_edge_low(&mut self) -> Gpio2EdgeLowField {
+        Gpio2EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_high(&mut self) -> Gpio2LevelHighField {
+        Gpio2LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO2_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio2_level_low(&mut self) -> Gpio2LevelLowField {
+        Gpio2LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_high(&mut self) -> Gpio1EdgeHighField {
+        Gpio1EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_edge_low(&mut self) -> Gpio1EdgeLowField {
+        Gpio1EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_high(&mut self) -> Gpio1LevelHighField {
+        Gpio1LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO1_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio1_level_low(&mut self) -> Gpio1LevelLowField {
+        Gpio1LevelLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_high(&mut self) -> Gpio0EdgeHighField {
+        Gpio0EdgeHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_EDGE_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_edge_low(&mut self) -> Gpio0EdgeLowField {
+        Gpio0EdgeLowField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_HIGH
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_high(&mut self) -> Gpio0LevelHighField {
+        Gpio0LevelHighField::new(&mut self.reg)
+    }
+
+  /// Hardware description: GPIO0_LEVEL_LOW
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn gpio0_level_low(&mut self) -> Gpio0LevelLowField {
+        Gpio0LevelLowField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type Gpio7EdgeHighField<'a> = zermio::BitField<'a, 31, 1, u32, zermio::access::Read>;
+  type Gpio7EdgeLowField<'a> = zermio::BitField<'a, 30, 1, u32, zermio::access::Read>;
+  type Gpio7LevelHighField<'a> = zermio::BitField<'a, 29, 1, u32, zermio::access::Read>;
+  type Gpio7LevelLowField<'a> = zermio::BitField<'a, 28, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeHighField<'a> = zermio::BitField<'a, 27, 1, u32, zermio::access::Read>;
+  type Gpio6EdgeLowField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::Read>;
+  type Gpio6LevelHighField<'a> = zermio::BitField<'a, 25, 1, u32, zermio::access::Read>;
+  type Gpio6LevelLowField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeHighField<'a> = zermio::BitField<'a, 23, 1, u32, zermio::access::Read>;
+  type Gpio5EdgeLowField<'a> = zermio::BitField<'a, 22, 1, u32, zermio::access::Read>;
+  type Gpio5LevelHighField<'a> = zermio::BitField<'a, 21, 1, u32, zermio::access::Read>;
+  type Gpio5LevelLowField<'a> = zermio::BitField<'a, 20, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeHighField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::Read>;
+  type Gpio4EdgeLowField<'a> = zermio::BitField<'a, 18, 1, u32, zermio::access::Read>;
+  type Gpio4LevelHighField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::Read>;
+  type Gpio4LevelLowField<'a> = zermio::BitField<'a, 16, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeHighField<'a> = zermio::BitField<'a, 15, 1, u32, zermio::access::Read>;
+  type Gpio3EdgeLowField<'a> = zermio::BitField<'a, 14, 1, u32, zermio::access::Read>;
+  type Gpio3LevelHighField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::Read>;
+  type Gpio3LevelLowField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeHighField<'a> = zermio::BitField<'a, 11, 1, u32, zermio::access::Read>;
+  type Gpio2EdgeLowField<'a> = zermio::BitField<'a, 10, 1, u32, zermio::access::Read>;
+  type Gpio2LevelHighField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Read>;
+  type Gpio2LevelLowField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeHighField<'a> = zermio::BitField<'a, 7, 1, u32, zermio::access::Read>;
+  type Gpio1EdgeLowField<'a> = zermio::BitField<'a, 6, 1, u32, zermio::access::Read>;
+  type Gpio1LevelHighField<'a> = zermio::BitField<'a, 5, 1, u32, zermio::access::Read>;
+  type Gpio1LevelLowField<'a> = zermio::BitField<'a, 4, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeHighField<'a> = zermio::BitField<'a, 3, 1, u32, zermio::access::Read>;
+  type Gpio0EdgeLowField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::Read>;
+  type Gpio0LevelHighField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type Gpio0LevelLowField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// GPIO status.  
+pub use self::gpio_status::*;
+mod gpio_status {
+
+  use super::*;
+
+  /// # GPIO_STATUS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | IRQTOPROC | interrupt to processors, after override is applied | [26:26] | 0x0 | false |true |
+  /// | IRQFROMPAD | interrupt from pad before override is applied | [24:24] | 0x0 | false |true |
+  /// | INTOPERI | input signal to peripheral, after override is applied | [19:19] | 0x0 | false |true |
+  /// | INFROMPAD | input signal from pad, before override is applied | [17:17] | 0x0 | false |true |
+  /// | OETOPAD | output enable to pad after register override is applied | [13:13] | 0x0 | false |true |
+  /// | OEFROMPERI | output enable from selected peripheral, before register override is applied | [12:12] | 0x0 | false |true |
+  /// | OUTTOPAD | output signal to pad after register override is applied | [9:9] | 0x0 | false |true |
+  /// | OUTFROMPERI | output signal from selected peripheral, before register override is applied | [8:8] | 0x0 | false |true |
+  pub struct GpioStatus {
+    reg: zermio::Register<u32>,
+  }
+
+  impl GpioStatus {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: interrupt to processors, after override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn irqtoproc(&mut self) -> IrqtoprocField {
+        IrqtoprocField::new(&mut self.reg)
+    }
+
+  /// Hardware description: interrupt from pad before override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn irqfrompad(&mut self) -> IrqfrompadField {
+        IrqfrompadField::new(&mut self.reg)
+    }
+
+  /// Hardware description: input signal to peripheral, after override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn intoperi(&mut self) -> IntoperiField {
+        IntoperiField::new(&mut self.reg)
+    }
+
+  /// Hardware description: input signal from pad, before override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn infrompad(&mut self) -> InfrompadField {
+        InfrompadField::new(&mut self.reg)
+    }
+
+  /// Hardware description: output enable to pad after register override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn oetopad(&mut self) -> OetopadField {
+        OetopadField::new(&mut self.reg)
+    }
+
+  /// Hardware description: output enable from selected peripheral, before register override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn oefromperi(&mut self) -> OefromperiField {
+        OefromperiField::new(&mut self.reg)
+    }
+
+  /// Hardware description: output signal to pad after register override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn outtopad(&mut self) -> OuttopadField {
+        OuttopadField::new(&mut self.reg)
+    }
+
+  /// Hardware description: output signal from selected peripheral, before register override is applied
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn outfromperi(&mut self) -> OutfromperiField {
+        OutfromperiField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type IrqtoprocField<'a> = zermio::BitField<'a, 26, 1, u32, zermio::access::Read>;
+  type IrqfrompadField<'a> = zermio::BitField<'a, 24, 1, u32, zermio::access::Read>;
+  type IntoperiField<'a> = zermio::BitField<'a, 19, 1, u32, zermio::access::Read>;
+  type InfrompadField<'a> = zermio::BitField<'a, 17, 1, u32, zermio::access::Read>;
+  type OetopadField<'a> = zermio::BitField<'a, 13, 1, u32, zermio::access::Read>;
+  type OefromperiField<'a> = zermio::BitField<'a, 12, 1, u32, zermio::access::Read>;
+  type OuttopadField<'a> = zermio::BitField<'a, 9, 1, u32, zermio::access::Read>;
+  type OutfromperiField<'a> = zermio::BitField<'a, 8, 1, u32, zermio::access::Read>;
+}
+
+
+
+/// GPIO control including function select and overrides..  
+pub use self::gpio_ctrl::*;
+mod gpio_ctrl {
+
+  use super::*;
+
+  /// # GPIO_CTRL fields.  
+  /// Reset value: 0x1f
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | IRQOVER | IRQOVER | [29:28] | 0x0 | true |true |
+  /// | INOVER | INOVER | [17:16] | 0x0 | true |true |
+  /// | OEOVER | OEOVER | [13:12] | 0x0 | true |true |
+  /// | OUTOVER | OUTOVER | [9:8] | 0x0 | true |true |
+  /// | FUNCSEL | 0-31 -> selects pin function according to the GPIO table. Not all options are valid for all GPIO pins. | [4:0] | 0x1f | true |true |
+  pub struct GpioCtrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl GpioCtrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x1f;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: IRQOVER
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`GpioCtrlIrqover`]. |
+  /// | **`write_variant(val)`** | Writes a [`GpioCtrlIrqover`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn irqover(&mut self) -> IrqoverField {
+        IrqoverField::new(&mut self.reg)
+    }
+
+  /// Hardware description: INOVER
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`GpioCtrlInover`]. |
+  /// | **`write_variant(val)`** | Writes a [`GpioCtrlInover`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn inover(&mut self) -> InoverField {
+        InoverField::new(&mut self.reg)
+    }
+
+  /// Hardware description: OEOVER
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`GpioCtrlOeover`]. |
+  /// | **`write_variant(val)`** | Writes a [`GpioCtrlOeover`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn oeover(&mut self) -> OeoverField {
+        OeoverField::new(&mut self.reg)
+    }
+
+  /// Hardware description: OUTOVER
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`GpioCtrlOutover`]. |
+  /// | **`write_variant(val)`** | Writes a [`GpioCtrlOutover`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn outover(&mut self) -> OutoverField {
+        OutoverField::new(&mut self.reg)
+    }
+
+  /// Hardware description: 0-31 -> selects pin function according to the GPIO table. Not all options are valid for all GPIO pins.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`GpioCtrlFuncsel`]. |
+  /// | **`write_variant(val)`** | Writes a [`GpioCtrlFuncsel`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn funcsel(&mut self) -> FuncselField {
+        FuncselField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type IrqoverField<'a> = zermio::BitField<'a, 28, 2, u32, zermio::access::ReadWrite, GpioCtrlIrqover>;
+  type InoverField<'a> = zermio::BitField<'a, 16, 2, u32, zermio::access::ReadWrite, GpioCtrlInover>;
+  type OeoverField<'a> = zermio::BitField<'a, 12, 2, u32, zermio::access::ReadWrite, GpioCtrlOeover>;
+  type OutoverField<'a> = zermio::BitField<'a, 8, 2, u32, zermio::access::ReadWrite, GpioCtrlOutover>;
+  type FuncselField<'a> = zermio::BitField<'a, 0, 5, u32, zermio::access::ReadWrite, GpioCtrlFuncsel>;
+
+  /// Enumerated values of the field IRQOVER: IRQOVER
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum GpioCtrlIrqover {
+    /// don't invert the interrupt
+    Normal = 0x0,
+    /// invert the interrupt
+    Invert = 0x1,
+    /// drive interrupt low
+    Low = 0x2,
+    /// drive interrupt high
+    High = 0x3,
+  }
+
+  impl From<GpioCtrlIrqover> for u32 {
+    fn from(variant: GpioCtrlIrqover) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for GpioCtrlIrqover {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Normal),
+        0x1 => Ok(Self::Invert),
+        0x2 => Ok(Self::Low),
+        0x3 => Ok(Self::High),
+        _ => Err(value),
+      }
+    }
+  }
+
+  /// Enumerated values of the field INOVER: INOVER
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum GpioCtrlInover {
+    /// don't invert the peri input
+    Normal = 0x0,
+    /// invert the peri input
+    Invert = 0x1,
+    /// drive peri input low
+    Low = 0x2,
+    /// drive peri input high
+    High = 0x3,
+  }
+
+  impl From<GpioCtrlInover> for u32 {
+    fn from(variant: GpioCtrlInover) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for GpioCtrlInover {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Normal),
+        0x1 => Ok(Self::Invert),
+        0x2 => Ok(Self::Low),
+        0x3 => Ok(Self::High),
+        _ => Err(value),
+      }
+    }
+  }
+
+  /// Enumerated values of the field OEOVER: OEOVER
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum GpioCtrlOeover {
+    /// drive output enable from peripheral signal selected by funcsel
+    Normal = 0x0,
+    /// drive output enable from inverse of peripheral signal selected by funcsel
+    Invert = 0x1,
+    /// disable output
+    Disable = 0x2,
+    /// enable output
+    Enable = 0x3,
+  }
+
+  impl From<GpioCtrlOeover> for u32 {
+    fn from(variant: GpioCtrlOeover) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for GpioCtrlOeover {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Normal),
+        0x1 => Ok(Self::Invert),
+        0x2 => Ok(Self::Disable),
+        0x3 => Ok(Self::Enable),
+        _ => Err(value),
+      }
+    }
+  }
+
+  /// Enumerated values of the field OUTOVER: OUTOVER
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum GpioCtrlOutover {
+    /// drive output from peripheral signal selected by funcsel
+    Normal = 0x0,
+    /// drive output from inverse of peripheral signal selected by funcsel
+    Invert = 0x1,
+    /// drive output low
+    Low = 0x2,
+    /// drive output high
+    High = 0x3,
+  }
+
+  impl From<GpioCtrlOutover> for u32 {
+    fn from(variant: GpioCtrlOutover) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for GpioCtrlOutover {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Normal),
+        0x1 => Ok(Self::Invert),
+        0x2 => Ok(Self::Low),
+        0x3 => Ok(Self::High),
+        _ => Err(value),
+      }
+    }
+  }
+
+  /// Enumerated values of the field FUNCSEL: 0-31 -> selects pin function according to the GPIO table. Not all options are valid for all GPIO pins.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum GpioCtrlFuncsel {
+    /// Connect to JTAG peripheral
+    Jtag = 0x0,
+    /// Connect to matching SPI peripheral
+    Spi = 0x1,
+    /// Connect to matching UART peripheral
+    Uart = 0x2,
+    /// Connect to matching I2C peripheral
+    I2c = 0x3,
+    /// Connect to matching PWM peripheral
+    Pwm = 0x4,
+    /// Use as a GPIO pin (connect to SIO peripheral)
+    Sio = 0x5,
+    /// Connect to PIO0 peripheral
+    Pio0 = 0x6,
+    /// Connect to PIO1 peripheral
+    Pio1 = 0x7,
+    /// Connect to Clock peripheral
+    Clock = 0x8,
+    /// Connect to USB peripheral
+    Usb = 0x9,
+    /// Connect to nothing
+    Null = 0x1f,
+  }
+
+  impl From<GpioCtrlFuncsel> for u32 {
+    fn from(variant: GpioCtrlFuncsel) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for GpioCtrlFuncsel {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::Jtag),
+        0x1 => Ok(Self::Spi),
+        0x2 => Ok(Self::Uart),
+        0x3 => Ok(Self::I2c),
+        0x4 => Ok(Self::Pwm),
+        0x5 => Ok(Self::Sio),
+        0x6 => Ok(Self::Pio0),
+        0x7 => Ok(Self::Pio1),
+        0x8 => Ok(Self::Clock),
+        0x9 => Ok(Self::Usb),
+        0x1f => Ok(Self::Null),
+        _ => Err(value),
+      }
+    }
+  }
+}
+
+
+
+/// Interrupt Force.  
+pub use self::intf::*;
+mod intf {
+
+  use super::*;
+
+  /// # INTF fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | FORCE | FORCE | [31:0] | 0x0 | true |true |
+  pub struct Intf {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Intf {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: FORCE
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn force(&mut self) -> ForceField {
+        ForceField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type ForceField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// Interrupt status after masking and forcing.  
+pub use self::ints::*;
+mod ints {
+
+  use super::*;
+
+  /// # INTS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | STATUS | STATUS | [31:0] | 0x0 | false |true |
+  pub struct Ints {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ints {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: STATUS
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn status(&mut self) -> StatusField {
+        StatusField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type StatusField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::Read>;
+}
+
+
+
+/// Interrupt Enable for the processor 0.  
+pub use self::inte::*;
+mod inte {
+
+  use super::*;
+
+  /// # INTE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | ENABLE | ENABLE | [31:0] | 0x0 | true |true |
+  pub struct Inte {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Inte {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: ENABLE
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn enable(&mut self) -> EnableField {
+        EnableField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type EnableField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::ReadWrite>;
+}
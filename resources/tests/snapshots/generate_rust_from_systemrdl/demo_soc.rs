@@ -0,0 +1,48 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+
+use super::uart;
+use super::gpio;
+
+pub enum UartOffset{
+  Uart0 = 0x40000000,
+  Uart1 = 0x40001000,
+}
+
+pub enum GpioOffset{
+  Gpio0 = 0x40010000,
+}
+
+
+pub enum Interrupt{
+}
+
+#[no_mangle]
+static mut DEVICE_PERIPHERALS: bool = false;
+
+pub struct Peripherals {
+  pub uart0: uart::Uart,
+  pub uart1: uart::Uart,
+  pub gpio0: gpio::Gpio,}
+
+impl Peripherals {
+  #[inline]
+  pub fn take() -> Option<Self> {
+    //TODO: Critical section
+    if unsafe { DEVICE_PERIPHERALS } {
+        return None;
+    }
+    Some(unsafe { Peripherals::steal() })
+  }
+  #[inline]
+  pub unsafe fn steal() -> Self {
+    DEVICE_PERIPHERALS = true;
+    Self {
+        uart0: uart::Uart::new(UartOffset::Uart0 as u32),
+        uart1: uart::Uart::new(UartOffset::Uart1 as u32),
+        gpio0: gpio::Gpio::new(GpioOffset::Gpio0 as u32),}
+  }
+}
@@ -0,0 +1,519 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the clusters present in the gpio 
+//! | Cluster name       | Description  | Offset | Instances |
+//! | :---               | :---         | :---   | :--       |
+//! | BANK | Edge detection of a bank of pins. | 0x20 | 2 |
+
+//! These are the registers present in the gpio 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | OUT | OUT | 0x0 | true |true |
+//! | OE | OE | 0x2 | true |true |
+//! | DATA_IN0 | DATA_IN0 | 0x8 | true |true |
+//! | DATA_IN1 | DATA_IN1 | 0xc | true |true |
+
+use super::zermio;
+/// ``` rust
+/// gpio.out.update(|out| {
+///     if out.pins().read() == 1 {
+///        out.pins().write(0);
+///     }
+/// });
+/// ```
+pub struct Gpio {
+    /// OUT  
+    pub out : out::Out,
+    /// OE  
+    pub oe : oe::Oe,
+    /// DATA_IN0  
+    pub data_in : [data_in::DataIn; 2],
+    /// Edge detection of a bank of pins.
+    pub bank : [BankCluster; 2],
+}
+
+impl Gpio {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        out : out::Out::new(addr + 0x0 ),
+        oe : oe::Oe::new(addr + 0x2 ),
+        data_in : core::array::from_fn(|n| data_in::DataIn::new(addr + 0x8 + n as u32 * 0x4)),
+        bank : core::array::from_fn(|n| BankCluster::new(addr + 0x20 + n as u32 * 0x10)),
+      }
+  }
+}
+
+/// Edge detection of a bank of pins..  
+pub struct BankCluster {
+    /// CFG  
+    pub cfg : cfg::Cfg,
+    /// EVENTS  
+    pub events : events::Events,
+}
+
+impl BankCluster {
+    pub fn new(addr: u32) -> Self {
+      Self {
+        cfg : cfg::Cfg::new(addr + 0x0 ),
+        events : events::Events::new(addr + 0x4 ),
+      }
+  }
+}
+
+
+
+/// OUT.  
+pub use self::out::*;
+mod out {
+
+  use super::*;
+
+  /// # OUT fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | PINS | [15:0] | 0x0 | true |true |
+  pub struct Out {
+    reg: zermio::Register<u16>,
+  }
+
+  impl Out {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u16 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u16 = 0xffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u16>::new(addr as usize),
+      }
+    }
+  /// Hardware description: PINS
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pins(&mut self) -> PinsField {
+        PinsField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type PinsField<'a> = zermio::BitField<'a, 0, 16, u16, zermio::access::ReadWrite>;
+}
+
+
+
+/// OE.  
+pub use self::oe::*;
+mod oe {
+
+  use super::*;
+
+  /// # OE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | PINS | [15:0] | 0x0 | true |true |
+  pub struct Oe {
+    reg: zermio::Register<u16>,
+  }
+
+  impl Oe {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u16 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u16 = 0xffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u16>::new(addr as usize),
+      }
+    }
+  /// Hardware description: PINS
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pins(&mut self) -> PinsField {
+        PinsField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type PinsField<'a> = zermio::BitField<'a, 0, 16, u16, zermio::access::ReadWrite>;
+}
+
+
+
+/// DATA_IN0.  
+pub use self::data_in::*;
+mod data_in {
+
+  use super::*;
+
+  /// # DATA_IN fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | PINS | PINS | [15:0] | 0x0 | true |true |
+  pub struct DataIn {
+    reg: zermio::Register<u16>,
+  }
+
+  impl DataIn {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u16 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u16 = 0xffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u16>::new(addr as usize),
+      }
+    }
+  /// Hardware description: PINS
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn pins(&mut self) -> PinsField {
+        PinsField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type PinsField<'a> = zermio::BitField<'a, 0, 16, u16, zermio::access::ReadWrite>;
+}
+
+
+
+/// CFG.  
+pub use self::cfg::*;
+mod cfg {
+
+  use super::*;
+
+  /// # CFG fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | RISING | Detect the rising edges. | [15:0] | 0x0 | true |true |
+  /// | FALLING | Detect the falling edges. | [31:16] | 0x0 | true |true |
+  pub struct Cfg {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Cfg {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Detect the rising edges.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rising(&mut self) -> RisingField {
+        RisingField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Detect the falling edges.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn falling(&mut self) -> FallingField {
+        FallingField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type RisingField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::ReadWrite>;
+  type FallingField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+}
+
+
+
+/// EVENTS.  
+pub use self::events::*;
+mod events {
+
+  use super::*;
+
+  /// # EVENTS fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | EVENTS | An edge was detected. | [15:0] | 0x0 | false |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * EVENTS: Clear on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`Events::read_and_clear`].
+  pub struct Events {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Events {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: An edge was detected.
+  ///
+  /// Side effect on read: Clear.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn events(&mut self) -> EventsField {
+        EventsField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_and_clear(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type EventsField<'a> = zermio::BitField<'a, 0, 16, u32, zermio::access::Read, (), zermio::effect::Modify, zermio::load::SideEffects>;
+}
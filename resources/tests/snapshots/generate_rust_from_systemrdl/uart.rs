@@ -0,0 +1,710 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+//zermio
+
+
+//! These are the registers present in the uart 
+//! | Register name      | Description  | Offset | Write | Read| 
+//! | :---               | :---         | :---   | :--   | :-- |
+//! | INTR_STATE | Interrupt state, write one to clear. | 0x0 | true |true |
+//! | CTRL | Control register. | 0x4 | true |true |
+//! | STATUS | Status register. | 0x8 | false |true |
+//! | RDATA | Received data, popped from the RX FIFO on read. | 0x10 | false |true |
+//! | WDATA | Data to transmit. | 0x14 | true |false |
+//! | LOCK | Configuration locked after the first write. | 0x18 | true |false |
+
+use super::zermio;
+/// ``` rust
+/// uart.intr_state.update(|intr_state| {
+///     if intr_state.tx_empty().read() == 1 {
+///        intr_state.tx_empty().write(0);
+///     }
+/// });
+/// ```
+pub struct Uart {
+    /// Interrupt state, write one to clear.  
+    pub intr_state : intr_state::IntrState,
+    /// Control register.  
+    pub ctrl : ctrl::Ctrl,
+    /// Status register.  
+    pub status : status::Status,
+    /// Received data, popped from the RX FIFO on read.  
+    pub rdata : rdata::Rdata,
+    /// Data to transmit.  
+    pub wdata : wdata::Wdata,
+    /// Configuration locked after the first write.  
+    pub lock : lock::Lock,
+}
+
+impl Uart {
+    pub fn new(instance: u32) -> Self {
+    let addr = instance as u32;
+      Self {
+        intr_state : intr_state::IntrState::new(addr + 0x0 ),
+        ctrl : ctrl::Ctrl::new(addr + 0x4 ),
+        status : status::Status::new(addr + 0x8 ),
+        rdata : rdata::Rdata::new(addr + 0x10 ),
+        wdata : wdata::Wdata::new(addr + 0x14 ),
+        lock : lock::Lock::new(addr + 0x18 ),
+      }
+  }
+}
+
+
+
+/// Interrupt state, write one to clear..  
+pub use self::intr_state::*;
+mod intr_state {
+
+  use super::*;
+
+  /// # INTR_STATE fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_EMPTY | The TX FIFO is empty. | [0:0] | 0x0 | true |true |
+  /// | RX_FULL | The RX FIFO is full. | [1:1] | 0x0 | true |true |
+  /// | RX_FRAME_ERR | A frame error was received. | [2:2] | 0x0 | true |true |
+  pub struct IntrState {
+    reg: zermio::Register<u32>,
+  }
+
+  impl IntrState {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+    /// Bits of the fields that have side effects when written, i.e write one to clear.
+    pub const SIDE_EFFECT_MASK: u32 = 0x7;
+    /// Value of the side effect bits that can be written without modifying their fields.
+    pub const NEUTRAL_VALUE: u32 = 0x0;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::with_side_effects(
+          addr as usize,
+          Self::SIDE_EFFECT_MASK,
+          Self::NEUTRAL_VALUE,
+        ),
+      }
+    }
+  /// Hardware description: The TX FIFO is empty.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_empty(&mut self) -> TxEmptyField {
+        TxEmptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: The RX FIFO is full.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_full(&mut self) -> RxFullField {
+        RxFullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: A frame error was received.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`clear_flag()`**    | Writes only this field to clear it (OneToClear). |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_frame_err(&mut self) -> RxFrameErrField {
+        RxFrameErrField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    ///
+    /// The fields with write side effects are set to [`Self::NEUTRAL_VALUE`] before calling
+    /// action, so pending flags aren't cleared by accident.
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      self.reg.neutralize();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxEmptyField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxFullField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+  type RxFrameErrField<'a> = zermio::BitField<'a, 2, 1, u32, zermio::access::ReadWrite, (), zermio::effect::OneToClear>;
+}
+
+
+
+/// Control register..  
+pub use self::ctrl::*;
+mod ctrl {
+
+  use super::*;
+
+  /// # CTRL fields.  
+  /// Reset value: 0x680000
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TX_EN | Enable the transmitter. | [0:0] | 0x0 | true |true |
+  /// | RX_EN | Enable the receiver. | [1:1] | 0x0 | true |true |
+  /// | PARITY | The parity of the frames. | [3:2] | 0x0 | true |true |
+  /// | NCO | Clock divider of the baud rate. | [31:16] | 0x68 | true |true |
+  pub struct Ctrl {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Ctrl {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x680000;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: Enable the transmitter.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn tx_en(&mut self) -> TxEnField {
+        TxEnField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Enable the receiver.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`set()`**           | Sets a single-bit field to 1.                   |
+  /// | **`reset()`**         | Sets a single-bit field to 0.                   |
+  /// | **`toggle()`**        | Inverts the current state of a single-bit field.|
+  /// | **`assign(val)`**     | Sets a single-bit field to the boolean `val`.   |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rx_en(&mut self) -> RxEnField {
+        RxEnField::new(&mut self.reg)
+    }
+
+  /// Hardware description: The parity of the frames.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`variant()`**       | Returns the field value as [`CtrlParity`]. |
+  /// | **`write_variant(val)`** | Writes a [`CtrlParity`] to the field. |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn parity(&mut self) -> ParityField {
+        ParityField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Clock divider of the baud rate.
+  ///
+  /// Returns a BitField with ReadWrite permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn nco(&mut self) -> NcoField {
+        NcoField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+    /// Run a load instruction to read from the MMIO, call action, then run a store instruction 
+    /// to write it to the MMIO
+    pub fn update<F>(&mut self, action: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+      self.reg.fetch();
+      action(self);
+      self.reg.commit();
+    }
+  }
+  type TxEnField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::ReadWrite>;
+  type RxEnField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::ReadWrite>;
+  type ParityField<'a> = zermio::BitField<'a, 2, 2, u32, zermio::access::ReadWrite, CtrlParity>;
+  type NcoField<'a> = zermio::BitField<'a, 16, 16, u32, zermio::access::ReadWrite>;
+
+  /// Enumerated values of the field PARITY: The parity of the frames.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  #[repr(u32)]
+  pub enum CtrlParity {
+    /// No parity bit.
+    None = 0x0,
+    /// Even parity.
+    Even = 0x1,
+    /// Odd parity.
+    Odd = 0x2,
+  }
+
+  impl From<CtrlParity> for u32 {
+    fn from(variant: CtrlParity) -> u32 {
+      variant as u32
+    }
+  }
+
+  impl TryFrom<u32> for CtrlParity {
+    type Error = u32;
+    /// Returns the raw value as error if it doesn't match any variant.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+      match value {
+        0x0 => Ok(Self::None),
+        0x1 => Ok(Self::Even),
+        0x2 => Ok(Self::Odd),
+        _ => Err(value),
+      }
+    }
+  }
+}
+
+
+
+/// Status register..  
+pub use self::status::*;
+mod status {
+
+  use super::*;
+
+  /// # STATUS fields.  
+  /// Reset value: 0x2
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | TXFULL | The TX FIFO is full. | [0:0] | 0x0 | false |true |
+  /// | RXEMPTY | The RX FIFO is empty. | [1:1] | 0x1 | false |true |
+  /// | RXLVL | Number of bytes in the RX FIFO. | [15:8] | 0x0 | false |true |
+  pub struct Status {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Status {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x2;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: The TX FIFO is full.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn txfull(&mut self) -> TxfullField {
+        TxfullField::new(&mut self.reg)
+    }
+
+  /// Hardware description: The RX FIFO is empty.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`is_set()`**        | Returns `true` if the bit is 1.                 |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxempty(&mut self) -> RxemptyField {
+        RxemptyField::new(&mut self.reg)
+    }
+
+  /// Hardware description: Number of bytes in the RX FIFO.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn rxlvl(&mut self) -> RxlvlField {
+        RxlvlField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO
+    pub fn fetch(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type TxfullField<'a> = zermio::BitField<'a, 0, 1, u32, zermio::access::Read>;
+  type RxemptyField<'a> = zermio::BitField<'a, 1, 1, u32, zermio::access::Read>;
+  type RxlvlField<'a> = zermio::BitField<'a, 8, 8, u32, zermio::access::Read>;
+}
+
+
+
+/// Received data, popped from the RX FIFO on read..  
+pub use self::rdata::*;
+mod rdata {
+
+  use super::*;
+
+  /// # RDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | DATA | DATA | [7:0] | 0x0 | false |true |
+  ///
+  /// **Reading this register has side effects:**
+  /// * DATA: Clear on read.
+  ///
+  /// Thus `fetch()` and `update()` aren't provided, neither by the register nor by its fields,
+  /// the register can only be loaded explicitly
+  /// with [`Rdata::read_and_clear`].
+  pub struct Rdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Rdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: DATA
+  ///
+  /// Side effect on read: Clear.
+  ///
+  /// Returns a BitField with Read permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`get()`**           | Returns the current value of the field.         |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn data(&mut self) -> DataField {
+        DataField::new(&mut self.reg)
+    }
+
+    /// Run a load instruction to read from MMIO. Beware the load modifies the fields with read
+    /// side effects.
+    pub fn read_and_clear(&mut self) -> &mut Self {
+      self.reg.fetch();
+      self
+    }
+  }
+  type DataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Read, (), zermio::effect::Modify, zermio::load::SideEffects>;
+}
+
+
+
+/// Data to transmit..  
+pub use self::wdata::*;
+mod wdata {
+
+  use super::*;
+
+  /// # WDATA fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | DATA | DATA | [7:0] | 0x0 | true |false |
+  pub struct Wdata {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Wdata {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: DATA
+  ///
+  /// Returns a BitField with Write permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn data(&mut self) -> DataField {
+        DataField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO
+    pub fn write<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.commit();
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO
+    pub fn write_with_zero<F>(&mut self, action: F)
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.commit();
+    }
+    /// Run a store instruction to write the reset value to the MMIO
+    pub fn reset(&mut self) {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.commit();
+    }
+  }
+  type DataField<'a> = zermio::BitField<'a, 0, 8, u32, zermio::access::Write>;
+}
+
+
+
+/// Configuration locked after the first write..  
+pub use self::lock::*;
+mod lock {
+
+  use super::*;
+
+  /// # LOCK fields.  
+  /// Reset value: 0x0
+  ///
+  /// | Field name      | Description  | Offset | Reset | Write | Read| 
+  /// | :---               | :---         | :---   | :---  | :--   | :-- |
+  /// | KEY | KEY | [31:0] | 0x0 | true |false |
+  pub struct Lock {
+    reg: zermio::Register<u32>,
+  }
+
+  impl Lock {
+    /// Value of the register after a reset.
+    pub const RESET_VALUE: u32 = 0x0;
+    /// Bits of the register that have a defined reset value.
+    pub const RESET_MASK: u32 = 0xffffffff;
+
+    pub fn new(addr: u32) -> Self {
+      Self {
+        reg: zermio::Register::<u32>::new(addr as usize),
+      }
+    }
+  /// Hardware description: KEY
+  ///
+  /// Returns a BitField with WriteOnce permissions, providing the following functions:
+  /// | Method                | Description                                     |
+  /// | :---                  | :---                                            |
+  /// | **`write(val)`**      | Writes a multi-bit `val` to the field.          |
+  /// | **`try_commit()`**    | Writes the register unless it was already written. |
+  /// | **`clear()`**         | Sets all bits in the field to 0.                |
+  /// | **`write_mask(val)`** | Sets only the bits that are high (1) in `val`.  |
+  /// | **`mask()`**          | Returns the bitmask for this field.             |
+  /// | **`max()`**           | Returns the maximum value this field can hold.  |
+  ///
+    pub fn key(&mut self) -> KeyField {
+        KeyField::new(&mut self.reg)
+    }
+
+    /// Run a store instruction to write the MMIO
+    pub fn commit(&mut self) {
+      self.reg.commit()
+    }
+    /// Run a store instruction to write the MMIO, unless this instance already did it. The
+    /// write-once fields of this register ignore any write after the first one.
+    pub fn try_commit(&mut self) -> Result<(), zermio::AlreadyCommitted> {
+      self.reg.try_commit()
+    }
+    /// Set the inner self to the reset value and call action to update it, then run a store
+    /// instruction to write it to the MMIO, unless this instance already did it.
+    pub fn write<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = Self::RESET_VALUE;
+      action(self);
+      self.reg.try_commit()
+    }
+    /// Set the inner self to zero and call action to update it, then run a store instruction to
+    /// write it to the MMIO, unless this instance already did it.
+    pub fn write_with_zero<F>(&mut self, action: F) -> Result<(), zermio::AlreadyCommitted>
+    where
+      F: FnOnce(&mut Self),
+    {
+      self.reg.cache = 0;
+      action(self);
+      self.reg.try_commit()
+    }
+    /// Run a store instruction to write the reset value to the MMIO, unless this instance already
+    /// did it.
+    pub fn reset(&mut self) -> Result<(), zermio::AlreadyCommitted> {
+      self.reg.cache = Self::RESET_VALUE;
+      self.reg.try_commit()
+    }
+  }
+  type KeyField<'a> = zermio::BitField<'a, 0, 32, u32, zermio::access::WriteOnce>;
+}
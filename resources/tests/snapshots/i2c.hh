@@ -13,19 +13,29 @@ namespace i2c {
 /* I2C Control Register */
 union CtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Enable Host I2C functionality */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> enablehost;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> enablehost;
     /* Enable Target I2C functionality */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> enabletarget;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> enabletarget;
     /* Enable I2C line loopback testIf line loopback is enabled, the internal design sees ACQ and RX data as "1" */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> llpbk;
-    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> llpbk;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline CtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -37,33 +47,43 @@ union CtrlReg {
 /* I2C Live Status Register */
 union StatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* FMT FIFO is full */
-    zermio::BitField<0, 1, zermio::Permissions::Read> fmtfull;
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> fmtfull;
     /* RX FIFO is full */
-    zermio::BitField<1, 1, zermio::Permissions::Read> rxfull;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> rxfull;
     /* FMT FIFO is empty */
-    zermio::BitField<2, 1, zermio::Permissions::Read> fmtempty;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> fmtempty;
     /* RX FIFO is empty */
-    zermio::BitField<5, 1, zermio::Permissions::Read> rxempty;
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> rxempty;
     /* Host functionality is idle. No Host transaction is in progress */
-    zermio::BitField<3, 1, zermio::Permissions::Read> hostidle;
+    zermio::BitField<3, 1, zermio::Permissions::Read, uint32_t> hostidle;
     /* Target functionality is idle. No Target transaction is in progress */
-    zermio::BitField<4, 1, zermio::Permissions::Read> targetidle;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> targetidle;
     /* TX FIFO is full */
-    zermio::BitField<6, 1, zermio::Permissions::Read> txfull;
+    zermio::BitField<6, 1, zermio::Permissions::Read, uint32_t> txfull;
     /* ACQ FIFO is full */
-    zermio::BitField<7, 1, zermio::Permissions::Read> acqfull;
+    zermio::BitField<7, 1, zermio::Permissions::Read, uint32_t> acqfull;
     /* TX FIFO is empty */
-    zermio::BitField<8, 1, zermio::Permissions::Read> txempty;
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> txempty;
     /* ACQ FIFO is empty */
-    zermio::BitField<9, 1, zermio::Permissions::Read> acqempty;
-    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> acqempty;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr StatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline StatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -73,18 +93,32 @@ union StatusReg {
 
 
 /* I2C Read Data */
+/* Reading this register has side effects:
+ * - Value: Modify on read.
+ * Thus it can only be loaded explicitly with read_with_side_effects(). */
 union RdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint8_t> reg_;
     public:
     /* Value */
-    zermio::BitField<0, 8, zermio::Permissions::Read> value;
-    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint8_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint8_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint8_t reset_mask = 0xff;
+
+    constexpr RdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
-    inline RdataReg& fetch() {
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline RdataReg& read_with_side_effects() {
         reg_.fetch();
         return *this;
     }
@@ -95,25 +129,35 @@ union RdataReg {
 /* I2C Format Data */
 union FdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Format Byte. Directly transmitted if no flags are set. */
-    zermio::BitField<0, 8, zermio::Permissions::Write> fbyte;
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> fbyte;
     /* Issue a START condition before transmitting BYTE. */
-    zermio::BitField<8, 1, zermio::Permissions::Write> start;
+    zermio::BitField<8, 1, zermio::Permissions::Write, uint32_t> start;
     /* Issue a STOP condition after this operation */
-    zermio::BitField<9, 1, zermio::Permissions::Write> stop;
+    zermio::BitField<9, 1, zermio::Permissions::Write, uint32_t> stop;
     /* Read BYTE bytes from I2C. (256 if BYTE==0) */
-    zermio::BitField<10, 1, zermio::Permissions::Write> read;
+    zermio::BitField<10, 1, zermio::Permissions::Write, uint32_t> read;
     /* Do not NACK the last byte read, let the read operation continue */
-    zermio::BitField<11, 1, zermio::Permissions::Write> rcont;
+    zermio::BitField<11, 1, zermio::Permissions::Write, uint32_t> rcont;
     /* Do not signal an exception if the current byte is not ACK'd */
-    zermio::BitField<12, 1, zermio::Permissions::Write> nakok;
-    constexpr FdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<12, 1, zermio::Permissions::Write, uint32_t> nakok;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -125,25 +169,35 @@ union FdataReg {
 /* I2C FIFO control register */
 union FifoCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* RX fifo reset. Write 1 to the register resets RX_FIFO. Read returns 0 */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> rxrst;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> rxrst;
     /* FMT fifo reset. Write 1 to the register resets FMT_FIFO. Read returns 0 */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> fmtrst;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> fmtrst;
     /* Trigger level for RX interrupts. If the FIFO depth exceedsthis setting, it raises rx_threshold interrupt. */
-    zermio::BitField<2, 3, zermio::Permissions::ReadWrite> rxilvl;
+    zermio::BitField<2, 3, zermio::Permissions::ReadWrite, uint32_t> rxilvl;
     /* Trigger level for FMT interrupts. If the FIFO depth falls belowthis setting, it raises fmt_threshold interrupt. */
-    zermio::BitField<5, 2, zermio::Permissions::ReadWrite> fmtilvl;
+    zermio::BitField<5, 2, zermio::Permissions::ReadWrite, uint32_t> fmtilvl;
     /* ACQ FIFO reset. Write 1 to the register resets it. Read returns 0 */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> acqrst;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> acqrst;
     /* TX FIFO reset. Write 1 to the register resets it. Read returns 0 */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> txrst;
-    constexpr FifoCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> txrst;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FifoCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FifoCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -155,21 +209,31 @@ union FifoCtrlReg {
 /* I2C FIFO status register */
 union FifoStatusReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Current fill level of FMT fifo */
-    zermio::BitField<0, 7, zermio::Permissions::Read> fmtlvl;
+    zermio::BitField<0, 7, zermio::Permissions::Read, uint32_t> fmtlvl;
     /* Current fill level of RX fifo */
-    zermio::BitField<16, 7, zermio::Permissions::Read> rxlvl;
+    zermio::BitField<16, 7, zermio::Permissions::Read, uint32_t> rxlvl;
     /* Current fill level of TX fifo */
-    zermio::BitField<8, 7, zermio::Permissions::Read> txlvl;
+    zermio::BitField<8, 7, zermio::Permissions::Read, uint32_t> txlvl;
     /* Current fill level of ACQ fifo */
-    zermio::BitField<24, 7, zermio::Permissions::Read> acqlvl;
-    constexpr FifoStatusReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<24, 7, zermio::Permissions::Read, uint32_t> acqlvl;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr FifoStatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline FifoStatusReg& fetch() {
         reg_.fetch();
         return *this;
@@ -181,19 +245,29 @@ union FifoStatusReg {
 /* I2C Override Control Register */
 union OvrdReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Override the SDA and SCL TX signals. */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> txovrden;
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> txovrden;
     /* Value for SCL Override. Set to 0 to drive TX Low, and set to 1 for high-Z */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> sclval;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> sclval;
     /* Value for SDA Override. Set to 0 to drive TX Low, and set to 1 for high-Z */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> sdaval;
-    constexpr OvrdReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> sdaval;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr OvrdReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline OvrdReg& fetch() {
         reg_.fetch();
         return *this;
@@ -205,17 +279,27 @@ union OvrdReg {
 /* Oversampled RX values */
 union ValReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Last 16 oversampled values of SCL. Most recent bit is bit 0, oldest 15. */
-    zermio::BitField<0, 16, zermio::Permissions::Read> scl_rx;
+    zermio::BitField<0, 16, zermio::Permissions::Read, uint32_t> scl_rx;
     /* Last 16 oversampled values of SDA. Most recent bit is bit 16, oldest 31. */
-    zermio::BitField<16, 16, zermio::Permissions::Read> sda_rx;
-    constexpr ValReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::Read, uint32_t> sda_rx;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr ValReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline ValReg& fetch() {
         reg_.fetch();
         return *this;
@@ -227,17 +311,27 @@ union ValReg {
 /* Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period.These must be greater than 2 in order for the change in SCL to propagate to the input of the FSM so that acknowledgements are detected correctly. */
 union Timing0Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* The actual time to hold SCL high in a given pulse: in host mode, when there is no stretching this value is 3 cycles longer as tracked in issue #18962 */
-    zermio::BitField<0, 16, zermio::Permissions::ReadWrite> thigh;
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> thigh;
     /* The actual time to hold SCL low between any two SCL pulses */
-    zermio::BitField<16, 16, zermio::Permissions::ReadWrite> tlow;
-    constexpr Timing0Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> tlow;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing0Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing0Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -249,17 +343,27 @@ union Timing0Reg {
 /* Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing1Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* The nominal rise time to anticipate for the bus (depends on capacitance) */
-    zermio::BitField<0, 16, zermio::Permissions::ReadWrite> t_r;
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> t_r;
     /* The nominal fall time to anticipate for the bus (influences SDA hold times): this is currently counted twice in host mode as tracked in issue #18958 */
-    zermio::BitField<16, 16, zermio::Permissions::ReadWrite> t_f;
-    constexpr Timing1Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> t_f;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing1Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing1Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -271,17 +375,27 @@ union Timing1Reg {
 /* Detailed I2C Timings (directly corresponding to table 10 in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing2Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Actual setup time for repeated start signals */
-    zermio::BitField<0, 16, zermio::Permissions::ReadWrite> tsu_sta;
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> tsu_sta;
     /* Actual hold time for start signals */
-    zermio::BitField<16, 16, zermio::Permissions::ReadWrite> thd_sta;
-    constexpr Timing2Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> thd_sta;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing2Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing2Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -293,17 +407,27 @@ union Timing2Reg {
 /* Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing3Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Actual setup time for data (or ack) bits */
-    zermio::BitField<0, 16, zermio::Permissions::ReadWrite> tsu_dat;
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> tsu_dat;
     /* Actual hold time for data (or ack) bits(Note, where required, the parameters TVD_DAT is taken to be THD_DAT+T_F) */
-    zermio::BitField<16, 16, zermio::Permissions::ReadWrite> thd_dat;
-    constexpr Timing3Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> thd_dat;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing3Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing3Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -315,17 +439,27 @@ union Timing3Reg {
 /* Detailed I2C Timings (directly corresponding to table 10, in the I2C Specification).All values are expressed in units of the input clock period. */
 union Timing4Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Actual setup time for stop signals */
-    zermio::BitField<0, 16, zermio::Permissions::ReadWrite> tsu_sto;
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> tsu_sto;
     /* Actual time between each STOP signal and the following START signal */
-    zermio::BitField<16, 16, zermio::Permissions::ReadWrite> t_buf;
-    constexpr Timing4Reg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<16, 16, zermio::Permissions::ReadWrite, uint32_t> t_buf;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Timing4Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Timing4Reg& fetch() {
         reg_.fetch();
         return *this;
@@ -337,17 +471,27 @@ union Timing4Reg {
 /* I2C clock stretching timeout control */
 union TimeoutCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Clock stretching timeout value (in units of input clock frequency) */
-    zermio::BitField<0, 31, zermio::Permissions::ReadWrite> val;
+    zermio::BitField<0, 31, zermio::Permissions::ReadWrite, uint32_t> val;
     /* Enable timeout feature */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> en;
-    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> en;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TimeoutCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -359,21 +503,31 @@ union TimeoutCtrlReg {
 /* I2C target address and mask pairs */
 union TargetIdReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* I2C target address number 0 */
-    zermio::BitField<0, 7, zermio::Permissions::ReadWrite> address0;
+    zermio::BitField<0, 7, zermio::Permissions::ReadWrite, uint32_t> address0;
     /* I2C target mask number 0 */
-    zermio::BitField<7, 7, zermio::Permissions::ReadWrite> mask0;
+    zermio::BitField<7, 7, zermio::Permissions::ReadWrite, uint32_t> mask0;
     /* I2C target address number 1 */
-    zermio::BitField<14, 7, zermio::Permissions::ReadWrite> address1;
+    zermio::BitField<14, 7, zermio::Permissions::ReadWrite, uint32_t> address1;
     /* I2C target mask number 1 */
-    zermio::BitField<21, 7, zermio::Permissions::ReadWrite> mask1;
-    constexpr TargetIdReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<21, 7, zermio::Permissions::ReadWrite, uint32_t> mask1;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TargetIdReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TargetIdReg& fetch() {
         reg_.fetch();
         return *this;
@@ -385,17 +539,27 @@ union TargetIdReg {
 /* I2C target acquired data */
 union AcqdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Address for accepted transaction or acquired byte */
-    zermio::BitField<0, 8, zermio::Permissions::Read> abyte;
+    zermio::BitField<0, 8, zermio::Permissions::Read, uint32_t> abyte;
     /* Host issued a START before transmitting ABYTE, a STOP or a RESTART after the preceeding ABYTE */
-    zermio::BitField<8, 2, zermio::Permissions::Read> signal;
-    constexpr AcqdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<8, 2, zermio::Permissions::Read, uint32_t> signal;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr AcqdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline AcqdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -407,15 +571,25 @@ union AcqdataReg {
 /* I2C target transmit data */
 union TxdataReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Value */
-    zermio::BitField<0, 8, zermio::Permissions::Write> value;
-    constexpr TxdataReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 8, zermio::Permissions::Write, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr TxdataReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline TxdataReg& fetch() {
         reg_.fetch();
         return *this;
@@ -427,15 +601,25 @@ union TxdataReg {
 /* I2C host clock generation timeout value (in units of input clock frequency) */
 union HostTimeoutCtrlReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* Value */
-    zermio::BitField<0, 32, zermio::Permissions::ReadWrite> value;
-    constexpr HostTimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr HostTimeoutCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline HostTimeoutCtrlReg& fetch() {
         reg_.fetch();
         return *this;
@@ -444,6 +628,102 @@ union HostTimeoutCtrlReg {
 
 
 
+/* I2C configuration lock, only the first write after reset takes effect */
+union CfgLockReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Key that unlocks the target configuration */
+    zermio::BitField<0, 8, zermio::Permissions::WriteOnce, uint32_t> key;
+    /* Lock the timing configuration until the next reset */
+    zermio::BitField<8, 1, zermio::Permissions::ReadWriteOnce, uint32_t> locked;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CfgLockReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    /* Write the mmio unless this instance already did it, returns false otherwise. */
+    inline bool try_commit() { return reg_.try_commit(); }
+
+    /* Write the reset value unless this instance already wrote the mmio, returns false otherwise. */
+    inline bool reset() {
+        reg_.cache = reset_value;
+        return reg_.try_commit();
+    }
+
+    inline CfgLockReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Enumerated values of the field MODE. */
+enum class PadCtrlMode: uint32_t {
+    /* Only drive the pad low */
+    Opendrain = 0x0,
+    /* Drive the pad low and high */
+    Pushpull = 0x1,
+};
+
+/* I2C pads configuration */
+union PadCtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Drive mode of the SCL pad */
+    zermio::BitField<0, 2, zermio::Permissions::ReadWrite, uint32_t, PadCtrlMode> mode_scl;
+    /* Field array MODE, i.e `mode[n]` is the same as `mode_scl` for n = 0. */
+    zermio::FieldArray<0, 2, 4, 2, zermio::Permissions::ReadWrite, uint32_t, PadCtrlMode> mode;
+    /* Drive mode of the SDA pad */
+    zermio::BitField<4, 2, zermio::Permissions::ReadWrite, uint32_t, PadCtrlMode> mode_sda;
+    /* Enable the internal pull-up of the pin 0 */
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> pullup0;
+    /* Field array PULLUP, i.e `pullup[n]` is the same as `pullup0` for n = 0. */
+    zermio::FieldArray<16, 1, 1, 8, zermio::Permissions::ReadWrite, uint32_t> pullup;
+    /* Enable the internal pull-up of the pin 1 */
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> pullup1;
+    /* Enable the internal pull-up of the pin 2 */
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> pullup2;
+    /* Enable the internal pull-up of the pin 3 */
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> pullup3;
+    /* Enable the internal pull-up of the pin 4 */
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> pullup4;
+    /* Enable the internal pull-up of the pin 5 */
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> pullup5;
+    /* Enable the internal pull-up of the pin 6 */
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> pullup6;
+    /* Enable the internal pull-up of the pin 7 */
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> pullup7;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr PadCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline PadCtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
 /* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
 struct I2c {
     CtrlReg ctrl;
@@ -464,6 +744,8 @@ struct I2c {
     AcqdataReg acqdata;
     TxdataReg txdata;
     HostTimeoutCtrlReg host_timeout_ctrl;
+    CfgLockReg cfg_lock;
+    PadCtrlReg pad_ctrl;
     
     constexpr I2c (platform::I2c addr):
         ctrl(addr + 0x10), 
@@ -483,7 +765,9 @@ struct I2c {
         target_id(addr + 0x78), 
         acqdata(addr + 0x7c), 
         txdata(addr + 0x80), 
-        host_timeout_ctrl(addr + 0x84){}
+        host_timeout_ctrl(addr + 0x84), 
+        cfg_lock(addr + 0x88), 
+        pad_ctrl(addr + 0x8c){}
 };
 
 
@@ -10,146 +10,107 @@
 namespace mmio {
 namespace io_bank {
 
-/* GPIO status */
-union GpioStatusReg { 
-    private:
-      zermio::Register reg_;
-    public:
-    /* interrupt to processors, after override is applied */
-    zermio::BitField<26, 1, zermio::Permissions::Read> irqtoproc;
-    /* interrupt from pad before override is applied */
-    zermio::BitField<24, 1, zermio::Permissions::Read> irqfrompad;
-    /* input signal to peripheral, after override is applied */
-    zermio::BitField<19, 1, zermio::Permissions::Read> intoperi;
-    /* input signal from pad, before override is applied */
-    zermio::BitField<17, 1, zermio::Permissions::Read> infrompad;
-    /* output enable to pad after register override is applied */
-    zermio::BitField<13, 1, zermio::Permissions::Read> oetopad;
-    /* output enable from selected peripheral, before register override is applied */
-    zermio::BitField<12, 1, zermio::Permissions::Read> oefromperi;
-    /* output signal to pad after register override is applied */
-    zermio::BitField<9, 1, zermio::Permissions::Read> outtopad;
-    /* output signal from selected peripheral, before register override is applied */
-    zermio::BitField<8, 1, zermio::Permissions::Read> outfromperi;
-    constexpr GpioStatusReg (uintptr_t addr): reg_{.addr = addr}
-    {}
-
-    inline void commit() { reg_.commit(); }
-
-    inline GpioStatusReg& fetch() {
-        reg_.fetch();
-        return *this;
-    }
-};
-
-
-
-/* GPIO control including function select and overrides. */
-union GpioCtrlReg { 
-    private:
-      zermio::Register reg_;
-    public:
-    /* IRQOVER */
-    zermio::BitField<28, 2, zermio::Permissions::ReadWrite> irqover;
-    /* INOVER */
-    zermio::BitField<16, 2, zermio::Permissions::ReadWrite> inover;
-    /* OEOVER */
-    zermio::BitField<12, 2, zermio::Permissions::ReadWrite> oeover;
-    /* OUTOVER */
-    zermio::BitField<8, 2, zermio::Permissions::ReadWrite> outover;
-    /* 0-31 -> selects pin function according to the GPIO table. Not all options are valid for all GPIO pins. */
-    zermio::BitField<0, 5, zermio::Permissions::ReadWrite> funcsel;
-    constexpr GpioCtrlReg (uintptr_t addr): reg_{.addr = addr}
-    {}
-
-    inline void commit() { reg_.commit(); }
-
-    inline GpioCtrlReg& fetch() {
-        reg_.fetch();
-        return *this;
-    }
-};
-
-
-
 /* Raw Interrupts */
 union IntrReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::Read> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::Read, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::Read> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::Read, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::Read> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::Read, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::Read> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::Read, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::Read> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::Read, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::Read> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::Read, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::Read> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::Read, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::Read> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::Read, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::Read> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::Read, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::Read> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::Read, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::Read> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::Read> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::Read> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::Read> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t, uint32_t, zermio::WriteEffect::OneToClear> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::Read> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::Read> gpio0_level_low;
-    constexpr IntrReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint32_t side_effect_mask = 0xcccccccc;
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint32_t neutral_value = 0x0;
+
+    constexpr IntrReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline IntrReg& fetch() {
         reg_.fetch();
         return *this;
     }
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline IntrReg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
 };
 
 
@@ -157,77 +118,87 @@ union IntrReg {
 /* Interrupt Enable for proc0 */
 union Proc0InteReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::ReadWrite> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::ReadWrite> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::ReadWrite> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::ReadWrite> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::ReadWrite> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::ReadWrite> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::ReadWrite> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::ReadWrite> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> gpio0_level_low;
-    constexpr Proc0InteReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Proc0InteReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Proc0InteReg& fetch() {
         reg_.fetch();
         return *this;
@@ -239,77 +210,87 @@ union Proc0InteReg {
 /* Interrupt Force for proc0 */
 union Proc0IntfReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::ReadWrite> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::ReadWrite> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::ReadWrite> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::ReadWrite> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::ReadWrite> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::ReadWrite> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::ReadWrite> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::ReadWrite> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> gpio0_level_low;
-    constexpr Proc0IntfReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Proc0IntfReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Proc0IntfReg& fetch() {
         reg_.fetch();
         return *this;
@@ -321,77 +302,87 @@ union Proc0IntfReg {
 /* Interrupt status after masking & forcing for proc0 */
 union Proc0IntsReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::Read> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::Read, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::Read> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::Read, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::Read> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::Read, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::Read> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::Read, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::Read> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::Read, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::Read> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::Read, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::Read> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::Read, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::Read> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::Read, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::Read> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::Read, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::Read> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::Read, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::Read> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::Read, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::Read> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::Read, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::Read> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::Read, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::Read> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::Read, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::Read> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::Read, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::Read> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::Read, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::Read> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::Read, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::Read> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::Read, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::Read> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::Read, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::Read> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::Read, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::Read> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::Read, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::Read> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::Read, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::Read> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::Read> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::Read> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::Read, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::Read> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::Read, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::Read> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::Read> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::Read> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::Read, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::Read> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::Read> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::Read> gpio0_level_low;
-    constexpr Proc0IntsReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Proc0IntsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Proc0IntsReg& fetch() {
         reg_.fetch();
         return *this;
@@ -403,77 +394,87 @@ union Proc0IntsReg {
 /* Interrupt Enable for proc1 */
 union Proc1InteReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::ReadWrite> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::ReadWrite> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::ReadWrite> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::ReadWrite> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::ReadWrite> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::ReadWrite> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::ReadWrite> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::ReadWrite> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> gpio0_level_low;
-    constexpr Proc1InteReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Proc1InteReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Proc1InteReg& fetch() {
         reg_.fetch();
         return *this;
@@ -485,77 +486,87 @@ union Proc1InteReg {
 /* Interrupt Force for proc1 */
 union Proc1IntfReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::ReadWrite> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::ReadWrite> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::ReadWrite> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::ReadWrite> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::ReadWrite> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::ReadWrite> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::ReadWrite> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::ReadWrite> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> gpio0_level_low;
-    constexpr Proc1IntfReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Proc1IntfReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Proc1IntfReg& fetch() {
         reg_.fetch();
         return *this;
@@ -567,77 +578,87 @@ union Proc1IntfReg {
 /* Interrupt status after masking & forcing for proc1 */
 union Proc1IntsReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::Read> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::Read, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::Read> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::Read, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::Read> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::Read, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::Read> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::Read, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::Read> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::Read, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::Read> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::Read, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::Read> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::Read, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::Read> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::Read, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::Read> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::Read, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::Read> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::Read, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::Read> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::Read, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::Read> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::Read, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::Read> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::Read, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::Read> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::Read, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::Read> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::Read, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::Read> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::Read, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::Read> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::Read, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::Read> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::Read, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::Read> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::Read, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::Read> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::Read, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::Read> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::Read, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::Read> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::Read, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::Read> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::Read> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::Read> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::Read, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::Read> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::Read, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::Read> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::Read> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::Read> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::Read, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::Read> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::Read> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::Read> gpio0_level_low;
-    constexpr Proc1IntsReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr Proc1IntsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline Proc1IntsReg& fetch() {
         reg_.fetch();
         return *this;
@@ -649,77 +670,87 @@ union Proc1IntsReg {
 /* Interrupt Enable for dormant_wake */
 union DormantWakeInteReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::ReadWrite> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::ReadWrite> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::ReadWrite> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::ReadWrite> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::ReadWrite> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::ReadWrite> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::ReadWrite> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::ReadWrite> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> gpio0_level_low;
-    constexpr DormantWakeInteReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr DormantWakeInteReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline DormantWakeInteReg& fetch() {
         reg_.fetch();
         return *this;
@@ -731,77 +762,87 @@ union DormantWakeInteReg {
 /* Interrupt Force for dormant_wake */
 union DormantWakeIntfReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::ReadWrite> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::ReadWrite> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::ReadWrite> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::ReadWrite> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::ReadWrite, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::ReadWrite> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::ReadWrite> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::ReadWrite> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::ReadWrite> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::ReadWrite, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::ReadWrite> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::ReadWrite> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::ReadWrite> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::ReadWrite> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::ReadWrite, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::ReadWrite> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::ReadWrite> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::ReadWrite> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::ReadWrite> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::ReadWrite, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::ReadWrite> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::ReadWrite> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::ReadWrite> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::ReadWrite> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::ReadWrite, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::ReadWrite> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::ReadWrite> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::ReadWrite> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::ReadWrite> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::ReadWrite, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::ReadWrite> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::ReadWrite> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::ReadWrite> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::ReadWrite> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::ReadWrite, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::ReadWrite> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::ReadWrite> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::ReadWrite> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::ReadWrite> gpio0_level_low;
-    constexpr DormantWakeIntfReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr DormantWakeIntfReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline DormantWakeIntfReg& fetch() {
         reg_.fetch();
         return *this;
@@ -813,77 +854,87 @@ union DormantWakeIntfReg {
 /* Interrupt status after masking & forcing for dormant_wake */
 union DormantWakeIntsReg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint32_t> reg_;
     public:
     /* GPIO7_EDGE_HIGH */
-    zermio::BitField<31, 1, zermio::Permissions::Read> gpio7_edge_high;
+    zermio::BitField<31, 1, zermio::Permissions::Read, uint32_t> gpio7_edge_high;
     /* GPIO7_EDGE_LOW */
-    zermio::BitField<30, 1, zermio::Permissions::Read> gpio7_edge_low;
+    zermio::BitField<30, 1, zermio::Permissions::Read, uint32_t> gpio7_edge_low;
     /* GPIO7_LEVEL_HIGH */
-    zermio::BitField<29, 1, zermio::Permissions::Read> gpio7_level_high;
+    zermio::BitField<29, 1, zermio::Permissions::Read, uint32_t> gpio7_level_high;
     /* GPIO7_LEVEL_LOW */
-    zermio::BitField<28, 1, zermio::Permissions::Read> gpio7_level_low;
+    zermio::BitField<28, 1, zermio::Permissions::Read, uint32_t> gpio7_level_low;
     /* GPIO6_EDGE_HIGH */
-    zermio::BitField<27, 1, zermio::Permissions::Read> gpio6_edge_high;
+    zermio::BitField<27, 1, zermio::Permissions::Read, uint32_t> gpio6_edge_high;
     /* GPIO6_EDGE_LOW */
-    zermio::BitField<26, 1, zermio::Permissions::Read> gpio6_edge_low;
+    zermio::BitField<26, 1, zermio::Permissions::Read, uint32_t> gpio6_edge_low;
     /* GPIO6_LEVEL_HIGH */
-    zermio::BitField<25, 1, zermio::Permissions::Read> gpio6_level_high;
+    zermio::BitField<25, 1, zermio::Permissions::Read, uint32_t> gpio6_level_high;
     /* GPIO6_LEVEL_LOW */
-    zermio::BitField<24, 1, zermio::Permissions::Read> gpio6_level_low;
+    zermio::BitField<24, 1, zermio::Permissions::Read, uint32_t> gpio6_level_low;
     /* GPIO5_EDGE_HIGH */
-    zermio::BitField<23, 1, zermio::Permissions::Read> gpio5_edge_high;
+    zermio::BitField<23, 1, zermio::Permissions::Read, uint32_t> gpio5_edge_high;
     /* GPIO5_EDGE_LOW */
-    zermio::BitField<22, 1, zermio::Permissions::Read> gpio5_edge_low;
+    zermio::BitField<22, 1, zermio::Permissions::Read, uint32_t> gpio5_edge_low;
     /* GPIO5_LEVEL_HIGH */
-    zermio::BitField<21, 1, zermio::Permissions::Read> gpio5_level_high;
+    zermio::BitField<21, 1, zermio::Permissions::Read, uint32_t> gpio5_level_high;
     /* GPIO5_LEVEL_LOW */
-    zermio::BitField<20, 1, zermio::Permissions::Read> gpio5_level_low;
+    zermio::BitField<20, 1, zermio::Permissions::Read, uint32_t> gpio5_level_low;
     /* GPIO4_EDGE_HIGH */
-    zermio::BitField<19, 1, zermio::Permissions::Read> gpio4_edge_high;
+    zermio::BitField<19, 1, zermio::Permissions::Read, uint32_t> gpio4_edge_high;
     /* GPIO4_EDGE_LOW */
-    zermio::BitField<18, 1, zermio::Permissions::Read> gpio4_edge_low;
+    zermio::BitField<18, 1, zermio::Permissions::Read, uint32_t> gpio4_edge_low;
     /* GPIO4_LEVEL_HIGH */
-    zermio::BitField<17, 1, zermio::Permissions::Read> gpio4_level_high;
+    zermio::BitField<17, 1, zermio::Permissions::Read, uint32_t> gpio4_level_high;
     /* GPIO4_LEVEL_LOW */
-    zermio::BitField<16, 1, zermio::Permissions::Read> gpio4_level_low;
+    zermio::BitField<16, 1, zermio::Permissions::Read, uint32_t> gpio4_level_low;
     /* GPIO3_EDGE_HIGH */
-    zermio::BitField<15, 1, zermio::Permissions::Read> gpio3_edge_high;
+    zermio::BitField<15, 1, zermio::Permissions::Read, uint32_t> gpio3_edge_high;
     /* GPIO3_EDGE_LOW */
-    zermio::BitField<14, 1, zermio::Permissions::Read> gpio3_edge_low;
+    zermio::BitField<14, 1, zermio::Permissions::Read, uint32_t> gpio3_edge_low;
     /* GPIO3_LEVEL_HIGH */
-    zermio::BitField<13, 1, zermio::Permissions::Read> gpio3_level_high;
+    zermio::BitField<13, 1, zermio::Permissions::Read, uint32_t> gpio3_level_high;
     /* GPIO3_LEVEL_LOW */
-    zermio::BitField<12, 1, zermio::Permissions::Read> gpio3_level_low;
+    zermio::BitField<12, 1, zermio::Permissions::Read, uint32_t> gpio3_level_low;
     /* GPIO2_EDGE_HIGH */
-    zermio::BitField<11, 1, zermio::Permissions::Read> gpio2_edge_high;
+    zermio::BitField<11, 1, zermio::Permissions::Read, uint32_t> gpio2_edge_high;
     /* GPIO2_EDGE_LOW */
-    zermio::BitField<10, 1, zermio::Permissions::Read> gpio2_edge_low;
+    zermio::BitField<10, 1, zermio::Permissions::Read, uint32_t> gpio2_edge_low;
     /* GPIO2_LEVEL_HIGH */
-    zermio::BitField<9, 1, zermio::Permissions::Read> gpio2_level_high;
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> gpio2_level_high;
     /* GPIO2_LEVEL_LOW */
-    zermio::BitField<8, 1, zermio::Permissions::Read> gpio2_level_low;
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> gpio2_level_low;
     /* GPIO1_EDGE_HIGH */
-    zermio::BitField<7, 1, zermio::Permissions::Read> gpio1_edge_high;
+    zermio::BitField<7, 1, zermio::Permissions::Read, uint32_t> gpio1_edge_high;
     /* GPIO1_EDGE_LOW */
-    zermio::BitField<6, 1, zermio::Permissions::Read> gpio1_edge_low;
+    zermio::BitField<6, 1, zermio::Permissions::Read, uint32_t> gpio1_edge_low;
     /* GPIO1_LEVEL_HIGH */
-    zermio::BitField<5, 1, zermio::Permissions::Read> gpio1_level_high;
+    zermio::BitField<5, 1, zermio::Permissions::Read, uint32_t> gpio1_level_high;
     /* GPIO1_LEVEL_LOW */
-    zermio::BitField<4, 1, zermio::Permissions::Read> gpio1_level_low;
+    zermio::BitField<4, 1, zermio::Permissions::Read, uint32_t> gpio1_level_low;
     /* GPIO0_EDGE_HIGH */
-    zermio::BitField<3, 1, zermio::Permissions::Read> gpio0_edge_high;
+    zermio::BitField<3, 1, zermio::Permissions::Read, uint32_t> gpio0_edge_high;
     /* GPIO0_EDGE_LOW */
-    zermio::BitField<2, 1, zermio::Permissions::Read> gpio0_edge_low;
+    zermio::BitField<2, 1, zermio::Permissions::Read, uint32_t> gpio0_edge_low;
     /* GPIO0_LEVEL_HIGH */
-    zermio::BitField<1, 1, zermio::Permissions::Read> gpio0_level_high;
+    zermio::BitField<1, 1, zermio::Permissions::Read, uint32_t> gpio0_level_high;
     /* GPIO0_LEVEL_LOW */
-    zermio::BitField<0, 1, zermio::Permissions::Read> gpio0_level_low;
-    constexpr DormantWakeIntsReg (uintptr_t addr): reg_{.addr = addr}
+    zermio::BitField<0, 1, zermio::Permissions::Read, uint32_t> gpio0_level_low;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr DormantWakeIntsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
 
     inline void commit() { reg_.commit(); }
 
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
     inline DormantWakeIntsReg& fetch() {
         reg_.fetch();
         return *this;
@@ -892,68 +943,287 @@ union DormantWakeIntsReg {
 
 
 
+/* GPIO status */
+union GpioStatusReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* interrupt to processors, after override is applied */
+    zermio::BitField<26, 1, zermio::Permissions::Read, uint32_t> irqtoproc;
+    /* interrupt from pad before override is applied */
+    zermio::BitField<24, 1, zermio::Permissions::Read, uint32_t> irqfrompad;
+    /* input signal to peripheral, after override is applied */
+    zermio::BitField<19, 1, zermio::Permissions::Read, uint32_t> intoperi;
+    /* input signal from pad, before override is applied */
+    zermio::BitField<17, 1, zermio::Permissions::Read, uint32_t> infrompad;
+    /* output enable to pad after register override is applied */
+    zermio::BitField<13, 1, zermio::Permissions::Read, uint32_t> oetopad;
+    /* output enable from selected peripheral, before register override is applied */
+    zermio::BitField<12, 1, zermio::Permissions::Read, uint32_t> oefromperi;
+    /* output signal to pad after register override is applied */
+    zermio::BitField<9, 1, zermio::Permissions::Read, uint32_t> outtopad;
+    /* output signal from selected peripheral, before register override is applied */
+    zermio::BitField<8, 1, zermio::Permissions::Read, uint32_t> outfromperi;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr GpioStatusReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline GpioStatusReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Enumerated values of the field IRQOVER. */
+enum class GpioCtrlIrqover: uint32_t {
+    /* don't invert the interrupt */
+    Normal = 0x0,
+    /* invert the interrupt */
+    Invert = 0x1,
+    /* drive interrupt low */
+    Low = 0x2,
+    /* drive interrupt high */
+    High = 0x3,
+};
+
+/* Enumerated values of the field INOVER. */
+enum class GpioCtrlInover: uint32_t {
+    /* don't invert the peri input */
+    Normal = 0x0,
+    /* invert the peri input */
+    Invert = 0x1,
+    /* drive peri input low */
+    Low = 0x2,
+    /* drive peri input high */
+    High = 0x3,
+};
+
+/* Enumerated values of the field OEOVER. */
+enum class GpioCtrlOeover: uint32_t {
+    /* drive output enable from peripheral signal selected by funcsel */
+    Normal = 0x0,
+    /* drive output enable from inverse of peripheral signal selected by funcsel */
+    Invert = 0x1,
+    /* disable output */
+    Disable = 0x2,
+    /* enable output */
+    Enable = 0x3,
+};
+
+/* Enumerated values of the field OUTOVER. */
+enum class GpioCtrlOutover: uint32_t {
+    /* drive output from peripheral signal selected by funcsel */
+    Normal = 0x0,
+    /* drive output from inverse of peripheral signal selected by funcsel */
+    Invert = 0x1,
+    /* drive output low */
+    Low = 0x2,
+    /* drive output high */
+    High = 0x3,
+};
+
+/* Enumerated values of the field FUNCSEL. */
+enum class GpioCtrlFuncsel: uint32_t {
+    /* Connect to JTAG peripheral */
+    Jtag = 0x0,
+    /* Connect to matching SPI peripheral */
+    Spi = 0x1,
+    /* Connect to matching UART peripheral */
+    Uart = 0x2,
+    /* Connect to matching I2C peripheral */
+    I2c = 0x3,
+    /* Connect to matching PWM peripheral */
+    Pwm = 0x4,
+    /* Use as a GPIO pin (connect to SIO peripheral) */
+    Sio = 0x5,
+    /* Connect to PIO0 peripheral */
+    Pio0 = 0x6,
+    /* Connect to PIO1 peripheral */
+    Pio1 = 0x7,
+    /* Connect to Clock peripheral */
+    Clock = 0x8,
+    /* Connect to USB peripheral */
+    Usb = 0x9,
+    /* Connect to nothing */
+    Null = 0x1f,
+};
+
+/* GPIO control including function select and overrides. */
+union GpioCtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* IRQOVER */
+    zermio::BitField<28, 2, zermio::Permissions::ReadWrite, uint32_t, GpioCtrlIrqover> irqover;
+    /* INOVER */
+    zermio::BitField<16, 2, zermio::Permissions::ReadWrite, uint32_t, GpioCtrlInover> inover;
+    /* OEOVER */
+    zermio::BitField<12, 2, zermio::Permissions::ReadWrite, uint32_t, GpioCtrlOeover> oeover;
+    /* OUTOVER */
+    zermio::BitField<8, 2, zermio::Permissions::ReadWrite, uint32_t, GpioCtrlOutover> outover;
+    /* 0-31 -> selects pin function according to the GPIO table. Not all options are valid for all GPIO pins. */
+    zermio::BitField<0, 5, zermio::Permissions::ReadWrite, uint32_t, GpioCtrlFuncsel> funcsel;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x1f;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr GpioCtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline GpioCtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt Force */
+union IntfReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* FORCE */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> force;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntfReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntfReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt status after masking and forcing */
+union IntsReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* STATUS */
+    zermio::BitField<0, 32, zermio::Permissions::Read, uint32_t> status;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr IntsReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline IntsReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Interrupt Enable for the processor 0 */
+union InteReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* ENABLE */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> enable;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr InteReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline InteReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Cluster GPIO%s, containing GPIO*_STATUS, GPIO*_CTRL */
+struct GpioCluster {
+    GpioStatusReg gpio_status;
+    GpioCtrlReg gpio_ctrl;
+    
+    constexpr GpioCluster (uintptr_t addr):
+        gpio_status(addr + 0x0), 
+        gpio_ctrl(addr + 0x4){}
+};
+
+
+/* Interrupt force and status of the bank %s */
+struct IrqCluster {
+    IntfReg intf;
+    IntsReg ints;
+    
+    constexpr IrqCluster (uintptr_t addr):
+        intf(addr + 0x0), 
+        ints(addr + 0x4){}
+};
+
+
+/* Interrupt control for the processor 0 */
+struct Proc0Cluster {
+    InteReg inte;
+    std::array<IrqCluster, 2> irq;
+    
+    constexpr Proc0Cluster (uintptr_t addr):
+        inte(addr + 0x0), 
+        irq(zermio::make_array<IrqCluster, 2>(addr + 0x10, 0x8)){}
+};
+
+
 /* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
 struct IoBank {
-    GpioStatusReg gpio0_status;
-    GpioStatusReg gpio1_status;
-    GpioStatusReg gpio2_status;
-    GpioStatusReg gpio3_status;
-    GpioStatusReg gpio4_status;
-    GpioStatusReg gpio5_status;
-    GpioStatusReg gpio6_status;
-    GpioStatusReg gpio7_status;
-    GpioStatusReg gpio8_status;
-    GpioStatusReg gpio9_status;
-    GpioStatusReg gpio10_status;
-    GpioStatusReg gpio11_status;
-    GpioStatusReg gpio12_status;
-    GpioStatusReg gpio13_status;
-    GpioStatusReg gpio14_status;
-    GpioStatusReg gpio15_status;
-    GpioStatusReg gpio16_status;
-    GpioStatusReg gpio17_status;
-    GpioStatusReg gpio18_status;
-    GpioStatusReg gpio19_status;
-    GpioStatusReg gpio20_status;
-    GpioStatusReg gpio21_status;
-    GpioStatusReg gpio22_status;
-    GpioStatusReg gpio23_status;
-    GpioStatusReg gpio24_status;
-    GpioStatusReg gpio25_status;
-    GpioStatusReg gpio26_status;
-    GpioStatusReg gpio27_status;
-    GpioStatusReg gpio28_status;
-    GpioStatusReg gpio29_status;
-    GpioCtrlReg gpio0_ctrl;
-    GpioCtrlReg gpio1_ctrl;
-    GpioCtrlReg gpio2_ctrl;
-    GpioCtrlReg gpio3_ctrl;
-    GpioCtrlReg gpio4_ctrl;
-    GpioCtrlReg gpio5_ctrl;
-    GpioCtrlReg gpio6_ctrl;
-    GpioCtrlReg gpio7_ctrl;
-    GpioCtrlReg gpio8_ctrl;
-    GpioCtrlReg gpio9_ctrl;
-    GpioCtrlReg gpio10_ctrl;
-    GpioCtrlReg gpio11_ctrl;
-    GpioCtrlReg gpio12_ctrl;
-    GpioCtrlReg gpio13_ctrl;
-    GpioCtrlReg gpio14_ctrl;
-    GpioCtrlReg gpio15_ctrl;
-    GpioCtrlReg gpio16_ctrl;
-    GpioCtrlReg gpio17_ctrl;
-    GpioCtrlReg gpio18_ctrl;
-    GpioCtrlReg gpio19_ctrl;
-    GpioCtrlReg gpio20_ctrl;
-    GpioCtrlReg gpio21_ctrl;
-    GpioCtrlReg gpio22_ctrl;
-    GpioCtrlReg gpio23_ctrl;
-    GpioCtrlReg gpio24_ctrl;
-    GpioCtrlReg gpio25_ctrl;
-    GpioCtrlReg gpio26_ctrl;
-    GpioCtrlReg gpio27_ctrl;
-    GpioCtrlReg gpio28_ctrl;
-    GpioCtrlReg gpio29_ctrl;
     IntrReg intr0;
     IntrReg intr1;
     IntrReg intr2;
@@ -994,68 +1264,11 @@ struct IoBank {
     DormantWakeIntsReg dormant_wake_ints1;
     DormantWakeIntsReg dormant_wake_ints2;
     DormantWakeIntsReg dormant_wake_ints3;
+    std::array<GpioCluster, 30> gpio;
+    Proc0Cluster proc0;
+    Proc0Cluster proc1;
     
     constexpr IoBank (platform::IoBank addr):
-        gpio0_status(addr + 0x0), 
-        gpio1_status(addr + 0x8), 
-        gpio2_status(addr + 0x10), 
-        gpio3_status(addr + 0x18), 
-        gpio4_status(addr + 0x20), 
-        gpio5_status(addr + 0x28), 
-        gpio6_status(addr + 0x30), 
-        gpio7_status(addr + 0x38), 
-        gpio8_status(addr + 0x40), 
-        gpio9_status(addr + 0x48), 
-        gpio10_status(addr + 0x50), 
-        gpio11_status(addr + 0x58), 
-        gpio12_status(addr + 0x60), 
-        gpio13_status(addr + 0x68), 
-        gpio14_status(addr + 0x70), 
-        gpio15_status(addr + 0x78), 
-        gpio16_status(addr + 0x80), 
-        gpio17_status(addr + 0x88), 
-        gpio18_status(addr + 0x90), 
-        gpio19_status(addr + 0x98), 
-        gpio20_status(addr + 0xa0), 
-        gpio21_status(addr + 0xa8), 
-        gpio22_status(addr + 0xb0), 
-        gpio23_status(addr + 0xb8), 
-        gpio24_status(addr + 0xc0), 
-        gpio25_status(addr + 0xc8), 
-        gpio26_status(addr + 0xd0), 
-        gpio27_status(addr + 0xd8), 
-        gpio28_status(addr + 0xe0), 
-        gpio29_status(addr + 0xe8), 
-        gpio0_ctrl(addr + 0x4), 
-        gpio1_ctrl(addr + 0xc), 
-        gpio2_ctrl(addr + 0x14), 
-        gpio3_ctrl(addr + 0x1c), 
-        gpio4_ctrl(addr + 0x24), 
-        gpio5_ctrl(addr + 0x2c), 
-        gpio6_ctrl(addr + 0x34), 
-        gpio7_ctrl(addr + 0x3c), 
-        gpio8_ctrl(addr + 0x44), 
-        gpio9_ctrl(addr + 0x4c), 
-        gpio10_ctrl(addr + 0x54), 
-        gpio11_ctrl(addr + 0x5c), 
-        gpio12_ctrl(addr + 0x64), 
-        gpio13_ctrl(addr + 0x6c), 
-        gpio14_ctrl(addr + 0x74), 
-        gpio15_ctrl(addr + 0x7c), 
-        gpio16_ctrl(addr + 0x84), 
-        gpio17_ctrl(addr + 0x8c), 
-        gpio18_ctrl(addr + 0x94), 
-        gpio19_ctrl(addr + 0x9c), 
-        gpio20_ctrl(addr + 0xa4), 
-        gpio21_ctrl(addr + 0xac), 
-        gpio22_ctrl(addr + 0xb4), 
-        gpio23_ctrl(addr + 0xbc), 
-        gpio24_ctrl(addr + 0xc4), 
-        gpio25_ctrl(addr + 0xcc), 
-        gpio26_ctrl(addr + 0xd4), 
-        gpio27_ctrl(addr + 0xdc), 
-        gpio28_ctrl(addr + 0xe4), 
-        gpio29_ctrl(addr + 0xec), 
         intr0(addr + 0xf0), 
         intr1(addr + 0xf4), 
         intr2(addr + 0xf8), 
@@ -1095,7 +1308,10 @@ struct IoBank {
         dormant_wake_ints0(addr + 0x180), 
         dormant_wake_ints1(addr + 0x184), 
         dormant_wake_ints2(addr + 0x188), 
-        dormant_wake_ints3(addr + 0x18c){}
+        dormant_wake_ints3(addr + 0x18c), 
+        gpio(zermio::make_array<GpioCluster, 30>(addr + 0x0, 0x8)), 
+        proc0(addr + 0x100), 
+        proc1(addr + 0x140){}
 };
 
 
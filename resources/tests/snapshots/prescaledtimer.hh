@@ -0,0 +1,225 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace prescaledtimer {
+
+/* mtime low */
+union MtimelReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* mtime low */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimelReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline MtimelReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* mtime high */
+union MtimehReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* mtime high */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimehReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline MtimehReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* mtime cmp low */
+union MtimecmplReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* mtime cmp low */
+    zermio::BitField<0, 32, zermio::Permissions::ReadWrite, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimecmplReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline MtimecmplReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* mtime cmp high, applied on the write of mtime cmp low */
+union MtimecmphReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* mtime cmp high */
+    zermio::BitField<0, 32, zermio::Permissions::Write, uint32_t> value;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr MtimecmphReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline MtimecmphReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Timer control */
+union CtrlReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Enable the timer */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> enable;
+    /* Stop the timer when it expires */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> oneshot;
+    /* Reset the counter */
+    zermio::BitField<2, 1, zermio::Permissions::Write, uint32_t> reset_field;
+    /* Clock cycles per tick */
+    zermio::BitField<8, 8, zermio::Permissions::ReadWrite, uint32_t> prescale;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CtrlReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* Timer control applied on the next expiration */
+union CtrlShadowReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* Enable the timer */
+    zermio::BitField<0, 1, zermio::Permissions::ReadWrite, uint32_t> enable;
+    /* Stop the timer when it expires */
+    zermio::BitField<1, 1, zermio::Permissions::ReadWrite, uint32_t> oneshot;
+    /* Reset the counter */
+    zermio::BitField<2, 1, zermio::Permissions::Write, uint32_t> reset_field;
+    /* Clock cycles per tick */
+    zermio::BitField<8, 8, zermio::Permissions::ReadWrite, uint32_t> prescale;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr CtrlShadowReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline CtrlShadowReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Prescaledtimer {
+    MtimelReg mtimel;
+    MtimehReg mtimeh;
+    MtimecmplReg mtimecmpl;
+    MtimecmphReg mtimecmph;
+    CtrlReg ctrl;
+    CtrlShadowReg ctrl_shadow;
+    
+    constexpr Prescaledtimer (platform::Prescaledtimer addr):
+        mtimel(addr + 0x0), 
+        mtimeh(addr + 0x4), 
+        mtimecmpl(addr + 0x8), 
+        mtimecmph(addr + 0xc), 
+        ctrl(addr + 0x10), 
+        ctrl_shadow(addr + 0x14){}
+};
+
+
+} // namespace prescaledtimer
+} // namespace mmio
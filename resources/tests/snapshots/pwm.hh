@@ -0,0 +1,53 @@
+
+/*
+ * File autogenerated by the test.
+ */
+
+#pragma once
+/* The `platform.hh` should be created and include the specific platform header which will contain the device addresses.*/
+#include  "platform.hh" 
+#include  "mmio.hh" 
+namespace mmio {
+namespace pwm {
+
+/* Duty cycle in clock cycles */
+union DutyReg { 
+    private:
+      zermio::Register<uint32_t> reg_;
+    public:
+    /* CYCLES */
+    zermio::BitField<0, 16, zermio::Permissions::ReadWrite, uint32_t> cycles;
+    /* Value of the register after a reset. */
+    static constexpr uint32_t reset_value = 0x0;
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint32_t reset_mask = 0xffffffff;
+
+    constexpr DutyReg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
+    {}
+
+    inline void commit() { reg_.commit(); }
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+
+    inline DutyReg& fetch() {
+        reg_.fetch();
+        return *this;
+    }
+};
+
+
+
+/* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
+struct Pwm {
+    DutyReg duty;
+    
+    constexpr Pwm (platform::Pwm addr):
+        duty(addr + 0x0){}
+};
+
+
+} // namespace pwm
+} // namespace mmio
@@ -5,6 +5,7 @@
 
 #pragma once
 
+#include  <cstddef>
 #include  <cstdint>
 namespace platform  {
 /* Addresses for the devices of the type I2c.*/
@@ -13,9 +14,33 @@ namespace platform  {
 /* Addresses for the devices of the type Timer.*/
     enum Timer: uintptr_t{Timer0 = 0x80040000,Timer1 = 0x80400100,};
 
+/* Addresses for the devices of the type Prescaledtimer.*/
+    enum Prescaledtimer: uintptr_t{Timer2 = 0x80400200,};
+
 /* Addresses for the devices of the type IoBank.*/
     enum IoBank: uintptr_t{IoBank0 = 0x40014000,};
 
-enum Interrupt: uintptr_t{I2c0EventIrq = 2,I2c0ErrorIrq = 3,I2c1EventIrq = 3,Timer0FinishIrq = 10,Timer1FinishIrq = 10,IoBank0IoIrqBank0 = 13,};
+/* Addresses for the devices of the type Pwm.*/
+    enum Pwm: uintptr_t{Pwm0 = 0x80500000,Pwm1 = 0x80500100,};
+
+enum Interrupt: uintptr_t{I2c0EventIrq = 2,I2c0ErrorIrq = 3,I2c1EventIrq = 3,Timer0FinishIrq = 10,Timer1FinishIrq = 10,Timer2FinishIrq = 11,IoBank0IoIrqBank0 = 13,Pwm0Irq = 20,Pwm1Irq = 21,};
+
+/* The address range of a memory region, the `size` is in bytes.*/
+struct MemoryRegion {
+    uintptr_t base;
+    size_t size;
+    uint8_t width;
+    bool readable;
+    bool writable;
+
+    constexpr uintptr_t end() const { return base + size; }
+    constexpr size_t entries() const { return size / (width / 8); }
+    constexpr bool contains(uintptr_t addr) const { return addr >= base && addr < end(); }
+};
+
+/* ROM, 4096 words of 32 bits.*/
+inline constexpr MemoryRegion Rom{0x8000, 0x4000, 32, true, false};
+/* SRAM, 32768 words of 32 bits.*/
+inline constexpr MemoryRegion Sram{0x10000000, 0x20000, 32, true, true};
 } // namespace platform
 
@@ -1,4 +1,5 @@
 use core::cmp::PartialEq;
+use core::marker::PhantomData;
 use core::ops::{
     Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr, Sub,
 };
@@ -7,6 +8,11 @@ use core::ops::{
 pub mod access {
     pub trait Readable {}
     pub trait Writable {}
+    // Only the first write after a reset has effect on the hardware.
+    pub trait Once {}
+
+    // Every write has effect on the hardware, so the register can be committed without checks.
+    pub trait Rewritable {}
 
     pub struct Read {}
     impl Readable for Read {}
@@ -14,11 +20,106 @@ pub mod access {
     pub struct ReadWrite {}
     impl Readable for ReadWrite {}
     impl Writable for ReadWrite {}
+    impl Rewritable for ReadWrite {}
 
     pub struct Write {}
     impl Writable for Write {}
+    impl Rewritable for Write {}
+
+    pub struct WriteOnce {}
+    impl Writable for WriteOnce {}
+    impl Once for WriteOnce {}
+
+    pub struct ReadWriteOnce {}
+    impl Readable for ReadWriteOnce {}
+    impl Writable for ReadWriteOnce {}
+    impl Once for ReadWriteOnce {}
+}
+
+// Define how the hardware modifies a field when it's written.
+pub mod effect {
+    // Whether writing ones or zeros to the field bits triggers its side effect.
+    pub trait Trigger {
+        const ONES: bool;
+    }
+    pub trait ClearFlag: Trigger {}
+    pub trait SetFlag: Trigger {}
+    pub trait ToggleFlag: Trigger {}
+
+    // The written value is stored in the field.
+    pub struct Modify {}
+
+    pub struct OneToClear {}
+    impl Trigger for OneToClear {
+        const ONES: bool = true;
+    }
+    impl ClearFlag for OneToClear {}
+
+    pub struct OneToSet {}
+    impl Trigger for OneToSet {
+        const ONES: bool = true;
+    }
+    impl SetFlag for OneToSet {}
+
+    pub struct OneToToggle {}
+    impl Trigger for OneToToggle {
+        const ONES: bool = true;
+    }
+    impl ToggleFlag for OneToToggle {}
+
+    pub struct ZeroToClear {}
+    impl Trigger for ZeroToClear {
+        const ONES: bool = false;
+    }
+    impl ClearFlag for ZeroToClear {}
+
+    pub struct ZeroToSet {}
+    impl Trigger for ZeroToSet {
+        const ONES: bool = false;
+    }
+    impl SetFlag for ZeroToSet {}
+
+    pub struct ZeroToToggle {}
+    impl Trigger for ZeroToToggle {
+        const ONES: bool = false;
+    }
+    impl ToggleFlag for ZeroToToggle {}
+
+    // Any write clears the field.
+    pub struct Clear {}
+    impl Trigger for Clear {
+        const ONES: bool = true;
+    }
+    impl ClearFlag for Clear {}
+
+    // Any write sets the field.
+    pub struct Set {}
+    impl Trigger for Set {
+        const ONES: bool = true;
+    }
+    impl SetFlag for Set {}
 }
 
+// Define whether loading the register modifies its fields, i.e clear on read.
+pub mod load {
+    // The register can be loaded implicitly by its fields.
+    pub trait Fetch {}
+
+    pub struct Plain {}
+    impl Fetch for Plain {}
+
+    // The load has side effects, it's only run explicitly by the register.
+    pub struct SideEffects {}
+}
+
+// Error returned when trying to commit a write-once register more than once.
+#[derive(Debug, PartialEq)]
+pub struct AlreadyCommitted;
+
+// Error returned when trying to write a register while its write enable is clear.
+#[derive(Debug, PartialEq)]
+pub struct Locked;
+
 // Super trait to constraint an integer type.
 pub trait UnsignedInteger:
     Add<Output = Self>
@@ -38,6 +139,18 @@ pub trait UnsignedInteger:
     fn from(x: usize) -> Self;
 }
 
+impl UnsignedInteger for u8 {
+    fn from(x: usize) -> Self {
+        x as u8
+    }
+}
+
+impl UnsignedInteger for u16 {
+    fn from(x: usize) -> Self {
+        x as u16
+    }
+}
+
 impl UnsignedInteger for u32 {
     fn from(x: usize) -> Self {
         x as u32
@@ -50,72 +163,193 @@ impl UnsignedInteger for u64 {
     }
 }
 
-// Define the BitField type with ReadWrite access as default
-pub struct BitField<'a, const OFFSET: usize, const BITS: usize, T, ACCESS = access::ReadWrite> {
-    reg: &'a mut Register<T>,
-    _data: core::marker::PhantomData<ACCESS>,
+// Where a field starts in its register: known at compile time by the fields, and only at runtime
+// by the elements of a field array selected by index.
+pub trait Offset: Copy {
+    fn get(self) -> usize;
 }
 
-// Impl block for any access permission.
-impl<'a, const OFFSET: usize, const BITS: usize, T, ACCESS> BitField<'a, OFFSET, BITS, T, ACCESS>
+#[derive(Clone, Copy)]
+pub struct Const<const N: usize>;
+
+impl<const N: usize> Offset for Const<N> {
+    fn get(self) -> usize {
+        N
+    }
+}
+
+impl Offset for usize {
+    fn get(self) -> usize {
+        self
+    }
+}
+
+// A field of a register, the API is provided by the `BitField` and `IndexedBitField` aliases. The
+// `ENUM` type carries the enumerated values of the field, if any, `LOAD` whether the field can
+// fetch its register and `SHADOWED` whether the register is shadowed.
+pub struct Field<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool> {
+    reg: &'a mut Register<T, SHADOWED>,
+    offset: O,
+    _data: core::marker::PhantomData<(ACCESS, ENUM, EFFECT, LOAD)>,
+}
+
+// Define the BitField type with ReadWrite access as default.
+pub type BitField<
+    'a,
+    const OFFSET: usize,
+    const BITS: usize,
+    T,
+    ACCESS = access::ReadWrite,
+    ENUM = (),
+    EFFECT = effect::Modify,
+    LOAD = load::Plain,
+    const SHADOWED: bool = false,
+> = Field<'a, Const<OFFSET>, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>;
+
+// A field of a field array selected at runtime, i.e `pin(n)`. It offers the same API of the
+// `BitField` type, except for the write side effects, with the offset stored in the instance.
+pub type IndexedBitField<
+    'a,
+    const BITS: usize,
+    T,
+    ACCESS = access::ReadWrite,
+    ENUM = (),
+    LOAD = load::Plain,
+    const SHADOWED: bool = false,
+> = Field<'a, usize, BITS, T, ACCESS, ENUM, effect::Modify, LOAD, SHADOWED>;
+
+impl<
+    'a,
+    const OFFSET: usize,
+    const BITS: usize,
+    T,
+    ACCESS,
+    ENUM,
+    EFFECT,
+    LOAD,
+    const SHADOWED: bool,
+> BitField<'a, OFFSET, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
 where
     T: UnsignedInteger,
 {
-    pub fn new(reg: &'a mut Register<T>) -> Self {
+    pub fn new(reg: &'a mut Register<T, SHADOWED>) -> Self {
         Self {
             reg,
-            _data: core::marker::PhantomData::<ACCESS>,
+            offset: Const,
+            _data: core::marker::PhantomData,
         }
     }
 
     pub fn mask() -> T {
-        if BITS == size_of::<T>() * 8usize {
-            return T::from(size_of::<T>() * 8usize);
+        Self::max() << T::from(OFFSET)
+    }
+}
+
+impl<'a, const BITS: usize, T, ACCESS, ENUM, LOAD, const SHADOWED: bool>
+    IndexedBitField<'a, BITS, T, ACCESS, ENUM, LOAD, SHADOWED>
+where
+    T: UnsignedInteger,
+{
+    pub fn new(reg: &'a mut Register<T, SHADOWED>, offset: usize) -> Self {
+        Self {
+            reg,
+            offset,
+            _data: core::marker::PhantomData,
         }
-        T::from(((0x01 << BITS) - 1) << OFFSET)
     }
 
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn mask(&self) -> T {
+        self.bits()
+    }
+}
+
+// Impl block for any access permission.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    T: UnsignedInteger,
+{
     pub fn max() -> T {
-        T::from((1 << BITS) - 1)
+        // Computed in `T` to not overflow `usize` with 64 bits registers on 32 bits targets.
+        !T::from(0) >> T::from(size_of::<T>() * 8usize - BITS)
+    }
+
+    fn shift(&self) -> T {
+        T::from(self.offset.get())
+    }
+
+    // The mask of the field bits in the register.
+    fn bits(&self) -> T {
+        Self::max() << self.shift()
     }
 }
 
 // Impl block for Write access permission.
-impl<'a, const OFFSET: usize, const BITS: usize, T, ACCESS> BitField<'a, OFFSET, BITS, T, ACCESS>
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
 where
+    O: Offset,
     ACCESS: access::Writable,
     T: UnsignedInteger,
 {
     pub fn write(&mut self, value: T) -> &mut Self {
         self.clear();
-        self.reg.cache |= (value << T::from(OFFSET)) & Self::mask();
+        self.reg.cache |= (value << self.shift()) & self.bits();
         self
     }
 
     pub fn clear(&mut self) -> &mut Self {
-        self.reg.cache &= !Self::mask();
+        self.reg.cache &= !self.bits();
         self
     }
 
     pub fn write_mask(&mut self, val: T) -> &mut Self {
-        self.reg.cache &= !(val << T::from(OFFSET) & Self::mask());
-        self.reg.cache |= (val << T::from(OFFSET)) & Self::mask();
+        self.reg.cache &= !(val << self.shift() & self.bits());
+        self.reg.cache |= (val << self.shift()) & self.bits();
         self
     }
+}
 
+// Impl block for Write access permission without the write-once restriction, the write-once fields
+// are committed with `try_commit`.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    ACCESS: access::Rewritable,
+    T: UnsignedInteger,
+{
     pub fn commit(&mut self) {
         self.reg.commit()
     }
 }
 
+// Impl block for WriteOnce and ReadWriteOnce access permissions.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    ACCESS: access::Once,
+    T: UnsignedInteger,
+{
+    pub fn try_commit(&mut self) -> Result<(), AlreadyCommitted> {
+        self.reg.try_commit()
+    }
+}
+
 // Impl block for single bit fields and Write access permission.
-impl<'a, const OFFSET: usize, T, ACCESS> BitField<'a, OFFSET, 1, T, ACCESS>
+impl<'a, O, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, 1, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
 where
+    O: Offset,
     ACCESS: access::Writable,
     T: UnsignedInteger,
 {
     pub fn set(&mut self) -> &mut Self {
-        self.reg.cache |= T::from(0x01usize << OFFSET);
+        self.reg.cache |= T::from(0x01) << self.shift();
         self
     }
 
@@ -125,27 +359,38 @@ where
     }
 
     pub fn toggle(&mut self) -> &mut Self {
-        self.reg.cache ^= T::from(0x01usize << OFFSET);
+        self.reg.cache ^= T::from(0x01) << self.shift();
         self
     }
 
     pub fn assign(&mut self, bit: bool) -> &mut Self {
-        self.reg.cache &= !T::from(0x01 << OFFSET);
-        self.reg.cache |= T::from((bit as usize) << OFFSET);
+        self.reg.cache &= !(T::from(0x01) << self.shift());
+        self.reg.cache |= T::from(bit as usize) << self.shift();
         self
     }
 }
 
 // Impl block for Read access permission.
-impl<'a, const OFFSET: usize, const BITS: usize, T, ACCESS> BitField<'a, OFFSET, BITS, T, ACCESS>
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
 where
+    O: Offset,
     ACCESS: access::Readable,
     T: UnsignedInteger,
 {
     pub fn get(&self) -> T {
-        (self.reg.cache & Self::mask()) >> T::from(OFFSET)
+        (self.reg.cache & self.bits()) >> self.shift()
     }
+}
 
+// Impl block for Read access permission of the registers without read side effects.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    ACCESS: access::Readable,
+    T: UnsignedInteger,
+    LOAD: load::Fetch,
+{
     pub fn fetch(&mut self) -> &mut Self {
         self.reg.fetch();
         self
@@ -153,22 +398,122 @@ where
 }
 
 // Impl block for single bit fields and Read access permission.
-impl<'a, const OFFSET: usize, T, ACCESS> BitField<'a, OFFSET, 1, T, ACCESS>
+impl<'a, O, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, 1, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
 where
+    O: Offset,
     ACCESS: access::Readable,
     T: UnsignedInteger,
 {
     pub fn is_set(&self) -> bool {
-        (self.reg.cache & Self::mask()) == Self::mask()
+        (self.reg.cache & self.bits()) == self.bits()
+    }
+}
+
+// Impl block for fields with enumerated values and Read access permission.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    ACCESS: access::Readable,
+    T: UnsignedInteger,
+    ENUM: TryFrom<T, Error = T>,
+{
+    /// Returns the enumerated value of the field, or the raw value if it is not a known variant.
+    pub fn variant(&self) -> Result<ENUM, T> {
+        ENUM::try_from(self.get())
     }
 }
 
-pub struct Register<T> {
+// Impl block for fields with enumerated values and Write access permission.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    ACCESS: access::Writable,
+    T: UnsignedInteger,
+    ENUM: Into<T>,
+{
+    pub fn write_variant(&mut self, variant: ENUM) -> &mut Self {
+        self.write(variant.into())
+    }
+}
+
+// Impl block for fields with write side effects and Write access permission.
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    ACCESS: access::Writable,
+    T: UnsignedInteger,
+    EFFECT: effect::Trigger,
+{
+    // Run a store instruction with the trigger value in this field bits and the neutral value in
+    // the bits of the other fields with side effects. The cache is left untouched.
+    fn trigger(&mut self) {
+        let mut value = self.reg.neutral();
+        if EFFECT::ONES {
+            value |= self.bits();
+        } else {
+            value &= !self.bits();
+        }
+        self.reg.store(value);
+    }
+}
+
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    ACCESS: access::Writable,
+    T: UnsignedInteger,
+    EFFECT: effect::ClearFlag,
+{
+    pub fn clear_flag(&mut self) {
+        self.trigger();
+    }
+}
+
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    ACCESS: access::Writable,
+    T: UnsignedInteger,
+    EFFECT: effect::SetFlag,
+{
+    pub fn set_flag(&mut self) {
+        self.trigger();
+    }
+}
+
+impl<'a, O, const BITS: usize, T, ACCESS, ENUM, EFFECT, LOAD, const SHADOWED: bool>
+    Field<'a, O, BITS, T, ACCESS, ENUM, EFFECT, LOAD, SHADOWED>
+where
+    O: Offset,
+    ACCESS: access::Writable,
+    T: UnsignedInteger,
+    EFFECT: effect::ToggleFlag,
+{
+    pub fn toggle_flag(&mut self) {
+        self.trigger();
+    }
+}
+
+// A shadowed register, with `SHADOWED` set, only takes a value after two consecutive writes of
+// it, a mismatch is reported by the hardware as an update error. Thus every store is run twice.
+pub struct Register<T, const SHADOWED: bool = false> {
     pub cache: T,
     ptr: *mut T,
+    committed: bool,
+    // The bits of the fields with write side effects and the value that doesn't trigger them.
+    neutral_mask: T,
+    neutral_value: T,
+    #[cfg(test)]
+    stores: usize,
 }
 
-impl<T> Register<T>
+impl<T, const SHADOWED: bool> Register<T, SHADOWED>
 where
     T: UnsignedInteger,
 {
@@ -176,11 +521,67 @@ where
         Self {
             ptr: addr as *mut T,
             cache: T::from(0usize),
+            committed: false,
+            neutral_mask: T::from(0usize),
+            neutral_value: T::from(0usize),
+            #[cfg(test)]
+            stores: 0,
+        }
+    }
+
+    pub fn with_side_effects(addr: usize, neutral_mask: T, neutral_value: T) -> Self {
+        Self {
+            neutral_mask,
+            neutral_value: neutral_value & neutral_mask,
+            ..Self::new(addr)
         }
     }
 
     pub fn commit(&mut self) {
-        unsafe { self.ptr.write_volatile(self.cache) };
+        self.store(self.cache);
+    }
+
+    fn store(&mut self, value: T) {
+        self.write_volatile(value);
+        if SHADOWED {
+            self.write_volatile(value);
+        }
+        self.committed = true;
+    }
+
+    fn write_volatile(&mut self, value: T) {
+        #[cfg(test)]
+        {
+            self.stores += 1;
+        }
+        unsafe { self.ptr.write_volatile(value) };
+    }
+
+    // The cache with the side effect bits replaced by their neutral value.
+    pub fn neutral(&self) -> T {
+        (self.cache & !self.neutral_mask) | self.neutral_value
+    }
+
+    // Replace the side effect bits in the cache by their neutral value, so it can be written back
+    // without, for instance, clearing pending write-one-to-clear flags.
+    pub fn neutralize(&mut self) -> &mut Self {
+        self.cache = self.neutral();
+        self
+    }
+
+    // Whether this instance has already run a store instruction to the MMIO.
+    pub fn is_committed(&self) -> bool {
+        self.committed
+    }
+
+    // Only run the store instruction if this instance never did it before, which detects a
+    // second write to write-once registers that the hardware would silently ignore.
+    pub fn try_commit(&mut self) -> Result<(), AlreadyCommitted> {
+        if self.committed {
+            return Err(AlreadyCommitted);
+        }
+        self.commit();
+        Ok(())
     }
 
     pub fn fetch(&mut self) -> &mut Self {
@@ -189,6 +590,110 @@ where
     }
 }
 
+// Error returned when accessing a memory region beyond its end.
+#[derive(Debug, PartialEq)]
+pub struct OutOfBounds;
+
+// A region of memory, like a SRAM, a ROM or the window of a device, accessed in words of type
+// `T`. Unlike the registers there is no cache, every access runs a load or store instruction after
+// checking the index against the size of the region.
+pub struct MemoryRegion<T, ACCESS = access::ReadWrite> {
+    base: usize,
+    size: usize,
+    _marker: PhantomData<(T, ACCESS)>,
+}
+
+impl<T, ACCESS> MemoryRegion<T, ACCESS> {
+    // The `size` is in bytes.
+    pub const fn new(base: usize, size: usize) -> Self {
+        Self {
+            base,
+            size,
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn base(&self) -> usize {
+        self.base
+    }
+
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    // The address right after the region.
+    pub const fn end(&self) -> usize {
+        self.base + self.size
+    }
+
+    // The number of words in the region.
+    pub const fn len(&self) -> usize {
+        self.size / core::mem::size_of::<T>()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub const fn contains(&self, addr: usize) -> bool {
+        addr >= self.base && addr < self.end()
+    }
+
+    fn ptr(&self, index: usize) -> Result<*mut T, OutOfBounds> {
+        if index >= self.len() {
+            return Err(OutOfBounds);
+        }
+        Ok((self.base as *mut T).wrapping_add(index))
+    }
+}
+
+// Impl block for Read access permission.
+impl<T, ACCESS> MemoryRegion<T, ACCESS>
+where
+    ACCESS: access::Readable,
+    T: Copy,
+{
+    pub fn read(&self, index: usize) -> Result<T, OutOfBounds> {
+        Ok(unsafe { self.ptr(index)?.read_volatile() })
+    }
+
+    // Read the words starting at `index` into `buf`, one volatile load per word.
+    pub fn read_slice(&self, index: usize, buf: &mut [T]) -> Result<(), OutOfBounds> {
+        match index.checked_add(buf.len()) {
+            Some(end) if end <= self.len() => {}
+            _ => return Err(OutOfBounds),
+        }
+        for (n, word) in buf.iter_mut().enumerate() {
+            *word = self.read(index + n)?;
+        }
+        Ok(())
+    }
+}
+
+// Impl block for Write access permission.
+impl<T, ACCESS> MemoryRegion<T, ACCESS>
+where
+    ACCESS: access::Writable,
+    T: Copy,
+{
+    pub fn write(&self, index: usize, value: T) -> Result<(), OutOfBounds> {
+        unsafe { self.ptr(index)?.write_volatile(value) };
+        Ok(())
+    }
+
+    // Write the words of `data` starting at `index`, one volatile store per word.
+    pub fn write_slice(&self, index: usize, data: &[T]) -> Result<(), OutOfBounds> {
+        match index.checked_add(data.len()) {
+            Some(end) if end <= self.len() => {}
+            _ => return Err(OutOfBounds),
+        }
+        for (n, word) in data.iter().enumerate() {
+            self.write(index + n, *word)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(all(test, target_arch = "x86_64"))]
 mod unittest {
     use super::*;
@@ -419,6 +924,159 @@ mod unittest {
         assert_eq!(mem, (u32::MAX & !(0xff << 5)) | (0x7a << 5));
     }
 
+    #[test]
+    fn test_full_width() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        let mut field = BitField::<0, 32, u32, access::ReadWrite>::new(&mut reg);
+        field.write(0xdead_beef).commit();
+        assert_eq!(mem, 0xdead_beef);
+        assert_eq!(field.fetch().get(), 0xdead_beef);
+    }
+
+    #[test]
+    fn test_u8() {
+        let mem = [0u8; 4];
+        let mut reg = Register::<u8>::new((&mem[1] as *const u8) as usize);
+        let mut field = BitField::<0, 8, u8, access::ReadWrite>::new(&mut reg);
+        field.write(0xa5).commit();
+        assert_eq!(mem, [0, 0xa5, 0, 0]);
+        let mut field = BitField::<7, 1, u8, access::ReadWrite>::new(&mut reg);
+        field.fetch().toggle().commit();
+        assert_eq!(mem, [0, 0x25, 0, 0]);
+    }
+
+    #[test]
+    fn test_u16() {
+        let mem = [0u16; 2];
+        let mut reg = Register::<u16>::new((&mem[0] as *const u16) as usize);
+        let mut field = BitField::<4, 12, u16, access::ReadWrite>::new(&mut reg);
+        field.write(0xfff).commit();
+        assert_eq!(mem, [0xfff0, 0]);
+        assert_eq!(field.fetch().get(), 0xfff);
+    }
+
+    #[test]
+    fn test_u64() {
+        let mem = 0u64;
+        let mut reg = Register::<u64>::new((&mem as *const u64) as usize);
+        let mut field = BitField::<63, 1, u64, access::ReadWrite>::new(&mut reg);
+        field.set().commit();
+        assert_eq!(mem, 1 << 63);
+        let mut field = BitField::<8, 48, u64, access::ReadWrite>::new(&mut reg);
+        field.write(u64::MAX).commit();
+        assert_eq!(mem, (1 << 63) | (((1 << 48) - 1) << 8));
+    }
+
+    #[test]
+    fn test_write_once() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        let mut field = BitField::<0, 8, u32, access::WriteOnce>::new(&mut reg);
+        assert_eq!(field.write(0xab).try_commit(), Ok(()));
+        assert_eq!(mem, 0xab);
+        assert_eq!(field.write(0xcd).try_commit(), Err(AlreadyCommitted));
+        assert_eq!(mem, 0xab);
+        assert!(reg.is_committed());
+    }
+
+    #[test]
+    fn test_read_write_once() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        let mut field = BitField::<8, 1, u32, access::ReadWriteOnce>::new(&mut reg);
+        field.set();
+        assert_eq!(field.try_commit(), Ok(()));
+        assert!(field.fetch().is_set());
+        assert_eq!(field.reset().try_commit(), Err(AlreadyCommitted));
+        assert_eq!(mem, 1 << 8);
+    }
+
+    #[test]
+    fn test_indexed_write_once() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        let mut field = IndexedBitField::<4, u32, access::WriteOnce>::new(&mut reg, 4);
+        assert_eq!(field.write(0x5).try_commit(), Ok(()));
+        assert_eq!(field.write(0xa).try_commit(), Err(AlreadyCommitted));
+        assert_eq!(mem, 0x50);
+    }
+
+    #[test]
+    fn test_clear_flag() {
+        let mem = 0b1111u32;
+        let mut reg = Register::<u32>::with_side_effects((&mem as *const u32) as usize, 0b11, 0);
+        reg.fetch();
+        let mut field =
+            BitField::<1, 1, u32, access::ReadWrite, (), effect::OneToClear>::new(&mut reg);
+        field.clear_flag();
+        // Only the targeted flag is written as one, the normal fields keep the cached value.
+        assert_eq!(mem, 0b1110);
+        assert_eq!(reg.cache, 0b1111);
+        reg.neutralize();
+        assert_eq!(reg.cache, 0b1100);
+    }
+
+    #[test]
+    fn test_zero_to_clear_flag() {
+        let mem = u32::MAX;
+        let mut reg = Register::<u32>::with_side_effects((&mem as *const u32) as usize, 0xff, 0xff);
+        let mut field =
+            BitField::<4, 4, u32, access::ReadWrite, (), effect::ZeroToClear>::new(&mut reg);
+        field.clear_flag();
+        assert_eq!(mem, 0x0f);
+    }
+
+    #[test]
+    fn test_set_toggle_flag() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::with_side_effects((&mem as *const u32) as usize, 0b11, 0);
+        let mut field = BitField::<0, 1, u32, access::Write, (), effect::OneToSet>::new(&mut reg);
+        field.set_flag();
+        assert_eq!(mem, 0b01);
+        let mut field =
+            BitField::<1, 1, u32, access::Write, (), effect::OneToToggle>::new(&mut reg);
+        field.toggle_flag();
+        assert_eq!(mem, 0b10);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Idle = 0,
+        Run = 2,
+    }
+
+    impl From<Mode> for u32 {
+        fn from(value: Mode) -> u32 {
+            value as u32
+        }
+    }
+
+    impl TryFrom<u32> for Mode {
+        type Error = u32;
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::Idle),
+                2 => Ok(Self::Run),
+                _ => Err(value),
+            }
+        }
+    }
+
+    #[test]
+    fn test_variant() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        let mut field = BitField::<4, 2, u32, access::ReadWrite, Mode>::new(&mut reg);
+        field.write_variant(Mode::Run).commit();
+        assert_eq!(mem, 2 << 4);
+        assert_eq!(field.fetch().variant(), Ok(Mode::Run));
+        field.write(3).commit();
+        assert_eq!(field.fetch().variant(), Err(3));
+        field.write_variant(Mode::Idle).commit();
+        assert_eq!(field.fetch().variant(), Ok(Mode::Idle));
+    }
+
     #[test]
     fn test_write_mask() {
         let mem = u32::MAX;
@@ -434,4 +1092,68 @@ mod unittest {
         field.fetch().write_mask(0x1 << 2).commit();
         assert_eq!(mem, (0x80 | (0x01 << 2)) << 5);
     }
+
+    #[test]
+    fn test_indexed_field() {
+        let mem = 0u32;
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        for pin in 0..8 {
+            IndexedBitField::<2, u32, access::ReadWrite>::new(&mut reg, pin * 2)
+                .write(pin as u32 % 4);
+        }
+        reg.commit();
+        assert_eq!(mem, 0b11_10_01_00_11_10_01_00);
+
+        let mut field = IndexedBitField::<1, u32, access::ReadWrite>::new(&mut reg, 31);
+        field.fetch().set().commit();
+        assert!(field.is_set());
+        assert_eq!(mem, 1 << 31 | 0b11_10_01_00_11_10_01_00);
+        assert_eq!(field.mask(), 1 << 31);
+    }
+
+    #[test]
+    fn test_memory_region() {
+        let mem = [0u16; 4];
+        let region = MemoryRegion::<u16, access::ReadWrite>::new(mem.as_ptr() as usize, 8);
+        assert_eq!(region.len(), 4);
+        assert!(region.contains(region.base() + 7));
+        assert!(!region.contains(region.end()));
+        region.write(3, 0xbeef).unwrap();
+        assert_eq!(mem[3], 0xbeef);
+        assert_eq!(region.read(3), Ok(0xbeef));
+        assert_eq!(region.write(4, 0), Err(OutOfBounds));
+        assert_eq!(region.read(4), Err(OutOfBounds));
+
+        region.write_slice(1, &[1, 2]).unwrap();
+        assert_eq!(mem, [0, 1, 2, 0xbeef]);
+        let mut buf = [0u16; 3];
+        region.read_slice(1, &mut buf).unwrap();
+        assert_eq!(buf, [1, 2, 0xbeef]);
+        assert_eq!(region.write_slice(3, &[1, 2]), Err(OutOfBounds));
+        assert_eq!(region.read_slice(2, &mut buf), Err(OutOfBounds));
+        assert_eq!(region.write_slice(usize::MAX, &[1]), Err(OutOfBounds));
+        assert_eq!(region.read_slice(usize::MAX, &mut buf), Err(OutOfBounds));
+    }
+
+    #[test]
+    fn test_shadowed() {
+        let mem = 0u32;
+        let mut reg = Register::<u32, true>::new((&mem as *const u32) as usize);
+        reg.cache = 0xa5;
+        reg.commit();
+        assert_eq!(mem, 0xa5);
+        assert_eq!(reg.stores, 2);
+        assert!(reg.is_committed());
+
+        let mut field =
+            BitField::<0, 1, u32, access::Write, (), effect::OneToClear, load::Plain, true>::new(
+                &mut reg,
+            );
+        field.clear_flag();
+        assert_eq!(reg.stores, 4);
+
+        let mut reg = Register::<u32>::new((&mem as *const u32) as usize);
+        reg.commit();
+        assert_eq!(reg.stores, 1);
+    }
 }
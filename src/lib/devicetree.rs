@@ -0,0 +1,1167 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A reader of devicetrees, either the `.dts` sources or the `.dtb` blobs, for the addresses
+//! and the interrupts of the instances of a platform.
+//!
+//! The sources are expected to be preprocessed, the directives and the macros of the C
+//! preprocessor are reported as errors, but its line markers are skipped. The `/include/`
+//! directive, labels, references to labels and paths, `/delete-node/`, `/delete-property/`,
+//! `/bits/` and the expressions in parentheses are supported. The labels of a blob are taken
+//! from its `__symbols__` node, when it has one.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub name: String,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Node {
+    // The name with the unit address, i.e `serial@10000000`.
+    pub name: String,
+    pub labels: Vec<String>,
+    pub properties: Vec<Property>,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn property(&self, name: &str) -> Option<&[u8]> {
+        self.properties
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.value.as_slice())
+    }
+
+    /// The value of a property as big endian 32 bits cells.
+    pub fn cells(&self, name: &str) -> Option<Vec<u32>> {
+        self.property(name).map(|value| {
+            value
+                .chunks_exact(4)
+                .map(|c| u32::from_be_bytes(c.try_into().unwrap()))
+                .collect()
+        })
+    }
+
+    pub fn cell(&self, name: &str) -> Option<u32> {
+        self.cells(name).and_then(|cells| cells.first().copied())
+    }
+
+    /// The value of a property as a list of null terminated strings.
+    pub fn strings(&self, name: &str) -> Vec<String> {
+        let Some(value) = self.property(name) else {
+            return vec![];
+        };
+        value
+            .strip_suffix(&[0])
+            .unwrap_or(value)
+            .split(|b| *b == 0)
+            .map(|s| String::from_utf8_lossy(s).into_owned())
+            .collect()
+    }
+
+    fn phandle(&self) -> Option<u32> {
+        self.cell("phandle").or_else(|| self.cell("linux,phandle"))
+    }
+
+    fn address_cells(&self) -> usize {
+        self.cell("#address-cells").unwrap_or(2) as usize
+    }
+
+    fn size_cells(&self) -> usize {
+        self.cell("#size-cells").unwrap_or(1) as usize
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Interrupt {
+    // From the `interrupt-names` of the node.
+    pub name: Option<String>,
+    pub id: u32,
+}
+
+/// A node with a `compatible` and a `reg`.
+#[derive(Debug, Clone)]
+pub struct Instance {
+    pub path: String,
+    // The first label of the node, or its name when it has none.
+    pub name: String,
+    pub compatible: Vec<String>,
+    // The address seen by the CPUs, none when it can not be translated by the `ranges` of a bus.
+    pub address: Option<u64>,
+    pub interrupts: Vec<Interrupt>,
+    // Whether the `status` is `okay`.
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Tree {
+    pub root: Node,
+}
+
+impl Tree {
+    /// Load a `.dtb`, recognized by its magic number, or a `.dts`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let bytes = std::fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let root = if bytes.starts_with(&DTB_MAGIC.to_be_bytes()) {
+            parse_dtb(&bytes).map_err(|e| format!("{}: {e}", path.display()))?
+        } else {
+            let mut parser = DtsParser { sources: vec![] };
+            parser.push_source(path)?;
+            parser.parse()?
+        };
+        Ok(Self { root })
+    }
+
+    pub fn instances(&self) -> Result<Vec<Instance>, String> {
+        let symbols: HashMap<String, String> = self
+            .root
+            .children
+            .iter()
+            .find(|n| n.name == "__symbols__")
+            .map(|symbols| {
+                symbols
+                    .properties
+                    .iter()
+                    .map(|p| (symbols.strings(&p.name).concat(), p.name.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut instances = Vec::new();
+        self.walk(&self.root, "", &mut vec![], &symbols, &mut instances)?;
+        Ok(instances)
+    }
+
+    fn walk<'a>(
+        &'a self,
+        node: &'a Node,
+        path: &str,
+        ancestors: &mut Vec<&'a Node>,
+        symbols: &HashMap<String, String>,
+        instances: &mut Vec<Instance>,
+    ) -> Result<(), String> {
+        let compatible = node.strings("compatible");
+        let device_type = node.strings("device_type");
+        let is_device = !ancestors.is_empty()
+            && !compatible.is_empty()
+            && node.property("reg").is_some()
+            && !device_type.iter().any(|t| t == "cpu" || t == "memory");
+        if is_device {
+            let name = node
+                .labels
+                .first()
+                .or_else(|| symbols.get(path))
+                .cloned()
+                .unwrap_or_else(|| node.name.clone());
+            instances.push(Instance {
+                path: path.to_string(),
+                name,
+                compatible,
+                address: address(ancestors, node, path)?,
+                interrupts: self.interrupts(ancestors, node, path)?,
+                enabled: matches!(
+                    node.strings("status").first().map(String::as_str),
+                    None | Some("okay" | "ok")
+                ),
+            });
+        }
+
+        ancestors.push(node);
+        for child in &node.children {
+            let path = format!("{path}/{}", child.name);
+            self.walk(child, &path, ancestors, symbols, instances)?;
+        }
+        ancestors.pop();
+        Ok(())
+    }
+
+    fn find_phandle(node: &Node, phandle: u32) -> Option<&Node> {
+        if node.phandle() == Some(phandle) {
+            return Some(node);
+        }
+        node.children
+            .iter()
+            .find_map(|child| Self::find_phandle(child, phandle))
+    }
+
+    fn controller(&self, phandle: u32, path: &str) -> Result<&Node, String> {
+        Self::find_phandle(&self.root, phandle).ok_or(format!(
+            "{path}: no interrupt controller with the phandle {phandle}"
+        ))
+    }
+
+    fn interrupts(
+        &self,
+        ancestors: &[&Node],
+        node: &Node,
+        path: &str,
+    ) -> Result<Vec<Interrupt>, String> {
+        let mut ids = Vec::new();
+        if let Some(cells) = node.cells("interrupts-extended") {
+            let mut rest = cells.as_slice();
+            while let Some((&phandle, tail)) = rest.split_first() {
+                let controller = self.controller(phandle, path)?;
+                let count = interrupt_cells(Some(controller));
+                if tail.len() < count || count == 0 {
+                    return Err(format!("{path}: malformed interrupts-extended"));
+                }
+                ids.push(decode_interrupt(Some(controller), &tail[..count]));
+                rest = &tail[count..];
+            }
+        } else if let Some(cells) = node.cells("interrupts") {
+            // The interrupt parent is inherited from the ancestors.
+            let controller = match std::iter::once(node)
+                .chain(ancestors.iter().rev().copied())
+                .find_map(|n| n.cell("interrupt-parent"))
+            {
+                Some(phandle) => Some(self.controller(phandle, path)?),
+                None => None,
+            };
+            let count = interrupt_cells(controller);
+            if count == 0 || cells.len() % count != 0 {
+                return Err(format!(
+                    "{path}: interrupts is not a multiple of #interrupt-cells {count}"
+                ));
+            }
+            ids = cells
+                .chunks(count)
+                .map(|spec| decode_interrupt(controller, spec))
+                .collect();
+        }
+
+        let names = node.strings("interrupt-names");
+        Ok(ids
+            .into_iter()
+            .enumerate()
+            .map(|(idx, id)| Interrupt {
+                name: names.get(idx).cloned(),
+                id,
+            })
+            .collect())
+    }
+}
+
+/// Combine the cells of an address or a size, of which only the last two are kept.
+fn to_u64(cells: &[u32]) -> u64 {
+    cells.iter().fold(0, |acc, cell| acc << 32 | *cell as u64)
+}
+
+/// The first address of the `reg` of a node translated by the `ranges` of its buses.
+fn address(ancestors: &[&Node], node: &Node, path: &str) -> Result<Option<u64>, String> {
+    let parent = ancestors.last().unwrap();
+    let (address_cells, size_cells) = (parent.address_cells(), parent.size_cells());
+    let reg = node.cells("reg").unwrap_or_default();
+    if address_cells == 0 || reg.len() < address_cells + size_cells {
+        return Err(format!(
+            "{path}: reg does not fit #address-cells {address_cells} and #size-cells {size_cells}"
+        ));
+    }
+
+    let mut address = to_u64(&reg[..address_cells]);
+    for idx in (1..ancestors.len()).rev() {
+        let (bus, parent) = (ancestors[idx], ancestors[idx - 1]);
+        let Some(ranges) = bus.cells("ranges") else {
+            return Ok(None);
+        };
+        // An empty `ranges` is an identity mapping.
+        if ranges.is_empty() {
+            continue;
+        }
+        let (child_cells, parent_cells) = (bus.address_cells(), parent.address_cells());
+        let entry = child_cells + parent_cells + bus.size_cells();
+        if entry == 0 {
+            return Err(format!("{path}: ranges of {} has no cells", bus.name));
+        }
+        if ranges.len() % entry != 0 {
+            return Err(format!(
+                "{path}: ranges of {} is not a multiple of {entry} cells",
+                bus.name
+            ));
+        }
+        let translated = ranges.chunks_exact(entry).find_map(|range| {
+            let child = to_u64(&range[..child_cells]);
+            let size = to_u64(&range[child_cells + parent_cells..]);
+            (address >= child && address - child < size)
+                .then(|| to_u64(&range[child_cells..child_cells + parent_cells]) + address - child)
+        });
+        match translated {
+            Some(translated) => address = translated,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(address))
+}
+
+fn interrupt_cells(controller: Option<&Node>) -> usize {
+    controller
+        .and_then(|c| c.cell("#interrupt-cells"))
+        .unwrap_or(1) as usize
+}
+
+fn decode_interrupt(controller: Option<&Node>, spec: &[u32]) -> u32 {
+    // The specifier of the ARM GIC is the type, the number and the flags, the numbers of the
+    // shared interrupts start at 32 and the ones of the private interrupts at 16.
+    let is_gic = controller.is_some_and(|c| {
+        c.strings("compatible")
+            .iter()
+            .any(|c| c.starts_with("arm,") && c.contains("gic"))
+    });
+    match spec {
+        [kind, number, ..] if is_gic => number + if *kind == 0 { 32 } else { 16 },
+        _ => spec[0],
+    }
+}
+
+// ----------------------------------------------------------------------------------------------
+// Blob.
+
+const DTB_MAGIC: u32 = 0xd00dfeed;
+const FDT_BEGIN_NODE: u32 = 1;
+const FDT_END_NODE: u32 = 2;
+const FDT_PROP: u32 = 3;
+const FDT_NOP: u32 = 4;
+const FDT_END: u32 = 9;
+
+fn parse_dtb(bytes: &[u8]) -> Result<Node, String> {
+    let word = |offset: usize| -> Result<usize, String> {
+        bytes
+            .get(offset..offset + 4)
+            .map(|b| u32::from_be_bytes(b.try_into().unwrap()) as usize)
+            .ok_or("truncated devicetree blob".to_string())
+    };
+    let string = |offset: usize| -> Result<String, String> {
+        let tail = bytes.get(offset..).ok_or("truncated devicetree blob")?;
+        let end = tail
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or("unterminated string in the devicetree blob")?;
+        Ok(String::from_utf8_lossy(&tail[..end]).into_owned())
+    };
+    let align = |offset: usize| (offset + 3) & !3;
+
+    let (off_struct, off_strings, version) = (word(8)?, word(12)?, word(20)?);
+    if version < 16 {
+        return Err(format!("devicetree blob version {version} not supported"));
+    }
+
+    let mut pos = off_struct;
+    let mut stack: Vec<Node> = Vec::new();
+    let mut root = None;
+    loop {
+        let token = word(pos)? as u32;
+        pos += 4;
+        match token {
+            FDT_BEGIN_NODE => {
+                let name = string(pos)?;
+                pos = align(pos + name.len() + 1);
+                stack.push(Node {
+                    name,
+                    ..Default::default()
+                });
+            }
+            FDT_END_NODE => {
+                let node = stack
+                    .pop()
+                    .ok_or("unbalanced nodes in the devicetree blob")?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(node),
+                    None => root = Some(node),
+                }
+            }
+            FDT_PROP => {
+                let (len, name) = (word(pos)?, word(pos + 4)?);
+                let value = bytes
+                    .get(pos + 8..pos + 8 + len)
+                    .ok_or("truncated devicetree blob")?
+                    .to_vec();
+                pos = align(pos + 8 + len);
+                let property = Property {
+                    name: string(off_strings + name)?,
+                    value,
+                };
+                stack
+                    .last_mut()
+                    .ok_or("property outside of a node in the devicetree blob")?
+                    .properties
+                    .push(property);
+            }
+            FDT_NOP => {}
+            FDT_END => break,
+            _ => return Err(format!("unknown token {token:#x} at {:#x}", pos - 4)),
+        }
+    }
+    root.ok_or("devicetree blob without a root node".to_string())
+}
+
+// ----------------------------------------------------------------------------------------------
+// Source.
+
+/// A reference to a node, i.e `&uart0` or `&{/soc/serial@10000000}`.
+#[derive(Debug, Clone)]
+enum Ref {
+    Label(String),
+    Path(String),
+}
+
+impl std::fmt::Display for Ref {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Ref::Label(label) => write!(f, "&{label}"),
+            Ref::Path(path) => write!(f, "&{{{path}}}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Chunk {
+    Bytes(Vec<u8>),
+    // A reference in the cells, replaced by the phandle of the node.
+    Phandle(Ref, String),
+    // A reference outside of the cells, replaced by the path of the node.
+    Path(Ref, String),
+}
+
+/// The statements of the body of a node.
+enum Op {
+    Property(String, Vec<Chunk>),
+    Child(Vec<String>, String, Vec<Op>),
+    DeleteNode(String),
+    DeleteProperty(String),
+}
+
+#[derive(Debug, Default)]
+struct DtsNode {
+    name: String,
+    labels: Vec<String>,
+    properties: Vec<(String, Vec<Chunk>)>,
+    children: Vec<DtsNode>,
+}
+
+impl DtsNode {
+    fn apply(&mut self, ops: Vec<Op>) {
+        for op in ops {
+            match op {
+                Op::Property(name, value) => {
+                    match self.properties.iter_mut().find(|(n, _)| *n == name) {
+                        Some(property) => property.1 = value,
+                        None => self.properties.push((name, value)),
+                    }
+                }
+                Op::Child(labels, name, ops) => {
+                    let idx = match self.children.iter().position(|c| c.name == name) {
+                        Some(idx) => idx,
+                        None => {
+                            self.children.push(DtsNode {
+                                name,
+                                ..Default::default()
+                            });
+                            self.children.len() - 1
+                        }
+                    };
+                    let child = &mut self.children[idx];
+                    for label in labels {
+                        if !child.labels.contains(&label) {
+                            child.labels.push(label);
+                        }
+                    }
+                    child.apply(ops);
+                }
+                Op::DeleteNode(name) => self.children.retain(|c| !matches_name(&c.name, &name)),
+                Op::DeleteProperty(name) => self.properties.retain(|(n, _)| *n != name),
+            }
+        }
+    }
+
+    /// The indexes of the children leading to the referenced node.
+    fn find(&self, reference: &Ref) -> Option<Vec<usize>> {
+        match reference {
+            Ref::Label(label) => self.find_label(label),
+            Ref::Path(path) => {
+                let mut node = self;
+                let mut indexes = Vec::new();
+                for name in path.split('/').filter(|n| !n.is_empty()) {
+                    let idx = node
+                        .children
+                        .iter()
+                        .position(|c| matches_name(&c.name, name))?;
+                    indexes.push(idx);
+                    node = &node.children[idx];
+                }
+                Some(indexes)
+            }
+        }
+    }
+
+    fn find_label(&self, label: &str) -> Option<Vec<usize>> {
+        if self.labels.iter().any(|l| l == label) {
+            return Some(vec![]);
+        }
+        self.children.iter().enumerate().find_map(|(idx, child)| {
+            child.find_label(label).map(|mut indexes| {
+                indexes.insert(0, idx);
+                indexes
+            })
+        })
+    }
+
+    fn get(&self, indexes: &[usize]) -> &DtsNode {
+        indexes.iter().fold(self, |node, idx| &node.children[*idx])
+    }
+
+    fn get_mut(&mut self, indexes: &[usize]) -> &mut DtsNode {
+        indexes
+            .iter()
+            .fold(self, |node, idx| &mut node.children[*idx])
+    }
+
+    fn path(&self, indexes: &[usize]) -> String {
+        let mut node = self;
+        let mut path = String::new();
+        for idx in indexes {
+            node = &node.children[*idx];
+            path += &format!("/{}", node.name);
+        }
+        if path.is_empty() { "/".into() } else { path }
+    }
+
+    fn phandle(&self) -> Option<u32> {
+        self.properties
+            .iter()
+            .find(|(n, _)| n == "phandle" || n == "linux,phandle")
+            .and_then(|(_, value)| match value.as_slice() {
+                [Chunk::Bytes(bytes)] if bytes.len() == 4 => {
+                    Some(u32::from_be_bytes(bytes.as_slice().try_into().unwrap()))
+                }
+                _ => None,
+            })
+    }
+
+    fn references<'a>(&'a self, refs: &mut Vec<(&'a Ref, &'a String)>) {
+        for (_, value) in &self.properties {
+            for chunk in value {
+                if let Chunk::Phandle(reference, loc) = chunk {
+                    refs.push((reference, loc));
+                }
+            }
+        }
+        self.children.iter().for_each(|c| c.references(refs));
+    }
+
+    fn max_phandle(&self) -> u32 {
+        self.children
+            .iter()
+            .map(DtsNode::max_phandle)
+            .fold(self.phandle().unwrap_or(0), u32::max)
+    }
+}
+
+/// Whether a node name matches a name with, or without, the unit address.
+fn matches_name(node: &str, name: &str) -> bool {
+    node == name || (!name.contains('@') && node.split('@').next() == Some(name))
+}
+
+/// Replace the references by phandles and paths, adding a `phandle` to the referenced nodes.
+fn resolve(mut root: DtsNode) -> Result<Node, String> {
+    let mut refs = Vec::new();
+    root.references(&mut refs);
+    let mut targets = Vec::new();
+    for (reference, loc) in refs {
+        match root.find(reference) {
+            Some(indexes) => targets.push(indexes),
+            None => return Err(format!("{loc}: no node referenced by {reference}")),
+        }
+    }
+    let mut next = root.max_phandle() + 1;
+    for indexes in targets {
+        let node = root.get_mut(&indexes);
+        if node.phandle().is_none() {
+            let value = vec![Chunk::Bytes(next.to_be_bytes().to_vec())];
+            node.properties.push(("phandle".into(), value));
+            next += 1;
+        }
+    }
+    convert(&root, &root)
+}
+
+fn convert(root: &DtsNode, node: &DtsNode) -> Result<Node, String> {
+    let mut properties = Vec::new();
+    for (name, chunks) in &node.properties {
+        let mut value = Vec::new();
+        for chunk in chunks {
+            match chunk {
+                Chunk::Bytes(bytes) => value.extend(bytes),
+                Chunk::Phandle(reference, loc) | Chunk::Path(reference, loc) => {
+                    let indexes = root
+                        .find(reference)
+                        .ok_or(format!("{loc}: no node referenced by {reference}"))?;
+                    match chunk {
+                        Chunk::Phandle(..) => {
+                            let phandle = root.get(&indexes).phandle().unwrap();
+                            value.extend(phandle.to_be_bytes());
+                        }
+                        _ => {
+                            value.extend(root.path(&indexes).into_bytes());
+                            value.push(0);
+                        }
+                    }
+                }
+            }
+        }
+        properties.push(Property {
+            name: name.clone(),
+            value,
+        });
+    }
+    Ok(Node {
+        name: node.name.clone(),
+        labels: node.labels.clone(),
+        properties,
+        children: node
+            .children
+            .iter()
+            .map(|c| convert(root, c))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+struct Source {
+    file: Rc<PathBuf>,
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    col: usize,
+}
+
+/// The binary operators of the expressions, by increasing precedence.
+const OPERATORS: &[&[&str]] = &[
+    &["||"],
+    &["&&"],
+    &["|"],
+    &["^"],
+    &["&"],
+    &["==", "!="],
+    &["<=", ">=", "<", ">"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+const DIRECTIVES: &[&str] = &[
+    "include", "define", "undef", "if", "ifdef", "ifndef", "elif", "else", "endif", "pragma",
+    "error",
+];
+
+struct DtsParser {
+    // The files being read, the last one included by the previous.
+    sources: Vec<Source>,
+}
+
+impl DtsParser {
+    fn push_source(&mut self, path: &Path) -> Result<(), String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if self.sources.is_empty() => return Err(format!("{}: {e}", path.display())),
+            Err(e) => return self.error(format!("{}: {e}", path.display())),
+        };
+        self.sources.push(Source {
+            file: Rc::new(path.to_path_buf()),
+            chars: text.chars().collect(),
+            pos: 0,
+            line: 1,
+            col: 1,
+        });
+        Ok(())
+    }
+
+    fn loc(&self) -> String {
+        let source = self.sources.last().unwrap();
+        format!("{}:{}:{}", source.file.display(), source.line, source.col)
+    }
+
+    fn error<T>(&self, msg: impl std::fmt::Display) -> Result<T, String> {
+        Err(format!("{}: {msg}", self.loc()))
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        loop {
+            let source = self.sources.last().unwrap();
+            if let Some(c) = source.chars.get(source.pos) {
+                return Some(*c);
+            }
+            if self.sources.len() == 1 {
+                return None;
+            }
+            self.sources.pop();
+        }
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        let source = self.sources.last().unwrap();
+        source.chars.get(source.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        let source = self.sources.last_mut().unwrap();
+        source.pos += 1;
+        if c == '\n' {
+            source.line += 1;
+            source.col = 1;
+        } else {
+            source.col += 1;
+        }
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_blank()?;
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(c) => self.error(format!("expected `{expected}`, found `{c}`")),
+            None => self.error(format!("expected `{expected}`, found the end of the file")),
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(c) = self.bump() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    /// Skip the blanks, the comments and the line markers of the C preprocessor.
+    fn skip_blank(&mut self) -> Result<(), String> {
+        loop {
+            match (self.peek(), self.peek_at(1)) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.bump();
+                }
+                (Some('/'), Some('/')) => self.skip_line(),
+                (Some('/'), Some('*')) => {
+                    self.bump();
+                    self.bump();
+                    while !(self.peek() == Some('*') && self.peek_at(1) == Some('/')) {
+                        if self.bump().is_none() {
+                            return self.error("unterminated comment");
+                        }
+                    }
+                    self.bump();
+                    self.bump();
+                }
+                (Some('#'), Some(' ' | '0'..='9')) => self.skip_line(),
+                (Some('#'), _) => {
+                    let source = self.sources.last().unwrap();
+                    let word: String = source.chars[source.pos + 1..]
+                        .iter()
+                        .take_while(|c| c.is_ascii_alphabetic())
+                        .collect();
+                    if DIRECTIVES.contains(&word.as_str()) {
+                        return self.error(format!(
+                            "the directive #{word} requires the C preprocessor, run `cpp` first"
+                        ));
+                    }
+                    return Ok(());
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn is_name_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || ",._+*#?@-".contains(c)
+    }
+
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(c) = self.peek().filter(|c| Self::is_name_char(*c)) {
+            word.push(c);
+            self.bump();
+        }
+        word
+    }
+
+    /// A keyword between slashes, i.e `/dts-v1/`.
+    fn keyword(&mut self) -> Result<String, String> {
+        self.expect('/')?;
+        let word = self.word();
+        if self.bump() != Some('/') {
+            return self.error(format!("unterminated keyword /{word}"));
+        }
+        Ok(word)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(string),
+                Some('\\') => string.push(self.escape()?),
+                Some('\n') | None => return self.error("unterminated string"),
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, String> {
+        let c = match self.bump() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some('x') => {
+                let digits: String = (0..2).filter_map(|_| self.bump()).collect();
+                match u8::from_str_radix(&digits, 16) {
+                    Ok(byte) => byte as char,
+                    Err(_) => return self.error(format!("invalid escape \\x{digits}")),
+                }
+            }
+            Some(c) => c,
+            None => return self.error("unterminated escape"),
+        };
+        Ok(c)
+    }
+
+    fn reference(&mut self) -> Result<Ref, String> {
+        self.expect('&')?;
+        if self.peek() == Some('{') {
+            self.bump();
+            let mut path = String::new();
+            while let Some(c) = self.bump() {
+                if c == '}' {
+                    return Ok(Ref::Path(path));
+                }
+                path.push(c);
+            }
+            return self.error("unterminated path reference");
+        }
+        let label = self.word();
+        if label.is_empty() {
+            return self.error("expected a label after `&`");
+        }
+        Ok(Ref::Label(label))
+    }
+
+    fn parse(&mut self) -> Result<Node, String> {
+        let mut root = DtsNode::default();
+        loop {
+            self.skip_blank()?;
+            match (self.peek(), self.peek_at(1)) {
+                (None, _) => break,
+                (Some('/'), Some(c)) if c.is_ascii_alphabetic() => match self.keyword()?.as_str() {
+                    "dts-v1" => self.expect(';')?,
+                    "memreserve" => while self.bump().is_some_and(|c| c != ';') {},
+                    "include" => {
+                        let path = self.include_path()?;
+                        self.push_source(&path)?;
+                    }
+                    "delete-node" => {
+                        let loc = self.loc();
+                        let reference = self.reference()?;
+                        self.expect(';')?;
+                        let Some((last, parent)) = root.find(&reference).and_then(|indexes| {
+                            indexes
+                                .split_last()
+                                .map(|(last, parent)| (*last, parent.to_vec()))
+                        }) else {
+                            return Err(format!("{loc}: no node referenced by {reference}"));
+                        };
+                        root.get_mut(&parent).children.remove(last);
+                    }
+                    keyword => return self.error(format!("/{keyword}/ is not supported")),
+                },
+                (Some('/'), _) => {
+                    self.bump();
+                    let ops = self.body()?;
+                    root.apply(ops);
+                }
+                (Some('&'), _) => {
+                    let loc = self.loc();
+                    let reference = self.reference()?;
+                    let ops = self.body()?;
+                    match root.find(&reference) {
+                        Some(indexes) => root.get_mut(&indexes).apply(ops),
+                        None => return Err(format!("{loc}: no node referenced by {reference}")),
+                    }
+                }
+                (Some(c), _) => return self.error(format!("unexpected `{c}`")),
+            }
+        }
+        resolve(root)
+    }
+
+    fn include_path(&mut self) -> Result<PathBuf, String> {
+        self.skip_blank()?;
+        let name = self.string()?;
+        let file = self.sources.last().unwrap().file.clone();
+        Ok(file.parent().unwrap_or(Path::new(".")).join(name))
+    }
+
+    /// The body of a node, from the opening brace to the semicolon after the closing one.
+    fn body(&mut self) -> Result<Vec<Op>, String> {
+        self.expect('{')?;
+        let mut ops = Vec::new();
+        loop {
+            self.skip_blank()?;
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                Some('/') => match self.keyword()?.as_str() {
+                    "include" => {
+                        let path = self.include_path()?;
+                        self.push_source(&path)?;
+                    }
+                    "delete-node" => {
+                        self.skip_blank()?;
+                        ops.push(Op::DeleteNode(self.word()));
+                        self.expect(';')?;
+                    }
+                    "delete-property" => {
+                        self.skip_blank()?;
+                        ops.push(Op::DeleteProperty(self.word()));
+                        self.expect(';')?;
+                    }
+                    keyword => return self.error(format!("/{keyword}/ is not supported")),
+                },
+                None => return self.error("unterminated node"),
+                Some(_) => {
+                    let mut labels = Vec::new();
+                    let name = loop {
+                        let word = self.word();
+                        if word.is_empty() {
+                            let c = self.peek().unwrap();
+                            return self.error(format!("unexpected `{c}`"));
+                        }
+                        if self.peek() != Some(':') {
+                            break word;
+                        }
+                        self.bump();
+                        labels.push(word);
+                        self.skip_blank()?;
+                    };
+                    self.skip_blank()?;
+                    match self.peek() {
+                        Some('{') => ops.push(Op::Child(labels, name, self.body()?)),
+                        Some('=') => {
+                            self.bump();
+                            ops.push(Op::Property(name, self.value()?));
+                            self.expect(';')?;
+                        }
+                        Some(';') => {
+                            self.bump();
+                            ops.push(Op::Property(name, vec![]));
+                        }
+                        _ => return self.error(format!("expected a value or a node after {name}")),
+                    }
+                }
+            }
+        }
+        self.expect(';')?;
+        Ok(ops)
+    }
+
+    fn value(&mut self) -> Result<Vec<Chunk>, String> {
+        let mut chunks = Vec::new();
+        loop {
+            self.skip_blank()?;
+            match self.peek() {
+                Some('"') => {
+                    let mut bytes = self.string()?.into_bytes();
+                    bytes.push(0);
+                    chunks.push(Chunk::Bytes(bytes));
+                }
+                Some('<') => self.cells(32, &mut chunks)?,
+                Some('[') => {
+                    self.bump();
+                    let mut bytes = Vec::new();
+                    loop {
+                        self.skip_blank()?;
+                        if self.peek() == Some(']') {
+                            self.bump();
+                            break;
+                        }
+                        let digits: String = (0..2).filter_map(|_| self.bump()).collect();
+                        match u8::from_str_radix(&digits, 16) {
+                            Ok(byte) => bytes.push(byte),
+                            Err(_) => return self.error(format!("invalid byte `{digits}`")),
+                        }
+                    }
+                    chunks.push(Chunk::Bytes(bytes));
+                }
+                Some('&') => {
+                    let loc = self.loc();
+                    chunks.push(Chunk::Path(self.reference()?, loc));
+                }
+                Some('/') => {
+                    let keyword = self.keyword()?;
+                    if keyword != "bits" {
+                        return self.error(format!("/{keyword}/ is not supported in a value"));
+                    }
+                    self.skip_blank()?;
+                    let bits = self.primary()?;
+                    if ![8, 16, 32, 64].contains(&bits) {
+                        return self.error(format!("/bits/ {bits} is not supported"));
+                    }
+                    self.skip_blank()?;
+                    self.cells(bits as u32, &mut chunks)?;
+                }
+                _ => return self.error("expected a value"),
+            }
+            self.skip_blank()?;
+            if self.peek() != Some(',') {
+                return Ok(chunks);
+            }
+            self.bump();
+        }
+    }
+
+    fn cells(&mut self, bits: u32, chunks: &mut Vec<Chunk>) -> Result<(), String> {
+        self.expect('<')?;
+        loop {
+            self.skip_blank()?;
+            let value = match self.peek() {
+                Some('>') => {
+                    self.bump();
+                    return Ok(());
+                }
+                Some('&') if bits == 32 => {
+                    let loc = self.loc();
+                    chunks.push(Chunk::Phandle(self.reference()?, loc));
+                    continue;
+                }
+                _ => self.primary()?,
+            };
+            let bytes = value.to_be_bytes();
+            chunks.push(Chunk::Bytes(bytes[8 - bits as usize / 8..].to_vec()));
+        }
+    }
+
+    /// A number, a character or an expression in parentheses.
+    fn primary(&mut self) -> Result<u64, String> {
+        self.skip_blank()?;
+        match self.peek() {
+            Some('(') => {
+                self.bump();
+                let value = self.expression(0)?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some('-') => {
+                self.bump();
+                Ok(self.primary()?.wrapping_neg())
+            }
+            Some('~') => {
+                self.bump();
+                Ok(!self.primary()?)
+            }
+            Some('!') => {
+                self.bump();
+                Ok((self.primary()? == 0) as u64)
+            }
+            Some('\'') => {
+                self.bump();
+                let c = match self.bump() {
+                    Some('\\') => self.escape()?,
+                    Some(c) => c,
+                    None => return self.error("unterminated character"),
+                };
+                if self.bump() != Some('\'') {
+                    return self.error("unterminated character");
+                }
+                Ok(c as u64)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut literal = String::new();
+                while let Some(c) = self.peek().filter(|c| c.is_ascii_alphanumeric()) {
+                    literal.push(c);
+                    self.bump();
+                }
+                let digits = literal.trim_end_matches(['u', 'U', 'l', 'L']);
+                let value = if let Some(hex) = digits
+                    .strip_prefix("0x")
+                    .or_else(|| digits.strip_prefix("0X"))
+                {
+                    u64::from_str_radix(hex, 16)
+                } else if digits.len() > 1 && digits.starts_with('0') {
+                    u64::from_str_radix(&digits[1..], 8)
+                } else {
+                    digits.parse()
+                };
+                value.or_else(|_| self.error(format!("invalid number `{literal}`")))
+            }
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                let word = self.word();
+                self.error(format!(
+                    "unresolved `{word}`, the macros require the C preprocessor, run `cpp` first"
+                ))
+            }
+            Some(c) => self.error(format!("unexpected `{c}` in the cells")),
+            None => self.error("unterminated cells"),
+        }
+    }
+
+    /// The operator of the level at the position, the longest one when several match, i.e `||`
+    /// rather than `|`.
+    fn operator(&mut self, level: usize) -> Option<&'static str> {
+        let (first, second) = (self.peek()?, self.peek_at(1));
+        let op = OPERATORS
+            .iter()
+            .flat_map(|ops| ops.iter())
+            .filter(|op| {
+                let mut chars = op.chars();
+                chars.next() == Some(first) && chars.next().is_none_or(|c| Some(c) == second)
+            })
+            .max_by_key(|op| op.len())?;
+        OPERATORS[level].contains(op).then_some(*op)
+    }
+
+    fn expression(&mut self, level: usize) -> Result<u64, String> {
+        if level == OPERATORS.len() {
+            return self.primary();
+        }
+        let mut value = self.expression(level + 1)?;
+        loop {
+            self.skip_blank()?;
+            let Some(op) = self.operator(level) else {
+                return Ok(value);
+            };
+            op.chars().for_each(|_| {
+                self.bump();
+            });
+            let rhs = self.expression(level + 1)?;
+            value = match op {
+                "||" => (value != 0 || rhs != 0) as u64,
+                "&&" => (value != 0 && rhs != 0) as u64,
+                "|" => value | rhs,
+                "^" => value ^ rhs,
+                "&" => value & rhs,
+                "==" => (value == rhs) as u64,
+                "!=" => (value != rhs) as u64,
+                "<=" => (value <= rhs) as u64,
+                ">=" => (value >= rhs) as u64,
+                "<" => (value < rhs) as u64,
+                ">" => (value > rhs) as u64,
+                "<<" => value.wrapping_shl(rhs as u32),
+                ">>" => value.wrapping_shr(rhs as u32),
+                "+" => value.wrapping_add(rhs),
+                "-" => value.wrapping_sub(rhs),
+                "*" => value.wrapping_mul(rhs),
+                _ if rhs == 0 => return self.error("division by zero"),
+                "/" => value / rhs,
+                _ => value % rhs,
+            };
+        }
+    }
+}
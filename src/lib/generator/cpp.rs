@@ -11,13 +11,14 @@ use crate::mmio;
 #[template(
     ext = "txt",
     source = "
+#include  <cstddef>
 #include  <cstdint>
 namespace platform  {
 {% for device_type in data.device_types -%}
     /* Addresses for the devices of the type {{ device_type.type_name|pascal_case }}.*/
     enum {{ device_type.type_name|pascal_case }}: uintptr_t{ 
         {%- for device in device_type.devices -%}
-            {{ device.name|pascal_case }} = {{device.address}},
+            {{ device.name|pascal_case }} = {{device.address|hex}},
         {%- endfor -%}
     };
 
@@ -28,6 +29,25 @@ enum Interrupt: uintptr_t{
         {{ interrupt.name|pascal_case }} = {{interrupt.value}}, 
     {%- endfor -%}
 };
+{%- if !data.memories.is_empty() %}
+
+/* The address range of a memory region, the `size` is in bytes.*/
+struct MemoryRegion {
+    uintptr_t base;
+    size_t size;
+    uint8_t width;
+    bool readable;
+    bool writable;
+
+    constexpr uintptr_t end() const { return base + size; }
+    constexpr size_t entries() const { return size / (width / 8); }
+    constexpr bool contains(uintptr_t addr) const { return addr >= base && addr < end(); }
+};
+{% for memory in data.memories %}
+/* {{ memory.name }}, {{ memory.entries() }} words of {{ memory.width }} bits.*/
+inline constexpr MemoryRegion {{ memory.name|pascal_case }}{ {{- memory.base|hex }}, {{ memory.size|hex }}, {{ memory.width }}, {{ memory.access.is_readable() }}, {{ memory.access.is_writable() }}};
+{%- endfor %}
+{%- endif %}
 } // namespace platform
 "
 )]
@@ -42,19 +62,66 @@ pub struct Platform<'a> {
 /* To facilitate compiler optimization of this abstraction, prefer using this struct within a small scope.*/
 struct {{ device.type_|pascal_case }} { 
 {%- for register in device.registers %}
+    {%- if let Some(array) = register.array %}
+    std::array<{{ register.info[0].type_|pascal_case }}Reg, {{ array.dim }}> {{ array.name|lower }};
+    {%- else %}
     {%- for info in register.info %}
     {{ info.type_|pascal_case }}Reg {{info.name|lower}};
     {%- endfor %}
+    {%- endif %}
+{%- endfor %}
+{%- for cluster in device.clusters %}
+    {%- if let Some(array) = cluster.array %}
+    std::array<{{ cluster.type_|pascal_case }}Cluster, {{ array.dim }}> {{ cluster.name|lower }};
+    {%- else %}
+    {{ cluster.type_|pascal_case }}Cluster {{ cluster.name|lower }};
+    {%- endif %}
+{%- endfor %}
+{%- for window in device.windows %}
+    /* The window {{ window.name }}, {{ window.entries }} words of {{ window.width }} bits.*/
+    zermio::Window<uint{{ window.width }}_t, {{ window.entries }}, zermio::{{ window.access }}> {{ window.name|lower }};
+{%- endfor %}
+{%- for param in device.parameters %}
+    /* Hardware parameter {{ param.name }}.{% if !param.desc.is_empty() %} {{ param.desc }}{% endif %}*/
+    static constexpr uint32_t {{ param.name|pascal_case }} = {{ param.value }};
 {%- endfor %}
     
     constexpr {{ device.type_|pascal_case }} (platform::{{ device.type_|pascal_case }} addr): 
 {%- for register in device.registers %}
+    {%- if let Some(array) = register.array %}
+        {{ array.name|lower }}(zermio::make_array<{{ register.info[0].type_|pascal_case }}Reg, {{ array.dim }}>(addr + {{ register.info[0].offset|hex }}, {{ array.increment|hex }}))
+    {%- else %}
     {%- for info in register.info %}
         {{info.name|lower}}(addr + {{ info.offset|hex }}){%- if !loop.last -%}, {% endif %}
     {%- endfor %}
+    {%- endif %}
     {%- if !loop.last -%}, {% endif -%}
+{%- endfor -%}
+{%- for cluster in device.clusters %}
+    {%- if loop.first && !device.registers.is_empty() -%}, {% endif %}
+        {{ cluster.name|lower }}({% if let Some(array) = cluster.array %}zermio::make_array<{{ cluster.type_|pascal_case }}Cluster, {{ array.dim }}>(addr + {{ cluster.offset|hex }}, {{ array.increment|hex }}){% else %}addr + {{ cluster.offset|hex }}{% endif %}){%- if !loop.last -%}, {% endif %}
+{%- endfor -%}
+{%- for window in device.windows %}
+    {%- if loop.first && !(device.registers.is_empty() && device.clusters.is_empty()) -%}, {% endif %}
+        {{ window.name|lower }}(addr + {{ window.offset|hex }}){%- if !loop.last -%}, {% endif %}
 {%- endfor -%}
     {}
+{%- for (reg, field) in device.shadow_errors() %}
+
+    /* Fetch {{ reg.info[0].name }} and return whether {{ field.name }} is set: {{ field.desc }}*/
+    inline bool {{ field.name|lower }}() { return {{ reg.info[0].name|lower }}.fetch().{{ field.accessor() }}.is_set(); }
+{%- endfor %}
+{%- for (reg, lock, field) in device.write_locks() %}
+  {%- if let Some(enable) = reg.write_enable %}
+
+    /* Fetch {{ lock.info[0].name }} and only commit {{ reg.info[0].name }} if {{ enable.field }} is set, returns false otherwise. */
+    inline bool try_commit_{{ reg.info[0].name|lower }}() {
+        if (!{{ lock.info[0].name|lower }}.fetch().{{ field.accessor() }}.is_set()) { return false; }
+        {{ reg.info[0].name|lower }}.commit();
+        return true;
+    }
+  {%- endif %}
+{%- endfor %}
 };
 
 "
@@ -67,25 +134,153 @@ pub struct Device<'a> {
 #[template(
     ext = "txt",
     source = "
+/* {{ cluster.desc }} */
+struct {{ cluster.type_|pascal_case }}Cluster { 
+{%- for register in cluster.registers %}
+    {%- for info in register.info %}
+    {{ info.type_|pascal_case }}Reg {{info.name|lower}};
+    {%- endfor %}
+{%- endfor %}
+{%- for inner in cluster.clusters %}
+    {%- if let Some(array) = inner.array %}
+    std::array<{{ inner.type_|pascal_case }}Cluster, {{ array.dim }}> {{ inner.name|lower }};
+    {%- else %}
+    {{ inner.type_|pascal_case }}Cluster {{ inner.name|lower }};
+    {%- endif %}
+{%- endfor %}
+    
+    constexpr {{ cluster.type_|pascal_case }}Cluster (uintptr_t addr): 
+{%- for register in cluster.registers %}
+    {%- for info in register.info %}
+        {{info.name|lower}}(addr + {{ info.offset|hex }}){%- if !loop.last -%}, {% endif %}
+    {%- endfor %}
+    {%- if !loop.last -%}, {% endif -%}
+{%- endfor -%}
+{%- for inner in cluster.clusters %}
+    {%- if loop.first && !cluster.registers.is_empty() -%}, {% endif %}
+        {{ inner.name|lower }}({% if let Some(array) = inner.array %}zermio::make_array<{{ inner.type_|pascal_case }}Cluster, {{ array.dim }}>(addr + {{ inner.offset|hex }}, {{ array.increment|hex }}){% else %}addr + {{ inner.offset|hex }}{% endif %}){%- if !loop.last -%}, {% endif %}
+{%- endfor -%}
+    {}
+};
+"
+)]
+pub struct Cluster<'a> {
+    pub cluster: &'a mmio::Cluster,
+}
+
+#[derive(Template)]
+#[template(
+    ext = "txt",
+    source = "
+{%- for bitfield in register.bitfields %}
+{%- if !bitfield.enumerated_values.is_empty() && bitfield.owns_type() %}
+/* Enumerated values of the field {{ bitfield.type_name() }}. */
+enum class {{ register.info[0].type_|pascal_case }}{{ bitfield.type_name()|pascal_case }}: uint{{ register.width }}_t {
+    {%- for value in bitfield.enumerated_values %}
+    /* {{ value.desc }} */
+    {{ value.name|pascal_case }} = {{ value.value|hex }},
+    {%- endfor %}
+};
+{% endif %}
+{%- endfor %}
 /* {{ register.info[0].desc }} */
+{%- if register.has_read_effect() %}
+/* Reading this register has side effects:
+  {%- for bitfield in register.bitfields %}
+    {%- if !bitfield.read_effect.is_none() %}
+ * - {{ bitfield.name }}: {{ bitfield.read_effect }} on read.
+    {%- endif %}
+  {%- endfor %}
+ * Thus it can only be loaded explicitly with {% if register.is_clear_on_read() %}read_and_clear(){% else %}read_with_side_effects(){% endif %}. */
+{%- endif %}
+{%- if let Some(enable) = register.write_enable %}
+/* Write enable: the hardware ignores the writes to this register while {{ enable.register }}.{{ enable.field }} is clear. */
+{%- endif %}
 union {{ register.info[0].type_|pascal_case }}Reg { 
     private:
-      zermio::Register reg_;
+      zermio::Register<uint{{ register.width }}_t{% if register.shadowed %}, true{% endif %}> reg_;
     public:
     {% for bitfield in register.bitfields -%}
     /* {{ bitfield.desc }} */
-    zermio::BitField<{{ bitfield.offset }}, {{ bitfield.bit_size }}, zermio::Permissions::{{ bitfield.permissions }}> {{ bitfield.name|lower }};
+    {%- if !bitfield.write_effect.is_modify() || register.shadowed %}
+    zermio::BitField<{{ bitfield.offset }}, {{ bitfield.bit_size }}, zermio::Permissions::{{ bitfield.permissions }}, uint{{ register.width }}_t, {% if bitfield.enumerated_values.is_empty() %}uint{{ register.width }}_t{% else %}{{ register.info[0].type_|pascal_case }}{{ bitfield.type_name()|pascal_case }}{% endif %}, zermio::WriteEffect::{{ bitfield.write_effect }}{% if register.shadowed %}, true{% endif %}> {{ bitfield.accessor() }};
+    {%- else if bitfield.enumerated_values.is_empty() %}
+    zermio::BitField<{{ bitfield.offset }}, {{ bitfield.bit_size }}, zermio::Permissions::{{ bitfield.permissions }}, uint{{ register.width }}_t> {{ bitfield.accessor() }};
+    {%- else %}
+    zermio::BitField<{{ bitfield.offset }}, {{ bitfield.bit_size }}, zermio::Permissions::{{ bitfield.permissions }}, uint{{ register.width }}_t, {{ register.info[0].type_|pascal_case }}{{ bitfield.type_name()|pascal_case }}> {{ bitfield.accessor() }};
+    {%- endif %}
+    {%- if let Some(array) = bitfield.array %}
+    {%- if array.index == 0 %}
+    /* Field array {{ array.name }}, i.e `{{ array.accessor() }}[n]` is the same as `{{ bitfield.accessor() }}` for n = 0. */
+    zermio::FieldArray<{{ bitfield.offset }}, {{ bitfield.bit_size }}, {{ array.stride }}, {{ array.dim }}, zermio::Permissions::{{ bitfield.permissions }}, uint{{ register.width }}_t{% if register.shadowed %}, {% if bitfield.enumerated_values.is_empty() %}uint{{ register.width }}_t{% else %}{{ register.info[0].type_|pascal_case }}{{ bitfield.type_name()|pascal_case }}{% endif %}, true{% else if !bitfield.enumerated_values.is_empty() %}, {{ register.info[0].type_|pascal_case }}{{ bitfield.type_name()|pascal_case }}{% endif %}> {{ array.accessor() }};
+    {%- endif %}
+    {%- endif %}
     {% endfor -%}
     
-    constexpr {{ register.info[0].type_|pascal_case }}Reg (uintptr_t addr): reg_{.addr = addr}
+    /* Value of the register after a reset. */
+    static constexpr uint{{ register.width }}_t reset_value = {{ register.reset_value|hex }};
+    /* Bits of the register that have a defined reset value. */
+    static constexpr uint{{ register.width }}_t reset_mask = {{ register.reset_mask|hex }};
+{%- if register.side_effect_mask() != 0 %}
+    /* Bits of the fields that have side effects when written, i.e write one to clear. */
+    static constexpr uint{{ register.width }}_t side_effect_mask = {{ register.side_effect_mask()|hex }};
+    /* Value of the side effect bits that can be written without modifying their fields. */
+    static constexpr uint{{ register.width }}_t neutral_value = {{ register.neutral_value()|hex }};
+
+    constexpr {{ register.info[0].type_|pascal_case }}Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value,
+        .neutral_mask = side_effect_mask, .neutral_value = neutral_value}
+    {}
+{%- else %}
+
+    constexpr {{ register.info[0].type_|pascal_case }}Reg (uintptr_t addr): reg_{.addr = addr, .cache = reset_value}
     {}
+{%- endif %}
+
+{% if register.shadowed %}    /* Shadowed register: every store instruction is run twice with the same value, as the
+     * hardware only takes it after two consecutive writes. */
+{% endif %}    inline void commit() { reg_.commit(); }
+{%- if register.is_once() %}
+
+    /* Write the mmio unless this instance already did it, returns false otherwise. */
+    inline bool try_commit() { return reg_.try_commit(); }
+
+    /* Write the reset value unless this instance already wrote the mmio, returns false otherwise. */
+    inline bool reset() {
+        reg_.cache = reset_value;
+        return reg_.try_commit();
+    }
+{%- else %}
+
+    inline void reset() {
+        reg_.cache = reset_value;
+        reg_.commit();
+    }
+{%- endif %}
+
+{%- if register.has_read_effect() %}
 
-    inline void commit() { reg_.commit(); }
+    /* Run a load instruction, beware it modifies the fields with read side effects. */
+    inline {{ register.info[0].type_|pascal_case }}Reg& {% if register.is_clear_on_read() %}read_and_clear{% else %}read_with_side_effects{% endif %}() {
+        reg_.fetch();
+        return *this;
+    }
+{%- else %}
 
     inline {{ register.info[0].type_|pascal_case }}Reg& fetch() {
         reg_.fetch();
         return *this;
     }
+{%- endif %}
+{%- if register.side_effect_mask() != 0 && !register.has_read_effect() %}
+
+    /* Fetch the register with the side effect bits set to their neutral value, so it can be
+     * modified and committed back without triggering them. */
+    inline {{ register.info[0].type_|pascal_case }}Reg& update() {
+        reg_.fetch();
+        reg_.neutralize();
+        return *this;
+    }
+{%- endif %}
 };
 
 "
@@ -120,7 +315,7 @@ pub fn generate(
             device_iter.type_.clone().to_lowercase()
         )?;
 
-        for register_iter in &device_iter.registers {
+        for register_iter in device_iter.all_registers() {
             writeln!(
                 device_handler,
                 "{}",
@@ -132,6 +327,18 @@ pub fn generate(
                 .replace(",\n\n", ",\n")
             )?;
         }
+        for cluster_iter in device_iter.all_clusters() {
+            writeln!(
+                device_handler,
+                "{}",
+                Cluster {
+                    cluster: cluster_iter
+                }
+                .render()
+                .unwrap()
+                .replace(",\n\n", ",\n")
+            )?;
+        }
         writeln!(
             device_handler,
             "{}",
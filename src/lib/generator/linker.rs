@@ -0,0 +1,52 @@
+use crate::filters;
+use askama::Template;
+
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::mmio;
+
+#[derive(Template)]
+#[template(
+    ext = "txt",
+    source = "
+MEMORY
+{
+{%- for memory in data.memories %}
+  {{ memory.name }} ({{ self.attributes(memory) }}) : ORIGIN = {{ memory.base|hex }}, LENGTH = {{ memory.size|hex }}
+{%- endfor %}
+}
+{% for device_type in data.device_types %}
+/* Addresses for the devices of the type {{ device_type.type_name|pascal_case }}.*/
+{%- for device in device_type.devices %}
+PROVIDE({{ device.name|upper }}_BASE = {{ device.address|hex }});
+{%- endfor %}
+{% endfor -%}
+"
+)]
+pub struct Memory<'a> {
+    pub data: &'a mmio::Platform,
+}
+
+impl Memory<'_> {
+    // Read only memories, like a ROM, hold code but can't be written.
+    fn attributes(&self, memory: &mmio::Memory) -> &'static str {
+        match (memory.access.is_readable(), memory.access.is_writable()) {
+            (true, true) => "rwx",
+            (true, false) => "rx",
+            _ => "w",
+        }
+    }
+}
+
+/// Write the `memory.x` linker script, with the memories of the platform in a `MEMORY` block
+/// and the base address of each device as a `<DEVICE>_BASE` symbol.
+pub fn generate(soc: &mmio::Platform, out_dir: PathBuf, file_header: &str) -> anyhow::Result<()> {
+    let filename = out_dir.join("memory.x");
+    let mut file = File::create(&filename)?;
+    writeln!(file, "{}", file_header)?;
+    writeln!(file, "{}", Memory { data: soc }.render().unwrap())?;
+    println!("{} generated", filename.display());
+    Ok(())
+}
@@ -25,6 +25,12 @@ pub struct Device<'a> {
     pub inner: &'a mmio::Device,
 }
 
+#[derive(Template)]
+#[template(path = "rust/cluster.rs.txt")]
+pub struct Cluster<'a> {
+    pub inner: &'a mmio::Cluster,
+}
+
 #[derive(Template)]
 #[template(path = "rust/register.rs.txt")]
 pub struct Register<'a> {
@@ -52,7 +58,16 @@ pub fn generate(soc: &mmio::Platform, out_dir: PathBuf, file_header: &str) -> an
             template.render().unwrap().replace(",\n\n", ",\n")
         )?;
 
-        for reg in &device.registers {
+        for cluster in device.all_clusters() {
+            let template = Cluster { inner: cluster };
+            writeln!(
+                f_handle,
+                "{}",
+                template.render().unwrap().replace(",\n\n", ",\n")
+            )?;
+        }
+
+        for reg in device.all_registers() {
             let template = Register { inner: reg };
             writeln!(
                 f_handle,
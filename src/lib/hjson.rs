@@ -0,0 +1,729 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The OpenTitan reggen description of an IP, `uart.hjson`, and a top-level file listing the
+//! instances of the IPs:
+//! ``` hjson
+//! {
+//!   name: "earlgrey"
+//!   // The interrupts are numbered in this order, starting from 1.
+//!   instances: [
+//!     { name: "uart0", ip: "uart.hjson", base_addr: "0x40000000" }
+//!     { name: "uart1", ip: "uart.hjson", base_addr: "0x40010000" }
+//!   ]
+//! }
+//! ```
+//! The `ip` paths are relative to the top-level file.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// A number in reggen, either an integer, a string like `"0x10"` or the name of a parameter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Number {
+    Int(u64),
+    Str(String),
+}
+
+impl Number {
+    /// The value of the number, where a parameter name is replaced by its default value.
+    pub fn value(&self, params: &[Param]) -> Result<u64, String> {
+        let s = match self {
+            Number::Int(num) => return Ok(*num),
+            Number::Str(s) => s.trim(),
+        };
+        if let Some(param) = params.iter().find(|p| p.name == s) {
+            return param.default.value(&[]);
+        }
+        let parsed = match s.strip_prefix("0x").or(s.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(&hex.replace('_', ""), 16),
+            None => s.replace('_', "").parse(),
+        };
+        parsed.map_err(|_| format!("Invalid number {s}"))
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Number::Int(0)
+    }
+}
+
+/// A boolean in reggen, either `true` or a string like `"true"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Flag {
+    Bool(bool),
+    Str(String),
+}
+
+impl Flag {
+    pub fn is_set(&self) -> bool {
+        match self {
+            Flag::Bool(flag) => *flag,
+            Flag::Str(s) => s.eq_ignore_ascii_case("true") || s == "1",
+        }
+    }
+}
+
+impl Default for Flag {
+    fn default() -> Self {
+        Flag::Bool(false)
+    }
+}
+
+/// The software access of a field or window, i.e `rw1c` for read and write one to clear.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SwAccess {
+    Ro,
+    /// Read only and cleared on read.
+    Rc,
+    Rw,
+    Wo,
+    Rw0c,
+    Rw1c,
+    Rw1s,
+    /// Reads as zero and write one to clear.
+    R0w1c,
+    None,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ip {
+    pub name: String,
+    #[serde(default)]
+    pub regwidth: Option<Number>,
+    #[serde(default)]
+    pub param_list: Vec<Param>,
+    #[serde(default)]
+    pub interrupt_list: Vec<Signal>,
+    #[serde(default)]
+    pub alert_list: Vec<Signal>,
+    /// Whether the `INTR_*` and `ALERT_TEST` registers are described in `registers` instead of
+    /// generated from the lists above.
+    #[serde(default)]
+    pub no_auto_intr_regs: Flag,
+    #[serde(default)]
+    pub no_auto_alert_regs: Flag,
+    #[serde(default)]
+    pub bus_interfaces: Vec<BusInterface>,
+    pub registers: Registers,
+}
+
+/// A bus interface of the IP, i.e `{ protocol: "tlul", direction: "device", name: "core" }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusInterface {
+    #[serde(default)]
+    pub protocol: Option<String>,
+    #[serde(default)]
+    pub direction: Option<String>,
+    /// Names the register interface when the IP has several, see [`Registers::Interfaces`].
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Some IPs have more than one register interface, i.e `{ core: [...], prim: [...] }`, of which
+/// only the one of the first device bus interface is used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Registers {
+    List(Vec<Entry>),
+    Interfaces(Map<String, Value>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Param {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<String>,
+    #[serde(rename = "type", default)]
+    pub type_name: Option<String>,
+    #[serde(default)]
+    pub default: Number,
+    #[serde(default)]
+    pub local: Flag,
+}
+
+/// An interrupt or an alert.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Signal {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<String>,
+    #[serde(default)]
+    pub width: Option<Number>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Entry {
+    Multireg(Multireg),
+    Window(Window),
+    /// Move the next register to the given offset.
+    Skipto(Number),
+    /// Skip the given number of registers.
+    Reserved(Number),
+    #[serde(untagged)]
+    Register(Register),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Register {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<String>,
+    /// The default software access of the fields, i.e `rw` or `rw1c`.
+    #[serde(default)]
+    pub swaccess: Option<SwAccess>,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+    #[serde(default)]
+    pub shadowed: Flag,
+    /// The register, or register and field (`CTRL_REGWEN.EN`), that gates the writes.
+    #[serde(default)]
+    pub regwen: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Multireg {
+    #[serde(flatten)]
+    pub register: Register,
+    pub count: Number,
+    /// Whether the instances of a single field are packed in the same register.
+    #[serde(default)]
+    pub compact: Option<Flag>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Window {
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<String>,
+    pub items: Number,
+    #[serde(default)]
+    pub swaccess: Option<SwAccess>,
+    #[serde(default)]
+    pub validbits: Option<Number>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Field {
+    /// Defaults to the name of the register when it has a single field.
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub desc: Option<String>,
+    /// The msb and lsb of the field, i.e `"7:0"`, or its only bit.
+    pub bits: Number,
+    #[serde(default)]
+    pub swaccess: Option<SwAccess>,
+    #[serde(default)]
+    pub resval: Option<Value>,
+    #[serde(rename = "enum", default)]
+    pub enum_: Vec<EnumMember>,
+}
+
+impl Field {
+    /// The offset and the size in bits of the field.
+    pub fn bit_range(&self) -> Result<(u32, u32), String> {
+        let parse = |s: &str| {
+            s.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid bits {s}"))
+        };
+        match &self.bits {
+            Number::Int(bit) => Ok((*bit as u32, 1)),
+            Number::Str(s) => match s.split_once(':') {
+                Some((msb, lsb)) => {
+                    let (msb, lsb) = (parse(msb)?, parse(lsb)?);
+                    if msb < lsb {
+                        return Err(format!("Invalid bits {s}"));
+                    }
+                    Ok((lsb, msb - lsb + 1))
+                }
+                None => Ok((parse(s)?, 1)),
+            },
+        }
+    }
+
+    /// The reset value, where `x` (unknown) is taken as zero.
+    pub fn reset_value(&self) -> Result<u64, String> {
+        match &self.resval {
+            None | Some(Value::Null) => Ok(0),
+            Some(Value::Bool(flag)) => Ok(*flag as u64),
+            Some(Value::Number(num)) => num.as_u64().ok_or(format!("Invalid resval {num}")),
+            Some(Value::String(s)) if s == "x" => Ok(0),
+            Some(Value::String(s)) if s == "true" || s == "false" => Ok((s == "true") as u64),
+            Some(Value::String(s)) => Number::Str(s.clone()).value(&[]),
+            Some(other) => Err(format!("Invalid resval {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnumMember {
+    pub value: Number,
+    pub name: String,
+    #[serde(default)]
+    pub desc: Option<String>,
+}
+
+impl Register {
+    /// The fields with the name and the software access inherited from the register when missing.
+    pub fn resolved_fields(&self) -> Vec<Field> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                field.name = Some(field.name.unwrap_or(self.name.clone()).to_uppercase());
+                field.swaccess = field.swaccess.or(self.swaccess);
+                field
+            })
+            .collect()
+    }
+}
+
+impl Ip {
+    pub fn try_from(hjson_str: &str) -> Result<Self, String> {
+        let value = parse(hjson_str)?;
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+
+    /// The registers of the first device interface listed in `bus_interfaces`, the order of the
+    /// interfaces in `registers` being lost by the JSON map.
+    pub fn entries(&self) -> Result<Vec<Entry>, String> {
+        match &self.registers {
+            Registers::List(list) => Ok(list.clone()),
+            Registers::Interfaces(interfaces) => {
+                let device = self
+                    .bus_interfaces
+                    .iter()
+                    .filter(|bus| bus.direction.as_deref() == Some("device"))
+                    .find_map(|bus| interfaces.get(bus.name.as_deref()?));
+                let list = match device {
+                    Some(list) => list,
+                    None if interfaces.is_empty() => return Ok(vec![]),
+                    None if interfaces.len() == 1 => interfaces.values().next().unwrap(),
+                    None => {
+                        return Err(format!(
+                            "No device bus interface of {} selects one of its register interfaces",
+                            self.name
+                        ));
+                    }
+                };
+                serde_json::from_value(list.clone()).map_err(|e| e.to_string())
+            }
+        }
+    }
+
+    /// The registers that reggen generates from the interrupts and the alerts, at the start of
+    /// the register map.
+    pub fn auto_registers(&self) -> Result<Vec<Register>, String> {
+        let fields = |signals: &[Signal], swaccess: SwAccess| -> Result<Vec<Field>, String> {
+            let mut lsb = 0;
+            let mut fields = Vec::new();
+            for signal in signals {
+                let width = match &signal.width {
+                    Some(width) => width.value(&self.param_list)?,
+                    None => 1,
+                };
+                if width == 0 {
+                    return Err(format!("The signal {} has a width of 0", signal.name));
+                }
+                fields.push(Field {
+                    name: Some(signal.name.to_uppercase()),
+                    desc: signal.desc.clone(),
+                    bits: Number::Str(format!("{}:{}", lsb + width - 1, lsb)),
+                    swaccess: Some(swaccess),
+                    resval: None,
+                    enum_: vec![],
+                });
+                lsb += width;
+            }
+            Ok(fields)
+        };
+        let register = |name: &str, desc: &str, fields| Register {
+            name: name.to_owned(),
+            desc: Some(desc.to_owned()),
+            swaccess: None,
+            fields,
+            shadowed: Flag::default(),
+            regwen: None,
+        };
+
+        let mut registers = Vec::new();
+        if !self.interrupt_list.is_empty() && !self.no_auto_intr_regs.is_set() {
+            let (list, state, enable, test) = (
+                &self.interrupt_list,
+                SwAccess::Rw1c,
+                SwAccess::Rw,
+                SwAccess::Wo,
+            );
+            registers.extend([
+                register(
+                    "INTR_STATE",
+                    "Interrupt State Register",
+                    fields(list, state)?,
+                ),
+                register(
+                    "INTR_ENABLE",
+                    "Interrupt Enable Register",
+                    fields(list, enable)?,
+                ),
+                register("INTR_TEST", "Interrupt Test Register", fields(list, test)?),
+            ]);
+        }
+        if !self.alert_list.is_empty() && !self.no_auto_alert_regs.is_set() {
+            registers.push(register(
+                "ALERT_TEST",
+                "Alert Test Register",
+                fields(&self.alert_list, SwAccess::Wo)?,
+            ));
+        }
+        Ok(registers)
+    }
+
+    /// The size of the registers in bits.
+    pub fn regwidth(&self) -> Result<u32, String> {
+        let width = match &self.regwidth {
+            Some(width) => width.value(&self.param_list)? as u32,
+            None => 32,
+        };
+        Ok(width)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Instance {
+    pub name: String,
+    /// The path to the hjson of the IP, relative to the top-level file.
+    pub ip: String,
+    pub base_addr: Number,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Top {
+    pub name: String,
+    pub instances: Vec<Instance>,
+}
+
+/// The top-level file with the IPs of its instances loaded.
+#[derive(Debug, Clone)]
+pub struct SoC {
+    pub name: String,
+    pub instances: Vec<(Instance, Ip)>,
+}
+
+impl SoC {
+    pub fn load(top: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(top).map_err(|e| format!("{}: {e}", top.display()))?;
+        let top_value = parse(&text).map_err(|e| format!("{}: {e}", top.display()))?;
+        let top_desc: Top =
+            serde_json::from_value(top_value).map_err(|e| format!("{}: {e}", top.display()))?;
+
+        let dir = top.parent().unwrap_or(Path::new("."));
+        let mut ips: Vec<(String, Ip)> = Vec::new();
+        let mut instances = Vec::new();
+        for instance in top_desc.instances {
+            let ip = match ips.iter().find(|(path, _)| *path == instance.ip) {
+                Some((_, ip)) => ip.clone(),
+                None => {
+                    let path = dir.join(&instance.ip);
+                    let text = std::fs::read_to_string(&path)
+                        .map_err(|e| format!("{}: {e}", path.display()))?;
+                    let ip = Ip::try_from(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+                    ips.push((instance.ip.clone(), ip.clone()));
+                    ip
+                }
+            };
+            instances.push((instance, ip));
+        }
+        Ok(Self {
+            name: top_desc.name,
+            instances,
+        })
+    }
+}
+
+/// Parse a hjson document, with comments, unquoted keys and strings and optional commas, into
+/// the equivalent json value.
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        line: 1,
+    };
+    parser.skip_blank()?;
+    // The braces of the root object can be omitted.
+    let value = match parser.peek() {
+        Some('{') | Some('[') => parser.value()?,
+        _ => parser.members(None)?,
+    };
+    parser.skip_blank()?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(c) => Err(parser.error(&format!("unexpected '{c}' after the root value"))),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Parser {
+    fn error(&self, msg: &str) -> String {
+        format!("line {}: {}", self.line, msg)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    /// Skip the white spaces, the new lines and the comments.
+    fn skip_blank(&mut self) -> Result<(), String> {
+        while let Some(c) = self.peek() {
+            match c {
+                c if c.is_whitespace() => {
+                    self.next();
+                }
+                '#' => self.skip_line(),
+                '/' if self.peek_at(1) == Some('/') => self.skip_line(),
+                '/' if self.peek_at(1) == Some('*') => {
+                    let line = self.line;
+                    self.pos += 2;
+                    loop {
+                        match self.next() {
+                            Some('*') if self.peek() == Some('/') => {
+                                self.next();
+                                break;
+                            }
+                            Some(_) => {}
+                            None => return Err(format!("line {line}: unterminated comment")),
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+
+    fn skip_line(&mut self) {
+        while self.peek().is_some_and(|c| c != '\n') {
+            self.next();
+        }
+    }
+
+    /// Skip the optional comma that separates two members or elements.
+    fn skip_separator(&mut self) -> Result<(), String> {
+        self.skip_blank()?;
+        if self.peek() == Some(',') {
+            self.next();
+        }
+        self.skip_blank()
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('{') => {
+                self.next();
+                self.members(Some('}'))
+            }
+            Some('[') => {
+                self.next();
+                self.elements()
+            }
+            Some('"') => Ok(Value::String(self.quoted('"')?)),
+            Some('\'') if self.starts_with("'''") => Ok(Value::String(self.multiline()?)),
+            Some('\'') => Ok(Value::String(self.quoted('\'')?)),
+            Some(_) => Ok(self.quoteless()),
+            None => Err(self.error("unexpected end of file")),
+        }
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        s.chars()
+            .enumerate()
+            .all(|(i, c)| self.peek_at(i) == Some(c))
+    }
+
+    /// The members of an object up to the `end` brace, or to the end of file for a root object
+    /// without braces.
+    fn members(&mut self, end: Option<char>) -> Result<Value, String> {
+        let mut map = Map::new();
+        loop {
+            self.skip_blank()?;
+            match (self.peek(), end) {
+                (None, None) => break,
+                (None, Some(_)) => return Err(self.error("unterminated object")),
+                (Some(c), Some(end)) if c == end => {
+                    self.next();
+                    break;
+                }
+                _ => {}
+            }
+            let key = self.key()?;
+            self.skip_blank()?;
+            if self.next() != Some(':') {
+                return Err(self.error(&format!("expected ':' after the key {key}")));
+            }
+            self.skip_blank()?;
+            let value = self.value()?;
+            map.insert(key, value);
+            self.skip_separator()?;
+        }
+        Ok(Value::Object(map))
+    }
+
+    fn elements(&mut self) -> Result<Value, String> {
+        let mut list = Vec::new();
+        loop {
+            self.skip_blank()?;
+            match self.peek() {
+                None => return Err(self.error("unterminated array")),
+                Some(']') => {
+                    self.next();
+                    break;
+                }
+                _ => {}
+            }
+            list.push(self.value()?);
+            self.skip_separator()?;
+        }
+        Ok(Value::Array(list))
+    }
+
+    fn key(&mut self) -> Result<String, String> {
+        match self.peek() {
+            Some('"') => self.quoted('"'),
+            Some('\'') => self.quoted('\''),
+            _ => {
+                let mut key = String::new();
+                while let Some(c) = self.peek() {
+                    if c.is_whitespace() || ",:[]{}".contains(c) {
+                        break;
+                    }
+                    key.push(c);
+                    self.next();
+                }
+                if key.is_empty() {
+                    return Err(self.error("expected a key"));
+                }
+                Ok(key)
+            }
+        }
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<String, String> {
+        let line = self.line;
+        self.next();
+        let mut s = String::new();
+        loop {
+            match self.next() {
+                Some(c) if c == quote => return Ok(s),
+                Some('\\') => match self.next() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some('r') => s.push('\r'),
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('u') => {
+                        let hex: String = (0..4).filter_map(|_| self.next()).collect();
+                        let c = u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or(self.error(&format!("invalid escape \\u{hex}")))?;
+                        s.push(c);
+                    }
+                    Some(c) => s.push(c),
+                    None => break,
+                },
+                Some(c) => s.push(c),
+                None => break,
+            }
+        }
+        Err(format!("line {line}: unterminated string"))
+    }
+
+    /// A `'''` string, where the indentation of the first line is removed from all lines.
+    fn multiline(&mut self) -> Result<String, String> {
+        let line = self.line;
+        let indent = self.chars[..self.pos]
+            .iter()
+            .rev()
+            .take_while(|c| **c != '\n')
+            .count();
+        self.pos += 3;
+        let mut raw = String::new();
+        loop {
+            if self.starts_with("'''") {
+                self.pos += 3;
+                break;
+            }
+            match self.next() {
+                Some(c) => raw.push(c),
+                None => return Err(format!("line {line}: unterminated multiline string")),
+            }
+        }
+        let raw = raw.strip_prefix('\n').unwrap_or(&raw);
+        let lines: Vec<_> = raw
+            .lines()
+            .map(|l| {
+                let blank = l.chars().take(indent).take_while(|c| *c == ' ').count();
+                &l[blank..]
+            })
+            .collect();
+        Ok(lines.join("\n").trim_end().to_owned())
+    }
+
+    /// A string without quotes, up to the end of the line, which may also be a number, a
+    /// boolean or null.
+    fn quoteless(&mut self) -> Value {
+        let mut s = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                break;
+            }
+            // The elements of an inline array or object, i.e `[1, 2]`, end at the punctuators,
+            // as does a comment after a literal.
+            let comment = c == '#' || (c == '/' && self.peek_at(1) == Some('/'));
+            if (comment || ",]}".contains(c)) && literal(s.trim()).is_some() {
+                break;
+            }
+            s.push(c);
+            self.next();
+        }
+        let s = s.trim();
+        literal(s).unwrap_or(Value::String(s.to_owned()))
+    }
+}
+
+fn literal(s: &str) -> Option<Value> {
+    match s {
+        "true" => Some(Value::Bool(true)),
+        "false" => Some(Value::Bool(false)),
+        "null" => Some(Value::Null),
+        _ => serde_json::from_str::<serde_json::Number>(s)
+            .ok()
+            .map(Value::Number),
+    }
+}
@@ -0,0 +1,565 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A reader of the memory maps of IP-XACT (IEEE 1685-2009, 2014 and 2022) components: the
+//! `addressBlock`, `registerFile`, `register`, `field` and `enumeratedValue` elements, with
+//! their `access`, `modifiedWriteValue`, `readAction`, resets and arrays.
+//!
+//! The elements are matched by their local name, so the `spirit:` and `ipxact:` namespaces are
+//! both accepted. Only the first memory map of a component is read, the `usage` of its address
+//! blocks makes them registers or windows. The values must be numbers, i.e `0x10`, `'h10` or
+//! `4K`, the expressions with parameters are reported as errors.
+//!
+//! Several components are combined into a platform by a map, a json or hjson file listing the
+//! instances with the path to their component and their base address.
+
+use crate::hjson;
+use roxmltree::{Document, Node};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use svd_rs::access::Access;
+use svd_rs::modifiedwritevalues::ModifiedWriteValues;
+use svd_rs::readaction::ReadAction;
+
+#[derive(Debug, Clone)]
+pub struct EnumValue {
+    pub name: String,
+    pub desc: Option<String>,
+    pub value: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub name: String,
+    pub desc: Option<String>,
+    pub offset: u32,
+    pub width: u32,
+    // Inherited from the register when the field has none.
+    pub access: Access,
+    pub modified_write: Option<ModifiedWriteValues>,
+    pub read_action: Option<ReadAction>,
+    pub reset: u64,
+    pub enumerated_values: Vec<EnumValue>,
+}
+
+/// The instances of a dimensioned register or register file.
+#[derive(Debug, Clone)]
+pub struct Array {
+    pub dim: u32,
+    // The distance in bytes between two instances.
+    pub stride: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Register {
+    pub name: String,
+    pub desc: Option<String>,
+    // In bytes, relative to the memory map or to the register file.
+    pub offset: u32,
+    // In bits.
+    pub size: u32,
+    pub array: Option<Array>,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegisterFile {
+    pub name: String,
+    pub desc: Option<String>,
+    // In bytes, relative to the memory map or to the parent register file.
+    pub offset: u32,
+    pub array: Option<Array>,
+    pub registers: Vec<Register>,
+    pub register_files: Vec<RegisterFile>,
+}
+
+/// An address block with the usage `memory`.
+#[derive(Debug, Clone)]
+pub struct Memory {
+    pub name: String,
+    pub desc: Option<String>,
+    // In bytes, relative to the memory map.
+    pub offset: u32,
+    // In bytes.
+    pub range: u32,
+    // The width in bits of the words.
+    pub width: u32,
+    pub access: Access,
+}
+
+/// The first memory map of a component, with its address blocks flattened.
+#[derive(Debug, Clone)]
+pub struct Component {
+    pub name: String,
+    pub desc: Option<String>,
+    pub registers: Vec<Register>,
+    pub register_files: Vec<RegisterFile>,
+    pub memories: Vec<Memory>,
+}
+
+impl Component {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let doc = Document::parse(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+        Reader { path }.component(doc.root_element())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MapInstance {
+    name: String,
+    /// The path to the IP-XACT component, relative to the map.
+    component: String,
+    base_addr: hjson::Number,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Map {
+    name: String,
+    instances: Vec<MapInstance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Instance {
+    pub name: String,
+    pub address: u64,
+    pub component: Component,
+}
+
+#[derive(Debug, Clone)]
+pub struct SoC {
+    pub name: String,
+    pub instances: Vec<Instance>,
+}
+
+impl SoC {
+    /// Load the components of the instances listed by a base address map.
+    pub fn load(map: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(map).map_err(|e| format!("{}: {e}", map.display()))?;
+        let value = hjson::parse(&text).map_err(|e| format!("{}: {e}", map.display()))?;
+        let desc: Map =
+            serde_json::from_value(value).map_err(|e| format!("{}: {e}", map.display()))?;
+
+        let dir = map.parent().unwrap_or(Path::new("."));
+        let mut components: Vec<(String, Component)> = Vec::new();
+        let mut instances = Vec::new();
+        for instance in desc.instances {
+            let component = match components
+                .iter()
+                .find(|(path, _)| *path == instance.component)
+            {
+                Some((_, component)) => component.clone(),
+                None => {
+                    let component = Component::load(&dir.join(&instance.component))?;
+                    if let Some((path, _)) =
+                        components.iter().find(|(_, c)| c.name == component.name)
+                    {
+                        return Err(format!(
+                            "{}: the component {} is defined by both {} and {}",
+                            map.display(),
+                            component.name,
+                            path,
+                            instance.component
+                        ));
+                    }
+                    components.push((instance.component.clone(), component.clone()));
+                    component
+                }
+            };
+            let address = instance
+                .base_addr
+                .value(&[])
+                .map_err(|e| format!("{}: {e} in {}", map.display(), instance.name))?;
+            instances.push(Instance {
+                name: instance.name,
+                address,
+                component,
+            });
+        }
+        Ok(Self {
+            name: desc.name,
+            instances,
+        })
+    }
+
+    /// A platform with a single component at the address 0, named after it.
+    pub fn from_component(path: &Path) -> Result<Self, String> {
+        let component = Component::load(path)?;
+        Ok(Self {
+            name: component.name.clone(),
+            instances: vec![Instance {
+                name: component.name.clone(),
+                address: 0,
+                component,
+            }],
+        })
+    }
+}
+
+/// Parse a `scaledNonNegativeInteger`, i.e `16`, `0x10`, `#10` or `4K`, or a SystemVerilog
+/// literal, i.e `'h10` or `8'b0001_0000`.
+fn parse_number(text: &str) -> Option<u64> {
+    let text = text.trim().trim_start_matches('+').replace('_', "");
+    if let Some((_, literal)) = text.split_once('\'') {
+        let literal = literal.trim_start_matches(['s', 'S']);
+        let radix = match literal.chars().next()?.to_ascii_lowercase() {
+            'h' => 16,
+            'd' => 10,
+            'o' => 8,
+            'b' => 2,
+            _ => return None,
+        };
+        return u64::from_str_radix(&literal[1..], radix).ok();
+    }
+
+    let (digits, scale) = match text.char_indices().last()? {
+        (idx, 'k' | 'K') => (&text[..idx], 1 << 10),
+        (idx, 'm' | 'M') => (&text[..idx], 1 << 20),
+        (idx, 'g' | 'G') => (&text[..idx], 1 << 30),
+        (idx, 't' | 'T') => (&text[..idx], 1 << 40),
+        _ => (text.as_str(), 1),
+    };
+    let value = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+        .or_else(|| digits.strip_prefix('#'))
+    {
+        u64::from_str_radix(hex, 16).ok()?
+    } else {
+        digits.parse().ok()?
+    };
+    value.checked_mul(scale)
+}
+
+fn child<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    children(node, name).next()
+}
+
+fn children<'a, 'i>(node: Node<'a, 'i>, name: &str) -> impl Iterator<Item = Node<'a, 'i>> {
+    node.children()
+        .filter(move |c| c.is_element() && c.tag_name().name() == name)
+}
+
+fn text(node: Node, name: &str) -> Option<String> {
+    child(node, name).map(|c| c.text().unwrap_or_default().trim().to_string())
+}
+
+/// A property of the access, directly in the element up to IP-XACT 2014 or in its
+/// `accessPolicy` or `fieldAccessPolicy` since 2022.
+fn policy<'a, 'i>(node: Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    child(node, name).or_else(|| {
+        ["accessPolicies", "fieldAccessPolicies"]
+            .into_iter()
+            .filter_map(|policies| child(node, policies))
+            .flat_map(|policies| policies.children().filter(Node::is_element))
+            .find_map(|policy| child(policy, name))
+    })
+}
+
+struct Reader<'p> {
+    path: &'p Path,
+}
+
+impl Reader<'_> {
+    fn error<T>(&self, node: Node, msg: impl std::fmt::Display) -> Result<T, String> {
+        let pos = node.document().text_pos_at(node.range().start);
+        Err(format!(
+            "{}:{}:{}: {msg}",
+            self.path.display(),
+            pos.row,
+            pos.col
+        ))
+    }
+
+    fn name(&self, node: Node) -> Result<String, String> {
+        match text(node, "name") {
+            Some(name) if !name.is_empty() => Ok(name),
+            _ => self.error(node, format!("{} without a name", node.tag_name().name())),
+        }
+    }
+
+    fn desc(&self, node: Node) -> Option<String> {
+        text(node, "description").map(|desc| desc.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
+    fn number(&self, node: Node, name: &str) -> Result<Option<u64>, String> {
+        let Some(child) = child(node, name) else {
+            return Ok(None);
+        };
+        let text = child.text().unwrap_or_default().trim();
+        match parse_number(text) {
+            Some(value) => Ok(Some(value)),
+            None => self.error(child, format!("unsupported {name} `{text}`")),
+        }
+    }
+
+    fn required(&self, node: Node, name: &str) -> Result<u64, String> {
+        match self.number(node, name)? {
+            Some(value) => Ok(value),
+            None => self.error(node, format!("{} without {name}", node.tag_name().name())),
+        }
+    }
+
+    /// The value of a number that is an address, scaled from addressing units to bytes.
+    fn address(&self, node: Node, name: &str, unit: u64) -> Result<u32, String> {
+        let value = self.required(node, name)? * unit;
+        match u32::try_from(value) {
+            Ok(value) => Ok(value),
+            Err(_) => self.error(node, format!("{name} {value:#x} exceeds 32 bits")),
+        }
+    }
+
+    fn access(&self, node: Node, inherited: Access) -> Result<Access, String> {
+        let Some(access) = policy(node, "access") else {
+            return Ok(inherited);
+        };
+        let text = access.text().unwrap_or_default().trim();
+        match Access::parse_str(text) {
+            Some(access) => Ok(access),
+            None => self.error(access, format!("unknown access `{text}`")),
+        }
+    }
+
+    /// Whether the element is not removed by a constant `isPresent`.
+    fn is_present(&self, node: Node) -> bool {
+        !matches!(text(node, "isPresent").as_deref(), Some("0" | "false"))
+    }
+
+    /// The `dim` of a register or register file, directly in the element up to IP-XACT 2014 or
+    /// in its `array` since 2022.
+    fn array(&self, node: Node, stride: u32, unit: u64) -> Result<Option<Array>, String> {
+        let holder = child(node, "array").unwrap_or(node);
+        let dims: Vec<_> = children(holder, "dim").collect();
+        match dims.len() {
+            0 => Ok(None),
+            1 => {
+                let dim = self.required(holder, "dim")? as u32;
+                let stride = match self.number(holder, "stride")? {
+                    Some(stride) => (stride * unit) as u32,
+                    None => stride,
+                };
+                Ok(Some(Array { dim, stride }))
+            }
+            _ => self.error(dims[1], "multidimensional arrays are not supported"),
+        }
+    }
+
+    fn component(&self, root: Node) -> Result<Component, String> {
+        if root.tag_name().name() != "component" {
+            return self.error(
+                root,
+                format!("expected a component, found {}", root.tag_name().name()),
+            );
+        }
+        let name = self.name(root)?;
+        let maps: Vec<_> = child(root, "memoryMaps")
+            .map(|maps| children(maps, "memoryMap").collect())
+            .unwrap_or_default();
+        let Some(&map) = maps.first() else {
+            return self.error(root, format!("the component {name} has no memory map"));
+        };
+        if maps.len() > 1 {
+            println!(
+                "Warning: {}: only the first of the {} memory maps of {name} is imported",
+                self.path.display(),
+                maps.len()
+            );
+        }
+
+        let unit = match self.number(map, "addressUnitBits")? {
+            Some(bits) if bits % 8 == 0 && bits > 0 => bits / 8,
+            Some(bits) => return self.error(map, format!("unsupported addressUnitBits {bits}")),
+            None => 1,
+        };
+
+        let mut component = Component {
+            name,
+            desc: self.desc(root),
+            registers: Vec::new(),
+            register_files: Vec::new(),
+            memories: Vec::new(),
+        };
+        for block in children(map, "addressBlock").filter(|b| self.is_present(*b)) {
+            let base = self.address(block, "baseAddress", unit)?;
+            let access = self.access(block, Access::ReadWrite)?;
+            match text(block, "usage").as_deref().unwrap_or("register") {
+                "register" => {
+                    for register in children(block, "register").filter(|r| self.is_present(*r)) {
+                        component
+                            .registers
+                            .push(self.register(register, base, access, unit)?);
+                    }
+                    for file in children(block, "registerFile").filter(|r| self.is_present(*r)) {
+                        component
+                            .register_files
+                            .push(self.register_file(file, base, access, unit)?);
+                    }
+                }
+                "memory" => component.memories.push(Memory {
+                    name: self.name(block)?,
+                    desc: self.desc(block),
+                    offset: base,
+                    range: self.address(block, "range", unit)?,
+                    width: self.required(block, "width")? as u32,
+                    access,
+                }),
+                "reserved" => {}
+                usage => return self.error(block, format!("unknown usage `{usage}`")),
+            }
+        }
+        Ok(component)
+    }
+
+    fn register_file(
+        &self,
+        node: Node,
+        base: u32,
+        access: Access,
+        unit: u64,
+    ) -> Result<RegisterFile, String> {
+        let access = self.access(node, access)?;
+        let range = self.address(node, "range", unit)?;
+        let mut this = RegisterFile {
+            name: self.name(node)?,
+            desc: self.desc(node),
+            offset: base + self.address(node, "addressOffset", unit)?,
+            array: self.array(node, range, unit)?,
+            registers: Vec::new(),
+            register_files: Vec::new(),
+        };
+        for register in children(node, "register").filter(|r| self.is_present(*r)) {
+            this.registers
+                .push(self.register(register, 0, access, unit)?);
+        }
+        for file in children(node, "registerFile").filter(|r| self.is_present(*r)) {
+            this.register_files
+                .push(self.register_file(file, 0, access, unit)?);
+        }
+        Ok(this)
+    }
+
+    fn register(
+        &self,
+        node: Node,
+        base: u32,
+        access: Access,
+        unit: u64,
+    ) -> Result<Register, String> {
+        let name = self.name(node)?;
+        let size = self.required(node, "size")? as u32;
+        if ![8, 16, 32, 64].contains(&size) {
+            return self.error(node, format!("unsupported size {size} of {name}"));
+        }
+        let access = self.access(node, access)?;
+        // The reset of the whole register, up to IP-XACT 2009.
+        let reset = match child(node, "reset") {
+            Some(reset) => self.required(reset, "value")?,
+            None => 0,
+        };
+
+        let mut fields: Vec<Field> = Vec::new();
+        for field in children(node, "field").filter(|f| self.is_present(*f)) {
+            let field = self.field(field, access, reset, size)?;
+            if let Some(other) = fields.iter().find(|f| {
+                f.offset < field.offset + field.width && field.offset < f.offset + f.width
+            }) {
+                return self.error(
+                    node,
+                    format!(
+                        "the fields {} and {} of {name} overlap",
+                        other.name, field.name
+                    ),
+                );
+            }
+            fields.push(field);
+        }
+
+        Ok(Register {
+            desc: self.desc(node),
+            offset: base + self.address(node, "addressOffset", unit)?,
+            array: self.array(node, size / 8, unit)?,
+            name,
+            size,
+            fields,
+        })
+    }
+
+    fn field(&self, node: Node, access: Access, reset: u64, size: u32) -> Result<Field, String> {
+        let name = self.name(node)?;
+        let offset = self.required(node, "bitOffset")? as u32;
+        let width = self.required(node, "bitWidth")? as u32;
+        if width == 0 || offset + width > size {
+            return self.error(
+                node,
+                format!("the field {name} does not fit in a register of {size} bits"),
+            );
+        }
+        let mask = u64::MAX >> (64 - width);
+
+        let modified_write = match policy(node, "modifiedWriteValue") {
+            Some(child) => {
+                let text = child.text().unwrap_or_default().trim();
+                match ModifiedWriteValues::parse_str(text) {
+                    Some(value) => Some(value),
+                    None => {
+                        return self.error(child, format!("unknown modifiedWriteValue `{text}`"));
+                    }
+                }
+            }
+            None => None,
+        };
+        let read_action = match policy(node, "readAction") {
+            Some(child) => {
+                let text = child.text().unwrap_or_default().trim();
+                match ReadAction::parse_str(text) {
+                    Some(value) => Some(value),
+                    None => return self.error(child, format!("unknown readAction `{text}`")),
+                }
+            }
+            None => None,
+        };
+
+        // The resets of the field since IP-XACT 2014, preferring the one of the default type.
+        let resets: Vec<_> = child(node, "resets")
+            .map(|resets| children(resets, "reset").collect())
+            .unwrap_or_default();
+        let reset = match resets
+            .iter()
+            .find(|r| r.attribute("resetTypeRef").is_none())
+            .or(resets.first())
+        {
+            Some(reset) => self.required(*reset, "value")?,
+            None => reset >> offset,
+        };
+
+        let mut enumerated_values: Vec<EnumValue> = Vec::new();
+        let values = child(node, "enumeratedValues");
+        for value in values
+            .into_iter()
+            .flat_map(|v| children(v, "enumeratedValue"))
+        {
+            let value = EnumValue {
+                name: self.name(value)?,
+                desc: self.desc(value),
+                value: self.required(value, "value")?,
+            };
+            // The read and write usages of a value are described by separated elements.
+            if !enumerated_values.iter().any(|v| v.name == value.name) {
+                enumerated_values.push(value);
+            }
+        }
+
+        Ok(Field {
+            name,
+            desc: self.desc(node),
+            offset,
+            width,
+            access: self.access(node, access)?,
+            modified_write,
+            read_action,
+            reset: reset & mask,
+            enumerated_values,
+        })
+    }
+}
@@ -2,10 +2,33 @@
 // Licensed under the Apache License, Version 2.0, see LICENSE for details.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod devicetree;
 mod filters;
 pub mod generator;
+pub mod hjson;
+pub mod ipxact;
 pub mod mmio;
 pub mod rdljson;
+pub mod svdpatch;
+pub mod systemrdl;
+
+// The runtime is copied into the generated crates, it's compiled here to run its unit tests.
+#[cfg(test)]
+#[path = "../../resources/zermio.rs"]
+#[allow(dead_code, clippy::needless_lifetimes, clippy::bool_assert_comparison)]
+mod zermio;
+
+// A generated device, compiled against the runtime to check its behaviour.
+#[cfg(test)]
+#[path = "../../resources/tests/snapshots/generate_rust_from_rdljson/uart.rs"]
+#[allow(
+    dead_code,
+    unused_imports,
+    clippy::unnecessary_cast,
+    clippy::identity_op
+)]
+#[rustfmt::skip]
+mod uart;
 
 #[cfg(test)]
 mod libtest {
@@ -46,11 +69,49 @@ mod libtest {
 
         check_eq("i2c.hh", None);
         check_eq("timer.hh", None);
+        check_eq("prescaledtimer.hh", None);
         check_eq("io_bank.hh", None);
+        check_eq("pwm.hh", None);
         check_eq("test_platform.hh", None);
         check_eq("mmio.hh", Some("../../mmio.hh"));
     }
 
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_svd() {
+        let svd = PathBuf::from("resources/tests/input/i2c.svd");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let xml = std::fs::read_to_string(&svd).unwrap();
+        let device = svd_parser::parse(&xml).unwrap().try_into().unwrap();
+
+        generator::rust::generate(&device, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("Test.rs", None);
+        check_eq("i2c.rs", None);
+        check_eq("timer.rs", None);
+        check_eq("prescaledtimer.rs", None);
+        check_eq("io_bank.rs", None);
+        check_eq("pwm.rs", None);
+        check_eq("zermio.rs", Some("../../../zermio.rs"));
+    }
+
     #[test]
     #[function_name::named]
     fn generate_cpp_from_rdljson() {
@@ -133,6 +194,1293 @@ mod libtest {
         check_eq("spi.rs", None);
     }
 
+    #[test]
+    #[function_name::named]
+    fn generate_linker_from_svd() {
+        let svd = PathBuf::from("resources/tests/input/i2c.svd");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let xml = std::fs::read_to_string(&svd).unwrap();
+        let device = svd_parser::parse(&xml).unwrap().try_into().unwrap();
+
+        generator::linker::generate(&device, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let res = output_dir.join("memory.x");
+        let snapshot = snapshot_dir.join(function_name!()).join("memory.x");
+        assert!(
+            compare_files(&snapshot, &res).unwrap(),
+            "Run the command to check the diff:\nmeld {} {}",
+            res.as_os_str().to_str().unwrap(),
+            snapshot.as_os_str().to_str().unwrap(),
+        );
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_linker_from_rdljson() {
+        let rdljson = PathBuf::from("resources/tests/input/rdl.json");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let json = std::fs::read_to_string(&rdljson).unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        soc.homogeneous_interfaces_to_periperals();
+        let soc = soc.try_into().unwrap();
+
+        generator::linker::generate(&soc, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let res = output_dir.join("memory.x");
+        let snapshot = snapshot_dir.join(function_name!()).join("memory.x");
+        assert!(
+            compare_files(&snapshot, &res).unwrap(),
+            "Run the command to check the diff:\nmeld {} {}",
+            res.as_os_str().to_str().unwrap(),
+            snapshot.as_os_str().to_str().unwrap(),
+        );
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_cpp_from_hjson() {
+        let top = PathBuf::from("resources/tests/input/hjson/top.hjson");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = hjson::SoC::load(&top).unwrap().try_into().unwrap();
+
+        generator::cpp::generate(&soc, output_dir.clone(), output_dir.clone(), FILE_HEADER)
+            .unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("earlgrey_platform.hh", None);
+        check_eq("uart.hh", None);
+        check_eq("gpio.hh", None);
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_hjson() {
+        let top = PathBuf::from("resources/tests/input/hjson/top.hjson");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = hjson::SoC::load(&top).unwrap().try_into().unwrap();
+
+        generator::rust::generate(&soc, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("earlgrey.rs", None);
+        check_eq("lib.rs", None);
+        check_eq("uart.rs", None);
+        check_eq("gpio.rs", None);
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_cpp_from_hjson_with_devicetree() {
+        let top = PathBuf::from("resources/tests/input/hjson/top.hjson");
+        let dts = PathBuf::from("resources/tests/input/devicetree/earlgrey.dts");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let mut soc: mmio::Platform = hjson::SoC::load(&top).unwrap().try_into().unwrap();
+        let tree = devicetree::Tree::load(&dts).unwrap();
+        let unmatched = soc.apply_devicetree(&tree, &[]).unwrap();
+        assert_eq!(
+            unmatched,
+            [
+                "/soc/i2c@50000 (opentitan,i2c)",
+                "/soc/interrupt-controller@8000000 (sifive,plic-1.0.0)"
+            ]
+        );
+
+        generator::cpp::generate(&soc, output_dir.clone(), output_dir.clone(), FILE_HEADER)
+            .unwrap();
+
+        let res = output_dir.join("earlgrey_platform.hh");
+        let snapshot = snapshot_dir
+            .join(function_name!())
+            .join("earlgrey_platform.hh");
+        assert!(
+            compare_files(&snapshot, &res).unwrap(),
+            "Run the command to check the diff:\nmeld {} {}",
+            res.as_os_str().to_str().unwrap(),
+            snapshot.as_os_str().to_str().unwrap(),
+        );
+    }
+
+    #[test]
+    #[function_name::named]
+    fn compile_cpp_from_hjson_with_devicetree() {
+        let top = PathBuf::from("resources/tests/input/hjson/top.hjson");
+        let dts = PathBuf::from("resources/tests/input/devicetree/earlgrey.dts");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        // UART2 has a single unnamed interrupt, which must not clash with the instance.
+        let mut soc: mmio::Platform = hjson::SoC::load(&top).unwrap().try_into().unwrap();
+        let tree = devicetree::Tree::load(&dts).unwrap();
+        soc.apply_devicetree(&tree, &[]).unwrap();
+        generator::cpp::generate(&soc, output_dir.clone(), output_dir.clone(), FILE_HEADER)
+            .unwrap();
+
+        let source = output_dir.join("main.cc");
+        std::fs::write(&source, "#include \"earlgrey_platform.hh\"\n").unwrap();
+        let compiler = std::env::var("CXX").unwrap_or("c++".into());
+        let output = match std::process::Command::new(&compiler)
+            .args(["-std=c++20", "-fsyntax-only"])
+            .arg(&source)
+            .output()
+        {
+            Ok(output) => output,
+            Err(e) => {
+                println!("Warning: can't run {compiler}: {e}, skipping.");
+                return;
+            }
+        };
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_hjson_with_devicetree() {
+        let top = PathBuf::from("resources/tests/input/hjson/top.hjson");
+        let dtb = PathBuf::from("resources/tests/input/devicetree/earlgrey.dtb");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let mut soc: mmio::Platform = hjson::SoC::load(&top).unwrap().try_into().unwrap();
+        let tree = devicetree::Tree::load(&dtb).unwrap();
+        let unmatched = soc.apply_devicetree(&tree, &[]).unwrap();
+        assert_eq!(unmatched.len(), 2);
+
+        generator::rust::generate(&soc, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let res = output_dir.join("earlgrey.rs");
+        let snapshot = snapshot_dir.join(function_name!()).join("earlgrey.rs");
+        assert!(
+            compare_files(&snapshot, &res).unwrap(),
+            "Run the command to check the diff:\nmeld {} {}",
+            res.as_os_str().to_str().unwrap(),
+            snapshot.as_os_str().to_str().unwrap(),
+        );
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_cpp_from_systemrdl() {
+        let rdl = PathBuf::from("resources/tests/input/systemrdl/soc.rdl");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap();
+
+        generator::cpp::generate(&soc, output_dir.clone(), output_dir.clone(), FILE_HEADER)
+            .unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("demo_soc_platform.hh", None);
+        check_eq("uart.hh", None);
+        check_eq("gpio.hh", None);
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_systemrdl() {
+        let rdl = PathBuf::from("resources/tests/input/systemrdl/soc.rdl");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap();
+
+        generator::rust::generate(&soc, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("demo_soc.rs", None);
+        check_eq("lib.rs", None);
+        check_eq("uart.rs", None);
+        check_eq("gpio.rs", None);
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_cpp_from_ipxact() {
+        let map = PathBuf::from("resources/tests/input/ipxact/map.json");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = ipxact::SoC::load(&map).unwrap().try_into().unwrap();
+
+        generator::cpp::generate(&soc, output_dir.clone(), output_dir.clone(), FILE_HEADER)
+            .unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("ipxact_soc_platform.hh", None);
+        check_eq("uart.hh", None);
+        check_eq("timer.hh", None);
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_ipxact() {
+        let map = PathBuf::from("resources/tests/input/ipxact/map.json");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = ipxact::SoC::load(&map).unwrap().try_into().unwrap();
+
+        generator::rust::generate(&soc, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("ipxact_soc.rs", None);
+        check_eq("lib.rs", None);
+        check_eq("uart.rs", None);
+        check_eq("timer.rs", None);
+    }
+
+    fn merged_soc() -> mmio::Platform {
+        let rdl = PathBuf::from("resources/tests/input/systemrdl/soc.rdl");
+        let timers = PathBuf::from("resources/tests/input/merge/timers.json");
+
+        let mut soc: mmio::Platform = systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap();
+        soc.merge(ipxact::SoC::load(&timers).unwrap().try_into().unwrap())
+            .unwrap();
+        soc
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_cpp_from_merged() {
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = merged_soc();
+
+        generator::cpp::generate(&soc, output_dir.clone(), output_dir.clone(), FILE_HEADER)
+            .unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("demo_soc_platform.hh", None);
+        check_eq("uart.hh", Some("../generate_cpp_from_systemrdl/uart.hh"));
+        check_eq("timer.hh", Some("../generate_cpp_from_ipxact/timer.hh"));
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_merged() {
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let soc = merged_soc();
+
+        generator::rust::generate(&soc, output_dir.clone(), FILE_HEADER).unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("demo_soc.rs", None);
+        check_eq("lib.rs", None);
+        check_eq("gpio.rs", Some("../generate_rust_from_systemrdl/gpio.rs"));
+        check_eq("timer.rs", Some("../generate_rust_from_ipxact/timer.rs"));
+    }
+
+    #[test]
+    fn merge_collisions() {
+        let rdl = PathBuf::from("resources/tests/input/systemrdl/soc.rdl");
+        let load = |path: &str| -> mmio::Platform {
+            ipxact::SoC::load(&PathBuf::from(path))
+                .unwrap()
+                .try_into()
+                .unwrap()
+        };
+
+        // The same description twice is merged into itself.
+        let mut soc: mmio::Platform = systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap();
+        soc.merge(systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap())
+            .unwrap();
+        assert_eq!(
+            soc.device_types
+                .iter()
+                .map(|t| t.devices.len())
+                .sum::<usize>(),
+            3
+        );
+        assert_eq!(soc.devices.len(), 2);
+
+        let mut soc: mmio::Platform = systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap();
+        let err = soc
+            .merge(load("resources/tests/input/ipxact/map.json"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "The device type uart of ipxact_soc differs from the one of demo_soc"
+        );
+
+        let mut soc: mmio::Platform = systemrdl::SoC::load(&rdl).unwrap().try_into().unwrap();
+        let err = soc
+            .merge(load("resources/tests/input/merge/overlap.json"))
+            .unwrap_err();
+        assert!(err.starts_with("TIMER0 at 0x40001000.."), "{err}");
+
+        let mut soc = merged_soc();
+        let err = soc
+            .merge(load("resources/tests/input/merge/overlap.json"))
+            .unwrap_err();
+        assert_eq!(err, "The name TIMER0 of overlap is already taken by TIMER0");
+    }
+
+    #[test]
+    #[function_name::named]
+    fn generate_rust_from_patched_svd() {
+        let svd = PathBuf::from("resources/tests/input/i2c.svd");
+        let patch = PathBuf::from("resources/tests/input/patch/i2c.toml");
+        let snapshot_dir = PathBuf::from("resources/tests/snapshots");
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+
+        let _ = std::fs::create_dir(&output_dir);
+
+        let xml = std::fs::read_to_string(&svd).unwrap();
+        let mut device = svd_parser::parse(&xml).unwrap();
+        let applied = svdpatch::Patch::load(&patch)
+            .unwrap()
+            .apply(&mut device)
+            .unwrap();
+        let header = FILE_HEADER.to_string() + &svdpatch::header("i2c.toml", &applied);
+        let device = device.try_into().unwrap();
+
+        generator::rust::generate(&device, output_dir.clone(), &header).unwrap();
+
+        let check_eq = |name: &str, snapshot: Option<&str>| {
+            let res = output_dir.join(name);
+            let snapshot = snapshot_dir
+                .join(function_name!())
+                .join(snapshot.unwrap_or(name));
+            assert!(
+                compare_files(&snapshot, &res).unwrap(),
+                "Run the command to check the diff:\nmeld {} {}",
+                res.as_os_str().to_str().unwrap(),
+                snapshot.as_os_str().to_str().unwrap(),
+            );
+        };
+
+        check_eq("Test.rs", None);
+        check_eq("i2c.rs", None);
+        check_eq("timer.rs", None);
+        check_eq("prescaledtimer.rs", None);
+    }
+
+    #[test]
+    fn rdljson_memory_errors() {
+        let memory = |width: u8, sw_writable: bool, sw_readable: bool| rdljson::Memory {
+            name: "SRAM".into(),
+            entries: 1024,
+            sw_writable,
+            sw_readable,
+            width,
+            offset: 0x1000,
+            size: 1024,
+        };
+
+        assert_eq!(
+            mmio::Memory::try_from(&memory(24, true, true)).unwrap_err(),
+            "Memory SRAM has an unsupported width 24"
+        );
+        assert_eq!(
+            mmio::Memory::try_from(&memory(8, false, false)).unwrap_err(),
+            "Memory SRAM is neither readable nor writable"
+        );
+        assert_eq!(
+            mmio::Window::try_from(&memory(128, true, false)).unwrap_err(),
+            "Window SRAM has an unsupported width 128"
+        );
+        assert!(mmio::Memory::try_from(&memory(32, true, true)).is_ok());
+    }
+
+    #[test]
+    fn rdljson_interrupts() {
+        let json = std::fs::read_to_string("resources/tests/input/rdl.json").unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        // The PLIC ids start from 1, a line without a matching device is only counted.
+        soc.interrupts = ["uart_2", "gpio", "I2C_1_host_timeout", "uart_0_tx_empty"]
+            .map(String::from)
+            .to_vec();
+        soc.homogeneous_interfaces_to_periperals();
+        let soc: mmio::Platform = soc.try_into().unwrap();
+
+        let interrupts: Vec<_> = soc
+            .interrupts
+            .iter()
+            .map(|i| (i.name.as_str(), i.value))
+            .collect();
+        assert_eq!(
+            interrupts,
+            [
+                ("UART2_irq", 1),
+                ("I2C1_host_timeout", 3),
+                ("UART0_tx_empty", 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn memory_regions() {
+        use askama::Template;
+
+        // The regions of the `MEMORY` block, as `(name, origin, length)`.
+        let regions = |soc: &mmio::Platform| {
+            let script = generator::linker::Memory { data: soc }.render().unwrap();
+            script
+                .lines()
+                .filter_map(|line| {
+                    let (name, rest) = line.trim().split_once(" (")?;
+                    let (_, rest) = rest.split_once("ORIGIN = 0x")?;
+                    let (origin, length) = rest.split_once(", LENGTH = 0x")?;
+                    let origin = u64::from_str_radix(origin, 16).unwrap();
+                    let length = u64::from_str_radix(length, 16).unwrap();
+                    Some((name.to_string(), origin, length))
+                })
+                .collect::<Vec<_>>()
+        };
+        let assert_disjoint = |regions: &[(String, u64, u64)]| {
+            for (idx, (name, origin, length)) in regions.iter().enumerate() {
+                for (other, start, size) in &regions[idx + 1..] {
+                    assert!(
+                        origin + length <= *start || start + size <= *origin,
+                        "{name} overlaps {other}"
+                    );
+                }
+            }
+        };
+
+        let json = std::fs::read_to_string("resources/tests/input/rdl.json").unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        soc.homogeneous_interfaces_to_periperals();
+        let soc: mmio::Platform = soc.try_into().unwrap();
+        let sram = &soc.memories[0];
+        assert!(sram.access.is_readable() && sram.access.is_writable());
+        assert_eq!(regions(&soc), [("SRAM".to_string(), 0x100000, 0x20000)]);
+
+        let xml = std::fs::read_to_string("resources/tests/input/i2c.svd").unwrap();
+        let soc: mmio::Platform = svd_parser::parse(&xml).unwrap().try_into().unwrap();
+        let regions = regions(&soc);
+        assert_eq!(regions.len(), 2);
+        assert_disjoint(&regions);
+    }
+
+    #[test]
+    fn rdljson_window_bounds() {
+        let json = std::fs::read_to_string("resources/tests/input/rdl.json").unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        soc.homogeneous_interfaces_to_periperals();
+        let size = |name: &str| {
+            soc.devices.iter().find_map(|device| match device {
+                rdljson::Device::Device(peripheral) if peripheral.name == name => {
+                    Some(peripheral.size)
+                }
+                _ => None,
+            })
+        };
+        let (uart_size, i2c_size) = (size("uart").unwrap(), size("I2C").unwrap());
+        let soc: mmio::Platform = soc.try_into().unwrap();
+
+        let window = |device: &str| {
+            let device = soc.devices.iter().find(|d| d.name == device).unwrap();
+            let window = &device.windows[0];
+            // The window follows the registers and ends in the address space of the device.
+            let end = device
+                .registers
+                .iter()
+                .map(|r| r.info.last().unwrap().offset);
+            assert!(end.max().unwrap() < window.offset);
+            (window.clone(), window.offset + window.size())
+        };
+
+        let (tx_buf, end) = window("UART");
+        assert_eq!(
+            (tx_buf.entries, tx_buf.width, tx_buf.size()),
+            (16, 32, 0x40)
+        );
+        assert!(tx_buf.access.is_writable() && !tx_buf.access.is_readable());
+        assert!(end <= uart_size);
+
+        let (rx_buf, end) = window("I2C");
+        assert_eq!((rx_buf.entries, rx_buf.width, rx_buf.size()), (64, 8, 0x40));
+        assert!(!rx_buf.access.is_writable() && rx_buf.access.is_readable());
+        assert!(end <= i2c_size);
+    }
+
+    #[test]
+    #[function_name::named]
+    fn systemrdl_top_with_include() {
+        let output_dir = PathBuf::from(format!("target/test_{}", function_name!()));
+        let _ = std::fs::create_dir(&output_dir);
+
+        // The addrmap of the included file is defined at a later line than the top one.
+        std::fs::write(
+            output_dir.join("timer.rdl"),
+            "\n\n\n\n\n\n\n\n\naddrmap timer_t {\n  reg { field {} en; } CTRL;\n};\n",
+        )
+        .unwrap();
+        std::fs::write(
+            output_dir.join("soc.rdl"),
+            "`include \"timer.rdl\"\naddrmap soc {\n  timer_t TIMER0 @ 0x1000;\n};\n",
+        )
+        .unwrap();
+
+        let soc = systemrdl::SoC::load(&output_dir.join("soc.rdl")).unwrap();
+        assert_eq!(soc.name, "soc");
+        let devices: Vec<_> = soc.devices.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(devices, ["TIMER0"]);
+    }
+
+    #[test]
+    fn ipxact_width_errors() {
+        let register = |size| ipxact::Register {
+            name: "ctrl".into(),
+            desc: None,
+            offset: 0,
+            size,
+            array: None,
+            fields: vec![],
+        };
+        let memory = |width| ipxact::Memory {
+            name: "buffer".into(),
+            desc: None,
+            offset: 0x100,
+            range: u32::MAX,
+            width,
+            access: svd_rs::Access::ReadWrite,
+        };
+        let component = |registers, memories| ipxact::Component {
+            name: "dma".into(),
+            desc: None,
+            registers,
+            register_files: vec![],
+            memories,
+        };
+
+        assert_eq!(
+            mmio::Device::try_from(&component(vec![register(24)], vec![])).unwrap_err(),
+            "Register CTRL with 24 bits not supported in DMA"
+        );
+        assert_eq!(
+            mmio::Device::try_from(&component(vec![], vec![memory(128)])).unwrap_err(),
+            "Window buffer has an unsupported width 128 in DMA"
+        );
+        let dma = mmio::Device::try_from(&component(vec![register(32)], vec![memory(32)]));
+        assert_eq!(dma.unwrap().windows[0].entries, u32::MAX / 4);
+    }
+
+    #[test]
+    fn hjson_ip_errors() {
+        let device = |hjson: &str| mmio::Device::try_from(&hjson::Ip::try_from(hjson).unwrap());
+
+        // The `mem` interface comes first in the JSON map, but `regs` is the first device one.
+        let dm = device(
+            r#"{ name: "dm", bus_interfaces: [
+                { protocol: "tlul", direction: "device", name: "regs" }
+                { protocol: "tlul", direction: "device", name: "mem" }
+              ]
+              registers: {
+                mem: [{ name: "HALTED", swaccess: "ro", fields: [{ bits: "0" }] }]
+                regs: [{ name: "CTRL", swaccess: "rw", fields: [{ bits: "0" }] }]
+              }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(dm.registers[0].info[0].name, "CTRL");
+
+        assert_eq!(
+            device(r#"{ name: "dm", registers: { mem: [], regs: [] } }"#).unwrap_err(),
+            "No device bus interface of dm selects one of its register interfaces"
+        );
+        assert_eq!(
+            device(
+                r#"{ name: "uart", interrupt_list: [{ name: "rx", width: "0" }], registers: [] }"#
+            )
+            .unwrap_err(),
+            "The signal rx has a width of 0"
+        );
+        assert_eq!(
+            device(
+                r#"{ name: "uart", registers: [{ window: { name: "FIFO", items: "4", swaccess: "none" } }] }"#
+            )
+            .unwrap_err(),
+            "The swaccess none has no software access in window FIFO"
+        );
+        assert_eq!(
+            device(
+                r#"{ name: "gpio", param_list: [{ name: "N", default: "2" }], registers: [
+                  { multireg: { name: "DATA", count: "N", cname: "DATA", swaccess: "rw",
+                    fields: [{ bits: "35:30" }] } }
+                ] }"#
+            )
+            .unwrap_err(),
+            "Multireg DATA field of 6 bits at bit 30 does not fit 32 bits"
+        );
+    }
+
+    #[test]
+    fn multireg_arrays() {
+        let json = std::fs::read_to_string("resources/tests/input/rdl.json").unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        soc.homogeneous_interfaces_to_periperals();
+        let soc: mmio::Platform = soc.try_into().unwrap();
+        let xadc = soc.devices.iter().find(|d| d.name == "XADC").unwrap();
+        let test_reg = xadc
+            .registers
+            .iter()
+            .find(|r| r.info[0].name == "TEST_REG0");
+        let test_reg = test_reg.unwrap();
+        let array = test_reg.array.as_ref().unwrap();
+        assert_eq!((array.dim, array.increment), (4, 1));
+        let offsets: Vec<_> = test_reg.info.iter().map(|i| i.offset).collect();
+        assert_eq!(offsets, [0x43, 0x44, 0x45, 0x46]);
+
+        let registers = |count: u32, bits: &str, compact: bool| {
+            let hjson = format!(
+                r#"{{ name: "gpio", registers: [
+                  {{ multireg: {{ name: "DATA", count: "{count}", cname: "DATA", swaccess: "rw",
+                    compact: "{compact}", fields: [{{ name: "pin", bits: "{bits}" }}] }} }}
+                ] }}"#
+            );
+            let ip = hjson::Ip::try_from(&hjson[..]).unwrap();
+            mmio::Device::try_from(&ip).unwrap().registers
+        };
+        // The offset and width of the fields, and their index in the packed array.
+        let fields = |register: &mmio::Register| {
+            let fields = register.bitfields.iter().map(|f| {
+                let array = f.array.as_ref().unwrap();
+                (f.offset, f.bit_size, array.index, array.dim, array.stride)
+            });
+            fields.collect::<Vec<_>>()
+        };
+
+        // Eight fields of 4 bits fill one register.
+        let packed = registers(8, "3:0", true);
+        assert_eq!(packed.len(), 1);
+        assert!(packed[0].array.is_none());
+        assert_eq!(fields(&packed[0])[7], (28, 4, 7, 8, 4));
+
+        // The packing starts from the bit of the field and stops at the width of the register.
+        let packed = registers(4, "9:8", true);
+        let offsets: Vec<_> = fields(&packed[0]).iter().map(|f| f.0).collect();
+        assert_eq!(offsets, [8, 10, 12, 14]);
+
+        // Registers with the same fields become an array.
+        let uniform = registers(16, "3:0", true);
+        assert_eq!(uniform.len(), 1);
+        let array = uniform[0].array.as_ref().unwrap();
+        assert_eq!(
+            (&array.name[..], array.dim, array.increment),
+            ("DATA", 2, 4)
+        );
+        assert_eq!(uniform[0].info[1].offset, 4);
+
+        // The last register with less fields keeps them apart.
+        let partial = registers(10, "3:0", true);
+        let names: Vec<_> = partial.iter().map(|r| &r.info[0].name[..]).collect();
+        assert_eq!(names, ["DATA0", "DATA1"]);
+        assert_eq!(fields(&partial[1]).len(), 2);
+
+        // Without compaction each instance has its own register.
+        let single = registers(3, "3:0", false);
+        assert_eq!(single[0].array.as_ref().unwrap().dim, 3);
+        assert_eq!(single[0].bitfields.len(), 1);
+    }
+
+    #[test]
+    fn rdljson_shadowed_registers() {
+        use askama::Template;
+
+        let json = std::fs::read_to_string("resources/tests/input/rdl.json").unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        soc.homogeneous_interfaces_to_periperals();
+        let soc: mmio::Platform = soc.try_into().unwrap();
+        let uart = soc.devices.iter().find(|d| d.name == "UART").unwrap();
+        let register = |name: &str| {
+            let register = uart.registers.iter().find(|r| r.info[0].name == name);
+            let register = register.unwrap();
+            let rendered = generator::rust::Register { inner: register }.render();
+            (register.shadowed, rendered.unwrap())
+        };
+
+        // The register and its fields are the const generic variants that store twice.
+        let (shadowed, timeout_ctrl) = register("TIMEOUT_CTRL");
+        assert!(shadowed);
+        assert!(timeout_ctrl.contains("reg: zermio::Register<u32, true>,"));
+        assert!(timeout_ctrl.contains("zermio::load::Plain, true>;"));
+        let (shadowed, ctrl) = register("CTRL");
+        assert!(!shadowed);
+        assert!(ctrl.contains("reg: zermio::Register<u32>,"));
+        assert!(!ctrl.contains(", true>"));
+
+        let errors: Vec<_> = uart
+            .shadow_errors()
+            .iter()
+            .map(|(r, f)| format!("{}.{}", r.info[0].name, f.name))
+            .collect();
+        assert_eq!(
+            errors,
+            ["ERR_CODE.SHADOW_UPDATE_ERR", "ERR_CODE.SHADOW_STORAGE_ERR"]
+        );
+    }
+
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn try_write_locked_register() {
+        unsafe extern "C" {
+            fn mmap(addr: usize, len: usize, prot: i32, flags: i32, fd: i32, off: i64) -> usize;
+        }
+        // The generated devices take 32 bits addresses, so the registers are mapped at the one of
+        // the first UART: PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS | MAP_FIXED_NOREPLACE.
+        let base = 0x8010_0000;
+        if unsafe { mmap(base, 0x1000, 0x3, 0x10_0022, -1, 0) } != base {
+            println!("Warning: Could not map the UART at {base:#x}, skipping.");
+            return;
+        }
+        let reg = |offset: usize| (base + offset) as *mut u32;
+        let (timeout_ctrl, timeout_regwen) = (reg(0x30), reg(0x38));
+
+        let mut uart = uart::Uart::new(base as u32);
+        assert_eq!(
+            uart.try_write_timeout_ctrl(|r| {
+                r.val().write(0x10);
+            }),
+            Err(zermio::Locked)
+        );
+        assert_eq!(unsafe { timeout_ctrl.read_volatile() }, 0);
+
+        unsafe { timeout_regwen.write_volatile(1) };
+        assert_eq!(
+            uart.try_write_timeout_ctrl(|r| {
+                r.val().write(0x10);
+                r.en().set();
+            }),
+            Ok(())
+        );
+        assert_eq!(unsafe { timeout_ctrl.read_volatile() }, 1 << 31 | 0x10);
+    }
+
+    #[test]
+    fn rdljson_parameters() {
+        let parameter = |value: serde_json::Value| {
+            let param = rdljson::Parameter {
+                name: "NumI2cHosts".into(),
+                type_name: "int".into(),
+                desc: None,
+                value,
+            };
+            mmio::Parameter::try_from(&param).map(|p| (p.name, p.value))
+        };
+        assert_eq!(
+            parameter(serde_json::json!(3)),
+            Ok(("NUM_I2C_HOSTS".to_string(), 3))
+        );
+        assert_eq!(
+            parameter(serde_json::json!(true)),
+            Ok(("NUM_I2C_HOSTS".to_string(), 1))
+        );
+        assert_eq!(
+            parameter(serde_json::json!(1u64 << 32)).unwrap_err(),
+            "Parameter NumI2cHosts value 4294967296 overflows"
+        );
+        assert_eq!(
+            parameter(serde_json::json!("abc")).unwrap_err(),
+            r#"Parameter NumI2cHosts has a non integer value "abc""#
+        );
+
+        let json = std::fs::read_to_string("resources/tests/input/rdl.json").unwrap();
+        let mut soc = rdljson::SoC::try_from(&json).unwrap();
+        soc.homogeneous_interfaces_to_periperals();
+        let soc: mmio::Platform = soc.try_into().unwrap();
+        let i2c = soc.devices.iter().find(|d| d.name == "I2C").unwrap();
+        let parameters: Vec<_> = i2c
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.value))
+            .collect();
+        assert_eq!(
+            parameters,
+            [("FIFO_DEPTH", 8), ("ACQ_FIFO_DEPTH", 8), ("NUM_ALERTS", 1)]
+        );
+        // The constants of the generated device.
+        assert_eq!((uart::RX_FIFO_DEPTH, uart::TX_FIFO_DEPTH), (8, 8));
+    }
+
+    #[test]
+    fn svd_patch_errors() {
+        let xml = std::fs::read_to_string("resources/tests/input/i2c.svd").unwrap();
+        let device = svd_parser::parse(&xml).unwrap();
+        let apply = |toml: &str| {
+            let mut device = device.clone();
+            svdpatch::Patch::try_from(toml)
+                .unwrap()
+                .apply(&mut device)
+                .unwrap_err()
+        };
+
+        assert_eq!(
+            apply(
+                "[[field]]\nperipheral = \"I2C*\"\nregister = \"CTRL\"\nname = \"ENABLE\"\ndelete = true"
+            ),
+            "No field matches I2C*.CTRL.ENABLE"
+        );
+        assert_eq!(
+            apply("[[peripheral]]\nname = \"TIMER0\"\ndelete = true"),
+            "The peripheral TIMER0 can't be deleted, TIMER1 is derived from it"
+        );
+        assert_eq!(
+            apply(
+                "[[add_field]]\nperipheral = \"I2C0\"\nregister = \"CTRL\"\nname = \"SPEED\"\nbit_offset = 2\nbit_width = 2"
+            ),
+            "The field I2C0.CTRL.SPEED clashes with LLPBK of CTRL"
+        );
+        assert_eq!(
+            apply("[[register]]\nperipheral = \"I2C0\"\nname = \"CTRL\"\naccess = \"read-mostly\""),
+            "Invalid access read-mostly"
+        );
+        assert_eq!(
+            apply(
+                "[[field]]\nperipheral = \"I2C0\"\nregister = \"CTRL\"\nname = \"ENABLE*\"\nrename = \"EN\""
+            ),
+            "The rename of I2C0.CTRL.ENABLE* matches 2 fields of CTRL"
+        );
+        assert_eq!(
+            apply("[[register]]\nperipheral = \"I2C0\"\nname = \"CTRL,STATUS\"\nrename = \"REG\""),
+            "The rename of I2C0.CTRL,STATUS matches 2 registers of I2C0"
+        );
+        assert_eq!(
+            apply("[[peripheral]]\nname = \"I2C*\"\nrename = \"I2C\""),
+            "The rename of I2C* matches 2 peripherals"
+        );
+        assert!(svdpatch::Patch::try_from("[[register]]\nname = \"CTRL\"").is_err());
+    }
+
+    #[test]
+    fn svd_register_sizes() {
+        let svd = |size: &str| {
+            format!(
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<device schemaVersion="1.3" xmlns:xs="http://www.w3.org/2001/XMLSchema-instance">
+  <name>Sizes</name>
+  <addressUnitBits>8</addressUnitBits>
+  <width>32</width>
+  <size>32</size>
+  <peripherals>
+    <peripheral>
+      <name>SPI0</name>
+      <baseAddress>0x40000000</baseAddress>
+      <size>16</size>
+      <registers>
+        <register>
+          <name>DATA</name>
+          <addressOffset>0x0</addressOffset>
+        </register>
+        <register>
+          <name>CTRL</name>
+          <addressOffset>0x4</addressOffset>
+          {size}
+        </register>
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>"#
+            )
+        };
+
+        let device = svd_parser::parse(&svd("")).unwrap();
+        let soc: mmio::Platform = device.try_into().unwrap();
+        let widths: Vec<_> = soc.devices[0].registers.iter().map(|r| r.width).collect();
+        assert_eq!(widths, [16, 16]);
+
+        let device = svd_parser::parse(&svd("<size>24</size>")).unwrap();
+        let err = mmio::Platform::try_from(device).unwrap_err();
+        assert_eq!(err, "Register CTRL with 24 bits not supported in SPI0");
+    }
+
+    #[test]
+    fn devicetree_ranges_errors() {
+        let dir = PathBuf::from("target/test_devicetree_ranges_errors");
+        let _ = std::fs::create_dir(&dir);
+        let instances = |cells: u32, ranges: &str| {
+            let dts = format!(
+                r#"/dts-v1/;
+/ {{
+	#address-cells = <{cells}>;
+	#size-cells = <{cells}>;
+	soc {{
+		#address-cells = <{cells}>;
+		#size-cells = <{cells}>;
+		ranges = <{ranges}>;
+		bus {{
+			#address-cells = <1>;
+			#size-cells = <1>;
+			ranges;
+			uart@0 {{
+				compatible = "lowrisc,uart";
+				reg = <0x0 0x1000>;
+			}};
+		}};
+	}};
+}};"#
+            );
+            let path = dir.join("soc.dts");
+            std::fs::write(&path, dts).unwrap();
+            devicetree::Tree::load(&path).unwrap().instances()
+        };
+
+        assert_eq!(
+            instances(0, "0x1").unwrap_err(),
+            "/soc/bus/uart@0: ranges of soc has no cells"
+        );
+        assert_eq!(
+            instances(1, "0x0 0x40000000").unwrap_err(),
+            "/soc/bus/uart@0: ranges of soc is not a multiple of 3 cells"
+        );
+        let uart = &instances(1, "0x0 0x40000000 0x10000").unwrap()[0];
+        assert_eq!(uart.address, Some(0x40000000));
+    }
+
+    #[test]
+    fn svd_array_interrupts() {
+        let svd = |interrupts: &str| {
+            format!(
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<device schemaVersion="1.3" xmlns:xs="http://www.w3.org/2001/XMLSchema-instance">
+  <name>Arrays</name>
+  <addressUnitBits>8</addressUnitBits>
+  <width>32</width>
+  <size>32</size>
+  <peripherals>
+    <peripheral>
+      <dim>2</dim>
+      <dimIncrement>0x1000</dimIncrement>
+      <name>UART[%s]</name>
+      <baseAddress>0x40000000</baseAddress>
+      {interrupts}
+      <registers>
+        <register>
+          <name>DATA</name>
+          <addressOffset>0x0</addressOffset>
+        </register>
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>"#
+            )
+        };
+        let interrupts = |xml: String| {
+            let soc: mmio::Platform = svd_parser::parse(&xml).unwrap().try_into().unwrap();
+            soc.interrupts
+                .iter()
+                .map(|i| (i.name.clone(), i.value))
+                .collect::<Vec<_>>()
+        };
+
+        let split = svd(r#"<interrupt><name>RX</name><value>3</value></interrupt>
+      <interrupt><name>TX</name><value>4</value></interrupt>"#);
+        assert_eq!(
+            interrupts(split),
+            [("UART0_RX".to_string(), 3), ("UART1_TX".to_string(), 4)]
+        );
+
+        let shared = svd(r#"<interrupt><name>IRQ</name><value>5</value></interrupt>"#);
+        assert_eq!(interrupts(shared), [("UART_IRQ".to_string(), 5)]);
+    }
+
+    #[test]
+    fn svd_patch_value_override() {
+        use askama::Template;
+
+        let xml = std::fs::read_to_string("resources/tests/input/i2c.svd").unwrap();
+        let mut device = svd_parser::parse(&xml).unwrap();
+        let patch = "[[field]]\nperipheral = \"IO_BANK0\"\nregister = \"GPIO_CTRL\"\nname = \"IRQOVER\"\nvalues = [{ name = \"INVERTED\", value = 1 }]";
+        svdpatch::Patch::try_from(patch)
+            .unwrap()
+            .apply(&mut device)
+            .unwrap();
+        let soc: mmio::Platform = device.try_into().unwrap();
+        let io_bank = soc.devices.iter().find(|d| d.name == "IO_BANK0").unwrap();
+        let register = io_bank.clusters[0]
+            .registers
+            .iter()
+            .find(|r| r.info[0].name == "GPIO_CTRL")
+            .unwrap();
+        let rendered = generator::rust::Register { inner: register }
+            .render()
+            .unwrap();
+
+        // The new name replaces the vendor one of the same value.
+        let start = rendered.find("pub enum GpioCtrlIrqover {").unwrap();
+        let irqover = &rendered[start..start + rendered[start..].find('}').unwrap()];
+        assert!(irqover.contains("Inverted = 0x1,"));
+        assert!(!irqover.contains("Invert = 0x1,"));
+        assert!(irqover.contains("Low = 0x2,"));
+    }
+
+    #[test]
+    fn read_effect_fields_without_fetch() {
+        use askama::Template;
+
+        let xml = std::fs::read_to_string("resources/tests/input/i2c.svd").unwrap();
+        let soc: mmio::Platform = svd_parser::parse(&xml).unwrap().try_into().unwrap();
+        let i2c = soc.devices.iter().find(|d| d.name == "I2C0").unwrap();
+        let render = |name: &str| {
+            let register = i2c.registers.iter().find(|r| r.info[0].name == name);
+            generator::rust::Register {
+                inner: register.unwrap(),
+            }
+            .render()
+            .unwrap()
+        };
+
+        // The fields of a clear on read register can't fetch it implicitly.
+        let rdata = render("RDATA");
+        assert!(rdata.contains("zermio::effect::Modify, zermio::load::SideEffects>;"));
+        assert!(!rdata.contains("pub fn fetch("));
+        assert!(!render("STATUS").contains("zermio::load::SideEffects"));
+    }
+
+    #[test]
+    fn svd_cluster_register_types() {
+        let svd = |ch_ctrl: &str| {
+            format!(
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<device schemaVersion="1.3" xmlns:xs="http://www.w3.org/2001/XMLSchema-instance">
+  <name>Types</name>
+  <addressUnitBits>8</addressUnitBits>
+  <width>32</width>
+  <size>32</size>
+  <peripherals>
+    <peripheral>
+      <name>DMA0</name>
+      <baseAddress>0x40000000</baseAddress>
+      <registers>
+        <register>
+          <name>CTRL</name>
+          <addressOffset>0x0</addressOffset>
+          <fields>
+            <field><name>EN</name><bitRange>[0:0]</bitRange></field>
+          </fields>
+        </register>
+        <cluster>
+          <dim>2</dim>
+          <dimIncrement>0x10</dimIncrement>
+          <name>CH[%s]</name>
+          <addressOffset>0x10</addressOffset>
+          <register>
+            <name>CTRL</name>
+            <addressOffset>0x0</addressOffset>
+            <fields>{ch_ctrl}</fields>
+          </register>
+        </cluster>
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>"#
+            )
+        };
+        let types = |xml: &str| {
+            let soc: mmio::Platform = svd_parser::parse(xml).unwrap().try_into().unwrap();
+            let device = &soc.devices[0];
+            let types: Vec<_> = device
+                .all_registers()
+                .iter()
+                .map(|r| r.info[0].type_.clone())
+                .collect();
+            types
+        };
+
+        // The same layout shares the type, another one gets the type of its cluster as prefix.
+        let same = "<field><name>EN</name><bitRange>[0:0]</bitRange></field>";
+        assert_eq!(types(&svd(same)), ["CTRL"]);
+        let other = "<field><name>SIZE</name><bitRange>[7:4]</bitRange></field>";
+        assert_eq!(types(&svd(other)), ["CTRL", "CH_CTRL"]);
+    }
+
+    #[test]
+    fn svd_nested_cluster_types() {
+        let cluster = |name: &str, register: &str| {
+            format!(
+                r#"<cluster>
+          <name>{name}</name>
+          <addressOffset>0x0</addressOffset>
+          <cluster>
+            <name>CFG</name>
+            <addressOffset>0x0</addressOffset>
+            <register>
+              <name>{register}</name>
+              <addressOffset>0x0</addressOffset>
+            </register>
+          </cluster>
+        </cluster>"#
+            )
+        };
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<device schemaVersion="1.3" xmlns:xs="http://www.w3.org/2001/XMLSchema-instance">
+  <name>Types</name>
+  <addressUnitBits>8</addressUnitBits>
+  <width>32</width>
+  <size>32</size>
+  <peripherals>
+    <peripheral>
+      <name>DMA0</name>
+      <baseAddress>0x40000000</baseAddress>
+      <registers>
+        {}
+        {}
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>"#,
+            cluster("RX", "SRC"),
+            cluster("TX", "DST"),
+        );
+
+        // The second CFG has other registers, so it doesn't share the type of the first one.
+        let soc: mmio::Platform = svd_parser::parse(&xml).unwrap().try_into().unwrap();
+        let device = &soc.devices[0];
+        let clusters: Vec<_> = device
+            .all_clusters()
+            .iter()
+            .map(|c| c.type_.clone())
+            .collect();
+        assert_eq!(clusters, ["CFG", "RX", "TX_CFG", "TX"]);
+        let registers: Vec<_> = device
+            .all_registers()
+            .iter()
+            .map(|r| r.info[0].type_.clone())
+            .collect();
+        assert_eq!(registers, ["SRC", "DST"]);
+    }
+
+    #[allow(clippy::expect_fun_call)]
     pub fn compare_files(file_path1: &PathBuf, file_path2: &PathBuf) -> anyhow::Result<bool> {
         // Read the contents of the first file into a vector
         let contents1: Vec<_> = std::fs::read(file_path1)
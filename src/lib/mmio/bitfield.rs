@@ -2,16 +2,71 @@
 // Licensed under the Apache License, Version 2.0, see LICENSE for details.
 // SPDX-License-Identifier: Apache-2.0
 
+pub use crate::hjson;
+pub use crate::ipxact;
+pub use crate::mmio::EnumeratedValue;
 pub use crate::mmio::Permissions;
+pub use crate::mmio::ReadSideEffect;
+pub use crate::mmio::WriteSideEffect;
 pub use crate::rdljson;
+pub use crate::systemrdl;
 
-#[derive(Debug)]
+/// The names that can't be given to the accessor of a field: the members generated for every
+/// register and the keywords of Rust and C++.
+#[rustfmt::skip]
+const RESERVED: &[&str] = &[
+    "new", "fetch", "update", "commit", "try_commit", "write", "write_with_zero", "reset",
+    "read_and_clear", "read_with_side_effects", "reset_value", "reset_mask", "side_effect_mask",
+    "neutral_value", "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else",
+    "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+    "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true",
+    "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield", "auto", "bool",
+    "case", "catch", "char", "class", "default", "delete", "double", "float", "friend", "goto",
+    "inline", "int", "long", "namespace", "operator", "private", "protected", "public", "register",
+    "short", "signed", "sizeof", "switch", "template", "this", "throw", "typedef", "typename",
+    "union", "unsigned", "void", "volatile",
+];
+
+/// The name of the accessor of a field, or field array, in the generated code, i.e `ctrl` for
+/// `CTRL` and `reset_field` for `RESET`.
+fn accessor(name: &str) -> String {
+    let name = name.to_lowercase();
+    match RESERVED.contains(&name.as_str()) {
+        true => format!("{name}_field"),
+        false => name,
+    }
+}
+
+/// Position of a bitfield within a field array, i.e `PIN%s` with `dim` 8.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldArray {
+    // The name of the array without the placeholder, i.e `PIN`.
+    pub name: String,
+    pub index: u32,
+    pub dim: u32,
+    // The distance in bits between two elements.
+    pub stride: u32,
+}
+
+impl FieldArray {
+    pub fn accessor(&self) -> String {
+        accessor(&self.name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub struct Bitfield {
     pub name: String,
     pub desc: String,
     pub bit_size: u32,
     pub offset: u32,
     pub permissions: Permissions,
+    pub enumerated_values: Vec<EnumeratedValue>,
+    pub reset_value: u64,
+    pub write_effect: WriteSideEffect,
+    pub read_effect: ReadSideEffect,
+    pub array: Option<FieldArray>,
 }
 
 impl Bitfield {
@@ -28,10 +83,77 @@ impl Bitfield {
             offset,
             bit_size,
             permissions,
+            enumerated_values: vec![],
+            reset_value: 0,
+            write_effect: WriteSideEffect::default(),
+            read_effect: ReadSideEffect::default(),
+            array: None,
         }
     }
 }
 
+impl Bitfield {
+    pub fn mask(&self) -> u64 {
+        if self.bit_size == 0 {
+            return 0;
+        }
+        (u64::MAX >> (64 - self.bit_size.min(64))) << self.offset
+    }
+
+    pub fn accessor(&self) -> String {
+        accessor(&self.name)
+    }
+
+    /// The name shared by all the elements of a field array, or the field name otherwise. Used to
+    /// name the types generated for the field, i.e its enumerated values.
+    pub fn type_name(&self) -> &str {
+        self.array.as_ref().map_or(&self.name, |array| &array.name)
+    }
+
+    /// Whether this is the first element of a field array.
+    pub fn is_array_head(&self) -> bool {
+        self.array.as_ref().is_some_and(|array| array.index == 0)
+    }
+
+    /// Whether the types generated for this field are declared by it, which is false for all but
+    /// the first element of a field array.
+    pub fn owns_type(&self) -> bool {
+        self.array.as_ref().is_none_or(|array| array.index == 0)
+    }
+
+    /// Expand a SVD field into its elements, one for single fields.
+    pub fn from_field(field: &svd_rs::field::Field) -> Vec<Self> {
+        let (info, dim) = match field {
+            svd_rs::field::Field::Single(info) => return vec![info.into()],
+            svd_rs::field::Field::Array(info, dim) => (info, dim),
+        };
+        // i.e `MODE_%s` becomes `MODE`.
+        let name = info
+            .name
+            .replace("[%s]", "")
+            .replace("%s", "")
+            .trim_end_matches('_')
+            .to_string();
+        svd_rs::array::names(info, dim)
+            .zip(svd_rs::array::descriptions(info, dim))
+            .enumerate()
+            .map(|(index, (element, desc))| {
+                let mut this = Self::from(info);
+                this.name = element;
+                this.desc = desc.unwrap_or(this.name.clone());
+                this.offset += index as u32 * dim.dim_increment;
+                this.array = Some(FieldArray {
+                    name: name.clone(),
+                    index: index as u32,
+                    dim: dim.dim,
+                    stride: dim.dim_increment,
+                });
+                this
+            })
+            .collect()
+    }
+}
+
 impl Default for Bitfield {
     fn default() -> Self {
         Self::new(
@@ -46,36 +168,109 @@ impl Default for Bitfield {
 
 impl From<&svd_rs::field::FieldInfo> for Bitfield {
     fn from(field: &svd_rs::field::FieldInfo) -> Self {
-        Self::new(
+        let mut this = Self::new(
             field.name.clone(),
             field.description.clone().unwrap_or(field.name.clone()),
             field.bit_range.width,
             field.bit_range.offset,
             Permissions::from(field.access.unwrap_or_default()),
-        )
-    }
-}
-
-impl TryFrom<&svd_rs::field::Field> for Bitfield {
-    type Error = String;
-    fn try_from(field: &svd_rs::field::Field) -> Result<Self, Self::Error> {
-        Ok(Self::from(match field {
-            svd_rs::field::Field::Single(info) => info,
-            svd_rs::field::Field::Array(_, _) => {
-                return Err("Field Array not supported".to_string());
+        );
+        this.write_effect = field.modified_write_values.unwrap_or_default().into();
+        this.read_effect = field.read_action.map(Into::into).unwrap_or_default();
+        // The same values may be declared for both the read and write usages, so only the first
+        // occurrence of each name or value is kept. Default values (`isDefault`) are skipped.
+        for value in field.enumerated_values.iter().flat_map(|e| e.values.iter()) {
+            let Ok(value) = EnumeratedValue::try_from(value) else {
+                continue;
+            };
+            if !this
+                .enumerated_values
+                .iter()
+                .any(|v| v.name == value.name || v.value == value.value)
+            {
+                this.enumerated_values.push(value);
             }
-        }))
+        }
+        this
     }
 }
 
 impl From<&rdljson::RegisterField> for Bitfield {
     fn from(field: &rdljson::RegisterField) -> Self {
-        Self::new(
+        let mut this = Self::new(
             field.name.clone(),
             field.desc.clone(),
             field.msb - field.lsb + 1,
             field.lsb,
             Permissions::from(field),
-        )
+        );
+        this.enumerated_values = field.encodings.iter().map(|e| e.into()).collect();
+        this.write_effect = field.into();
+        this.read_effect = field.into();
+        this
+    }
+}
+
+impl TryFrom<&hjson::Field> for Bitfield {
+    type Error = String;
+    /// The field must have its name and software access resolved, see
+    /// [`hjson::Register::resolved_fields`].
+    fn try_from(field: &hjson::Field) -> Result<Self, Self::Error> {
+        let name = field.name.clone().unwrap_or_default();
+        let access = field
+            .swaccess
+            .ok_or(format!("Field {name} has no swaccess"))?;
+        let (offset, bit_size) = field.bit_range()?;
+        let mut this = Self::new(
+            name.clone(),
+            field.desc.clone().unwrap_or(name.clone()),
+            bit_size,
+            offset,
+            access
+                .try_into()
+                .map_err(|e| format!("{e} in field {name}"))?,
+        );
+        this.enumerated_values = field
+            .enum_
+            .iter()
+            .map(EnumeratedValue::try_from)
+            .collect::<Result<_, _>>()?;
+        this.write_effect = access.into();
+        this.read_effect = access.into();
+        Ok(this)
+    }
+}
+
+impl From<&systemrdl::Field> for Bitfield {
+    fn from(field: &systemrdl::Field) -> Self {
+        let name = field.name.to_uppercase();
+        let mut this = Self::new(
+            name.clone(),
+            field.desc.clone().unwrap_or(name),
+            field.width,
+            field.lsb,
+            field.sw.into(),
+        );
+        this.enumerated_values = field.encode.iter().map(|e| e.into()).collect();
+        this.write_effect = field.into();
+        this.read_effect = field.into();
+        this
+    }
+}
+
+impl From<&ipxact::Field> for Bitfield {
+    fn from(field: &ipxact::Field) -> Self {
+        let name = field.name.to_uppercase();
+        let mut this = Self::new(
+            name.clone(),
+            field.desc.clone().unwrap_or(name),
+            field.width,
+            field.offset,
+            field.access.into(),
+        );
+        this.enumerated_values = field.enumerated_values.iter().map(|e| e.into()).collect();
+        this.write_effect = field.modified_write.map(|m| m.into()).unwrap_or_default();
+        this.read_effect = field.read_action.map(|r| r.into()).unwrap_or_default();
+        this
     }
 }
@@ -0,0 +1,244 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::ipxact;
+pub use crate::mmio::Register;
+pub use crate::systemrdl;
+use svd_rs::cluster;
+use svd_rs::registercluster::RegisterCluster;
+
+/// The instances of a dimensioned cluster, i.e `CH%s` with `dim` 8.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterArray {
+    pub dim: u32,
+    // The distance in bytes between two instances.
+    pub increment: u32,
+}
+
+/// A group of registers and clusters, like the channel of a DMA.
+#[derive(Debug, PartialEq)]
+pub struct Cluster {
+    // The name of the cluster without the placeholder, i.e `CH`.
+    pub name: String,
+    pub type_: String,
+    pub desc: String,
+    pub offset: u32,
+    pub array: Option<ClusterArray>,
+    pub registers: Vec<Register>,
+    pub clusters: Vec<Cluster>,
+}
+
+impl Cluster {
+    pub fn new(
+        name: impl Into<String>,
+        type_name: Option<String>,
+        desc: Option<String>,
+        offset: u32,
+    ) -> Self {
+        let name = Self::strip_placeholder(&name.into());
+        Self {
+            type_: type_name.map_or(name.clone(), |t| Self::strip_placeholder(&t)),
+            desc: desc.unwrap_or(name.clone()),
+            name,
+            offset,
+            array: None,
+            registers: vec![],
+            clusters: vec![],
+        }
+    }
+
+    /// The name of a cluster without the array placeholder, i.e `CH%s` or `CH[%s]` becomes `CH`.
+    /// The offset after the last byte of the last instance, from the offset of its parent.
+    pub fn end(&self) -> u32 {
+        let size = self
+            .registers
+            .iter()
+            .map(Register::end)
+            .chain(self.clusters.iter().map(Cluster::end))
+            .max()
+            .unwrap_or(0);
+        let last = self
+            .array
+            .as_ref()
+            .map_or(0, |array| (array.dim - 1) * array.increment);
+        self.offset + last + size
+    }
+
+    pub fn strip_placeholder(name: &str) -> String {
+        name.replace("[%s]", "")
+            .replace("%s", "")
+            .trim_end_matches('_')
+            .to_string()
+    }
+
+    /// This cluster and the clusters within it, the inner ones first.
+    pub fn flatten(&self) -> Vec<&Cluster> {
+        let mut res: Vec<_> = self.clusters.iter().flat_map(|c| c.flatten()).collect();
+        res.push(self);
+        res
+    }
+
+    /// Whether two clusters have the same registers and inner clusters, so they can share a type.
+    pub fn same_layout(&self, other: &Cluster) -> bool {
+        self.registers == other.registers && self.clusters == other.clusters
+    }
+
+    /// The types of this cluster, its registers and the inner clusters, in this order. Each one is
+    /// prefixed with the type of its parent when `scope` already has one with the same name but
+    /// another layout.
+    pub fn scoped_types<'a>(
+        &'a self,
+        parent: Option<&str>,
+        scope: &mut TypeScope<'a>,
+    ) -> Result<(), String> {
+        let scoped = parent.map(|parent| format!("{parent}_{}", self.type_));
+        let type_ = std::iter::once(self.type_.clone())
+            .chain(scoped)
+            .find(|name| {
+                scope
+                    .clusters
+                    .iter()
+                    .all(|(other, c)| other != name || c.same_layout(self))
+            })
+            .ok_or(format!("Cluster type {} with several layouts", self.type_))?;
+        scope.clusters.push((type_.clone(), self));
+        scope.types.push(type_.clone());
+
+        for register in &self.registers {
+            let name = &register.info[0].type_;
+            let scoped = [name.clone(), format!("{type_}_{name}")]
+                .into_iter()
+                .find(|name| {
+                    scope
+                        .registers
+                        .iter()
+                        .all(|(other, r)| other != name || r.same_layout(register))
+                })
+                .ok_or(format!("Register type {type_}_{name} with several layouts"))?;
+            scope.registers.push((scoped.clone(), register));
+            scope.types.push(scoped);
+        }
+        for cluster in &self.clusters {
+            cluster.scoped_types(Some(&type_), scope)?;
+        }
+        Ok(())
+    }
+
+    /// Set the types computed by [`Self::scoped_types`], in the same order.
+    pub fn set_types(&mut self, types: &mut impl Iterator<Item = String>) {
+        let Some(type_) = types.next() else {
+            return;
+        };
+        self.type_ = type_;
+        for register in &mut self.registers {
+            let Some(type_) = types.next() else {
+                return;
+            };
+            for info in &mut register.info {
+                info.type_ = type_.clone();
+            }
+        }
+        for cluster in &mut self.clusters {
+            cluster.set_types(types);
+        }
+    }
+}
+
+/// The register and cluster types of a device, they share a namespace.
+#[derive(Default)]
+pub struct TypeScope<'a> {
+    pub registers: Vec<(String, &'a Register)>,
+    pub clusters: Vec<(String, &'a Cluster)>,
+    // The types given by `Cluster::scoped_types`, in the order they were visited.
+    pub types: Vec<String>,
+}
+
+impl TryFrom<&cluster::Cluster> for Cluster {
+    type Error = String;
+    fn try_from(cluster: &cluster::Cluster) -> Result<Self, Self::Error> {
+        let (info, array) = match cluster {
+            cluster::Cluster::Single(info) => (info, None),
+            cluster::Cluster::Array(info, dim) => (
+                info,
+                Some(ClusterArray {
+                    dim: dim.dim,
+                    increment: dim.dim_increment,
+                }),
+            ),
+        };
+
+        if info.children.is_empty() {
+            return Err(format!("Empty cluster {}", info.name));
+        }
+
+        let mut this = Self::new(
+            info.name.clone(),
+            info.header_struct_name.clone(),
+            info.description.clone(),
+            info.address_offset,
+        );
+        this.array = array;
+        for children in &info.children {
+            match children {
+                RegisterCluster::Register(register) => this.registers.push(register.try_into()?),
+                RegisterCluster::Cluster(cluster) => this.clusters.push(cluster.try_into()?),
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl TryFrom<&systemrdl::RegFile> for Cluster {
+    type Error = String;
+    fn try_from(regfile: &systemrdl::RegFile) -> Result<Self, Self::Error> {
+        let mut this = Self::new(
+            regfile.name.to_uppercase(),
+            None,
+            regfile.desc.clone(),
+            regfile.offset,
+        );
+        this.array = regfile.array.as_ref().map(|array| ClusterArray {
+            dim: array.dim,
+            increment: array.stride,
+        });
+        this.registers = regfile
+            .registers
+            .iter()
+            .map(Register::try_from)
+            .collect::<Result<_, _>>()?;
+        this.clusters = regfile
+            .regfiles
+            .iter()
+            .map(Cluster::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(this)
+    }
+}
+
+impl TryFrom<&ipxact::RegisterFile> for Cluster {
+    type Error = String;
+    fn try_from(file: &ipxact::RegisterFile) -> Result<Self, Self::Error> {
+        let mut this = Self::new(
+            file.name.to_uppercase(),
+            None,
+            file.desc.clone(),
+            file.offset,
+        );
+        this.array = file.array.as_ref().map(|array| ClusterArray {
+            dim: array.dim,
+            increment: array.stride,
+        });
+        this.registers = file
+            .registers
+            .iter()
+            .map(Register::try_from)
+            .collect::<Result<_, _>>()?;
+        this.clusters = file
+            .register_files
+            .iter()
+            .map(Cluster::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(this)
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::mmio::Device;
+use svd_rs::{DeriveFrom, Field, Peripheral, PeripheralInfo, RegisterCluster};
+
+/// Resolve the `derivedFrom` of the peripherals, registers, clusters and fields of a SVD device,
+/// so the conversion into the IR doesn't need to follow them.
+///
+/// The elements declared by a derived element override the inherited ones with the same name, i.e
+/// a derived register that re-declares a field. A derived peripheral that doesn't declare registers
+/// shares the type of its base, otherwise it gets its own type named after it, which is recorded
+/// in the `headerStructName`.
+///
+/// The registers without a `size` also get the one of their closest cluster, peripheral or device
+/// that declares it.
+pub fn resolve(device: &svd_rs::Device) -> Result<svd_rs::Device, String> {
+    let mut device = device.clone();
+    // The elements are resolved in passes until nothing changes, an element waits for the next
+    // pass while its base is derived itself.
+    loop {
+        let mut resolver = Resolver {
+            peripherals: device.peripherals.clone(),
+            pending: vec![],
+            progress: false,
+        };
+        for peripheral in device.peripherals.iter_mut() {
+            resolver.peripheral(peripheral)?;
+        }
+        if !resolver.progress {
+            if resolver.pending.is_empty() {
+                inherit_sizes(&mut device);
+                return Ok(device);
+            }
+            return Err(format!(
+                "Circular derivedFrom in {}",
+                resolver.pending.join(", ")
+            ));
+        }
+    }
+}
+
+fn inherit_sizes(device: &mut svd_rs::Device) {
+    let size = device.default_register_properties.size;
+    for peripheral in device.peripherals.iter_mut() {
+        let size = peripheral.default_register_properties.size.or(size);
+        if let Some(registers) = peripheral.registers.as_mut() {
+            inherit_block_sizes(registers, size);
+        }
+    }
+}
+
+fn inherit_block_sizes(block: &mut [RegisterCluster], size: Option<u32>) {
+    for element in block.iter_mut() {
+        match element {
+            RegisterCluster::Register(register) => {
+                register.properties.size = register.properties.size.or(size);
+            }
+            RegisterCluster::Cluster(cluster) => {
+                let size = cluster.default_register_properties.size.or(size);
+                inherit_block_sizes(&mut cluster.children, size);
+            }
+        }
+    }
+}
+
+struct Resolver {
+    // The peripherals as they were at the beginning of the pass.
+    peripherals: Vec<Peripheral>,
+    pending: Vec<String>,
+    progress: bool,
+}
+
+impl Resolver {
+    fn peripheral(&mut self, peripheral: &mut Peripheral) -> Result<(), String> {
+        let Some(base_name) = peripheral.derived_from.clone() else {
+            let name = peripheral.name.clone();
+            return match peripheral.registers.as_mut() {
+                Some(registers) => self.block(registers, &name),
+                None => Ok(()),
+            };
+        };
+
+        let base = self.find_peripheral(&base_name).ok_or(format!(
+            "Peripheral {} derived from unknown {}",
+            peripheral.name, base_name
+        ))?;
+        if base.derived_from.is_some() {
+            self.pending.push(peripheral.name.clone());
+            return Ok(());
+        }
+
+        let info: &mut PeripheralInfo = peripheral;
+        if let Some(ref registers) = info.registers {
+            let mut derived = info.derive_from(base);
+            derived.registers = Some(merge(
+                base.registers.as_deref().unwrap_or_default(),
+                registers,
+                register_cluster_name,
+            ));
+            derived.header_struct_name =
+                info.header_struct_name.clone().or(Some(info.name.clone()));
+            derived.interrupt = info.interrupt.clone();
+            *info = derived;
+        } else {
+            info.header_struct_name = info
+                .header_struct_name
+                .clone()
+                .or(base.header_struct_name.clone())
+                .or(Some(Device::get_type(&base.name)));
+        }
+        info.derived_from = None;
+        self.progress = true;
+        Ok(())
+    }
+
+    fn block(&mut self, children: &mut [RegisterCluster], peripheral: &str) -> Result<(), String> {
+        let siblings = children.to_vec();
+        for child in children.iter_mut() {
+            match child {
+                RegisterCluster::Register(register) => {
+                    if let Some(ref path) = register.derived_from {
+                        match self.find(path, &siblings, peripheral) {
+                            Some(RegisterCluster::Register(base))
+                                if base.derived_from.is_none() =>
+                            {
+                                let mut derived = register.derive_from(&base);
+                                if let (Some(fields), Some(inherited)) =
+                                    (&register.fields, &base.fields)
+                                {
+                                    derived.fields = Some(merge(inherited, fields, field_name));
+                                }
+                                derived.derived_from = None;
+                                *register = derived;
+                                self.progress = true;
+                            }
+                            Some(RegisterCluster::Register(_)) => {
+                                self.pending
+                                    .push(format!("{}.{}", peripheral, register.name));
+                            }
+                            _ => {
+                                return Err(format!(
+                                    "Register {} derived from unknown register {}",
+                                    register.name, path
+                                ));
+                            }
+                        }
+                    }
+                    if let Some(fields) = register.fields.as_mut() {
+                        self.fields(fields, &siblings, peripheral)?;
+                    }
+                }
+                RegisterCluster::Cluster(cluster) => {
+                    if let Some(ref path) = cluster.derived_from {
+                        match self.find(path, &siblings, peripheral) {
+                            Some(RegisterCluster::Cluster(base)) if base.derived_from.is_none() => {
+                                let mut derived = cluster.derive_from(&base);
+                                if cluster.children.is_empty() {
+                                    // Shares the type of the base.
+                                    derived.header_struct_name = cluster
+                                        .header_struct_name
+                                        .clone()
+                                        .or(base.header_struct_name.clone())
+                                        .or(Some(base.name.clone()));
+                                } else {
+                                    derived.children = merge(
+                                        &base.children,
+                                        &cluster.children,
+                                        register_cluster_name,
+                                    );
+                                }
+                                derived.derived_from = None;
+                                *cluster = derived;
+                                self.progress = true;
+                            }
+                            Some(RegisterCluster::Cluster(_)) => {
+                                self.pending
+                                    .push(format!("{}.{}", peripheral, cluster.name));
+                            }
+                            _ => {
+                                return Err(format!(
+                                    "Cluster {} derived from unknown cluster {}",
+                                    cluster.name, path
+                                ));
+                            }
+                        }
+                    }
+                    self.block(&mut cluster.children, peripheral)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The fields can derive from a sibling, `FIELD`, or from a field in another register,
+    // `REGISTER.FIELD`.
+    fn fields(
+        &mut self,
+        fields: &mut [Field],
+        registers: &[RegisterCluster],
+        peripheral: &str,
+    ) -> Result<(), String> {
+        let siblings = fields.to_vec();
+        for field in fields.iter_mut() {
+            let Some(ref path) = field.derived_from else {
+                continue;
+            };
+            let base = match path.rsplit_once('.') {
+                None => siblings.iter().find(|f| &f.name == path).cloned(),
+                Some((register, name)) => match self.find(register, registers, peripheral) {
+                    Some(RegisterCluster::Register(register)) => register
+                        .fields
+                        .iter()
+                        .flatten()
+                        .find(|f| f.name == name)
+                        .cloned(),
+                    _ => None,
+                },
+            };
+            match base {
+                Some(base) if base.derived_from.is_none() => {
+                    let mut derived = field.derive_from(&base);
+                    derived.derived_from = None;
+                    *field = derived;
+                    self.progress = true;
+                }
+                Some(_) => self.pending.push(format!("{}.{}", peripheral, field.name)),
+                None => {
+                    return Err(format!(
+                        "Field {} derived from unknown field {}",
+                        field.name, path
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn find_peripheral(&self, name: &str) -> Option<&Peripheral> {
+        self.peripherals.iter().find(|p| p.name == name)
+    }
+
+    // Look for a register or cluster by its name among the siblings, or by its path, i.e
+    // `CLUSTER.REGISTER`, from the current peripheral or from the device, i.e
+    // `PERIPHERAL.REGISTER`.
+    fn find(
+        &self,
+        path: &str,
+        siblings: &[RegisterCluster],
+        peripheral: &str,
+    ) -> Option<RegisterCluster> {
+        let path: Vec<_> = path.split('.').collect();
+        if let [name] = path[..] {
+            return siblings
+                .iter()
+                .find(|rc| register_cluster_name(rc) == name)
+                .cloned();
+        }
+        let registers = |name: &str| {
+            self.find_peripheral(name)
+                .and_then(|p| p.registers.as_deref())
+        };
+        registers(peripheral)
+            .and_then(|children| find_in(children, &path))
+            .or_else(|| registers(path[0]).and_then(|children| find_in(children, &path[1..])))
+            .cloned()
+    }
+}
+
+fn find_in<'a>(children: &'a [RegisterCluster], path: &[&str]) -> Option<&'a RegisterCluster> {
+    let (name, path) = path.split_first()?;
+    let found = children
+        .iter()
+        .find(|rc| register_cluster_name(rc) == *name)?;
+    match (found, path.is_empty()) {
+        (_, true) => Some(found),
+        (RegisterCluster::Cluster(cluster), false) => find_in(&cluster.children, path),
+        (RegisterCluster::Register(_), false) => None,
+    }
+}
+
+// The inherited elements, replaced by the derived ones with the same name, followed by the new
+// ones.
+fn merge<T: Clone>(inherited: &[T], derived: &[T], name: fn(&T) -> &str) -> Vec<T> {
+    let mut res = inherited.to_vec();
+    for element in derived {
+        match res.iter_mut().find(|e| name(e) == name(element)) {
+            Some(found) => *found = element.clone(),
+            None => res.push(element.clone()),
+        }
+    }
+    res
+}
+
+fn register_cluster_name(rc: &RegisterCluster) -> &str {
+    match rc {
+        RegisterCluster::Register(register) => &register.name,
+        RegisterCluster::Cluster(cluster) => &cluster.name,
+    }
+}
+
+fn field_name(field: &Field) -> &str {
+    &field.name
+}
@@ -2,14 +2,28 @@
 // Licensed under the Apache License, Version 2.0, see LICENSE for details.
 // SPDX-License-Identifier: Apache-2.0
 
+pub use crate::hjson;
+pub use crate::ipxact;
+pub use crate::mmio::Bitfield;
+pub use crate::mmio::Cluster;
+pub use crate::mmio::FieldArray;
+pub use crate::mmio::Parameter;
 pub use crate::mmio::Register;
+pub use crate::mmio::RegisterArray;
+pub use crate::mmio::TypeScope;
+pub use crate::mmio::Window;
+pub use crate::mmio::WriteEnable;
 pub use crate::rdljson;
+pub use crate::systemrdl;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Device {
     pub name: String,
     pub type_: String,
     pub registers: Vec<Register>,
+    pub clusters: Vec<Cluster>,
+    pub windows: Vec<Window>,
+    pub parameters: Vec<Parameter>,
 }
 
 impl Device {
@@ -18,9 +32,166 @@ impl Device {
             name: name.into(),
             type_: type_name.into(),
             registers: vec![],
+            clusters: vec![],
+            windows: vec![],
+            parameters: vec![],
         }
     }
 
+    /// Whether the device has neither registers, clusters nor windows.
+    pub fn is_empty(&self) -> bool {
+        self.registers.is_empty() && self.clusters.is_empty() && self.windows.is_empty()
+    }
+
+    /// The size in bytes of the address space spanned by the registers, clusters and windows.
+    pub fn size(&self) -> u32 {
+        self.registers
+            .iter()
+            .map(Register::end)
+            .chain(self.clusters.iter().map(Cluster::end))
+            .chain(self.windows.iter().map(|w| w.offset + w.size()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether two devices have the same registers, clusters, windows and parameters, regardless
+    /// of the instance they were named after.
+    pub fn same_layout(&self, other: &Device) -> bool {
+        self.registers == other.registers
+            && self.clusters == other.clusters
+            && self.windows == other.windows
+            && self.parameters == other.parameters
+    }
+
+    /// The status fields of the errors of the shadowed registers, the ones named `*UPDATE_ERR`
+    /// or `*STORAGE_ERR`, i.e `RECOV_ERR_CODE.SHADOW_UPDATE_ERR`. Only the single bit fields that
+    /// can be fetched are considered.
+    pub fn shadow_errors(&self) -> Vec<(&Register, &Bitfield)> {
+        if !self.registers.iter().any(|r| r.shadowed) {
+            return vec![];
+        }
+        self.registers
+            .iter()
+            .filter(|r| r.array.is_none() && r.info.len() == 1 && !r.has_read_effect())
+            .flat_map(|r| r.bitfields.iter().map(move |f| (r, f)))
+            .filter(|(_, f)| {
+                let name = f.name.to_uppercase();
+                f.bit_size == 1
+                    && f.permissions.is_readable()
+                    && (name.ends_with("UPDATE_ERR") || name.ends_with("STORAGE_ERR"))
+            })
+            .collect()
+    }
+
+    /// The registers gated by a write enable, each one with the register and the field of its
+    /// write enable. Only the write enables whose field is a single bit that can be fetched are
+    /// considered.
+    pub fn write_locks(&self) -> Vec<(&Register, &Register, &Bitfield)> {
+        self.registers
+            .iter()
+            .filter(|r| r.array.is_none() && r.info.len() == 1 && r.is_writable())
+            .filter_map(|r| {
+                let enable = r.write_enable.as_ref()?;
+                let lock = self.find_register(&enable.register)?;
+                let field = lock.bitfields.iter().find(|f| f.name == enable.field)?;
+                (lock.array.is_none()
+                    && !lock.has_read_effect()
+                    && field.bit_size == 1
+                    && field.permissions.is_readable())
+                .then_some((r, lock, field))
+            })
+            .collect()
+    }
+
+    /// Check that the write enables exist and default to the first field of their register, i.e
+    /// `EN`.
+    fn resolve_write_enables(&mut self) {
+        let locks: Vec<_> = self
+            .registers
+            .iter()
+            .map(|r| {
+                let enable = r.write_enable.as_ref()?;
+                let Some(lock) = self.find_register(&enable.register) else {
+                    println!(
+                        "Warning: Write enable {} of {} not found in {}, skipping.",
+                        enable.register, r.info[0].name, self.name
+                    );
+                    return None;
+                };
+                let field = match enable.field.is_empty() {
+                    true => lock.bitfields.first()?.name.clone(),
+                    false => enable.field.clone(),
+                };
+                Some(WriteEnable {
+                    register: lock.info[0].name.clone(),
+                    field,
+                })
+            })
+            .collect();
+        for (register, lock) in self.registers.iter_mut().zip(locks) {
+            register.write_enable = lock;
+        }
+    }
+
+    fn find_register(&self, name: &str) -> Option<&Register> {
+        self.registers
+            .iter()
+            .find(|r| r.info[0].name.eq_ignore_ascii_case(name))
+    }
+
+    /// All the cluster types of the device, including the nested ones, the inner ones first.
+    /// Clusters derived from another one share its type, so only the first of each is returned.
+    pub fn all_clusters(&self) -> Vec<&Cluster> {
+        let mut res: Vec<&Cluster> = vec![];
+        for cluster in self.clusters.iter().flat_map(|c| c.flatten()) {
+            if !res.iter().any(|c| c.type_ == cluster.type_) {
+                res.push(cluster);
+            }
+        }
+        res
+    }
+
+    /// All the register types of the device, including the ones within clusters.
+    pub fn all_registers(&self) -> Vec<&Register> {
+        let mut res: Vec<&Register> = vec![];
+        let clusters = self.all_clusters();
+        for register in self
+            .registers
+            .iter()
+            .chain(clusters.into_iter().flat_map(|c| c.registers.iter()))
+        {
+            if !res
+                .iter()
+                .any(|r| r.info[0].type_ == register.info[0].type_)
+            {
+                res.push(register);
+            }
+        }
+        res
+    }
+
+    /// Give the clusters and their registers their own type, prefixed with the one of their
+    /// parent cluster, i.e `CH_CTRL` for `CH[%s].CTRL`, when another one of the device has the
+    /// same type but not the same layout, as all the types of a device share a namespace.
+    pub fn scope_cluster_types(&mut self) -> Result<(), String> {
+        let mut scope = TypeScope {
+            registers: self
+                .registers
+                .iter()
+                .map(|r| (r.info[0].type_.clone(), r))
+                .collect(),
+            ..Default::default()
+        };
+        for cluster in &self.clusters {
+            cluster.scoped_types(None, &mut scope)?;
+        }
+        let mut types = scope.types.into_iter();
+        for cluster in &mut self.clusters {
+            cluster.set_types(&mut types);
+        }
+        Ok(())
+    }
+
     pub fn get_type(s: &str) -> String {
         let re = regex::Regex::new(r"\d+$").unwrap();
         // Remove trailing digits
@@ -41,7 +212,10 @@ impl TryFrom<&svd_rs::peripheral::PeripheralInfo> for Device {
         let device_name = periph.name.replace(" ", "_").to_uppercase();
 
         // i.e UART
-        let device_type = Device::get_type(periph.derived_from.as_ref().unwrap_or(&device_name));
+        let device_type = match periph.header_struct_name {
+            Some(ref name) => name.to_lowercase(),
+            None => Device::get_type(periph.derived_from.as_ref().unwrap_or(&device_name)),
+        };
 
         let mut device = Device::new(&device_name, &device_type);
         let Some(ref registers) = periph.registers else {
@@ -51,24 +225,32 @@ impl TryFrom<&svd_rs::peripheral::PeripheralInfo> for Device {
         for register_cluster in registers {
             match register_cluster {
                 svd_rs::registercluster::RegisterCluster::Register(register) => {
-                    device.registers.push(register.into())
+                    device.registers.push(
+                        register
+                            .try_into()
+                            .map_err(|e| format!("{e} in {device_name}"))?,
+                    )
                 }
                 svd_rs::registercluster::RegisterCluster::Cluster(cluster) => {
-                    match Register::try_from(cluster) {
-                        Ok(registers) => {
-                            device.registers.extend(registers);
+                    match Cluster::try_from(cluster) {
+                        Ok(cluster) => {
+                            device.clusters.push(cluster);
                         }
                         Err(msg) => println!("Warning: {} in {}, skipping.", msg, device_name),
                     }
                 }
             };
         }
+        device
+            .scope_cluster_types()
+            .map_err(|e| format!("{e} in {device_name}"))?;
         Ok(device)
     }
 }
 
-impl From<&rdljson::Peripheral> for Device {
-    fn from(periph: &rdljson::Peripheral) -> Self {
+impl TryFrom<&rdljson::Peripheral> for Device {
+    type Error = String;
+    fn try_from(periph: &rdljson::Peripheral) -> Result<Self, Self::Error> {
         // i.e UART0
         let device_name = periph.name.replace(" ", "_").to_uppercase();
 
@@ -76,15 +258,242 @@ impl From<&rdljson::Peripheral> for Device {
         let device_type = &periph.type_name;
 
         let mut device = Device::new(&device_name, device_type);
-        device.registers.extend(
-            periph
-                .interfaces
-                .iter()
-                .flat_map(|inter| inter.regs.iter())
-                .map(|reg| reg.into())
-                .collect::<Vec<_>>(),
+        for register in periph.interfaces.iter().flat_map(|inter| inter.regs.iter()) {
+            let register = register
+                .try_into()
+                .map_err(|e| format!("{e} in {device_name}"))?;
+            device.registers.push(register);
+        }
+        device.resolve_write_enables();
+        for window in periph
+            .interfaces
+            .iter()
+            .flat_map(|inter| inter.windows.iter())
+        {
+            let window = window
+                .try_into()
+                .map_err(|e| format!("{e} in {device_name}"))?;
+            device.windows.push(window);
+        }
+        for param in &periph.parameters {
+            match Parameter::try_from(param) {
+                Ok(param) => device.parameters.push(param),
+                Err(e) => println!("Warning: {e} in {device_name}, skipping."),
+            }
+        }
+
+        Ok(device)
+    }
+}
+
+impl TryFrom<&hjson::Ip> for Device {
+    type Error = String;
+    fn try_from(ip: &hjson::Ip) -> Result<Self, Self::Error> {
+        // i.e UART
+        let device_name = ip.name.to_uppercase();
+        let mut device = Device::new(&device_name, ip.name.to_lowercase());
+
+        let width = ip.regwidth()?;
+        let step = width / 8;
+        let mut offset = 0;
+        let auto_registers = ip.auto_registers()?.into_iter().map(hjson::Entry::Register);
+        for entry in auto_registers.chain(ip.entries()?) {
+            match entry {
+                hjson::Entry::Register(register) => {
+                    device
+                        .registers
+                        .push(hjson_register(&register, offset, width)?);
+                    offset += step;
+                }
+                hjson::Entry::Multireg(multireg) => {
+                    let registers = hjson_multireg(&multireg, offset, width, &ip.param_list)?;
+                    offset += step * registers.iter().map(|r| r.info.len() as u32).sum::<u32>();
+                    device.registers.extend(registers);
+                }
+                hjson::Entry::Window(window) => {
+                    let entries = window.items.value(&ip.param_list)? as u32;
+                    // Reggen aligns the windows to their size rounded up to a power of two.
+                    let size = entries * step;
+                    offset = offset.next_multiple_of(size.next_power_of_two());
+                    device.windows.push(Window {
+                        name: window.name.to_uppercase(),
+                        offset,
+                        entries,
+                        width,
+                        access: window
+                            .swaccess
+                            .unwrap_or(hjson::SwAccess::Rw)
+                            .try_into()
+                            .map_err(|e| format!("{e} in window {}", window.name))?,
+                    });
+                    offset += size;
+                }
+                hjson::Entry::Skipto(to) => offset = to.value(&ip.param_list)? as u32,
+                hjson::Entry::Reserved(count) => {
+                    offset += step * count.value(&ip.param_list)? as u32
+                }
+            }
+        }
+        device.resolve_write_enables();
+        for param in &ip.param_list {
+            match Parameter::try_from(param) {
+                Ok(param) => device.parameters.push(param),
+                Err(e) => println!("Warning: {e} in {device_name}, skipping."),
+            }
+        }
+        Ok(device)
+    }
+}
+
+impl TryFrom<&systemrdl::AddrMap> for Device {
+    type Error = String;
+    fn try_from(addrmap: &systemrdl::AddrMap) -> Result<Self, Self::Error> {
+        let mut device = Device::new(
+            addrmap.name.to_uppercase(),
+            addrmap.type_name.to_lowercase(),
         );
+        let in_device = |e| format!("{e} in {}", addrmap.name.to_uppercase());
+        device.registers = addrmap
+            .registers
+            .iter()
+            .map(Register::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(in_device)?;
+        device.clusters = addrmap
+            .regfiles
+            .iter()
+            .map(Cluster::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(in_device)?;
+        device
+            .scope_cluster_types()
+            .map_err(|e| format!("{e} in {}", device.name))?;
+        Ok(device)
+    }
+}
 
+impl TryFrom<&ipxact::Component> for Device {
+    type Error = String;
+    fn try_from(component: &ipxact::Component) -> Result<Self, Self::Error> {
+        let mut device = Device::new(component.name.to_uppercase(), component.name.to_lowercase());
+        let in_device = |e| format!("{e} in {}", component.name.to_uppercase());
+        device.registers = component
+            .registers
+            .iter()
+            .map(Register::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(in_device)?;
+        device.clusters = component
+            .register_files
+            .iter()
+            .map(Cluster::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(in_device)?;
+        device.windows = component
+            .memories
+            .iter()
+            .map(Window::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(in_device)?;
         device
+            .scope_cluster_types()
+            .map_err(|e| format!("{e} in {}", device.name))?;
+        Ok(device)
+    }
+}
+
+/// Convert a hjson register and place it in the register map.
+fn hjson_register(register: &hjson::Register, offset: u32, width: u32) -> Result<Register, String> {
+    let mut this = Register::try_from(register)?;
+    this.info[0].offset = offset;
+    let reset_value = this.reset_value;
+    this.set_width(width)?;
+    this.set_reset(reset_value, this.reset_mask);
+    Ok(this)
+}
+
+/// Expand a multireg into `count` instances of its fields. A single field is packed as a field
+/// array in as few registers as possible, unless `compact` is false. The registers become an
+/// array when they all have the same fields.
+fn hjson_multireg(
+    multireg: &hjson::Multireg,
+    offset: u32,
+    width: u32,
+    params: &[hjson::Param],
+) -> Result<Vec<Register>, String> {
+    let name = multireg.register.name.to_uppercase();
+    let count = multireg.count.value(params)? as u32;
+    if count == 0 {
+        return Err(format!("Multireg {name} has no instances"));
+    }
+    let fields = multireg.register.resolved_fields();
+    let (lsb, field_width) = match &fields[..] {
+        [field] => field.bit_range()?,
+        _ => (0, width),
+    };
+    if lsb + field_width > width {
+        return Err(format!(
+            "Multireg {name} field of {field_width} bits at bit {lsb} does not fit {width} bits"
+        ));
+    }
+    let per_register = match multireg.compact.as_ref().is_none_or(hjson::Flag::is_set) {
+        true if fields.len() == 1 => ((width - lsb) / field_width).clamp(1, count),
+        _ => 1,
+    };
+    let num_registers = count.div_ceil(per_register);
+
+    let mut registers = Vec::new();
+    for idx in 0..num_registers {
+        let instances = per_register.min(count - idx * per_register);
+        let mut register = multireg.register.clone();
+        register.name = match num_registers {
+            1 => name.clone(),
+            _ => format!("{name}{idx}"),
+        };
+        if per_register > 1 {
+            register.fields = (0..instances)
+                .map(|n| {
+                    let mut field = fields[0].clone();
+                    let lsb = lsb + n * field_width;
+                    let field_name = field.name.as_deref().unwrap_or_default();
+                    field.name = Some(format!("{field_name}_{n}"));
+                    field.bits = hjson::Number::Str(format!("{}:{}", lsb + field_width - 1, lsb));
+                    field
+                })
+                .collect();
+        }
+        let mut register = hjson_register(&register, offset + idx * width / 8, width)?;
+        if per_register > 1 {
+            for (n, bitfield) in register.bitfields.iter_mut().enumerate() {
+                bitfield.array = Some(FieldArray {
+                    name: fields[0].name.clone().unwrap_or_default(),
+                    index: n as u32,
+                    dim: instances,
+                    stride: field_width,
+                });
+            }
+        }
+        registers.push(register);
+    }
+
+    // The last register may have less instances of a packed field.
+    let uniform = registers
+        .iter()
+        .all(|r| r.bitfields.len() == registers[0].bitfields.len());
+    if registers.len() == 1 || !uniform {
+        return Ok(registers);
+    }
+    let mut array = registers.remove(0);
+    array.info[0].type_ = name.clone();
+    for register in registers {
+        let mut info = register.info.into_iter().next().unwrap();
+        info.type_ = name.clone();
+        array.info.push(info);
     }
+    array.array = Some(RegisterArray {
+        name,
+        dim: num_registers,
+        increment: width / 8,
+    });
+    Ok(vec![array])
 }
@@ -0,0 +1,78 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::hjson;
+pub use crate::ipxact;
+pub use crate::rdljson;
+pub use crate::systemrdl;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumeratedValue {
+    pub name: String,
+    pub desc: String,
+    pub value: u64,
+}
+
+impl EnumeratedValue {
+    pub fn new(name: impl Into<String>, desc: Option<String>, value: u64) -> Self {
+        let name = name.into();
+        Self {
+            desc: desc.unwrap_or(name.clone()),
+            // A Rust/C++ identifier can't start with a digit, i.e `1_5_CYCLES`.
+            name: if name.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("V{name}")
+            } else {
+                name
+            },
+            value,
+        }
+    }
+}
+
+impl TryFrom<&svd_rs::EnumeratedValue> for EnumeratedValue {
+    type Error = String;
+    fn try_from(value: &svd_rs::EnumeratedValue) -> Result<Self, Self::Error> {
+        let Some(num) = value.value else {
+            return Err(format!("Enumerated value {} has no value", value.name));
+        };
+        Ok(Self::new(
+            value.name.clone(),
+            value.description.clone(),
+            num,
+        ))
+    }
+}
+
+impl From<&rdljson::EnumMember> for EnumeratedValue {
+    fn from(member: &rdljson::EnumMember) -> Self {
+        Self::new(member.name.clone(), member.desc.clone(), member.value)
+    }
+}
+
+impl TryFrom<&hjson::EnumMember> for EnumeratedValue {
+    type Error = String;
+    fn try_from(member: &hjson::EnumMember) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            member.name.to_uppercase(),
+            member.desc.clone(),
+            member.value.value(&[])?,
+        ))
+    }
+}
+
+impl From<&systemrdl::EnumMember> for EnumeratedValue {
+    fn from(member: &systemrdl::EnumMember) -> Self {
+        Self::new(
+            member.name.to_uppercase(),
+            member.desc.clone(),
+            member.value,
+        )
+    }
+}
+
+impl From<&ipxact::EnumValue> for EnumeratedValue {
+    fn from(value: &ipxact::EnumValue) -> Self {
+        Self::new(value.name.to_uppercase(), value.desc.clone(), value.value)
+    }
+}
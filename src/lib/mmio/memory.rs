@@ -0,0 +1,55 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::mmio::Permissions;
+pub use crate::rdljson;
+
+/// A region of memory in the address space of the platform, like a SRAM or a ROM.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Memory {
+    pub name: String,
+    pub base: u64,
+    // The size in bytes.
+    pub size: u64,
+    // The width in bits of the words accessed in the region.
+    pub width: u32,
+    pub access: Permissions,
+}
+
+impl Memory {
+    /// The number of words in the region.
+    pub fn entries(&self) -> u64 {
+        self.size / (self.width / 8) as u64
+    }
+
+    /// Whether a SVD peripheral only describes buffers, like a SRAM, instead of registers.
+    pub fn is_svd_memory(info: &svd_rs::peripheral::PeripheralInfo) -> bool {
+        info.registers.is_none()
+            && info.address_block.as_ref().is_some_and(|blocks| {
+                !blocks.is_empty()
+                    && blocks
+                        .iter()
+                        .all(|block| block.usage == svd_rs::AddressBlockUsage::Buffer)
+            })
+    }
+}
+
+impl TryFrom<&rdljson::Memory> for Memory {
+    type Error = String;
+    fn try_from(memory: &rdljson::Memory) -> Result<Self, Self::Error> {
+        if ![8, 16, 32, 64].contains(&memory.width) {
+            return Err(format!(
+                "Memory {} has an unsupported width {}",
+                memory.name, memory.width
+            ));
+        }
+        Ok(Self {
+            name: memory.name.replace(" ", "_").to_uppercase(),
+            base: memory.offset as u64,
+            size: memory.size as u64,
+            width: memory.width as u32,
+            access: memory.try_into()?,
+        })
+    }
+}
@@ -3,17 +3,36 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod bitfield;
+pub mod cluster;
+pub mod derive;
 pub mod device;
+pub mod enumerated_value;
 pub mod interrupt;
+pub mod memory;
+pub mod parameter;
 pub mod permissions;
 pub mod platform;
 pub mod register;
+pub mod side_effect;
+pub mod window;
 
 pub use bitfield::Bitfield;
+pub use bitfield::FieldArray;
+pub use cluster::Cluster;
+pub use cluster::ClusterArray;
+pub use cluster::TypeScope;
 pub use device::Device;
+pub use enumerated_value::EnumeratedValue;
 pub use interrupt::Interrupt;
+pub use memory::Memory;
+pub use parameter::Parameter;
 pub use permissions::Permissions;
 pub use platform::Platform;
 pub use register::Register;
+pub use register::RegisterArray;
+pub use register::WriteEnable;
+pub use side_effect::ReadSideEffect;
+pub use side_effect::WriteSideEffect;
+pub use window::Window;
 
 static WIDTH: u32 = 32;
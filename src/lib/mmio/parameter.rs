@@ -0,0 +1,66 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::hjson;
+pub use crate::rdljson;
+
+/// A constant of the hardware configuration of a device, like the depth of a FIFO.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    // In upper snake case, i.e `RX_FIFO_DEPTH` for `RxFifoDepth`.
+    pub name: String,
+    pub desc: String,
+    pub value: u32,
+}
+
+impl Parameter {
+    pub fn new(name: &str, desc: Option<String>, value: u32) -> Self {
+        let mut upper = String::new();
+        let mut prev_lower = false;
+        for c in name.chars() {
+            if c.is_ascii_uppercase() && prev_lower {
+                upper.push('_');
+            }
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            upper.push(c.to_ascii_uppercase());
+        }
+        Self {
+            name: upper,
+            desc: desc.unwrap_or_default(),
+            value,
+        }
+    }
+}
+
+impl TryFrom<&rdljson::Parameter> for Parameter {
+    type Error = String;
+
+    fn try_from(param: &rdljson::Parameter) -> Result<Self, Self::Error> {
+        let value = param
+            .value
+            .as_u64()
+            .or_else(|| param.value.as_bool().map(u64::from))
+            .ok_or(format!(
+                "Parameter {} has a non integer value {}",
+                param.name, param.value
+            ))?;
+        let value = u32::try_from(value)
+            .map_err(|_| format!("Parameter {} value {} overflows", param.name, value))?;
+        Ok(Self::new(&param.name, param.desc.clone(), value))
+    }
+}
+
+impl TryFrom<&hjson::Param> for Parameter {
+    type Error = String;
+
+    fn try_from(param: &hjson::Param) -> Result<Self, Self::Error> {
+        let value = param
+            .default
+            .value(&[])
+            .map_err(|e| format!("Parameter {}: {e}", param.name))?;
+        let value = u32::try_from(value)
+            .map_err(|_| format!("Parameter {} value {} overflows", param.name, value))?;
+        Ok(Self::new(&param.name, param.desc.clone(), value))
+    }
+}
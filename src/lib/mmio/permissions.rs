@@ -1,7 +1,9 @@
 // Copyright (c) 2025 Douglas Reis.
 // Licensed under the Apache License, Version 2.0, see LICENSE for details.
 // SPDX-License-Identifier: Apache-2.0
+pub use crate::hjson;
 pub use crate::rdljson;
+pub use crate::systemrdl;
 use svd_rs::access as svd_rs;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, strum::Display, strum::IntoStaticStr)]
@@ -19,6 +21,11 @@ impl Permissions {
         self == Self::ReadWrite || self == Self::Read || self == Self::ReadWriteOnce
     }
 
+    /// Only the first write after a reset has effect on the hardware.
+    pub fn is_once(self) -> bool {
+        self == Self::WriteOnce || self == Self::ReadWriteOnce
+    }
+
     pub fn is_writable(self) -> bool {
         self == Self::ReadWrite
             || self == Self::WriteOnce
@@ -33,6 +40,8 @@ impl From<&str> for Permissions {
             "read-only" => Self::Read,
             "write-only" => Self::Write,
             "read-write" => Self::ReadWrite,
+            "writeOnce" => Self::WriteOnce,
+            "read-writeOnce" => Self::ReadWriteOnce,
             _ => panic!("{} unsuported", s),
         }
     }
@@ -60,3 +69,45 @@ impl From<&rdljson::RegisterField> for Permissions {
         }
     }
 }
+
+impl TryFrom<&rdljson::Memory> for Permissions {
+    type Error = String;
+    fn try_from(memory: &rdljson::Memory) -> Result<Self, Self::Error> {
+        match (memory.sw_writable, memory.sw_readable) {
+            (false, true) => Ok(Permissions::Read),
+            (true, true) => Ok(Permissions::ReadWrite),
+            (true, false) => Ok(Permissions::Write),
+            (false, false) => Err(format!(
+                "Memory {} is neither readable nor writable",
+                memory.name
+            )),
+        }
+    }
+}
+
+impl TryFrom<hjson::SwAccess> for Permissions {
+    type Error = String;
+    fn try_from(access: hjson::SwAccess) -> Result<Self, Self::Error> {
+        match access {
+            hjson::SwAccess::Ro | hjson::SwAccess::Rc => Ok(Permissions::Read),
+            hjson::SwAccess::Wo | hjson::SwAccess::R0w1c => Ok(Permissions::Write),
+            hjson::SwAccess::Rw
+            | hjson::SwAccess::Rw0c
+            | hjson::SwAccess::Rw1c
+            | hjson::SwAccess::Rw1s => Ok(Permissions::ReadWrite),
+            hjson::SwAccess::None => Err("The swaccess none has no software access".to_owned()),
+        }
+    }
+}
+
+impl From<systemrdl::SwAccess> for Permissions {
+    fn from(access: systemrdl::SwAccess) -> Self {
+        match access {
+            systemrdl::SwAccess::Read => Permissions::Read,
+            systemrdl::SwAccess::Write => Permissions::Write,
+            systemrdl::SwAccess::ReadWrite => Permissions::ReadWrite,
+            systemrdl::SwAccess::Write1 => Permissions::WriteOnce,
+            systemrdl::SwAccess::ReadWrite1 => Permissions::ReadWriteOnce,
+        }
+    }
+}
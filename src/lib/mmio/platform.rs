@@ -4,14 +4,21 @@
 
 pub use crate::mmio::Device;
 pub use crate::mmio::Interrupt;
+pub use crate::mmio::Memory;
+pub use crate::mmio::Permissions;
 pub use crate::mmio::Register;
+pub use crate::mmio::derive;
 
+pub use crate::devicetree;
+pub use crate::hjson;
+pub use crate::ipxact;
 pub use crate::rdljson;
+pub use crate::systemrdl;
 
 #[derive(Debug)]
 pub struct DeviceAddr {
     pub name: String,
-    pub address: String,
+    pub address: u64,
 }
 
 #[derive(Debug)]
@@ -28,13 +35,14 @@ pub struct Platform {
     //Define the number of data bit-width of the maximum single data transfer supported by the bus infrastructure
     pub bus_width: u32,
     pub devices: Vec<Device>,
+    pub memories: Vec<Memory>,
 }
 
 impl Platform {
     pub fn add_device_addr(&mut self, type_name: String, device_name: String, address: u64) {
         let new_device = DeviceAddr {
             name: device_name,
-            address: format!("{:#x}", address),
+            address,
         };
         if let Some(found) = self
             .device_types
@@ -54,51 +62,158 @@ impl Platform {
 impl TryFrom<svd_rs::device::Device> for Platform {
     type Error = String;
     fn try_from(svd_device: svd_rs::device::Device) -> Result<Self, Self::Error> {
+        let svd_device = derive::resolve(&svd_device)?;
         let mut this = Self {
             name: svd_device.name,
             device_types: Vec::new(),
             interrupts: Vec::new(),
             bus_width: svd_device.width,
             devices: Vec::new(),
+            memories: Vec::new(),
         };
 
         for peripheral in &svd_device.peripherals {
-            let svd_rs::peripheral::Peripheral::Single(info) = peripheral else {
-                return Err("PeripheralInfo array not supported".to_string());
+            match peripheral {
+                svd_rs::peripheral::Peripheral::Single(info) => {
+                    this.add_svd_memories(info, info.base_address);
+                    if Memory::is_svd_memory(info) {
+                        continue;
+                    }
+                    let device: Device = info.try_into()?;
+                    this.add_svd_device(device, info.base_address, &info.interrupt);
+                }
+                svd_rs::peripheral::Peripheral::Array(info, dim) => {
+                    this.add_svd_device_array(info, dim)?
+                }
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl Platform {
+    // The address blocks used as buffers become memories, named after the peripheral and the
+    // position of the block when there are several of them.
+    fn add_svd_memories(&mut self, info: &svd_rs::peripheral::PeripheralInfo, address: u64) {
+        let buffers: Vec<_> = info
+            .address_block
+            .iter()
+            .flatten()
+            .filter(|block| block.usage == svd_rs::AddressBlockUsage::Buffer)
+            .collect();
+        for (idx, block) in buffers.iter().enumerate() {
+            let name = if buffers.len() > 1 {
+                format!("{}_{}", info.name, idx)
+            } else {
+                info.name.clone()
             };
-            let device: Device = info.try_into()?;
+            self.memories.push(Memory {
+                name: name.replace(" ", "_").to_uppercase(),
+                base: address + block.offset as u64,
+                size: block.size as u64,
+                width: self.bus_width,
+                access: info
+                    .default_register_properties
+                    .access
+                    .map_or(Permissions::ReadWrite, Permissions::from),
+            });
+        }
+    }
+
+    fn add_svd_device(&mut self, device: Device, address: u64, interrupts: &[svd_rs::Interrupt]) {
+        self.add_device_addr(device.type_.clone(), device.name.clone(), address);
+
+        for interrupt in interrupts {
+            let mut interrupt: Interrupt = interrupt.into();
+            interrupt.name = format!("{}_{}", device.name, interrupt.name);
+            self.interrupts.push(interrupt);
+        }
+        // If it's empty, it's likely derived and should not generate a type.
+        if !device.is_empty() {
+            self.devices.push(device);
+        }
+    }
 
-            this.add_device_addr(device.type_.clone(), device.name.clone(), info.base_address);
+    // Expand a peripheral array, i.e `UART[%s]`, into one instance of the same device type per
+    // element, `dimIncrement` bytes apart.
+    fn add_svd_device_array(
+        &mut self,
+        info: &svd_rs::peripheral::PeripheralInfo,
+        dim: &svd_rs::DimElement,
+    ) -> Result<(), String> {
+        // i.e UART
+        let base_name = info.name.replace("[%s]", "").replace("%s", "");
+        let device_type = Device::get_type(
+            info.derived_from
+                .as_ref()
+                .unwrap_or(&base_name)
+                .trim_end_matches('_'),
+        );
 
+        // The interrupts are split evenly among the instances when possible, i.e two interrupts
+        // for two instances gives one to each. Otherwise the instances share them, and they are
+        // emitted once under the base name, i.e `UART_IRQ`, to keep the interrupt numbers unique.
+        let chunk = info.interrupt.len() / dim.dim as usize;
+        let shared = chunk == 0 || info.interrupt.len() % dim.dim as usize != 0;
+        let mut devices = false;
+
+        for (index, name) in svd_rs::array::names(info, dim).enumerate() {
+            let mut instance = info.clone();
+            instance.name = name;
+            let address = info.base_address + index as u64 * dim.dim_increment as u64;
+            self.add_svd_memories(&instance, address);
+            if Memory::is_svd_memory(&instance) {
+                continue;
+            }
+            let mut device: Device = (&instance).try_into()?;
+            if info.header_struct_name.is_none() {
+                device.type_ = device_type.clone();
+            }
+            // The instances share the registers, so only the first one generates a type.
+            if index > 0 {
+                device.registers.clear();
+                device.clusters.clear();
+            }
+            let interrupts = if shared {
+                &[]
+            } else {
+                &info.interrupt[index * chunk..(index + 1) * chunk]
+            };
+            self.add_svd_device(device, address, interrupts);
+            devices = true;
+        }
+        if shared && devices {
             for interrupt in &info.interrupt {
                 let mut interrupt: Interrupt = interrupt.into();
-                interrupt.name = format!("{}_{}", device.name, interrupt.name);
-                this.interrupts.push(interrupt);
-            }
-            // If it's empty, it's likely derived and should not generate a type.
-            if !device.registers.is_empty() {
-                this.devices.push(device);
+                interrupt.name = format!("{}_{}", base_name.trim_end_matches('_'), interrupt.name);
+                self.interrupts.push(interrupt);
             }
         }
-        Ok(this)
+        Ok(())
     }
 }
 
-impl From<rdljson::SoC> for Platform {
-    fn from(soc: rdljson::SoC) -> Self {
+impl TryFrom<rdljson::SoC> for Platform {
+    type Error = String;
+    fn try_from(soc: rdljson::SoC) -> Result<Self, Self::Error> {
         let mut this = Self {
-            name: soc.name,
+            name: soc.name.clone(),
             device_types: Vec::new(),
             interrupts: Vec::new(),
             bus_width: 32,
             devices: Vec::new(),
+            memories: Vec::new(),
         };
 
         for peripheral in &soc.devices {
-            let rdljson::Device::Device(peripheral) = peripheral else {
-                continue;
+            let peripheral = match peripheral {
+                rdljson::Device::Device(peripheral) => peripheral,
+                rdljson::Device::Mem(memory) => {
+                    this.memories.push(memory.try_into()?);
+                    continue;
+                }
             };
-            let device: Device = peripheral.into();
+            let device: Device = peripheral.try_into()?;
 
             for (idx, addr) in peripheral.offsets.iter().enumerate() {
                 let suffix = if peripheral.offsets.len() > 1 {
@@ -115,6 +230,411 @@ impl From<rdljson::SoC> for Platform {
 
             this.devices.push(device);
         }
-        this
+        this.add_rdljson_interrupts(&soc);
+        Ok(this)
     }
 }
+
+impl TryFrom<hjson::SoC> for Platform {
+    type Error = String;
+    fn try_from(soc: hjson::SoC) -> Result<Self, Self::Error> {
+        let mut this = Self {
+            name: soc.name.clone(),
+            device_types: Vec::new(),
+            interrupts: Vec::new(),
+            bus_width: 32,
+            devices: Vec::new(),
+            memories: Vec::new(),
+        };
+
+        // The interrupts are numbered in the order of the instances, starting from 1 as the PLIC
+        // reserves the id 0.
+        let mut id = 1;
+        for (instance, ip) in &soc.instances {
+            let name = instance.name.to_uppercase();
+            let type_name = ip.name.to_lowercase();
+            if !this.devices.iter().any(|d| d.type_ == type_name) {
+                let device = Device::try_from(ip).map_err(|e| format!("{e} in {}", ip.name))?;
+                this.devices.push(device);
+            }
+            let address = instance.base_addr.value(&[])?;
+            this.add_device_addr(type_name, name.clone(), address);
+
+            for interrupt in &ip.interrupt_list {
+                let width = match &interrupt.width {
+                    Some(width) => width.value(&ip.param_list)? as u32,
+                    None => 1,
+                };
+                for idx in 0..width {
+                    let suffix = match width {
+                        1 => String::new(),
+                        _ => format!("{idx}"),
+                    };
+                    this.interrupts.push(Interrupt {
+                        name: format!("{}_{}{}", name, interrupt.name, suffix),
+                        description: interrupt.desc.clone().unwrap_or(interrupt.name.clone()),
+                        value: id,
+                    });
+                    id += 1;
+                }
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl TryFrom<systemrdl::SoC> for Platform {
+    type Error = String;
+    fn try_from(soc: systemrdl::SoC) -> Result<Self, Self::Error> {
+        let mut this = Self {
+            name: soc.name.clone(),
+            device_types: Vec::new(),
+            interrupts: Vec::new(),
+            bus_width: 32,
+            devices: Vec::new(),
+            memories: Vec::new(),
+        };
+
+        // The instances of the same addrmap definition share a device type.
+        for addrmap in &soc.devices {
+            let device = Device::try_from(addrmap)?;
+            this.add_device_addr(device.type_.clone(), device.name.clone(), addrmap.address);
+            if !this.devices.iter().any(|d| d.type_ == device.type_) {
+                this.devices.push(device);
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl TryFrom<ipxact::SoC> for Platform {
+    type Error = String;
+    fn try_from(soc: ipxact::SoC) -> Result<Self, Self::Error> {
+        let mut this = Self {
+            name: soc.name.clone(),
+            device_types: Vec::new(),
+            interrupts: Vec::new(),
+            bus_width: 32,
+            devices: Vec::new(),
+            memories: Vec::new(),
+        };
+
+        // The instances of the same component share a device type.
+        for instance in &soc.instances {
+            let device = Device::try_from(&instance.component)?;
+            this.add_device_addr(
+                device.type_.clone(),
+                instance.name.to_uppercase(),
+                instance.address,
+            );
+            if !this.devices.iter().any(|d| d.type_ == device.type_) {
+                this.devices.push(device);
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl Platform {
+    /// Number the interrupts of the devices after the position of their line in the top-level
+    /// `interrupts`, starting from 1 as the PLIC reserves the id 0. A line named after an instance,
+    /// i.e `uart_0`, combines all the interrupts of that instance, while a line named after a single
+    /// interrupt, i.e `uart_0_tx_empty`, only carries that one.
+    fn add_rdljson_interrupts(&mut self, soc: &rdljson::SoC) {
+        let peripherals = soc.devices.iter().filter_map(|device| match device {
+            rdljson::Device::Device(peripheral) => Some(peripheral),
+            rdljson::Device::Mem(_) => None,
+        });
+        // The name of each instance in the platform, i.e `UART0`, and in the interrupt lines,
+        // i.e `uart_0`.
+        let instances: Vec<_> = peripherals
+            .flat_map(|peripheral| {
+                // The name of the device, see `Device::try_from`.
+                let device = peripheral.name.replace(" ", "_").to_uppercase();
+                let count = peripheral.offsets.len();
+                (0..count).map(move |idx| {
+                    let (name, line) = if count > 1 {
+                        (
+                            format!("{}{}", device, idx),
+                            format!("{}_{}", peripheral.name, idx),
+                        )
+                    } else {
+                        (device.clone(), peripheral.name.clone())
+                    };
+                    (name, line.to_lowercase(), peripheral)
+                })
+            })
+            .collect();
+
+        for (id, line) in soc.interrupts.iter().enumerate() {
+            let line = line.to_lowercase();
+            let value = id as u32 + 1;
+            for (name, prefix, peripheral) in &instances {
+                if line == *prefix && !peripheral.interrupts.is_empty() {
+                    let names: Vec<_> = peripheral.interrupts.iter().map(|i| &i.name[..]).collect();
+                    self.interrupts.push(Interrupt {
+                        name: format!("{}_irq", name),
+                        description: format!("{} interrupts: {}", name, names.join(", ")),
+                        value,
+                    });
+                } else if let Some(interrupt) = peripheral
+                    .interrupts
+                    .iter()
+                    .find(|i| line == format!("{}_{}", prefix, i.name.to_lowercase()))
+                {
+                    self.interrupts.push(Interrupt {
+                        name: format!("{}_{}", name, interrupt.name),
+                        description: interrupt.desc.clone().unwrap_or(interrupt.name.clone()),
+                        value,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Platform {
+    /// Take the addresses and the interrupts of the instances from a devicetree, which is the
+    /// authority over the register descriptions for them.
+    ///
+    /// A node matches a device type when the model of one of its `compatible`, i.e `uart` of
+    /// `lowrisc,uart`, is the name of the type, or when the compatible is mapped to the type by
+    /// `compatibles`. The address of the instance of the same name is overridden, or the instance
+    /// is added, and the interrupts of the node replace the ones of the instance. The disabled
+    /// nodes are ignored.
+    ///
+    /// Returns the nodes that match no device type.
+    pub fn apply_devicetree(
+        &mut self,
+        tree: &devicetree::Tree,
+        compatibles: &[(String, String)],
+    ) -> Result<Vec<String>, String> {
+        let mut unmatched = Vec::new();
+        for instance in tree.instances()? {
+            let type_name = instance.compatible.iter().find_map(|compatible| {
+                let model = compatible.rsplit(',').next().unwrap();
+                compatibles
+                    .iter()
+                    .find(|(c, _)| c == compatible)
+                    .map(|(_, type_name)| type_name.clone())
+                    .or_else(|| {
+                        self.devices
+                            .iter()
+                            .find(|d| d.type_.eq_ignore_ascii_case(&identifier(model)))
+                            .map(|d| d.type_.clone())
+                    })
+            });
+            let Some(type_name) = type_name else {
+                unmatched.push(format!(
+                    "{} ({})",
+                    instance.path,
+                    instance.compatible.join(", ")
+                ));
+                continue;
+            };
+            if !self.devices.iter().any(|d| d.type_ == type_name) {
+                return Err(format!(
+                    "{} is mapped to the unknown device type {type_name}",
+                    instance.path
+                ));
+            }
+            if !instance.enabled {
+                continue;
+            }
+            let Some(address) = instance.address else {
+                println!(
+                    "Warning: the address of {} is not translated to the CPUs by the ranges of its buses",
+                    instance.path
+                );
+                continue;
+            };
+
+            let name = identifier(&instance.name).to_uppercase();
+            let existing = self.device_types.iter_mut().find_map(|t| {
+                let same_type = t.type_name == type_name;
+                t.devices
+                    .iter_mut()
+                    .find(|d| d.name.eq_ignore_ascii_case(&name))
+                    .map(|d| (same_type, d))
+            });
+            match existing {
+                Some((true, device)) => device.address = address,
+                Some((false, _)) => {
+                    return Err(format!(
+                        "{} is a {type_name}, but the instance {name} is not",
+                        instance.path
+                    ));
+                }
+                None => self.add_device_addr(type_name, name.clone(), address),
+            }
+
+            let prefix = format!("{name}_");
+            let (replaced, kept) =
+                std::mem::take(&mut self.interrupts)
+                    .into_iter()
+                    .partition(|i| {
+                        let interrupt = i.name.to_uppercase();
+                        interrupt == name || interrupt.starts_with(&prefix)
+                    });
+            self.interrupts = kept;
+            let count = instance.interrupts.len();
+            for (idx, interrupt) in instance.interrupts.iter().enumerate() {
+                // Suffixed even when it's the only one, the interrupts and the instances are
+                // enumerators of the same scope in C++.
+                let interrupt_name = match (&interrupt.name, count) {
+                    (Some(line), _) => format!("{name}_{}", identifier(line)),
+                    (None, 1) => format!("{name}_IRQ"),
+                    (None, _) => format!("{name}_{idx}"),
+                };
+                let description = replaced
+                    .iter()
+                    .find(|i: &&Interrupt| {
+                        i.name.eq_ignore_ascii_case(&interrupt_name)
+                            || (interrupt.name.is_none() && count == 1 && i.name == name)
+                    })
+                    .map_or(format!("Interrupt of {}", instance.path), |i| {
+                        i.description.clone()
+                    });
+                self.interrupts.push(Interrupt {
+                    name: interrupt_name,
+                    description,
+                    value: interrupt.id,
+                });
+            }
+        }
+        Ok(unmatched)
+    }
+}
+
+impl Platform {
+    /// Merge the devices, instances, interrupts and memories of another platform, i.e the SVD of
+    /// a CPU subsystem with the rdljson of the IPs around it.
+    ///
+    /// The device types of the same name must have the same layout, in which case they are
+    /// shared, and the instances and the memories must neither share a name nor overlap, unless
+    /// they are identical. The interrupts of the same name must have the same line.
+    pub fn merge(&mut self, other: Platform) -> Result<(), String> {
+        if other.bus_width != self.bus_width {
+            println!(
+                "Warning: the bus of {} is {} bits wide, the one of {} with {} bits is kept",
+                other.name, other.bus_width, self.name, self.bus_width
+            );
+        }
+
+        for device in other.devices {
+            match self.devices.iter().find(|d| d.type_ == device.type_) {
+                Some(existing) if existing.same_layout(&device) => {}
+                Some(_) => {
+                    return Err(format!(
+                        "The device type {} of {} differs from the one of {}",
+                        device.type_, other.name, self.name
+                    ));
+                }
+                None => self.devices.push(device),
+            }
+        }
+
+        // The address ranges taken by the instances and the memories, with their names.
+        let mut ranges: Vec<(String, u64, u64)> = self
+            .device_types
+            .iter()
+            .flat_map(|t| t.devices.iter().map(move |d| (t, d)))
+            .map(|(t, d)| (d.name.clone(), d.address, self.type_size(&t.type_name)))
+            .chain(
+                self.memories
+                    .iter()
+                    .map(|m| (m.name.clone(), m.base, m.size)),
+            )
+            .collect();
+        let mut check = |name: &str, base: u64, size: u64| -> Result<(), String> {
+            let end = base + size.max(1);
+            if let Some((existing, ..)) = ranges
+                .iter()
+                .find(|(existing, ..)| existing.eq_ignore_ascii_case(name))
+            {
+                return Err(format!(
+                    "The name {name} of {} is already taken by {existing}",
+                    other.name
+                ));
+            }
+            if let Some((other, other_base, other_size)) = ranges
+                .iter()
+                .find(|(_, b, s)| base < b + (*s).max(1) && *b < end)
+            {
+                return Err(format!(
+                    "{name} at {base:#x}..{end:#x} overlaps {other} at {other_base:#x}..{:#x}",
+                    other_base + (*other_size).max(1)
+                ));
+            }
+            ranges.push((name.to_string(), base, size));
+            Ok(())
+        };
+
+        for device_type in other.device_types {
+            let size = self.type_size(&device_type.type_name);
+            for device in device_type.devices {
+                let address = device.address;
+                let duplicate = self.device_types.iter().any(|t| {
+                    t.type_name == device_type.type_name
+                        && t.devices
+                            .iter()
+                            .any(|d| d.name == device.name && d.address == address)
+                });
+                if !duplicate {
+                    check(&device.name, address, size)?;
+                    self.add_device_addr(device_type.type_name.clone(), device.name, address);
+                }
+            }
+        }
+
+        for memory in other.memories {
+            if !self.memories.contains(&memory) {
+                check(&memory.name, memory.base, memory.size)?;
+                self.memories.push(memory);
+            }
+        }
+
+        for interrupt in other.interrupts {
+            match self
+                .interrupts
+                .iter()
+                .find(|i| i.name.eq_ignore_ascii_case(&interrupt.name))
+            {
+                Some(existing) if existing.value == interrupt.value => {}
+                Some(existing) => {
+                    return Err(format!(
+                        "The interrupt {} is the line {} in {} and {} in {}",
+                        interrupt.name, existing.value, self.name, interrupt.value, other.name
+                    ));
+                }
+                None => {
+                    if let Some(shared) =
+                        self.interrupts.iter().find(|i| i.value == interrupt.value)
+                    {
+                        println!(
+                            "Warning: the interrupt {} shares the line {} with {}",
+                            interrupt.name, interrupt.value, shared.name
+                        );
+                    }
+                    self.interrupts.push(interrupt);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn type_size(&self, type_name: &str) -> u64 {
+        self.devices
+            .iter()
+            .find(|d| d.type_ == type_name)
+            .map_or(0, |d| d.size() as u64)
+    }
+}
+
+/// Replace the characters of a devicetree name that are not valid in an identifier, i.e
+/// `tx-watermark` or `serial@10000000`, by underscores.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
@@ -2,13 +2,14 @@
 // Licensed under the Apache License, Version 2.0, see LICENSE for details.
 // SPDX-License-Identifier: Apache-2.0
 
+pub use crate::hjson;
+pub use crate::ipxact;
 pub use crate::mmio::Bitfield;
 pub use crate::mmio::Device;
 pub use crate::rdljson;
-use svd_rs::cluster;
-use svd_rs::registercluster;
+pub use crate::systemrdl;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct RegisterInfo {
     pub name: String,
     pub type_: String,
@@ -33,10 +34,39 @@ impl RegisterInfo {
     }
 }
 
-#[derive(Debug)]
+/// The instances of a register array, like the multiregs of rdljson, at evenly spaced offsets.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisterArray {
+    // The name of the array without the index, i.e `CONFIG`.
+    pub name: String,
+    pub dim: u32,
+    // The distance in bytes between two instances.
+    pub increment: u32,
+}
+
+/// The write enable, or REGWEN, that gates the writes to a register, i.e `CTRL_REGWEN.EN`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteEnable {
+    pub register: String,
+    // The field of `register` that enables the writes while set.
+    pub field: String,
+}
+
+#[derive(Debug, PartialEq)]
 pub struct Register {
     pub info: Vec<RegisterInfo>, // Must have at least one.
+    // When set, the instances in `info` are accessed as an array instead of by name.
+    pub array: Option<RegisterArray>,
     pub bitfields: Vec<Bitfield>,
+    // The size of the register in bits: 8, 16, 32 or 64.
+    pub width: u32,
+    pub reset_value: u64,
+    // The bits of the register that have a defined reset value.
+    pub reset_mask: u64,
+    // Whether every write must be done twice with the same value to take effect.
+    pub shadowed: bool,
+    // The hardware ignores the writes to this register while the write enable is clear.
+    pub write_enable: Option<WriteEnable>,
 }
 
 impl Register {
@@ -49,7 +79,61 @@ impl Register {
     ) -> Self {
         Self {
             info: vec![RegisterInfo::new(name, type_name, desc, offset)],
+            array: None,
             bitfields,
+            width: super::WIDTH,
+            reset_value: 0,
+            reset_mask: u64::MAX >> (64 - super::WIDTH),
+            shadowed: false,
+            write_enable: None,
+        }
+    }
+
+    /// Set the size of the register in bits, which also resets the reset mask to cover the whole
+    /// register.
+    pub fn set_width(&mut self, width: u32) -> Result<(), String> {
+        if ![8, 16, 32, 64].contains(&width) {
+            return Err(format!(
+                "Register {} with {} bits not supported",
+                self.info[0].name, width
+            ));
+        }
+        self.width = width;
+        self.reset_mask = u64::MAX >> (64 - width);
+        Ok(())
+    }
+
+    /// Set the value of the register after a reset and propagate it to the bitfields.
+    pub fn set_reset(&mut self, value: u64, mask: u64) {
+        self.reset_value = value & mask;
+        self.reset_mask = mask;
+        for bitfield in self.bitfields.iter_mut() {
+            bitfield.reset_value = (self.reset_value & bitfield.mask()) >> bitfield.offset;
+        }
+    }
+
+    /// Whether two registers have the same bitfields, width and reset, regardless of their
+    /// instances, so they can share a type.
+    pub fn same_layout(&self, other: &Register) -> bool {
+        self.bitfields == other.bitfields
+            && self.width == other.width
+            && self.reset_value == other.reset_value
+            && self.reset_mask == other.reset_mask
+            && self.shadowed == other.shadowed
+            && self.write_enable == other.write_enable
+    }
+
+    /// The offset after the last byte of the last instance.
+    pub fn end(&self) -> u32 {
+        let last = self.info.iter().map(|info| info.offset).max().unwrap_or(0);
+        last + self.width / 8
+    }
+
+    /// How the first instance is accessed from the device, i.e `config[0]` or `config0`.
+    pub fn first_member(&self) -> String {
+        match self.array {
+            Some(ref array) => format!("{}[0]", array.name.to_lowercase()),
+            None => self.info[0].name.to_lowercase(),
         }
     }
 
@@ -61,63 +145,81 @@ impl Register {
         self.bitfields.iter().any(|f| f.permissions.is_writable())
     }
 
-    pub fn try_from(cluster: &cluster::Cluster) -> Result<Vec<Self>, String> {
-        let cluster::Cluster::Array(info, dim) = cluster else {
-            unreachable!()
-        };
+    pub fn is_once(&self) -> bool {
+        self.bitfields.iter().any(|f| f.permissions.is_once())
+    }
+
+    /// Whether reading this register modifies any of its fields.
+    pub fn has_read_effect(&self) -> bool {
+        self.bitfields.iter().any(|f| !f.read_effect.is_none())
+    }
+
+    /// Whether all the fields with read side effects are cleared by the read.
+    pub fn is_clear_on_read(&self) -> bool {
+        self.has_read_effect()
+            && self
+                .bitfields
+                .iter()
+                .all(|f| f.read_effect.is_none() || f.read_effect.is_clear())
+    }
+
+    /// The bits of the fields with write side effects that can be neutralized.
+    pub fn side_effect_mask(&self) -> u64 {
+        self.bitfields
+            .iter()
+            .filter(|f| f.write_effect.is_neutralizable())
+            .fold(0, |acc, f| acc | f.mask())
+    }
 
-        let res = info
-            .children
+    /// A value for the side effect bits that doesn't modify their fields when written.
+    pub fn neutral_value(&self) -> u64 {
+        self.bitfields
             .iter()
-            .map(|children| {
-                let registercluster::RegisterCluster::Register(register) = children else {
-                    panic!("Too much recursion")
-                };
-                let mut dim = dim.clone();
-                dim.dim_name = Some(info.name.clone());
-                // This is a hack to reuse the `TryFrom<&svd_rs::register::Register> for Register`
-                // and avoid reimplementing the cluster parsing. We rely on the fact that
-                // the type `MaybeArray` implements `Deref`, so we deref register twice
-                // to get in inner type (`RegisterInfo`), then we create a
-                // `Register::Array` to be able to call `into`.
-                let register = svd_rs::register::Register::Array((**register).clone(), dim);
-                (&register).into()
-            })
-            .collect::<Vec<_>>();
-        Ok(res)
+            .filter(|f| f.write_effect.is_neutralizable() && f.write_effect.neutral_ones())
+            .fold(0, |acc, f| acc | f.mask())
     }
 }
 
-impl From<&svd_rs::register::RegisterInfo> for Register {
-    fn from(register: &svd_rs::register::RegisterInfo) -> Self {
+impl TryFrom<&svd_rs::register::RegisterInfo> for Register {
+    type Error = String;
+    /// The `size` inherited from the cluster, peripheral or device must be resolved, see
+    /// [`super::derive::resolve`].
+    fn try_from(register: &svd_rs::register::RegisterInfo) -> Result<Self, Self::Error> {
+        let width = register.properties.size.unwrap_or(super::WIDTH);
         let bitfields = if let Some(ref bitfields) = register.fields {
             bitfields
                 .iter()
-                .map(|field| {
-                    field
-                        .try_into()
-                        .unwrap_or_else(|e| panic!("{} register {}", e, register.name))
-                })
+                .flat_map(Bitfield::from_field)
                 .collect::<Vec<Bitfield>>()
         } else {
-            vec![Bitfield::default()]
+            vec![Bitfield {
+                bit_size: width,
+                ..Bitfield::default()
+            }]
         };
 
-        Self::new(
+        let mut this = Self::new(
             register.name.clone(),
             None,
             register.address_offset,
             register.description.clone(),
             bitfields,
-        )
+        );
+        this.set_width(width)?;
+        this.set_reset(
+            register.properties.reset_value.unwrap_or_default(),
+            register.properties.reset_mask.unwrap_or(this.reset_mask),
+        );
+        Ok(this)
     }
 }
 
-impl From<&svd_rs::register::Register> for Register {
-    fn from(register: &svd_rs::register::Register) -> Self {
+impl TryFrom<&svd_rs::register::Register> for Register {
+    type Error = String;
+    fn try_from(register: &svd_rs::register::Register) -> Result<Self, Self::Error> {
         let (mut register, dim): (Self, _) = match register {
-            svd_rs::register::Register::Single(info) => return info.into(),
-            svd_rs::register::Register::Array(info, dim) => (info.into(), dim),
+            svd_rs::register::Register::Single(info) => return info.try_into(),
+            svd_rs::register::Register::Array(info, dim) => (info.try_into()?, dim),
         };
 
         let base_name = dim
@@ -145,12 +247,13 @@ impl From<&svd_rs::register::Register> for Register {
             ));
             offset += dim.dim_increment;
         }
-        register
+        Ok(register)
     }
 }
 
-impl From<&rdljson::Register> for Register {
-    fn from(register: &rdljson::Register) -> Self {
+impl TryFrom<&rdljson::Register> for Register {
+    type Error = String;
+    fn try_from(register: &rdljson::Register) -> Result<Self, Self::Error> {
         let fields: Vec<_> = register.fields.iter().map(|field| field.into()).collect();
 
         let name = format!(
@@ -165,6 +268,9 @@ impl From<&rdljson::Register> for Register {
             register.desc.clone(),
             fields,
         );
+        this.set_width(register.width.unwrap_or(super::WIDTH))?;
+        // rdljson doesn't tell which bits have a reset, so the mask is the one of the fields.
+        this.set_reset(register.reset as u64, this.reset_mask);
         this.info.extend(
             register
                 .offsets
@@ -180,6 +286,148 @@ impl From<&rdljson::Register> for Register {
                     )
                 }),
         );
-        this
+        this.shadowed = register.shadowed;
+        // The field of the write enable is resolved by the device when it's not given.
+        this.write_enable = register
+            .fields
+            .iter()
+            .filter(|field| field.sw_write_en)
+            .find_map(|field| field.write_en_signal.as_ref())
+            .map(|signal| {
+                let (register, field) = signal.split_once('.').unwrap_or((signal, ""));
+                WriteEnable {
+                    register: register.to_uppercase(),
+                    field: field.to_uppercase(),
+                }
+            });
+        // The multiregs become an array when their instances are evenly spaced.
+        if register.is_multireg && register.offsets.len() > 1 {
+            let increment = register.offsets[1] - register.offsets[0];
+            if register
+                .offsets
+                .windows(2)
+                .all(|pair| pair[1] - pair[0] == increment)
+            {
+                this.array = Some(RegisterArray {
+                    name: register.name.clone(),
+                    dim: register.offsets.len() as u32,
+                    increment,
+                });
+            }
+        }
+        Ok(this)
+    }
+}
+
+impl TryFrom<&hjson::Register> for Register {
+    type Error = String;
+    /// The register is placed at the offset 0, the device moves it to its place in the map.
+    fn try_from(register: &hjson::Register) -> Result<Self, Self::Error> {
+        let name = register.name.to_uppercase();
+        let mut reset_value = 0;
+        let mut fields = Vec::new();
+        // The fields without software access are only visible to the hardware.
+        for field in register
+            .resolved_fields()
+            .iter()
+            .filter(|f| f.swaccess != Some(hjson::SwAccess::None))
+        {
+            let bitfield = Bitfield::try_from(field).map_err(|e| format!("{e} in {name}"))?;
+            reset_value |= (field.reset_value()? << bitfield.offset) & bitfield.mask();
+            fields.push(bitfield);
+        }
+        let mut this = Self::new(&name, None, 0, register.desc.clone(), fields);
+        this.set_reset(reset_value, this.reset_mask);
+        this.shadowed = register.shadowed.is_set();
+        this.write_enable = register.regwen.as_ref().map(|signal| {
+            let (register, field) = signal.split_once('.').unwrap_or((signal, ""));
+            WriteEnable {
+                register: register.to_uppercase(),
+                field: field.to_uppercase(),
+            }
+        });
+        Ok(this)
+    }
+}
+
+impl TryFrom<&systemrdl::Register> for Register {
+    type Error = String;
+    fn try_from(register: &systemrdl::Register) -> Result<Self, Self::Error> {
+        let name = register.name.to_uppercase();
+        let fields = register.fields.iter().map(|field| field.into()).collect();
+        let reset_value = register
+            .fields
+            .iter()
+            .fold(0, |acc, field| acc | field.reset << field.lsb);
+
+        let mut this = match register.array {
+            Some(_) => Self::new(
+                format!("{name}0"),
+                Some(name.clone()),
+                register.offset,
+                register.desc.clone(),
+                fields,
+            ),
+            None => Self::new(&name, None, register.offset, register.desc.clone(), fields),
+        };
+        this.set_width(register.width)?;
+        this.set_reset(reset_value, this.reset_mask);
+        if let Some(ref array) = register.array {
+            this.info.extend((1..array.dim).map(|idx| {
+                RegisterInfo::new(
+                    format!("{name}{idx}"),
+                    Some(name.clone()),
+                    register.desc.clone(),
+                    register.offset + idx * array.stride,
+                )
+            }));
+            this.array = Some(RegisterArray {
+                name,
+                dim: array.dim,
+                increment: array.stride,
+            });
+        }
+        Ok(this)
+    }
+}
+
+impl TryFrom<&ipxact::Register> for Register {
+    type Error = String;
+    fn try_from(register: &ipxact::Register) -> Result<Self, Self::Error> {
+        let name = register.name.to_uppercase();
+        let fields = register.fields.iter().map(|field| field.into()).collect();
+        let reset_value = register
+            .fields
+            .iter()
+            .fold(0, |acc, field| acc | field.reset << field.offset);
+
+        let mut this = match register.array {
+            Some(_) => Self::new(
+                format!("{name}0"),
+                Some(name.clone()),
+                register.offset,
+                register.desc.clone(),
+                fields,
+            ),
+            None => Self::new(&name, None, register.offset, register.desc.clone(), fields),
+        };
+        this.set_width(register.size)?;
+        this.set_reset(reset_value, this.reset_mask);
+        if let Some(ref array) = register.array {
+            this.info.extend((1..array.dim).map(|idx| {
+                RegisterInfo::new(
+                    format!("{name}{idx}"),
+                    Some(name.clone()),
+                    register.desc.clone(),
+                    register.offset + idx * array.stride,
+                )
+            }));
+            this.array = Some(RegisterArray {
+                name,
+                dim: array.dim,
+                increment: array.stride,
+            });
+        }
+        Ok(this)
     }
 }
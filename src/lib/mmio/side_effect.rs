@@ -0,0 +1,190 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::hjson;
+pub use crate::rdljson;
+pub use crate::systemrdl;
+use svd_rs::modifiedwritevalues::ModifiedWriteValues;
+use svd_rs::readaction::ReadAction;
+
+/// How the hardware modifies a field when software writes to it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, strum::Display, strum::IntoStaticStr)]
+pub enum WriteSideEffect {
+    #[default]
+    Modify,
+    OneToClear,
+    OneToSet,
+    OneToToggle,
+    ZeroToClear,
+    ZeroToSet,
+    ZeroToToggle,
+    Clear,
+    Set,
+}
+
+impl WriteSideEffect {
+    pub fn is_modify(self) -> bool {
+        self == Self::Modify
+    }
+
+    /// Whether writing a value different from the trigger leaves the field untouched, in which
+    /// case the bits of the field can be neutralized on read-modify-write operations.
+    pub fn is_neutralizable(self) -> bool {
+        !matches!(self, Self::Modify | Self::Clear | Self::Set)
+    }
+
+    /// The neutral value of the field bits, i.e ones for the `ZeroTo*` fields.
+    pub fn neutral_ones(self) -> bool {
+        matches!(
+            self,
+            Self::ZeroToClear | Self::ZeroToSet | Self::ZeroToToggle
+        )
+    }
+
+    pub fn is_clear(self) -> bool {
+        matches!(self, Self::OneToClear | Self::ZeroToClear | Self::Clear)
+    }
+
+    pub fn is_set(self) -> bool {
+        matches!(self, Self::OneToSet | Self::ZeroToSet | Self::Set)
+    }
+
+    pub fn is_toggle(self) -> bool {
+        matches!(self, Self::OneToToggle | Self::ZeroToToggle)
+    }
+}
+
+impl From<ModifiedWriteValues> for WriteSideEffect {
+    fn from(value: ModifiedWriteValues) -> Self {
+        match value {
+            ModifiedWriteValues::OneToClear => Self::OneToClear,
+            ModifiedWriteValues::OneToSet => Self::OneToSet,
+            ModifiedWriteValues::OneToToggle => Self::OneToToggle,
+            ModifiedWriteValues::ZeroToClear => Self::ZeroToClear,
+            ModifiedWriteValues::ZeroToSet => Self::ZeroToSet,
+            ModifiedWriteValues::ZeroToToggle => Self::ZeroToToggle,
+            ModifiedWriteValues::Clear => Self::Clear,
+            ModifiedWriteValues::Set => Self::Set,
+            ModifiedWriteValues::Modify => Self::Modify,
+        }
+    }
+}
+
+impl From<&rdljson::RegisterField> for WriteSideEffect {
+    fn from(field: &rdljson::RegisterField) -> Self {
+        // The rdl `woclr`, `woset` and `wzc` properties are only exported through the reggen
+        // software access, i.e `W1C` or `RW0C`.
+        let Some(access) = field
+            .opentitan
+            .as_ref()
+            .and_then(|ot| ot.reggen_sw_access.as_ref())
+        else {
+            return Self::Modify;
+        };
+        let access = access.to_uppercase();
+        if access.ends_with("W1C") {
+            Self::OneToClear
+        } else if access.ends_with("W1S") {
+            Self::OneToSet
+        } else if access.ends_with("W0C") {
+            Self::ZeroToClear
+        } else {
+            Self::Modify
+        }
+    }
+}
+
+impl From<hjson::SwAccess> for WriteSideEffect {
+    fn from(access: hjson::SwAccess) -> Self {
+        match access {
+            hjson::SwAccess::Rw1c | hjson::SwAccess::R0w1c => Self::OneToClear,
+            hjson::SwAccess::Rw1s => Self::OneToSet,
+            hjson::SwAccess::Rw0c => Self::ZeroToClear,
+            _ => Self::Modify,
+        }
+    }
+}
+
+impl From<&systemrdl::Field> for WriteSideEffect {
+    fn from(field: &systemrdl::Field) -> Self {
+        match field.onwrite {
+            None => Self::Modify,
+            Some(systemrdl::OnWrite::Woclr) => Self::OneToClear,
+            Some(systemrdl::OnWrite::Woset) => Self::OneToSet,
+            Some(systemrdl::OnWrite::Wot) => Self::OneToToggle,
+            Some(systemrdl::OnWrite::Wzc) => Self::ZeroToClear,
+            Some(systemrdl::OnWrite::Wzs) => Self::ZeroToSet,
+            Some(systemrdl::OnWrite::Wzt) => Self::ZeroToToggle,
+            Some(systemrdl::OnWrite::Wclr) => Self::Clear,
+            Some(systemrdl::OnWrite::Wset) => Self::Set,
+        }
+    }
+}
+
+/// How the hardware modifies a field when software reads it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, strum::Display, strum::IntoStaticStr)]
+pub enum ReadSideEffect {
+    #[default]
+    None,
+    Clear,
+    Set,
+    Modify,
+    ModifyExternal,
+}
+
+impl ReadSideEffect {
+    pub fn is_none(self) -> bool {
+        self == Self::None
+    }
+
+    pub fn is_clear(self) -> bool {
+        self == Self::Clear
+    }
+
+    pub fn is_set(self) -> bool {
+        self == Self::Set
+    }
+}
+
+impl From<ReadAction> for ReadSideEffect {
+    fn from(value: ReadAction) -> Self {
+        match value {
+            ReadAction::Clear => Self::Clear,
+            ReadAction::Set => Self::Set,
+            ReadAction::Modify => Self::Modify,
+            ReadAction::ModifyExternal => Self::ModifyExternal,
+        }
+    }
+}
+
+impl From<&rdljson::RegisterField> for ReadSideEffect {
+    fn from(field: &rdljson::RegisterField) -> Self {
+        if field.clear_onread {
+            Self::Clear
+        } else if field.set_onread {
+            Self::Set
+        } else {
+            Self::None
+        }
+    }
+}
+
+impl From<hjson::SwAccess> for ReadSideEffect {
+    fn from(access: hjson::SwAccess) -> Self {
+        match access {
+            hjson::SwAccess::Rc => Self::Clear,
+            _ => Self::None,
+        }
+    }
+}
+
+impl From<&systemrdl::Field> for ReadSideEffect {
+    fn from(field: &systemrdl::Field) -> Self {
+        match field.onread {
+            Some(systemrdl::OnRead::Rclr) => Self::Clear,
+            Some(systemrdl::OnRead::Rset) => Self::Set,
+            None => Self::None,
+        }
+    }
+}
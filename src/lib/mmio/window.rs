@@ -0,0 +1,64 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use crate::ipxact;
+pub use crate::mmio::Permissions;
+pub use crate::rdljson;
+
+/// A range of the address space of a device accessed as an array of words, like a FIFO or a
+/// buffer, instead of as registers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Window {
+    pub name: String,
+    pub offset: u32,
+    pub entries: u32,
+    // The width in bits of the words.
+    pub width: u32,
+    pub access: Permissions,
+}
+
+impl Window {
+    /// The size in bytes.
+    pub fn size(&self) -> u32 {
+        self.entries * self.width / 8
+    }
+}
+
+impl TryFrom<&rdljson::Memory> for Window {
+    type Error = String;
+    fn try_from(window: &rdljson::Memory) -> Result<Self, Self::Error> {
+        if ![8, 16, 32, 64].contains(&window.width) {
+            return Err(format!(
+                "Window {} has an unsupported width {}",
+                window.name, window.width
+            ));
+        }
+        Ok(Self {
+            name: window.name.clone(),
+            offset: window.offset,
+            entries: window.entries,
+            width: window.width as u32,
+            access: window.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<&ipxact::Memory> for Window {
+    type Error = String;
+    fn try_from(memory: &ipxact::Memory) -> Result<Self, Self::Error> {
+        if ![8, 16, 32, 64].contains(&memory.width) {
+            return Err(format!(
+                "Window {} has an unsupported width {}",
+                memory.name, memory.width
+            ));
+        }
+        Ok(Self {
+            name: memory.name.to_uppercase(),
+            offset: memory.offset,
+            entries: memory.range / (memory.width / 8),
+            width: memory.width,
+            access: memory.access.into(),
+        })
+    }
+}
@@ -20,7 +20,7 @@ impl Device {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Memory {
     pub name: String,
     pub entries: u32,
@@ -38,6 +38,15 @@ pub struct Peripheral {
     pub offsets: Vec<u32>,
     pub size: u32,
     pub interfaces: Vec<Interface>,
+    #[serde(default)]
+    pub enums: Vec<Enum>,
+    /// The interrupts of each instance, numbered by the top-level `interrupts` of the SoC.
+    #[serde(default)]
+    pub interrupts: Vec<DeviceInterrupt>,
+    #[serde(default)]
+    pub alerts: Vec<String>,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
 }
 
 impl From<&Interface> for Peripheral {
@@ -48,19 +57,44 @@ impl From<&Interface> for Peripheral {
             interfaces: vec![interface.clone()],
             size: 0,
             offsets: vec![],
+            enums: vec![],
+            interrupts: vec![],
+            alerts: vec![],
+            parameters: vec![],
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceInterrupt {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub desc: Option<String>,
+}
+
+/// A constant of the hardware configuration, i.e `{"name": "RxFifoDepth", "type": "int", "value": 8}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Parameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub desc: Option<String>,
+    pub value: serde_json::Value,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Interface {
     pub name: Option<String>,
     pub regs: Vec<Register>,
+    /// The ranges of the interface accessed as memory, i.e a FIFO or a buffer.
+    #[serde(default)]
+    pub windows: Vec<Memory>,
 }
 
 impl PartialEq for Interface {
     fn eq(&self, other: &Self) -> bool {
-        self.regs == other.regs
+        self.regs == other.regs && self.windows == other.windows
     }
 }
 impl Eq for Interface {}
@@ -71,11 +105,18 @@ pub struct Register {
     pub type_name: String,
     pub desc: Option<String>,
     pub offsets: Vec<u32>,
+    pub width: Option<u32>,
     #[serde(default)]
     pub fields: Vec<RegisterField>,
     pub sw_writable: bool,
     pub sw_readable: bool,
     pub reset: u32,
+    /// Whether the register is replicated at each of its `offsets`, i.e `CONFIG_0`, `CONFIG_1`.
+    #[serde(default)]
+    pub is_multireg: bool,
+    /// Whether it's an OpenTitan shadowed register, which needs two writes of the same value.
+    #[serde(default)]
+    pub shadowed: bool,
 }
 
 // Exclude the offsets
@@ -85,6 +126,7 @@ impl PartialEq for Register {
             && self.fields.eq(&other.fields)
             && self.sw_writable.eq(&other.sw_writable)
             && self.sw_readable.eq(&other.sw_readable)
+            && self.width.eq(&other.width)
             && self.reset.eq(&other.reset)
     }
 }
@@ -103,17 +145,79 @@ pub struct RegisterField {
     pub sw_readable: bool,
     pub set_onread: bool,
     pub clear_onread: bool,
+    /// Whether the writes are gated by the register, or field, named in `write_en_signal`, i.e
+    /// `CTRL_REGWEN.EN`.
+    #[serde(default)]
+    pub sw_write_en: bool,
+    #[serde(default)]
+    pub write_en_signal: Option<String>,
+    #[serde(default)]
+    pub opentitan: Option<OpenTitanField>,
+    /// The members of the enum referenced by `enum_field`, resolved when the SoC is loaded.
+    #[serde(skip)]
+    pub encodings: Vec<EnumMember>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenTitanField {
+    pub reggen_sw_access: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enum {
+    pub name: String,
+    pub members: Vec<EnumMember>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumMember {
+    pub name: String,
+    pub value: u64,
+    pub desc: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SoC {
     pub name: String,
     pub devices: Vec<Device>,
+    /// The interrupt lines connected to the PLIC in the order of their ids, i.e `uart_0` for all
+    /// the interrupts of the first instance of `uart` or `uart_0_tx_empty` for a single one.
+    #[serde(default)]
+    pub interrupts: Vec<String>,
 }
 
 impl SoC {
     pub fn try_from(json_str: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json_str)
+        let mut soc: Self = serde_json::from_str(json_str)?;
+        soc.resolve_enums()?;
+        Ok(soc)
+    }
+
+    /// Copy the members of the enums defined in each device into the fields that reference them.
+    fn resolve_enums(&mut self) -> Result<(), serde_json::Error> {
+        for device in self.devices.iter_mut() {
+            let Device::Device(device) = device else {
+                continue;
+            };
+            let fields = device
+                .interfaces
+                .iter_mut()
+                .flat_map(|inter| inter.regs.iter_mut())
+                .flat_map(|reg| reg.fields.iter_mut());
+            for field in fields {
+                let Some(ref name) = field.enum_field else {
+                    continue;
+                };
+                let Some(enum_) = device.enums.iter().find(|e| &e.name == name) else {
+                    return Err(serde::de::Error::custom(format!(
+                        "Enum {} referenced by the field {} not found in {}",
+                        name, field.name, device.name
+                    )));
+                };
+                field.encodings = enum_.members.clone();
+            }
+        }
+        Ok(())
     }
 
     /// If homogeneous interfaces are found, transform them into separate devices/pheripherals.
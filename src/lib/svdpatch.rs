@@ -0,0 +1,559 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Patches fixing a vendor SVD before its conversion into the IR, i.e a missing field or a wrong
+//! access, so a hand-edited copy of the SVD doesn't need to be kept:
+//! ``` toml
+//! [[peripheral]]
+//! name = "PWM*"
+//! delete = true
+//!
+//! [[register]]
+//! peripheral = "TIMER*"
+//! name = "MTIMECMP?"
+//! reset_value = 0xffffffff
+//!
+//! [[field]]
+//! peripheral = "I2C0"
+//! register = "CTRL"
+//! name = "LLPBK"
+//! rename = "LOOPBACK"
+//! values = [{ name = "DISABLED", value = 0 }, { name = "ENABLED", value = 1 }]
+//!
+//! [[add_field]]
+//! peripheral = "I2C0"
+//! register = "CTRL"
+//! name = "HOSTRST"
+//! bit_offset = 3
+//! bit_width = 1
+//! access = "write-only"
+//! ```
+//! The names are globs, `*` for any characters and `?` for a single one, and a comma separates
+//! several globs, i.e `TIMER0,TIMER1`. The registers inside clusters are matched by their own
+//! name. The fields are added and patched first, then the registers and the peripherals last, so
+//! the patches always refer to the names of the vendor SVD. A patch that matches nothing is an
+//! error, as it is likely out of date, and so is a rename that matches several peripherals,
+//! several registers of a peripheral or several fields of a register.
+
+use regex::Regex;
+use serde::Deserialize;
+use std::path::Path;
+use svd_rs::{
+    Access, BitRange, EnumeratedValue, EnumeratedValues, FieldInfo, RegisterCluster, RegisterInfo,
+    ValidateLevel,
+};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Patch {
+    #[serde(default)]
+    pub peripheral: Vec<PeripheralPatch>,
+    #[serde(default)]
+    pub register: Vec<RegisterPatch>,
+    #[serde(default)]
+    pub field: Vec<FieldPatch>,
+    #[serde(default)]
+    pub add_field: Vec<NewField>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PeripheralPatch {
+    pub name: String,
+    pub rename: Option<String>,
+    #[serde(default)]
+    pub delete: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegisterPatch {
+    pub peripheral: String,
+    pub name: String,
+    pub rename: Option<String>,
+    #[serde(default)]
+    pub delete: bool,
+    pub description: Option<String>,
+    /// The SVD access, i.e `read-only` or `read-writeOnce`.
+    pub access: Option<String>,
+    pub reset_value: Option<u64>,
+    pub reset_mask: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldPatch {
+    pub peripheral: String,
+    pub register: String,
+    pub name: String,
+    pub rename: Option<String>,
+    #[serde(default)]
+    pub delete: bool,
+    pub description: Option<String>,
+    pub access: Option<String>,
+    /// The value of the field after reset, which updates the reset value of its register.
+    pub reset: Option<u64>,
+    /// Enumerated values added to the field, replacing the ones of the same name.
+    #[serde(default)]
+    pub values: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NewField {
+    pub peripheral: String,
+    pub register: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub bit_offset: u32,
+    pub bit_width: u32,
+    pub access: Option<String>,
+    pub reset: Option<u64>,
+    #[serde(default)]
+    pub values: Vec<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Value {
+    pub name: String,
+    pub value: u64,
+    pub description: Option<String>,
+}
+
+impl TryFrom<&str> for Patch {
+    type Error = String;
+    fn try_from(toml: &str) -> Result<Self, Self::Error> {
+        basic_toml::from_str(toml).map_err(|e| e.to_string())
+    }
+}
+
+impl Patch {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let toml = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Patch::try_from(toml.as_str()).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// Apply the patches to the SVD and describe each of them, to be recorded in the generated
+    /// files.
+    pub fn apply(&self, device: &mut svd_rs::Device) -> Result<Vec<String>, String> {
+        let mut applied = Vec::new();
+        for patch in &self.add_field {
+            applied.push(patch.apply(device)?);
+        }
+        for patch in &self.field {
+            applied.push(patch.apply(device)?);
+        }
+        for patch in &self.register {
+            applied.push(patch.apply(device)?);
+        }
+        for patch in &self.peripheral {
+            applied.push(patch.apply(device)?);
+        }
+        Ok(applied)
+    }
+}
+
+/// A comment listing the patches applied to a SVD, for the header of the generated files.
+pub fn header(name: &str, applied: &[String]) -> String {
+    let mut header = format!("\n/*\n * The svd was patched with {name}:\n");
+    for patch in applied {
+        header += &format!(" *  - {patch}\n");
+    }
+    header + " */\n"
+}
+
+impl PeripheralPatch {
+    fn apply(&self, device: &mut svd_rs::Device) -> Result<String, String> {
+        let glob = Glob::new(&self.name);
+        let matched: Vec<String> = device
+            .peripherals
+            .iter()
+            .filter(|p| glob.matches(&p.name))
+            .map(|p| p.name.clone())
+            .collect();
+        if matched.is_empty() {
+            return Err(format!("No peripheral matches {}", self.name));
+        }
+        if self.rename.is_some() && matched.len() > 1 {
+            return Err(format!(
+                "The rename of {} matches {} peripherals",
+                self.name,
+                matched.len()
+            ));
+        }
+
+        if self.delete {
+            if let Some(derived) = device.peripherals.iter().find(|p| {
+                !glob.matches(&p.name)
+                    && p.derived_from
+                        .as_ref()
+                        .is_some_and(|base| glob.matches(base))
+            }) {
+                return Err(format!(
+                    "The peripheral {} can't be deleted, {} is derived from it",
+                    derived.derived_from.as_ref().unwrap(),
+                    derived.name
+                ));
+            }
+            device.peripherals.retain(|p| !glob.matches(&p.name));
+            return Ok(format!("peripheral {}: deleted", self.name));
+        }
+
+        for peripheral in device.peripherals.iter_mut() {
+            if let Some(description) = &self.description {
+                if glob.matches(&peripheral.name) {
+                    peripheral.description = Some(description.clone());
+                }
+            }
+            if let Some(name) = &self.rename {
+                if glob.matches(&peripheral.name) {
+                    peripheral.name = name.clone();
+                }
+                if peripheral
+                    .derived_from
+                    .as_ref()
+                    .is_some_and(|base| matched.contains(base))
+                {
+                    peripheral.derived_from = Some(name.clone());
+                }
+            }
+        }
+
+        let mut changes = vec![];
+        if let Some(name) = &self.rename {
+            changes.push(format!("renamed to {name}"));
+        }
+        if self.description.is_some() {
+            changes.push("description".to_string());
+        }
+        Ok(format!("peripheral {}: {}", self.name, changes.join(", ")))
+    }
+}
+
+impl RegisterPatch {
+    fn apply(&self, device: &mut svd_rs::Device) -> Result<String, String> {
+        let path = format!("{}.{}", self.peripheral, self.name);
+        let glob = Glob::new(&self.name);
+        if self.delete {
+            let mut deleted = 0;
+            for peripheral in peripherals(device, &self.peripheral) {
+                if let Some(registers) = peripheral.registers.as_mut() {
+                    deleted += delete_registers(registers, &glob);
+                }
+            }
+            if deleted == 0 {
+                return Err(format!("No register matches {path}"));
+            }
+            return Ok(format!("register {path}: deleted"));
+        }
+
+        let access = self.access.as_deref().map(parse_access).transpose()?;
+        let mut matched = 0;
+        for peripheral in peripherals(device, &self.peripheral) {
+            let count = peripheral
+                .all_registers()
+                .filter(|register| glob.matches(&register.name))
+                .count();
+            if self.rename.is_some() && count > 1 {
+                return Err(format!(
+                    "The rename of {path} matches {count} registers of {}",
+                    peripheral.name
+                ));
+            }
+            let mut renamed = vec![];
+            for register in peripheral.all_registers_mut() {
+                if !glob.matches(&register.name) {
+                    continue;
+                }
+                matched += 1;
+                if let Some(description) = &self.description {
+                    register.description = Some(description.clone());
+                }
+                if access.is_some() {
+                    register.properties.access = access;
+                }
+                if self.reset_value.is_some() {
+                    register.properties.reset_value = self.reset_value;
+                }
+                if self.reset_mask.is_some() {
+                    register.properties.reset_mask = self.reset_mask;
+                }
+                if let Some(name) = &self.rename {
+                    renamed.push(std::mem::replace(&mut register.name, name.clone()));
+                }
+            }
+            // The registers derived from a renamed one follow it.
+            for register in peripheral.all_registers_mut() {
+                if register
+                    .derived_from
+                    .as_ref()
+                    .is_some_and(|base| renamed.contains(base))
+                {
+                    register.derived_from = self.rename.clone();
+                }
+            }
+        }
+        if matched == 0 {
+            return Err(format!("No register matches {path}"));
+        }
+
+        let mut changes = vec![];
+        if let Some(name) = &self.rename {
+            changes.push(format!("renamed to {name}"));
+        }
+        if self.description.is_some() {
+            changes.push("description".to_string());
+        }
+        if let Some(access) = &self.access {
+            changes.push(format!("access {access}"));
+        }
+        if let Some(reset) = self.reset_value {
+            changes.push(format!("reset value {reset:#x}"));
+        }
+        if let Some(mask) = self.reset_mask {
+            changes.push(format!("reset mask {mask:#x}"));
+        }
+        Ok(format!("register {path}: {}", changes.join(", ")))
+    }
+}
+
+impl FieldPatch {
+    fn apply(&self, device: &mut svd_rs::Device) -> Result<String, String> {
+        let path = format!("{}.{}.{}", self.peripheral, self.register, self.name);
+        let glob = Glob::new(&self.name);
+        let access = self.access.as_deref().map(parse_access).transpose()?;
+        let mut matched = 0;
+        for register in registers(device, &self.peripheral, &self.register) {
+            let Some(fields) = register.fields.as_mut() else {
+                continue;
+            };
+            if self.delete {
+                let len = fields.len();
+                fields.retain(|field| !glob.matches(&field.name));
+                matched += len - fields.len();
+                continue;
+            }
+
+            let count = fields
+                .iter()
+                .filter(|field| glob.matches(&field.name))
+                .count();
+            if self.rename.is_some() && count > 1 {
+                return Err(format!(
+                    "The rename of {path} matches {count} fields of {}",
+                    register.name
+                ));
+            }
+            let mut resets = vec![];
+            for field in fields.iter_mut().filter(|field| glob.matches(&field.name)) {
+                matched += 1;
+                if let Some(description) = &self.description {
+                    field.description = Some(description.clone());
+                }
+                if access.is_some() {
+                    field.access = access;
+                }
+                add_values(field, &self.values);
+                if let Some(reset) = self.reset {
+                    resets.push((field.bit_range, reset, field.name.clone()));
+                }
+                if let Some(name) = &self.rename {
+                    field.name = name.clone();
+                }
+            }
+            for (bit_range, reset, name) in resets {
+                set_reset(register, bit_range, reset, &name)?;
+            }
+        }
+        if matched == 0 {
+            return Err(format!("No field matches {path}"));
+        }
+        if self.delete {
+            return Ok(format!("field {path}: deleted"));
+        }
+
+        let mut changes = vec![];
+        if let Some(name) = &self.rename {
+            changes.push(format!("renamed to {name}"));
+        }
+        if self.description.is_some() {
+            changes.push("description".to_string());
+        }
+        if let Some(access) = &self.access {
+            changes.push(format!("access {access}"));
+        }
+        if let Some(reset) = self.reset {
+            changes.push(format!("reset {reset:#x}"));
+        }
+        if !self.values.is_empty() {
+            let names: Vec<&str> = self.values.iter().map(|v| v.name.as_str()).collect();
+            changes.push(format!("values {}", names.join(", ")));
+        }
+        Ok(format!("field {path}: {}", changes.join(", ")))
+    }
+}
+
+impl NewField {
+    fn apply(&self, device: &mut svd_rs::Device) -> Result<String, String> {
+        let path = format!("{}.{}.{}", self.peripheral, self.register, self.name);
+        if self.bit_width == 0 {
+            return Err(format!("The field {path} is 0 bits wide"));
+        }
+        let bit_range = BitRange::from_offset_width(self.bit_offset, self.bit_width);
+        let mut field = FieldInfo::builder()
+            .name(self.name.clone())
+            .description(self.description.clone())
+            .bit_range(bit_range)
+            .access(self.access.as_deref().map(parse_access).transpose()?)
+            .build(ValidateLevel::Disabled)
+            .map_err(|e| format!("Invalid field {path}: {e}"))?;
+        add_values(&mut field, &self.values);
+
+        let mut matched = 0;
+        for register in registers(device, &self.peripheral, &self.register) {
+            matched += 1;
+            let end = self.bit_offset + self.bit_width;
+            if let Some(existing) = register.fields().find(|existing| {
+                existing.name == self.name
+                    || (existing.bit_offset() < end
+                        && self.bit_offset < existing.bit_offset() + existing.bit_width())
+            }) {
+                return Err(format!(
+                    "The field {path} clashes with {} of {}",
+                    existing.name, register.name
+                ));
+            }
+            register
+                .fields
+                .get_or_insert_with(Vec::new)
+                .push(field.clone().single());
+            if let Some(reset) = self.reset {
+                set_reset(register, bit_range, reset, &self.name)?;
+            }
+        }
+        if matched == 0 {
+            return Err(format!(
+                "No register matches {}.{}",
+                self.peripheral, self.register
+            ));
+        }
+        Ok(format!(
+            "field {path}: added at [{}:{}]",
+            bit_range.msb(),
+            bit_range.lsb()
+        ))
+    }
+}
+
+/// A glob matching names, `*` for any characters and `?` for one, or several separated by commas.
+struct Glob(Regex);
+
+impl Glob {
+    fn new(pattern: &str) -> Self {
+        let globs: Vec<String> = pattern
+            .split(',')
+            .map(|glob| {
+                regex::escape(glob.trim())
+                    .replace(r"\*", ".*")
+                    .replace(r"\?", ".")
+            })
+            .collect();
+        Glob(Regex::new(&format!("^(?:{})$", globs.join("|"))).unwrap())
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.0.is_match(name)
+    }
+}
+
+fn peripherals<'a>(
+    device: &'a mut svd_rs::Device,
+    pattern: &str,
+) -> impl Iterator<Item = &'a mut svd_rs::Peripheral> {
+    let glob = Glob::new(pattern);
+    device
+        .peripherals
+        .iter_mut()
+        .filter(move |p| glob.matches(&p.name))
+}
+
+/// The registers matching `register`, inside clusters too, of the peripherals matching
+/// `peripheral`.
+fn registers<'a>(
+    device: &'a mut svd_rs::Device,
+    peripheral: &str,
+    register: &str,
+) -> Vec<&'a mut svd_rs::Register> {
+    let glob = Glob::new(register);
+    peripherals(device, peripheral)
+        .flat_map(|p| p.all_registers_mut())
+        .filter(|r| glob.matches(&r.name))
+        .collect()
+}
+
+fn delete_registers(children: &mut Vec<RegisterCluster>, glob: &Glob) -> usize {
+    let len = children.len();
+    children.retain(|child| match child {
+        RegisterCluster::Register(register) => !glob.matches(&register.name),
+        RegisterCluster::Cluster(_) => true,
+    });
+    let mut deleted = len - children.len();
+    for child in children.iter_mut() {
+        if let RegisterCluster::Cluster(cluster) = child {
+            deleted += delete_registers(&mut cluster.children, glob);
+        }
+    }
+    deleted
+}
+
+fn parse_access(access: &str) -> Result<Access, String> {
+    Access::parse_str(access).ok_or(format!("Invalid access {access}"))
+}
+
+fn add_values(field: &mut FieldInfo, values: &[Value]) {
+    if values.is_empty() {
+        return;
+    }
+    if field.enumerated_values.is_empty() {
+        field.enumerated_values.push(
+            EnumeratedValues::builder()
+                .build(ValidateLevel::Disabled)
+                .unwrap(),
+        );
+    }
+    let enumerated = &mut field.enumerated_values[0].values;
+    for value in values {
+        enumerated
+            .retain(|existing| existing.name != value.name && existing.value != Some(value.value));
+        enumerated.push(
+            EnumeratedValue::builder()
+                .name(value.name.clone())
+                .description(value.description.clone())
+                .value(Some(value.value))
+                .build(ValidateLevel::Disabled)
+                .unwrap(),
+        );
+    }
+}
+
+/// Set the bits of a field in the reset value of its register.
+fn set_reset(
+    register: &mut RegisterInfo,
+    bit_range: BitRange,
+    reset: u64,
+    name: &str,
+) -> Result<(), String> {
+    if bit_range.width < 64 && reset >> bit_range.width != 0 {
+        return Err(format!(
+            "The reset {reset:#x} of {name} doesn't fit in {} bits",
+            bit_range.width
+        ));
+    }
+    let mask = u64::MAX >> (64 - bit_range.width) << bit_range.offset;
+    let value = register.properties.reset_value.unwrap_or(0);
+    register.properties.reset_value = Some(value & !mask | (reset << bit_range.offset) & mask);
+    Ok(())
+}
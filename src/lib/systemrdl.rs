@@ -0,0 +1,1305 @@
+// Copyright (c) 2025 Douglas Reis.
+// Licensed under the Apache License, Version 2.0, see LICENSE for details.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A parser for a practical subset of SystemRDL 2.0: `addrmap`, `regfile`, `reg`, `field` and
+//! `enum` definitions, anonymous and named, their instances with arrays, `@`, `+=` and `%=`,
+//! `default` properties and `` `include ``.
+//!
+//! The properties used are `desc`, `sw`, `reset`, `rclr`, `rset`, `onread`, `woclr`, `woset`,
+//! `onwrite`, `encode`, `fieldwidth` and `regwidth`, the others are ignored. Parameters, dynamic
+//! assignments, `mem`, `signal` and the other preprocessor directives are reported as errors.
+//!
+//! The last `addrmap` defined at the root is the top. Its `addrmap` instances are the devices of
+//! the platform, or the top is the only device when it has none.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// A position in a source file, displayed as `file:line:col`.
+#[derive(Debug, Clone)]
+pub struct Loc {
+    pub file: Rc<PathBuf>,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl fmt::Display for Loc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.col)
+    }
+}
+
+fn error<T>(loc: &Loc, msg: impl fmt::Display) -> Result<T, String> {
+    Err(format!("{loc}: {msg}"))
+}
+
+/// The software access of a field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SwAccess {
+    Read,
+    Write,
+    ReadWrite,
+    /// Only the first write after a reset has effect.
+    Write1,
+    ReadWrite1,
+}
+
+/// The `onread` side effects of a field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnRead {
+    Rclr,
+    Rset,
+}
+
+/// The `onwrite` side effects of a field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OnWrite {
+    Woclr,
+    Woset,
+    Wot,
+    Wzc,
+    Wzs,
+    Wzt,
+    Wclr,
+    Wset,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumMember {
+    pub name: String,
+    pub value: u64,
+    pub desc: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub name: String,
+    pub desc: Option<String>,
+    pub lsb: u32,
+    pub width: u32,
+    pub sw: SwAccess,
+    pub onread: Option<OnRead>,
+    pub onwrite: Option<OnWrite>,
+    pub reset: u64,
+    pub encode: Vec<EnumMember>,
+}
+
+/// The instances of an array, i.e `CTRL[4] @ 0x10 += 0x8`.
+#[derive(Debug, Clone)]
+pub struct Array {
+    pub dim: u32,
+    // The distance in bytes between two instances.
+    pub stride: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Register {
+    pub name: String,
+    pub desc: Option<String>,
+    // Relative to the parent.
+    pub offset: u32,
+    pub width: u32,
+    pub array: Option<Array>,
+    pub fields: Vec<Field>,
+}
+
+/// A `regfile`, or an `addrmap` nested in a device.
+#[derive(Debug, Clone)]
+pub struct RegFile {
+    pub name: String,
+    pub desc: Option<String>,
+    // Relative to the parent.
+    pub offset: u32,
+    pub array: Option<Array>,
+    pub registers: Vec<Register>,
+    pub regfiles: Vec<RegFile>,
+}
+
+/// An `addrmap` instance that becomes a device.
+#[derive(Debug, Clone)]
+pub struct AddrMap {
+    pub name: String,
+    // The name of the definition, shared by all its instances.
+    pub type_name: String,
+    pub desc: Option<String>,
+    pub address: u64,
+    pub registers: Vec<Register>,
+    pub regfiles: Vec<RegFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SoC {
+    pub name: String,
+    pub devices: Vec<AddrMap>,
+}
+
+impl SoC {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut tokens = Vec::new();
+        lex_file(path, None, &mut tokens, &mut Vec::new())?;
+        let root = Parser::new(tokens).root()?;
+        Elaborator::default().top(&root)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------
+// Lexer.
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Num(u64),
+    Str(String),
+    Sym(&'static str),
+    Eof,
+}
+
+impl fmt::Display for Tok {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Tok::Ident(s) => write!(f, "'{s}'"),
+            Tok::Num(n) => write!(f, "'{n}'"),
+            Tok::Str(s) => write!(f, "\"{s}\""),
+            Tok::Sym(s) => write!(f, "'{s}'"),
+            Tok::Eof => write!(f, "end of file"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    loc: Loc,
+}
+
+const SYMBOLS: [&str; 17] = [
+    "->", "+=", "%=", "::", "{", "}", "[", "]", "(", ")", ";", ",", "=", "@", ":", ".", "#",
+];
+
+/// Append the tokens of a file, and of the files it includes, to `tokens`.
+fn lex_file(
+    path: &Path,
+    included_at: Option<&Loc>,
+    tokens: &mut Vec<Token>,
+    stack: &mut Vec<PathBuf>,
+) -> Result<(), String> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => match included_at {
+            Some(loc) => return error(loc, format!("can't include {}: {e}", path.display())),
+            None => return Err(format!("{}: {e}", path.display())),
+        },
+    };
+    if stack.iter().any(|p| p == path) {
+        return error(
+            included_at.unwrap(),
+            format!("{} includes itself", path.display()),
+        );
+    }
+    stack.push(path.to_path_buf());
+
+    let file = Rc::new(path.to_path_buf());
+    let chars: Vec<char> = text.chars().collect();
+    let (mut pos, mut line, mut col) = (0, 1, 1);
+    macro_rules! advance {
+        () => {{
+            if chars[pos] == '\n' {
+                line += 1;
+                col = 1;
+            } else {
+                col += 1;
+            }
+            pos += 1;
+        }};
+    }
+    while pos < chars.len() {
+        let c = chars[pos];
+        let loc = Loc {
+            file: file.clone(),
+            line,
+            col,
+        };
+        let next = chars.get(pos + 1).copied();
+        if c.is_whitespace() {
+            advance!();
+        } else if c == '/' && next == Some('/') {
+            while pos < chars.len() && chars[pos] != '\n' {
+                advance!();
+            }
+        } else if c == '/' && next == Some('*') {
+            advance!();
+            advance!();
+            while pos < chars.len() && !(chars[pos] == '*' && chars.get(pos + 1) == Some(&'/')) {
+                advance!();
+            }
+            if pos >= chars.len() {
+                return error(&loc, "unterminated comment");
+            }
+            advance!();
+            advance!();
+        } else if c == '<' && next == Some('%') {
+            return error(&loc, "embedded Perl is not supported");
+        } else if c == '`' {
+            let start = pos + 1;
+            advance!();
+            while pos < chars.len() && chars[pos].is_ascii_alphanumeric() {
+                advance!();
+            }
+            let directive: String = chars[start..pos].iter().collect();
+            if directive != "include" {
+                return error(&loc, format!("`{directive} is not supported"));
+            }
+            while pos < chars.len() && chars[pos] != '\n' && chars[pos] != '"' {
+                advance!();
+            }
+            if pos >= chars.len() || chars[pos] != '"' {
+                return error(&loc, "expected a file name after `include");
+            }
+            advance!();
+            let start = pos;
+            while pos < chars.len() && chars[pos] != '"' && chars[pos] != '\n' {
+                advance!();
+            }
+            let name: String = chars[start..pos].iter().collect();
+            if pos < chars.len() && chars[pos] == '"' {
+                advance!();
+            }
+            let included = path.parent().unwrap_or(Path::new(".")).join(name);
+            lex_file(&included, Some(&loc), tokens, stack)?;
+        } else if c == '"' {
+            advance!();
+            let mut s = String::new();
+            loop {
+                match chars.get(pos) {
+                    None => return error(&loc, "unterminated string"),
+                    Some('"') => {
+                        advance!();
+                        break;
+                    }
+                    Some('\\') if pos + 1 < chars.len() => {
+                        advance!();
+                        s.push(chars[pos]);
+                        advance!();
+                    }
+                    Some(c) => {
+                        s.push(*c);
+                        advance!();
+                    }
+                }
+            }
+            tokens.push(Token {
+                tok: Tok::Str(s),
+                loc,
+            });
+        } else if c.is_ascii_digit() || c == '\'' {
+            let start = pos;
+            while pos < chars.len()
+                && (chars[pos].is_ascii_alphanumeric() || chars[pos] == '_' || chars[pos] == '\'')
+            {
+                advance!();
+            }
+            let text: String = chars[start..pos].iter().collect();
+            let Some(num) = parse_number(&text) else {
+                return error(&loc, format!("invalid number {text}"));
+            };
+            tokens.push(Token {
+                tok: Tok::Num(num),
+                loc,
+            });
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '\\' {
+            // An escaped identifier, i.e `\field`, is the identifier without the backslash.
+            if c == '\\' {
+                advance!();
+            }
+            let start = pos;
+            while pos < chars.len() && (chars[pos].is_ascii_alphanumeric() || chars[pos] == '_') {
+                advance!();
+            }
+            tokens.push(Token {
+                tok: Tok::Ident(chars[start..pos].iter().collect()),
+                loc,
+            });
+        } else if let Some(sym) = SYMBOLS.iter().find(|s| {
+            s.chars()
+                .enumerate()
+                .all(|(i, c)| chars.get(pos + i) == Some(&c))
+        }) {
+            for _ in 0..sym.len() {
+                advance!();
+            }
+            tokens.push(Token {
+                tok: Tok::Sym(sym),
+                loc,
+            });
+        } else {
+            return error(&loc, format!("unexpected character '{c}'"));
+        }
+    }
+
+    stack.pop();
+    if included_at.is_none() {
+        tokens.push(Token {
+            tok: Tok::Eof,
+            loc: Loc { file, line, col },
+        });
+    }
+    Ok(())
+}
+
+/// A decimal, hexadecimal (`0x1f`) or verilog style (`8'h1f`, `'b1`) number.
+fn parse_number(text: &str) -> Option<u64> {
+    let text = text.replace('_', "");
+    if let Some(hex) = text.strip_prefix("0x").or(text.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16).ok();
+    }
+    let Some((width, value)) = text.split_once('\'') else {
+        return text.parse().ok();
+    };
+    let mut chars = value.chars();
+    let radix = match chars.next()?.to_ascii_lowercase() {
+        'b' => 2,
+        'o' => 8,
+        'd' => 10,
+        'h' => 16,
+        _ => return None,
+    };
+    let value = u64::from_str_radix(chars.as_str(), radix).ok()?;
+    if !width.is_empty() {
+        let width: u32 = width.parse().ok()?;
+        if width == 0 || (width < 64 && value >> width != 0) {
+            return None;
+        }
+    }
+    Some(value)
+}
+
+// ----------------------------------------------------------------------------------------------
+// Parser.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    AddrMap,
+    RegFile,
+    Reg,
+    Field,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Kind::AddrMap => "addrmap",
+            Kind::RegFile => "regfile",
+            Kind::Reg => "reg",
+            Kind::Field => "field",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Bool(bool),
+    Num(u64),
+    Str(String),
+    Ident(String),
+}
+
+#[derive(Debug, Clone)]
+struct Property {
+    name: String,
+    value: Value,
+    loc: Loc,
+}
+
+#[derive(Debug, Clone)]
+struct EnumDef {
+    name: String,
+    members: Vec<EnumMember>,
+}
+
+#[derive(Debug, Clone)]
+enum Dims {
+    // `[4]`, the number of instances, or the width of a field.
+    Dim(u64),
+    // `[7:0]`, only for fields.
+    Range(u64, u64),
+}
+
+#[derive(Debug, Clone)]
+struct Instance {
+    name: String,
+    dims: Option<Dims>,
+    reset: Option<u64>,
+    at: Option<u64>,
+    stride: Option<u64>,
+    align: Option<u64>,
+    loc: Loc,
+}
+
+#[derive(Debug, Clone)]
+enum Item {
+    /// The instances of a definition, anonymous when it has no name.
+    Instances(Rc<Component>, Vec<Instance>),
+    /// The instances of a named definition, resolved during the elaboration.
+    Named(String, Loc, Vec<Instance>),
+}
+
+#[derive(Debug)]
+struct Component {
+    kind: Kind,
+    name: Option<String>,
+    properties: Vec<Property>,
+    // The defaults in effect where the component is defined.
+    defaults: Vec<Property>,
+    items: Vec<Item>,
+    definitions: HashMap<String, Rc<Component>>,
+    enums: HashMap<String, EnumDef>,
+    loc: Loc,
+    // The position of the definition in the tokens, which orders the definitions across the
+    // included files.
+    index: usize,
+}
+
+impl Component {
+    fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .rev()
+            .chain(self.defaults.iter().rev())
+            .find(|p| p.name == name)
+    }
+
+    fn string(&self, name: &str) -> Result<Option<String>, String> {
+        match self.property(name) {
+            None => Ok(None),
+            Some(Property {
+                value: Value::Str(s),
+                ..
+            }) => Ok(Some(s.split_whitespace().collect::<Vec<_>>().join(" "))),
+            Some(p) => error(&p.loc, format!("{name} must be a string")),
+        }
+    }
+
+    fn number(&self, name: &str) -> Result<Option<(u64, &Loc)>, String> {
+        match self.property(name) {
+            None => Ok(None),
+            Some(Property {
+                value: Value::Num(n),
+                loc,
+                ..
+            }) => Ok(Some((*n, loc))),
+            Some(p) => error(&p.loc, format!("{name} must be a number")),
+        }
+    }
+
+    fn flag(&self, name: &str) -> Result<bool, String> {
+        match self.property(name) {
+            None => Ok(false),
+            Some(Property {
+                value: Value::Bool(b),
+                ..
+            }) => Ok(*b),
+            Some(p) => error(&p.loc, format!("{name} must be a boolean")),
+        }
+    }
+
+    fn ident(&self, name: &str) -> Result<Option<(&str, &Loc)>, String> {
+        match self.property(name) {
+            None => Ok(None),
+            Some(Property {
+                value: Value::Ident(s),
+                loc,
+                ..
+            }) => Ok(Some((s, loc))),
+            Some(p) => error(&p.loc, format!("{name} has an invalid value")),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    // The `default` properties of each enclosing scope.
+    defaults: Vec<Vec<Property>>,
+}
+
+impl Parser {
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            pos: 0,
+            defaults: vec![vec![]],
+        }
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn is_sym(&self, sym: &str) -> bool {
+        self.peek().tok == Tok::Sym(sym_of(sym))
+    }
+
+    fn eat(&mut self, sym: &str) -> bool {
+        let found = self.is_sym(sym);
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    fn expect(&mut self, sym: &str) -> Result<(), String> {
+        let token = self.next();
+        if token.tok != Tok::Sym(sym_of(sym)) {
+            return error(&token.loc, format!("expected '{sym}', found {}", token.tok));
+        }
+        Ok(())
+    }
+
+    fn ident(&mut self) -> Result<(String, Loc), String> {
+        let token = self.next();
+        match token.tok {
+            Tok::Ident(s) => Ok((s, token.loc)),
+            tok => error(&token.loc, format!("expected an identifier, found {tok}")),
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, String> {
+        let token = self.next();
+        match token.tok {
+            Tok::Num(n) => Ok(n),
+            tok => error(&token.loc, format!("expected a number, found {tok}")),
+        }
+    }
+
+    fn root(&mut self) -> Result<Component, String> {
+        let loc = self.peek().loc.clone();
+        let mut root = Component {
+            kind: Kind::AddrMap,
+            name: None,
+            properties: vec![],
+            defaults: vec![],
+            items: vec![],
+            definitions: HashMap::new(),
+            enums: HashMap::new(),
+            loc,
+            index: 0,
+        };
+        while self.peek().tok != Tok::Eof {
+            self.statement(&mut root)?;
+        }
+        if let Some(Item::Named(_, loc, _)) = root
+            .items
+            .iter()
+            .find(|item| matches!(item, Item::Named(..)))
+        {
+            return error(loc, "components can't be instantiated at the root");
+        }
+        Ok(root)
+    }
+
+    /// Parse a statement of the body of `parent`.
+    fn statement(&mut self, parent: &mut Component) -> Result<(), String> {
+        let token = self.peek().clone();
+        let word = match &token.tok {
+            Tok::Sym(";") => {
+                self.next();
+                return Ok(());
+            }
+            Tok::Ident(word) => word.clone(),
+            tok => return error(&token.loc, format!("unexpected {tok}")),
+        };
+        let kind = match word.as_str() {
+            "addrmap" => Some(Kind::AddrMap),
+            "regfile" => Some(Kind::RegFile),
+            "reg" => Some(Kind::Reg),
+            "field" => Some(Kind::Field),
+            _ => None,
+        };
+        match word.as_str() {
+            _ if kind.is_some() => {
+                self.next();
+                let component = self.component(kind.unwrap(), &token.loc)?;
+                let component = Rc::new(component);
+                if let Some(ref name) = component.name {
+                    parent.definitions.insert(name.clone(), component.clone());
+                }
+                let instances = self.instances()?;
+                if component.name.is_none() && instances.is_empty() {
+                    return error(
+                        &token.loc,
+                        format!("anonymous {} without instances", component.kind),
+                    );
+                }
+                if !instances.is_empty() {
+                    parent.items.push(Item::Instances(component, instances));
+                }
+                self.expect(";")
+            }
+            "enum" => {
+                self.next();
+                let def = self.enum_def()?;
+                parent.enums.insert(def.name.clone(), def);
+                self.expect(";")
+            }
+            "default" => {
+                self.next();
+                let property = self.property()?;
+                self.defaults.last_mut().unwrap().push(property);
+                Ok(())
+            }
+            "mem" | "signal" | "constraint" | "property" | "struct" => {
+                error(&token.loc, format!("{word} is not supported"))
+            }
+            "external" | "internal" => {
+                self.next();
+                self.statement(parent)
+            }
+            _ => {
+                let next = &self.tokens[self.pos + 1];
+                match &next.tok {
+                    // A named definition followed by its instances, i.e `ctrl_t CTRL @ 0x0;`.
+                    Tok::Ident(_) => {
+                        self.next();
+                        if self.is_sym("#") {
+                            return error(&self.peek().loc, "parameters are not supported");
+                        }
+                        let instances = self.instances()?;
+                        parent
+                            .items
+                            .push(Item::Named(word.clone(), token.loc.clone(), instances));
+                        self.expect(";")
+                    }
+                    Tok::Sym("->") | Tok::Sym(".") => {
+                        error(&token.loc, "dynamic assignments are not supported")
+                    }
+                    _ => {
+                        let property = self.property()?;
+                        parent.properties.push(property);
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// A component definition after its keyword, up to the closing brace.
+    fn component(&mut self, kind: Kind, loc: &Loc) -> Result<Component, String> {
+        let name = match self.peek().tok {
+            Tok::Ident(_) => Some(self.ident()?.0),
+            _ => None,
+        };
+        if self.is_sym("#") {
+            return error(&self.peek().loc, "parameters are not supported");
+        }
+        self.expect("{")?;
+        let defaults = self.defaults.iter().flatten().cloned().collect();
+        let mut component = Component {
+            kind,
+            name,
+            properties: vec![],
+            defaults,
+            items: vec![],
+            definitions: HashMap::new(),
+            enums: HashMap::new(),
+            loc: loc.clone(),
+            index: self.pos,
+        };
+        self.defaults.push(vec![]);
+        while !self.eat("}") {
+            if self.peek().tok == Tok::Eof {
+                return error(&self.peek().loc, format!("unterminated {kind}"));
+            }
+            self.statement(&mut component)?;
+        }
+        self.defaults.pop();
+        if kind == Kind::Field && !component.items.is_empty() {
+            return error(loc, "a field can't have instances of components");
+        }
+        Ok(component)
+    }
+
+    /// A property assignment: `sw = rw;`, `desc = "..";` or `rclr;`.
+    fn property(&mut self) -> Result<Property, String> {
+        let (name, loc) = self.ident()?;
+        let value = if self.eat("=") {
+            let token = self.next();
+            match token.tok {
+                Tok::Num(n) => Value::Num(n),
+                Tok::Str(s) => Value::Str(s),
+                Tok::Ident(s) if s == "true" => Value::Bool(true),
+                Tok::Ident(s) if s == "false" => Value::Bool(false),
+                Tok::Ident(s) => {
+                    // A reference to an enum member, i.e `my_enum::VALUE`, is kept as the enum.
+                    if self.eat("::") {
+                        self.ident()?;
+                    }
+                    Value::Ident(s)
+                }
+                tok => return error(&token.loc, format!("invalid value {tok} for {name}")),
+            }
+        } else {
+            Value::Bool(true)
+        };
+        self.expect(";")?;
+        Ok(Property { name, value, loc })
+    }
+
+    /// The instances after a definition: `CTRL[4] @ 0x10 += 0x8, STATUS;`. Only the fields have a
+    /// reset value: `EN[3:0] = 0x1`.
+    fn instances(&mut self) -> Result<Vec<Instance>, String> {
+        let mut instances = Vec::new();
+        if self.is_sym(";") {
+            return Ok(instances);
+        }
+        loop {
+            let (name, loc) = self.ident()?;
+            let mut instance = Instance {
+                name,
+                dims: None,
+                reset: None,
+                at: None,
+                stride: None,
+                align: None,
+                loc,
+            };
+            if self.eat("[") {
+                let first = self.number()?;
+                instance.dims = Some(if self.eat(":") {
+                    Dims::Range(first, self.number()?)
+                } else {
+                    Dims::Dim(first)
+                });
+                self.expect("]")?;
+                if self.is_sym("[") {
+                    return error(
+                        &self.peek().loc,
+                        "multidimensional arrays are not supported",
+                    );
+                }
+            }
+            if self.eat("=") {
+                instance.reset = Some(self.number()?);
+            }
+            if self.eat("@") {
+                instance.at = Some(self.number()?);
+            }
+            if self.eat("+=") {
+                instance.stride = Some(self.number()?);
+            }
+            if self.eat("%=") {
+                instance.align = Some(self.number()?);
+            }
+            instances.push(instance);
+            if !self.eat(",") {
+                break;
+            }
+        }
+        Ok(instances)
+    }
+
+    /// An enum definition after the keyword: `name { A = 0 { desc = ".."; }; B; }`.
+    fn enum_def(&mut self) -> Result<EnumDef, String> {
+        let (name, _) = self.ident()?;
+        self.expect("{")?;
+        let mut members: Vec<EnumMember> = Vec::new();
+        while !self.eat("}") {
+            let (member, loc) = self.ident()?;
+            let value = match self.eat("=") {
+                true => self.number()?,
+                false => members.last().map_or(0, |m| m.value + 1),
+            };
+            let mut desc = None;
+            if self.eat("{") {
+                while !self.eat("}") {
+                    let property = self.property()?;
+                    if let ("desc", Value::Str(s)) = (property.name.as_str(), property.value) {
+                        desc = Some(s.split_whitespace().collect::<Vec<_>>().join(" "));
+                    }
+                }
+            }
+            if members.iter().any(|m| m.name == member) {
+                return error(&loc, format!("duplicated member {member} in enum {name}"));
+            }
+            members.push(EnumMember {
+                name: member,
+                value,
+                desc,
+            });
+            self.expect(";")?;
+        }
+        Ok(EnumDef { name, members })
+    }
+}
+
+fn sym_of(sym: &str) -> &'static str {
+    SYMBOLS.iter().find(|s| **s == sym).unwrap()
+}
+
+// ----------------------------------------------------------------------------------------------
+// Elaboration.
+
+/// Collect the names of the definitions instantiated in `component` and in its children.
+fn instantiated_names(component: &Component, names: &mut HashSet<String>) {
+    for item in &component.items {
+        match item {
+            Item::Instances(child, _) => instantiated_names(child, names),
+            Item::Named(name, ..) => {
+                names.insert(name.clone());
+            }
+        }
+    }
+    for definition in component.definitions.values() {
+        instantiated_names(definition, names);
+    }
+}
+
+#[derive(Default)]
+struct Elaborator<'a> {
+    // The components enclosing the one being elaborated, to look up the named definitions.
+    scopes: Vec<&'a Component>,
+}
+
+/// The offset, size and location of the instances already placed in a component, to detect the
+/// overlaps.
+type Occupied = Vec<(u64, u64, Loc)>;
+
+impl<'a> Elaborator<'a> {
+    fn top(mut self, root: &'a Component) -> Result<SoC, String> {
+        // The last addrmap defined at the root that isn't instantiated by another one.
+        let mut instantiated = HashSet::new();
+        instantiated_names(root, &mut instantiated);
+        let top = root
+            .definitions
+            .values()
+            .chain(root.items.iter().filter_map(|item| match item {
+                Item::Instances(component, _) => Some(component),
+                Item::Named(..) => None,
+            }))
+            .filter(|c| c.kind == Kind::AddrMap)
+            .filter(|c| !c.name.as_ref().is_some_and(|n| instantiated.contains(n)))
+            .max_by_key(|c| c.index);
+        let Some(top) = top else {
+            return error(&root.loc, "no addrmap found");
+        };
+        let name = top.name.clone().unwrap_or(String::from("top"));
+
+        self.scopes.push(root);
+        let instances = self.instances_of(top)?;
+        if !instances.iter().any(|(c, _)| c.kind == Kind::AddrMap) {
+            let device = self.addrmap(top, &name)?;
+            return Ok(SoC {
+                name,
+                devices: vec![device],
+            });
+        }
+
+        self.scopes.push(top);
+        let mut devices = Vec::new();
+        let mut occupied = Occupied::new();
+        let mut next = 0;
+        for (component, instances) in instances {
+            for instance in instances {
+                if component.kind != Kind::AddrMap {
+                    println!(
+                        "Warning: {}: {} {} outside of a device, skipping.",
+                        instance.loc, component.kind, instance.name
+                    );
+                    continue;
+                }
+                if instance.dims.is_some() {
+                    return error(&instance.loc, "arrays of devices are not supported");
+                }
+                let mut device = self.addrmap(component, &instance.name)?;
+                let size = children_size(&device.registers, &device.regfiles);
+                device.address = place(instance, next, size, 4, &mut occupied)?;
+                next = device.address + size;
+                devices.push(device);
+            }
+        }
+        Ok(SoC { name, devices })
+    }
+
+    /// The instances in the body of a component, with their definition.
+    fn instances_of(
+        &self,
+        component: &'a Component,
+    ) -> Result<Vec<(&'a Component, &'a [Instance])>, String> {
+        component
+            .items
+            .iter()
+            .map(|item| match item {
+                Item::Instances(def, instances) => Ok((def.as_ref(), &instances[..])),
+                Item::Named(name, loc, instances) => {
+                    Ok((self.lookup(component, name, loc)?, &instances[..]))
+                }
+            })
+            .collect()
+    }
+
+    /// The named definition visible from a component, which may be defined in any of the
+    /// enclosing components.
+    fn lookup(
+        &self,
+        component: &'a Component,
+        name: &str,
+        loc: &Loc,
+    ) -> Result<&'a Component, String> {
+        std::iter::once(component)
+            .chain(self.scopes.iter().rev().copied())
+            .find_map(|scope| scope.definitions.get(name))
+            .map(|def| def.as_ref())
+            .ok_or(format!("{loc}: undefined component {name}"))
+    }
+
+    fn lookup_enum(
+        &self,
+        component: &'a Component,
+        name: &str,
+        loc: &Loc,
+    ) -> Result<&'a EnumDef, String> {
+        std::iter::once(component)
+            .chain(self.scopes.iter().rev().copied())
+            .find_map(|scope| scope.enums.get(name))
+            .ok_or(format!("{loc}: undefined enum {name}"))
+    }
+
+    fn addrmap(&mut self, component: &'a Component, name: &str) -> Result<AddrMap, String> {
+        let (registers, regfiles) = self.children(component)?;
+        Ok(AddrMap {
+            name: name.to_owned(),
+            // Without the usual suffix of the definitions, i.e `uart` for `uart_t`.
+            type_name: component
+                .name
+                .as_ref()
+                .map_or(name, |n| n.trim_end_matches("_t"))
+                .to_owned(),
+            desc: component.string("desc")?,
+            address: 0,
+            registers,
+            regfiles,
+        })
+    }
+
+    /// Place the registers and the regfiles of a component, where the addrmaps are taken as
+    /// regfiles.
+    fn children(
+        &mut self,
+        component: &'a Component,
+    ) -> Result<(Vec<Register>, Vec<RegFile>), String> {
+        self.scopes.push(component);
+        let mut registers = Vec::new();
+        let mut regfiles = Vec::new();
+        let mut occupied = Occupied::new();
+        let mut next = 0;
+        for (def, instances) in self.instances_of(component)? {
+            for instance in instances {
+                if def.kind != Kind::Field && instance.reset.is_some() {
+                    return error(&instance.loc, "only fields can have a reset value");
+                }
+                match def.kind {
+                    Kind::Field => {
+                        return error(
+                            &instance.loc,
+                            format!("field {} must be in a reg", instance.name),
+                        );
+                    }
+                    Kind::Reg => {
+                        let mut register = self.register(def, instance)?;
+                        let bytes = register.width as u64 / 8;
+                        let (size, array) = array(instance, bytes)?;
+                        let offset = place(instance, next, size, bytes, &mut occupied)?;
+                        register.offset = to_u32(offset, &instance.loc)?;
+                        register.array = array;
+                        next = offset + size;
+                        registers.push(register);
+                    }
+                    Kind::RegFile | Kind::AddrMap => {
+                        let (inner_registers, inner_regfiles) = self.children(def)?;
+                        let element = children_size(&inner_registers, &inner_regfiles);
+                        if element == 0 {
+                            return error(&instance.loc, format!("{} is empty", instance.name));
+                        }
+                        let (size, array) = array(instance, element)?;
+                        let offset = place(instance, next, size, 4, &mut occupied)?;
+                        next = offset + size;
+                        regfiles.push(RegFile {
+                            name: instance.name.clone(),
+                            desc: def.string("desc")?,
+                            offset: to_u32(offset, &instance.loc)?,
+                            array,
+                            registers: inner_registers,
+                            regfiles: inner_regfiles,
+                        });
+                    }
+                }
+            }
+        }
+        self.scopes.pop();
+        Ok((registers, regfiles))
+    }
+
+    fn register(
+        &mut self,
+        component: &'a Component,
+        instance: &Instance,
+    ) -> Result<Register, String> {
+        let width = match component.number("regwidth")? {
+            Some((width, loc)) => {
+                if ![8, 16, 32, 64].contains(&width) {
+                    return error(loc, format!("regwidth {width} not supported"));
+                }
+                width as u32
+            }
+            None => 32,
+        };
+
+        self.scopes.push(component);
+        let mut fields: Vec<(Field, Loc)> = Vec::new();
+        let mut next = 0;
+        for (def, instances) in self.instances_of(component)? {
+            if def.kind != Kind::Field {
+                return error(
+                    &instances[0].loc,
+                    format!("{} {} can't be in a reg", def.kind, instances[0].name),
+                );
+            }
+            for instance in instances {
+                let field = self.field(def, instance, next, width)?;
+                if let Some((other, _)) = fields
+                    .iter()
+                    .find(|(f, _)| f.lsb < field.lsb + field.width && field.lsb < f.lsb + f.width)
+                {
+                    return error(
+                        &instance.loc,
+                        format!("field {} overlaps {}", field.name, other.name),
+                    );
+                }
+                next = field.lsb + field.width;
+                fields.push((field, instance.loc.clone()));
+            }
+        }
+        self.scopes.pop();
+
+        if fields.is_empty() {
+            return error(
+                &instance.loc,
+                format!("reg {} has no fields", instance.name),
+            );
+        }
+        Ok(Register {
+            name: instance.name.clone(),
+            desc: component.string("desc")?,
+            offset: 0,
+            width,
+            array: None,
+            fields: fields.into_iter().map(|(f, _)| f).collect(),
+        })
+    }
+
+    fn field(
+        &self,
+        component: &'a Component,
+        instance: &Instance,
+        next: u32,
+        reg_width: u32,
+    ) -> Result<Field, String> {
+        let loc = &instance.loc;
+        let (lsb, width) = match instance.dims {
+            Some(Dims::Range(msb, lsb)) => {
+                let (msb, lsb) = if msb >= lsb { (msb, lsb) } else { (lsb, msb) };
+                (lsb, msb - lsb + 1)
+            }
+            Some(Dims::Dim(width)) => (instance.at.unwrap_or(next as u64), width),
+            None => {
+                let width = component.number("fieldwidth")?.map_or(1, |(w, _)| w);
+                (instance.at.unwrap_or(next as u64), width)
+            }
+        };
+        if width == 0 || lsb + width > reg_width as u64 {
+            return error(
+                loc,
+                format!(
+                    "field {} [{}:{}] doesn't fit in a {reg_width} bits reg",
+                    instance.name,
+                    lsb + width.max(1) - 1,
+                    lsb
+                ),
+            );
+        }
+
+        let sw = match component.ident("sw")? {
+            None => SwAccess::ReadWrite,
+            Some(("rw", _)) | Some(("wr", _)) => SwAccess::ReadWrite,
+            Some(("r", _)) => SwAccess::Read,
+            Some(("w", _)) => SwAccess::Write,
+            Some(("w1", _)) => SwAccess::Write1,
+            Some(("rw1", _)) => SwAccess::ReadWrite1,
+            Some(("na", loc)) => return error(loc, "fields with sw = na are not supported"),
+            Some((other, loc)) => return error(loc, format!("invalid sw access {other}")),
+        };
+
+        let onread = match component.ident("onread")? {
+            Some(("rclr", _)) => Some(OnRead::Rclr),
+            Some(("rset", _)) => Some(OnRead::Rset),
+            Some((other, loc)) => return error(loc, format!("onread = {other} not supported")),
+            None if component.flag("rclr")? => Some(OnRead::Rclr),
+            None if component.flag("rset")? => Some(OnRead::Rset),
+            None => None,
+        };
+
+        let onwrite = match component.ident("onwrite")? {
+            Some((value, loc)) => Some(match value {
+                "woclr" => OnWrite::Woclr,
+                "woset" => OnWrite::Woset,
+                "wot" => OnWrite::Wot,
+                "wzc" => OnWrite::Wzc,
+                "wzs" => OnWrite::Wzs,
+                "wzt" => OnWrite::Wzt,
+                "wclr" => OnWrite::Wclr,
+                "wset" => OnWrite::Wset,
+                other => return error(loc, format!("onwrite = {other} not supported")),
+            }),
+            None if component.flag("woclr")? => Some(OnWrite::Woclr),
+            None if component.flag("woset")? => Some(OnWrite::Woset),
+            None => None,
+        };
+
+        let reset = match (instance.reset, component.number("reset")?) {
+            (Some(reset), _) | (None, Some((reset, _))) => reset,
+            (None, None) => 0,
+        };
+        if width < 64 && reset >> width != 0 {
+            return error(
+                loc,
+                format!(
+                    "reset {reset:#x} doesn't fit in the field {}",
+                    instance.name
+                ),
+            );
+        }
+
+        let encode = match component.ident("encode")? {
+            Some((name, loc)) => self.lookup_enum(component, name, loc)?.members.clone(),
+            None => vec![],
+        };
+        if let Some(member) = encode.iter().find(|m| width < 64 && m.value >> width != 0) {
+            return error(
+                loc,
+                format!(
+                    "enum value {} doesn't fit in the field {}",
+                    member.name, instance.name
+                ),
+            );
+        }
+
+        Ok(Field {
+            name: instance.name.clone(),
+            desc: component.string("desc")?,
+            lsb: lsb as u32,
+            width: width as u32,
+            sw,
+            onread,
+            onwrite,
+            reset,
+            encode,
+        })
+    }
+}
+
+/// The size of all the instances of an instance with elements of `element` bytes, and its array
+/// if it has one.
+fn array(instance: &Instance, element: u64) -> Result<(u64, Option<Array>), String> {
+    match instance.dims {
+        None => Ok((element, None)),
+        Some(Dims::Range(..)) => error(&instance.loc, "a range is only allowed for fields"),
+        Some(Dims::Dim(0)) => error(&instance.loc, "empty array"),
+        Some(Dims::Dim(dim)) => {
+            let stride = instance.stride.unwrap_or(element);
+            if stride < element {
+                return error(
+                    &instance.loc,
+                    format!("stride {stride:#x} is smaller than the element size {element:#x}"),
+                );
+            }
+            let array = Array {
+                dim: to_u32(dim, &instance.loc)?,
+                stride: to_u32(stride, &instance.loc)?,
+            };
+            Ok((stride * (dim - 1) + element, Some(array)))
+        }
+    }
+}
+
+/// The offset of an instance, either given by `@` or the next one aligned to `%=`, or to `align`
+/// by default, which must not overlap the instances already placed.
+fn place(
+    instance: &Instance,
+    next: u64,
+    size: u64,
+    align: u64,
+    occupied: &mut Occupied,
+) -> Result<u64, String> {
+    let offset = match instance.at {
+        Some(at) => at,
+        None => {
+            let align = instance.align.unwrap_or(align);
+            if !align.is_power_of_two() {
+                return error(
+                    &instance.loc,
+                    format!("alignment {align:#x} isn't a power of two"),
+                );
+            }
+            next.next_multiple_of(align)
+        }
+    };
+    if let Some((_, _, other)) = occupied
+        .iter()
+        .find(|(o, s, _)| *o < offset + size && offset < o + s)
+    {
+        return error(
+            &instance.loc,
+            format!(
+                "{} at {offset:#x} overlaps the instance at {other}",
+                instance.name
+            ),
+        );
+    }
+    occupied.push((offset, size, instance.loc.clone()));
+    Ok(offset)
+}
+
+fn to_u32(value: u64, loc: &Loc) -> Result<u32, String> {
+    u32::try_from(value).or(error(loc, format!("{value:#x} doesn't fit in 32 bits")))
+}
+
+/// The size in bytes of a component, up to the end of its last child.
+fn children_size(registers: &[Register], regfiles: &[RegFile]) -> u64 {
+    let size = |offset: u32, array: &Option<Array>, element: u64| {
+        let others = array
+            .as_ref()
+            .map_or(0, |a| (a.dim - 1) as u64 * a.stride as u64);
+        offset as u64 + others + element
+    };
+    registers
+        .iter()
+        .map(|r| size(r.offset, &r.array, r.width as u64 / 8))
+        .chain(
+            regfiles
+                .iter()
+                .map(|r| size(r.offset, &r.array, children_size(&r.registers, &r.regfiles))),
+        )
+        .max()
+        .unwrap_or(0)
+}
@@ -2,11 +2,17 @@
 // Licensed under the Apache License, Version 2.0, see LICENSE for details.
 // SPDX-License-Identifier: Apache-2.0
 
+use zermiolib::devicetree;
 use zermiolib::generator;
+use zermiolib::hjson;
+use zermiolib::ipxact;
+use zermiolib::mmio::Platform;
 use zermiolib::rdljson;
+use zermiolib::svdpatch;
+use zermiolib::systemrdl;
 
 use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 static FILE_HEADER: &str = r#"
@@ -16,11 +22,16 @@ static FILE_HEADER: &str = r#"
 "#;
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::enum_variant_names)]
 enum Input {
     ImportSvd {
         /// A path to a svd file
         #[arg(long, value_parser =  PathBuf::from_str)]
         svd: PathBuf,
+        /// Paths to toml files patching the svd, i.e fixing the access of a field, applied in
+        /// order.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        patch: Vec<PathBuf>,
         /// A path to a text with containing the licence header that should be added to every
         /// generated file.
         #[arg(long, value_parser =  PathBuf::from_str)]
@@ -46,9 +57,97 @@ enum Input {
         #[command(subcommand)]
         output: Output,
     },
+    ImportRdl {
+        /// A path to a SystemRDL file, the last addrmap defined is the top.
+        #[arg(long, short, value_parser =  PathBuf::from_str)]
+        rdl: PathBuf,
+
+        /// A path to a text with containing the licence header that should be added to every
+        /// generated file.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        header_file: Option<PathBuf>,
+        #[command(subcommand)]
+        output: Output,
+    },
+    ImportHjson {
+        /// A path to a hjson file listing the instances of the OpenTitan IPs, with the name of
+        /// the reggen hjson of each IP and its base address.
+        #[arg(long, short, value_parser =  PathBuf::from_str)]
+        top: PathBuf,
+
+        /// A path to a text with containing the licence header that should be added to every
+        /// generated file.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        header_file: Option<PathBuf>,
+        #[command(subcommand)]
+        output: Output,
+    },
+    ImportIpxact {
+        /// A path to an IP-XACT component, imported as the only device of the platform at the
+        /// address 0.
+        #[arg(long, short, value_parser =  PathBuf::from_str, required_unless_present = "map")]
+        xml: Option<PathBuf>,
+
+        /// A path to a json, or hjson, file listing the instances of the IP-XACT components, with
+        /// the path to the component of each instance and its base address.
+        #[arg(long, short, value_parser =  PathBuf::from_str, conflicts_with = "xml")]
+        map: Option<PathBuf>,
+
+        /// A path to a text with containing the licence header that should be added to every
+        /// generated file.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        header_file: Option<PathBuf>,
+        #[command(subcommand)]
+        output: Output,
+    },
+    /// Import several inputs, of mixed formats, into a single platform. They are merged in the
+    /// order of the svd, rdl-json, rdl, hjson and ipxact inputs.
+    ImportMany {
+        /// The name of the platform, the one of the first input by default.
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Paths to svd files.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        svd: Vec<PathBuf>,
+
+        /// A toml file patching one of the svd files, i.e `cpu.svd=fixes.toml`. The patches of
+        /// a svd are applied in order.
+        #[arg(long, value_parser = parse_svd_patch)]
+        svd_patch: Vec<(PathBuf, PathBuf)>,
+
+        /// Paths to rdl.json files.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        rdl_json: Vec<PathBuf>,
+
+        /// Whether the rdl.json parser should not group the devices of the same type and split
+        /// the homogeneous interfaces of a device into devices.
+        #[arg(long, action)]
+        no_optimize: bool,
+
+        /// Paths to SystemRDL files, the last addrmap defined in each is the top.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        rdl: Vec<PathBuf>,
+
+        /// Paths to top-level hjson files listing OpenTitan IPs.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        hjson: Vec<PathBuf>,
+
+        /// Paths to base address maps of IP-XACT components, or to components for the `.xml`.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        ipxact: Vec<PathBuf>,
+
+        /// A path to a text with containing the licence header that should be added to every
+        /// generated file.
+        #[arg(long, value_parser =  PathBuf::from_str)]
+        header_file: Option<PathBuf>,
+        #[command(subcommand)]
+        output: Output,
+    },
 }
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::enum_variant_names)]
 enum Output {
     ExportCpp {
         /// A dir to output the peripheral implementation.
@@ -64,6 +163,13 @@ enum Output {
         #[arg(long, short, value_parser =  PathBuf::from_str)]
         dir: PathBuf,
     },
+    /// Write a `memory.x` linker script with the memories in a `MEMORY` block and the base address
+    /// of the devices as `PROVIDE` symbols.
+    ExportLinker {
+        /// A dir to output the `memory.x`.
+        #[arg(long, short, value_parser =  PathBuf::from_str)]
+        dir: PathBuf,
+    },
 }
 
 // Define a struct to represent command-line options
@@ -72,65 +178,226 @@ enum Output {
 struct Args {
     #[command(subcommand)]
     input: Input,
+
+    /// A path to a devicetree, `.dts` or `.dtb`, with the addresses and the interrupts of the
+    /// instances, which override the ones of the input.
+    #[arg(long, global = true, value_parser =  PathBuf::from_str)]
+    devicetree: Option<PathBuf>,
+
+    /// Map a `compatible` of the devicetree to a device type, i.e `vendor,serial=uart`, for the
+    /// nodes whose model is not the name of the type.
+    #[arg(long, global = true, value_parser = parse_compatible)]
+    compatible: Vec<(String, String)>,
+}
+
+fn parse_compatible(arg: &str) -> Result<(String, String), String> {
+    arg.split_once('=')
+        .map(|(compatible, type_name)| (compatible.to_string(), type_name.to_lowercase()))
+        .ok_or(format!("Expected `compatible=type`, found `{arg}`"))
+}
+
+fn parse_svd_patch(arg: &str) -> Result<(PathBuf, PathBuf), String> {
+    arg.split_once('=')
+        .filter(|(svd, patch)| !svd.is_empty() && !patch.is_empty())
+        .map(|(svd, patch)| (PathBuf::from(svd), PathBuf::from(patch)))
+        .ok_or(format!("Expected `svd=patch`, found `{arg}`"))
+}
+
+/// Group the `--svd-patch` files by svd, in the order of the `--svd` inputs.
+fn svd_patches(
+    svds: &[PathBuf],
+    patches: &[(PathBuf, PathBuf)],
+) -> Result<Vec<(PathBuf, Vec<PathBuf>)>, String> {
+    if let Some((svd, patch)) = patches.iter().find(|(svd, _)| !svds.contains(svd)) {
+        return Err(format!(
+            "The patch {} is for {}, which is not a --svd input",
+            patch.display(),
+            svd.display()
+        ));
+    }
+    Ok(svds
+        .iter()
+        .map(|svd| {
+            let own = patches.iter().filter(|(s, _)| s == svd);
+            (svd.clone(), own.map(|(_, patch)| patch.clone()).collect())
+        })
+        .collect())
+}
+
+/// Read the licence header, followed by the notice of the tool.
+fn load_header(header_file: Option<PathBuf>) -> Result<String, String> {
+    let Some(header_file) = header_file else {
+        return Ok(FILE_HEADER.into());
+    };
+    if !header_file.exists() {
+        return Err("File with header does not exist!".to_string());
+    }
+    Ok(std::fs::read_to_string(header_file).unwrap() + FILE_HEADER)
+}
+
+/// Load a svd with its patches applied, which are listed in the returned comment for the header.
+fn load_svd(svd: &Path, patches: &[PathBuf]) -> Result<(Platform, String), String> {
+    if !svd.exists() {
+        return Err("Svd does not exist!".to_string());
+    }
+
+    println!("Loading the {}...", svd.display());
+    let xml = std::fs::read_to_string(svd).map_err(|e| format!("{}: {e}", svd.display()))?;
+    let mut device = svd_parser::parse(&xml).map_err(|e| format!("{}: {e:#}", svd.display()))?;
+    let mut applied = String::new();
+    for path in patches {
+        println!("Patching with the {}...", path.display());
+        let patch = svdpatch::Patch::load(path)?;
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        applied += &svdpatch::header(&name, &patch.apply(&mut device)?);
+    }
+    Ok((device.try_into()?, applied))
+}
+
+fn load_rdljson(rdl: &Path, no_optimize: bool) -> Result<Platform, String> {
+    if !rdl.exists() {
+        return Err("Rdljson file does not exist!".to_string());
+    }
+
+    println!("Loading the {}...", rdl.display());
+    let json = std::fs::read_to_string(rdl).map_err(|e| format!("{e} in {}", rdl.display()))?;
+    let mut soc = rdljson::SoC::try_from(&json).map_err(|e| format!("{e} in {}", rdl.display()))?;
+    if !no_optimize {
+        soc.homogeneous_interfaces_to_periperals();
+    }
+    soc.try_into()
+}
+
+fn load_rdl(rdl: &Path) -> Result<Platform, String> {
+    if !rdl.exists() {
+        return Err("Rdl file does not exist!".to_string());
+    }
+
+    println!("Loading the {}...", rdl.display());
+    systemrdl::SoC::load(rdl)?.try_into()
+}
+
+fn load_hjson(top: &Path) -> Result<Platform, String> {
+    if !top.exists() {
+        return Err("Hjson file does not exist!".to_string());
+    }
+
+    println!("Loading the {}...", top.display());
+    hjson::SoC::load(top)?.try_into()
+}
+
+/// Load a base address map of IP-XACT components, or a single component when it is a `.xml`.
+fn load_ipxact(path: &Path) -> Result<Platform, String> {
+    if !path.exists() {
+        return Err("IP-XACT file does not exist!".to_string());
+    }
+
+    println!("Loading the {}...", path.display());
+    let soc = match path.extension().and_then(|e| e.to_str()) {
+        Some("xml") => ipxact::SoC::from_component(path)?,
+        _ => ipxact::SoC::load(path)?,
+    };
+    soc.try_into()
 }
 
 fn main() -> anyhow::Result<(), String> {
     let args: Args = Args::parse();
 
-    let (device, output, header) = match args.input {
+    let (mut device, output, header) = match args.input {
         Input::ImportSvd {
             svd,
+            patch,
             output,
             header_file,
         } => {
-            if !svd.exists() {
-                return Err("Svd does not exist!".to_string());
-            }
-
-            println!("Loading the {}...", svd.display());
-            let xml = std::fs::read_to_string(&svd).unwrap();
-            let svd_rs = svd_parser::parse(&xml).unwrap().try_into()?;
-            let header = if let Some(header_file) = header_file {
-                if !header_file.exists() {
-                    return Err("File with header does not exist!".to_string());
-                }
-                std::fs::read_to_string(header_file).unwrap() + FILE_HEADER
-            } else {
-                FILE_HEADER.into()
-            };
-            (svd_rs, output, header)
+            let (platform, applied) = load_svd(&svd, &patch)?;
+            (platform, output, load_header(header_file)? + &applied)
         }
         Input::ImportRdlJson {
             rdl,
             output,
             no_optimize,
             header_file,
+        } => (
+            load_rdljson(&rdl, no_optimize)?,
+            output,
+            load_header(header_file)?,
+        ),
+        Input::ImportRdl {
+            rdl,
+            output,
+            header_file,
+        } => (load_rdl(&rdl)?, output, load_header(header_file)?),
+        Input::ImportHjson {
+            top,
+            output,
+            header_file,
+        } => (load_hjson(&top)?, output, load_header(header_file)?),
+        Input::ImportIpxact {
+            xml,
+            map,
+            output,
+            header_file,
         } => {
-            if !rdl.exists() {
-                return Err("Rdljson file does not exist!".to_string());
-            }
-
-            println!("Loading the {}...", rdl.display());
-            let json = std::fs::read_to_string(&rdl).unwrap();
-            let mut soc = rdljson::SoC::try_from(&json).unwrap();
-            if !no_optimize {
-                soc.homogeneous_interfaces_to_periperals();
-            }
-            let soc = soc.into();
-
-            let header = if let Some(header_file) = header_file {
-                if !header_file.exists() {
-                    return Err("File with header does not exist!".to_string());
+            // Clap requires one of them, the components are told apart by their extension.
+            let path = map.or(xml).unwrap();
+            (load_ipxact(&path)?, output, load_header(header_file)?)
+        }
+        Input::ImportMany {
+            name,
+            svd,
+            svd_patch,
+            rdl_json,
+            no_optimize,
+            rdl,
+            hjson,
+            ipxact,
+            output,
+            header_file,
+        } => {
+            let mut applied = String::new();
+            let platforms = svd_patches(&svd, &svd_patch)?
+                .into_iter()
+                .map(|(path, patches)| {
+                    let (platform, patches) = load_svd(&path, &patches)?;
+                    applied += &patches;
+                    Ok(platform)
+                })
+                .chain(rdl_json.iter().map(|path| load_rdljson(path, no_optimize)))
+                .chain(rdl.iter().map(|path| load_rdl(path)))
+                .chain(hjson.iter().map(|path| load_hjson(path)))
+                .chain(ipxact.iter().map(|path| load_ipxact(path)));
+            let mut merged: Option<Platform> = None;
+            for platform in platforms {
+                let platform = platform?;
+                match merged {
+                    Some(ref mut merged) => merged.merge(platform)?,
+                    None => merged = Some(platform),
                 }
-                std::fs::read_to_string(header_file).unwrap() + FILE_HEADER
-            } else {
-                FILE_HEADER.into()
+            }
+            let Some(mut merged) = merged else {
+                return Err("No input to import!".to_string());
             };
-            (soc, output, header)
+            if let Some(name) = name {
+                merged.name = name;
+            }
+            (merged, output, load_header(header_file)? + &applied)
         }
     };
     // dbg!(&device);
 
+    if let Some(devicetree) = args.devicetree {
+        if !devicetree.exists() {
+            return Err("Devicetree does not exist!".to_string());
+        }
+
+        println!("Loading the {}...", devicetree.display());
+        let tree = devicetree::Tree::load(&devicetree)?;
+        for node in device.apply_devicetree(&tree, &args.compatible)? {
+            println!("Warning: the devicetree node {node} matches no device type");
+        }
+    }
+
     match output {
         Output::ExportCpp { dir, periph_dir } => {
             if !dir.is_dir() {
@@ -150,7 +417,93 @@ fn main() -> anyhow::Result<(), String> {
 
             generator::rust::generate(&device, dir, &header).unwrap();
         }
+        Output::ExportLinker { dir } => {
+            if !dir.is_dir() {
+                return Err("Output path is not a dir!".to_string());
+            }
+
+            generator::linker::generate(&device, dir, &header).unwrap();
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write_svd(path: &Path, peripheral: &str, address: u32) {
+        let svd = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<device schemaVersion="1.1">
+  <name>{peripheral}_soc</name>
+  <addressUnitBits>8</addressUnitBits>
+  <width>32</width>
+  <size>32</size>
+  <peripherals>
+    <peripheral>
+      <name>{peripheral}</name>
+      <baseAddress>{address:#x}</baseAddress>
+      <registers>
+        <register>
+          <name>CTRL</name>
+          <addressOffset>0x0</addressOffset>
+          <fields>
+            <field><name>EN</name><bitOffset>0</bitOffset><bitWidth>1</bitWidth></field>
+          </fields>
+        </register>
+      </registers>
+    </peripheral>
+  </peripherals>
+</device>
+"#
+        );
+        std::fs::write(path, svd).unwrap();
+    }
+
+    #[test]
+    fn import_many_svd_patches() {
+        let dir = PathBuf::from("target/test_import_many_svd_patches");
+        let _ = std::fs::create_dir(&dir);
+        write_svd(&dir.join("cpu.svd"), "TIMER", 0x1000);
+        write_svd(&dir.join("ips.svd"), "UART", 0x2000);
+        let patch = "[[register]]\nperipheral = \"*\"\nname = \"CTRL\"\nrename = \"CONTROL\"";
+        std::fs::write(dir.join("fixes.toml"), patch).unwrap();
+
+        let dir = dir.to_str().unwrap();
+        let args = Args::try_parse_from([
+            "zermio",
+            "import-many",
+            "--svd",
+            &format!("{dir}/cpu.svd"),
+            "--svd",
+            &format!("{dir}/ips.svd"),
+            "--svd-patch",
+            &format!("{dir}/cpu.svd={dir}/fixes.toml"),
+            "export-rust",
+            "--dir",
+            dir,
+        ])
+        .unwrap();
+        let Input::ImportMany { svd, svd_patch, .. } = args.input else {
+            panic!("Expected import-many");
+        };
+
+        let registers: Vec<String> = svd_patches(&svd, &svd_patch)
+            .unwrap()
+            .iter()
+            .map(|(path, patches)| {
+                let (platform, _) = load_svd(path, patches).unwrap();
+                platform.devices[0].registers[0].info[0].name.clone()
+            })
+            .collect();
+        assert_eq!(registers, ["CONTROL", "CTRL"]);
+
+        assert!(parse_svd_patch("cpu.svd=").is_err());
+        assert!(svd_patches(&svd[1..], &svd_patch).is_err());
+        let broken = format!("{dir}/broken.svd");
+        std::fs::write(&broken, "<device>").unwrap();
+        assert!(load_svd(Path::new(&broken), &[]).is_err());
+    }
+}